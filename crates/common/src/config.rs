@@ -5,12 +5,21 @@ use smol_str::SmolStr;
 use toml::Value;
 use url::Url;
 
-use crate::{ingot::Version, urlext::UrlExt};
+use crate::{
+    diagnostics::LintLevel,
+    ingot::{Version, VersionReq},
+    urlext::UrlExt,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Config {
     pub metadata: IngotMetadata,
     pub dependencies: Vec<Dependency>,
+    /// Lint level overrides from the `[lints]` table, e.g.,
+    /// `unused_variables = "allow"`.
+    pub lints: Vec<(SmolStr, LintLevel)>,
+    /// The `[workspace]` table, if the config is a workspace manifest.
+    pub workspace: Option<WorkspaceConfig>,
     pub diagnostics: Vec<ConfigDiagnostic>,
 }
 
@@ -19,6 +28,8 @@ impl Config {
         let mut diagnostics = Vec::new();
         let mut metadata = IngotMetadata::default();
         let mut dependencies = Vec::new();
+        let mut lints = Vec::new();
+        let mut workspace = None;
 
         let parsed: Value = content.parse()?;
 
@@ -47,43 +58,82 @@ impl Config {
             } else {
                 diagnostics.push(ConfigDiagnostic::MissingVersion);
             }
-        } else {
+        } else if parsed.get("workspace").is_none() {
+            // A workspace manifest doesn't need to describe an ingot itself.
             diagnostics.push(ConfigDiagnostic::MissingIngotMetadata);
         }
 
+        if let Some(workspace_value) = parsed.get("workspace") {
+            match workspace_value.get("members") {
+                Some(Value::Array(members)) => {
+                    let mut paths = Vec::new();
+                    for member in members {
+                        match member.as_str() {
+                            Some(path) => paths.push(Utf8PathBuf::from(path)),
+                            None => diagnostics.push(ConfigDiagnostic::UnexpectedTomlData {
+                                field: "workspace.members".into(),
+                                found: member.type_str().to_lowercase().into(),
+                                expected: Some("string".into()),
+                            }),
+                        }
+                    }
+                    workspace = Some(WorkspaceConfig { members: paths });
+                }
+                Some(members) => diagnostics.push(ConfigDiagnostic::UnexpectedTomlData {
+                    field: "workspace.members".into(),
+                    found: members.type_str().to_lowercase().into(),
+                    expected: Some("array".into()),
+                }),
+                None => diagnostics.push(ConfigDiagnostic::MissingWorkspaceMembers),
+            }
+        }
+
         if let Some(table) = parsed
             .get("dependencies")
             .and_then(|value| value.as_table())
         {
-            diagnostics.push(ConfigDiagnostic::DependenciesNotYetSupported);
             for (alias, value) in table {
+                if !is_valid_alias(alias) {
+                    diagnostics.push(ConfigDiagnostic::InvalidDependencyAlias(alias.into()));
+                }
                 match value {
                     Value::String(path) => {
                         dependencies.push(Dependency::path(alias.into(), Utf8PathBuf::from(path)));
                     }
                     Value::Table(table) => {
-                        let path = table.get("path").and_then(|value| value.as_str());
-                        if let Some(path) = path {
-                            let mut parameters = DependencyParameters::default();
-                            if let Some(name) = table.get("name").and_then(|value| value.as_str()) {
+                        let mut parameters = DependencyParameters::default();
+                        if let Some(name) =
+                            dependency_string(table, alias, "name", &mut diagnostics)
+                        {
+                            if is_valid_name(name) {
                                 parameters.name = Some(SmolStr::new(name));
+                            } else {
+                                diagnostics
+                                    .push(ConfigDiagnostic::InvalidDependencyName(name.into()));
                             }
-                            if let Some(version) =
-                                table.get("version").and_then(|value| value.as_str())
-                            {
-                                if let Ok(version) = version.parse() {
-                                    parameters.version = Some(version);
-                                } else {
-                                    diagnostics
-                                        .push(ConfigDiagnostic::InvalidVersion(version.into()));
-                                }
+                        }
+                        if let Some(version) =
+                            dependency_string(table, alias, "version", &mut diagnostics)
+                        {
+                            if let Ok(version) = version.parse() {
+                                parameters.version = Some(version);
+                            } else {
+                                diagnostics.push(ConfigDiagnostic::InvalidDependencyVersion(
+                                    version.into(),
+                                ));
                             }
+                        }
+
+                        let path = dependency_string(table, alias, "path", &mut diagnostics);
+                        if let Some(path) = path {
                             dependencies.push(Dependency::path_with_arguments(
                                 alias.into(),
                                 Utf8PathBuf::from(path),
                                 parameters,
                             ));
-                        } else {
+                        } else if parameters.version.is_some() {
+                            dependencies.push(Dependency::registry(alias.into(), parameters));
+                        } else if !table.contains_key("version") && !table.contains_key("path") {
                             diagnostics.push(ConfigDiagnostic::MissingDependencyPath {
                                 alias: alias.into(),
                                 description: value.to_string(),
@@ -99,13 +149,43 @@ impl Config {
             }
         }
 
+        if let Some(lint_table) = parsed.get("lints") {
+            match lint_table.as_table() {
+                Some(table) => {
+                    for (lint, level) in table {
+                        match level.as_str().and_then(|level| level.parse().ok()) {
+                            Some(level) => lints.push((SmolStr::new(lint), level)),
+                            None => diagnostics.push(ConfigDiagnostic::InvalidLintLevel {
+                                lint: lint.into(),
+                                level: level.to_string().into(),
+                            }),
+                        }
+                    }
+                }
+                None => diagnostics.push(ConfigDiagnostic::UnexpectedTomlData {
+                    field: "lints".into(),
+                    found: lint_table.type_str().to_lowercase().into(),
+                    expected: Some("table".into()),
+                }),
+            }
+        }
+
         Ok(Self {
             metadata,
             dependencies,
+            lints,
+            workspace,
             diagnostics,
         })
     }
 
+    /// Returns the level the `[lints]` table sets for `lint`, if any.
+    pub fn lint_level(&self, lint: &str) -> Option<LintLevel> {
+        self.lints
+            .iter()
+            .find_map(|(name, level)| (name == lint).then_some(*level))
+    }
+
     pub fn based_dependencies(&self, base_url: &Url) -> Vec<BasedDependency> {
         self.dependencies
             .iter()
@@ -128,12 +208,39 @@ impl Config {
     }
 }
 
+/// Returns the string value of `key` in the table of the dependency `alias`,
+/// reporting values that aren't strings.
+fn dependency_string<'a>(
+    table: &'a toml::Table,
+    alias: &str,
+    key: &str,
+    diagnostics: &mut Vec<ConfigDiagnostic>,
+) -> Option<&'a str> {
+    match table.get(key)? {
+        Value::String(value) => Some(value),
+        value => {
+            diagnostics.push(ConfigDiagnostic::UnexpectedTomlData {
+                field: format!("dependencies.{alias}.{key}").into(),
+                found: value.type_str().to_lowercase().into(),
+                expected: Some("string".into()),
+            });
+            None
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct IngotMetadata {
     pub name: Option<SmolStr>,
     pub version: Option<Version>,
 }
 
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct WorkspaceConfig {
+    /// The paths of the member ingots, relative to the workspace root.
+    pub members: Vec<Utf8PathBuf>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum DependencyDescription {
     Path(Utf8PathBuf),
@@ -141,6 +248,9 @@ pub enum DependencyDescription {
         path: Utf8PathBuf,
         parameters: DependencyParameters,
     },
+    /// A dependency that is looked up by name and version requirement in the
+    /// vendored sources or the registry.
+    Registry(DependencyParameters),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -168,20 +278,29 @@ impl Dependency {
         }
     }
 
+    pub fn registry(alias: SmolStr, parameters: DependencyParameters) -> Self {
+        Self {
+            alias,
+            description: DependencyDescription::Registry(parameters),
+        }
+    }
+
     pub fn based(&self, base_url: &Url) -> BasedDependency {
         match &self.description {
             DependencyDescription::Path(path) => BasedDependency {
                 alias: self.alias.clone(),
                 parameters: DependencyParameters::default(),
-                url: base_url.join_directory(path).unwrap(),
+                location: DependencyLocation::Path(base_url.join_directory(path).unwrap()),
             },
-            DependencyDescription::PathWithParameters {
-                path,
-                parameters: _,
-            } => BasedDependency {
+            DependencyDescription::PathWithParameters { path, parameters } => BasedDependency {
                 alias: self.alias.clone(),
-                parameters: DependencyParameters::default(),
-                url: base_url.join_directory(path).unwrap(),
+                parameters: parameters.clone(),
+                location: DependencyLocation::Path(base_url.join_directory(path).unwrap()),
+            },
+            DependencyDescription::Registry(parameters) => BasedDependency {
+                alias: self.alias.clone(),
+                parameters: parameters.clone(),
+                location: DependencyLocation::Registry,
             },
         }
     }
@@ -191,13 +310,27 @@ impl Dependency {
 pub struct BasedDependency {
     pub alias: SmolStr,
     pub parameters: DependencyParameters,
-    pub url: Url,
+    pub location: DependencyLocation,
+}
+
+impl BasedDependency {
+    /// The name of the depended on ingot, which defaults to the alias.
+    pub fn name(&self) -> &SmolStr {
+        self.parameters.name.as_ref().unwrap_or(&self.alias)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DependencyLocation {
+    Path(Url),
+    /// The location is chosen when the dependency is resolved.
+    Registry,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct DependencyParameters {
     pub name: Option<SmolStr>,
-    pub version: Option<Version>,
+    pub version: Option<VersionReq>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -210,18 +343,22 @@ pub enum ConfigDiagnostic {
     InvalidDependencyAlias(SmolStr),
     InvalidDependencyName(SmolStr),
     InvalidDependencyVersion(SmolStr),
+    InvalidLintLevel {
+        lint: SmolStr,
+        level: SmolStr,
+    },
     MissingDependencyPath {
         alias: SmolStr,
         description: String,
     },
+    MissingWorkspaceMembers,
     UnexpectedTomlData {
         field: SmolStr,
         found: SmolStr,
         expected: Option<SmolStr>,
     },
-    /// TODO: push diagnostics for fields that should not exist
+    // TODO: push diagnostics for fields that should not exist
     // UnrecognizedField(SmolStr),
-    DependenciesNotYetSupported,
 }
 
 impl Display for ConfigDiagnostic {
@@ -241,10 +378,17 @@ impl Display for ConfigDiagnostic {
             Self::InvalidDependencyVersion(version) => {
                 write!(f, "invalid dependency version \"{version}\"")
             }
+            Self::InvalidLintLevel { lint, level } => write!(
+                f,
+                "invalid level {level} for lint \"{lint}\", expected \"allow\", \"warn\" or \"deny\""
+            ),
             Self::MissingDependencyPath { alias, description } => write!(
                 f,
-                "the dependency \"{alias}\" is missing a path argument \"{description}\""
+                "the dependency \"{alias}\" is missing a path or version argument \"{description}\""
             ),
+            Self::MissingWorkspaceMembers => {
+                write!(f, "the workspace is missing a \"members\" list")
+            }
             Self::UnexpectedTomlData {
                 field,
                 found,
@@ -259,7 +403,6 @@ impl Display for ConfigDiagnostic {
                     write!(f, "unexpected field {field}")
                 }
             }
-            Self::DependenciesNotYetSupported => write!(f, "dependencies are not yet supported"),
         }
     }
 }
@@ -268,6 +411,159 @@ fn is_valid_name_char(c: char) -> bool {
     c.is_alphanumeric() || c == '-'
 }
 
-fn is_valid_name(s: &str) -> bool {
-    s.chars().all(is_valid_name_char)
+/// Returns `true` if `s` can be used as an ingot name.
+pub fn is_valid_name(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(is_valid_name_char)
+}
+
+/// Returns `true` if `s` can be used as an identifier in `use` paths.
+fn is_valid_alias(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+        && chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_lints() {
+        let config = Config::parse(
+            r#"
+[ingot]
+name = "foo"
+version = "0.1.0"
+
+[lints]
+unused_variables = "allow"
+unused_imports = "deny"
+dead_code = "loud"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.lint_level("unused_variables"),
+            Some(LintLevel::Allow)
+        );
+        assert_eq!(config.lint_level("unused_imports"), Some(LintLevel::Deny));
+        assert_eq!(config.lint_level("dead_code"), None);
+        assert_eq!(
+            config.diagnostics,
+            vec![ConfigDiagnostic::InvalidLintLevel {
+                lint: "dead_code".into(),
+                level: "\"loud\"".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn parse_dependencies() {
+        let config = Config::parse(
+            r#"
+[ingot]
+name = "foo"
+version = "0.1.0"
+
+[dependencies]
+bar = "../bar"
+baz = { path = "../baz", name = "baz-lib", version = "1.2.0" }
+qux = { version = ">=0.3, <0.5" }
+"bad-alias" = "../bad"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.diagnostics,
+            vec![ConfigDiagnostic::InvalidDependencyAlias("bad-alias".into())]
+        );
+
+        let base_url = Url::parse("file:///ingots/foo/").unwrap();
+        let dependencies = config.based_dependencies(&base_url);
+        let baz = dependencies
+            .iter()
+            .find(|dependency| dependency.alias == "baz")
+            .unwrap();
+        assert_eq!(
+            baz.location,
+            DependencyLocation::Path(Url::parse("file:///ingots/baz/").unwrap())
+        );
+        assert_eq!(baz.parameters.name.as_deref(), Some("baz-lib"));
+        assert_eq!(baz.parameters.version, Some("^1.2.0".parse().unwrap()));
+
+        let qux = dependencies
+            .iter()
+            .find(|dependency| dependency.alias == "qux")
+            .unwrap();
+        assert_eq!(qux.location, DependencyLocation::Registry);
+        assert_eq!(qux.name(), "qux");
+        let requirement = qux.parameters.version.as_ref().unwrap();
+        assert!(requirement.matches(&"0.4.2".parse().unwrap()));
+        assert!(!requirement.matches(&"0.5.0".parse().unwrap()));
+    }
+
+    #[test]
+    fn parse_invalid_dependency_fields() {
+        let config = Config::parse(
+            r#"
+[ingot]
+name = "foo"
+version = "0.1.0"
+
+[dependencies]
+bar = { version = 1 }
+baz = { path = "../baz", name = 2 }
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.diagnostics,
+            vec![
+                ConfigDiagnostic::UnexpectedTomlData {
+                    field: "dependencies.bar.version".into(),
+                    found: "integer".into(),
+                    expected: Some("string".into()),
+                },
+                ConfigDiagnostic::UnexpectedTomlData {
+                    field: "dependencies.baz.name".into(),
+                    found: "integer".into(),
+                    expected: Some("string".into()),
+                },
+            ]
+        );
+        assert_eq!(config.dependencies.len(), 1);
+    }
+
+    #[test]
+    fn parse_workspace() {
+        let config = Config::parse(
+            r#"
+[workspace]
+members = ["ingots/foo", "ingots/bar"]
+"#,
+        )
+        .unwrap();
+
+        assert!(config.diagnostics.is_empty());
+        assert_eq!(
+            config.workspace.unwrap().members,
+            vec![
+                Utf8PathBuf::from("ingots/foo"),
+                Utf8PathBuf::from("ingots/bar")
+            ]
+        );
+
+        let config = Config::parse("[workspace]\nmembers = \"foo\"\n").unwrap();
+        assert_eq!(
+            config.diagnostics,
+            vec![ConfigDiagnostic::UnexpectedTomlData {
+                field: "workspace.members".into(),
+                found: "string".into(),
+                expected: Some("array".into()),
+            }]
+        );
+    }
 }
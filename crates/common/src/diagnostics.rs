@@ -1,4 +1,4 @@
-use std::fmt;
+use std::{fmt, str::FromStr};
 
 use parser::TextRange;
 
@@ -111,7 +111,9 @@ pub enum SpanKind {
     Original,
 
     /// A node corresponding to the span is generated by macro expansion.
-    Expanded,
+    /// The span points to the invocation of the macro, and `def` points to
+    /// the part of the macro definition the node is expanded from, if any.
+    Expanded { def: Option<TextRange> },
 
     /// No span information was found.
     /// This happens if analysis code tries to get a span for a node that is
@@ -139,6 +141,56 @@ pub enum Severity {
     Note,
 }
 
+/// The level at which a lint is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LintLevel {
+    /// The lint is not reported.
+    Allow,
+    /// The lint is reported as a warning.
+    Warn,
+    /// The lint is reported as an error.
+    Deny,
+}
+
+impl LintLevel {
+    /// Returns the severity of the diagnostics reported at this level, or
+    /// `None` if the lint is allowed.
+    pub fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Allow => None,
+            Self::Warn => Some(Severity::Warning),
+            Self::Deny => Some(Severity::Error),
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+impl FromStr for LintLevel {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(Self::Allow),
+            "warn" => Ok(Self::Warn),
+            "deny" => Ok(Self::Deny),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LintLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum DiagnosticPass {
     Parse,
@@ -152,6 +204,8 @@ pub enum DiagnosticPass {
     MethodDefinition,
     TyCheck,
 
+    Lint,
+
     ExternalAnalysis(ExternalAnalysisKey),
 }
 
@@ -166,6 +220,7 @@ impl DiagnosticPass {
             Self::TraitSatisfaction => 6,
             Self::MethodDefinition => 7,
             Self::TyCheck => 8,
+            Self::Lint => 9,
 
             Self::ExternalAnalysis(_) => u16::MAX,
         }
@@ -1,6 +1,7 @@
 use camino::Utf8PathBuf;
 use radix_immutable::{StringPrefixView, StringTrie, Trie};
 use salsa::Setter;
+use smol_str::SmolStr;
 use url::Url;
 
 use crate::InputDb;
@@ -16,12 +17,14 @@ pub enum InputIndexError {
 pub struct Workspace {
     files: StringTrie<Url, File>,
     paths: IndexMap<File, Url>,
+    /// The base URL of the selected version of each registry ingot, by name.
+    registry_ingots: IndexMap<SmolStr, Url>,
 }
 
 #[salsa::tracked]
 impl Workspace {
     pub fn default(db: &dyn InputDb) -> Self {
-        Workspace::new(db, Trie::new(), IndexMap::new())
+        Workspace::new(db, Trie::new(), IndexMap::new(), IndexMap::new())
     }
     pub(crate) fn set(
         &self,
@@ -101,6 +104,22 @@ impl Workspace {
     pub fn all_files(&self, db: &dyn InputDb) -> StringTrie<Url, File> {
         self.files(db)
     }
+
+    /// Records the ingot at `base_url` as the version of the registry ingot
+    /// `name` that the dependency resolver selected.
+    pub fn register_ingot(&self, db: &mut dyn InputDb, name: SmolStr, base_url: Url) {
+        let mut registry_ingots = self.registry_ingots(db);
+        if registry_ingots.get(&name) != Some(&base_url) {
+            registry_ingots.insert(name, base_url);
+            self.set_registry_ingots(db).to(registry_ingots);
+        }
+    }
+
+    /// Returns the base URL of the selected version of the registry ingot
+    /// `name`.
+    pub fn registry_ingot(&self, db: &dyn InputDb, name: &str) -> Option<Url> {
+        self.registry_ingots(db).get(name).cloned()
+    }
 }
 
 #[cfg(test)]
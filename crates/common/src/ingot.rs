@@ -5,7 +5,7 @@ use radix_immutable::StringPrefixView;
 use smol_str::SmolStr;
 use url::Url;
 
-use crate::config::Config;
+use crate::config::{Config, DependencyLocation};
 use crate::core::BUILTIN_CORE_BASE_URL;
 use crate::file::{File, Workspace};
 use crate::urlext::UrlExt;
@@ -123,7 +123,17 @@ impl<'db> Ingot<'db> {
             Some(config) => config
                 .based_dependencies(&base_url)
                 .into_iter()
-                .map(|based_dependency| (based_dependency.alias, based_dependency.url))
+                .filter_map(|based_dependency| {
+                    let url = match &based_dependency.location {
+                        DependencyLocation::Path(url) => url.clone(),
+                        // Registry dependencies are registered with the version
+                        // that the dependency resolver selected.
+                        DependencyLocation::Registry => {
+                            db.workspace().registry_ingot(db, based_dependency.name())?
+                        }
+                    };
+                    Some((based_dependency.alias, url))
+                })
                 .collect(),
             None => vec![],
         };
@@ -139,6 +149,7 @@ impl<'db> Ingot<'db> {
 }
 
 pub type Version = serde_semver::semver::Version;
+pub type VersionReq = serde_semver::semver::VersionReq;
 
 #[salsa::tracked]
 impl Workspace {
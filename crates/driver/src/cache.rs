@@ -0,0 +1,507 @@
+//! The on-disk analysis cache of `fe check`.
+//!
+//! The diagnostics of each module of an analyzed ingot are stored together
+//! with a fingerprint of everything they depend on: the compiler version and
+//! binary, and the contents of the files of the ingot and of the ingots it
+//! depends on, directly or indirectly. A later run whose fingerprint matches
+//! reuses the diagnostics instead of analyzing the ingot again, so changing
+//! one ingot of a workspace doesn't invalidate the ingots that don't depend on
+//! it.
+//!
+//! Only diagnostics are persisted, not parse trees or lowered HIR. Salsa can't
+//! be seeded with memoized query results, so persisted trees couldn't be
+//! reused by the analysis of a changed ingot, and an unchanged ingot isn't
+//! parsed at all since a cache hit skips both parsing and analysis.
+
+use std::{cell::RefCell, collections::BTreeSet, sync::OnceLock};
+
+use camino::Utf8PathBuf;
+use common::{
+    diagnostics::{
+        CompleteDiagnostic, DiagnosticPass, GlobalErrorCode, LabelStyle, Severity, Span, SpanKind,
+        SubDiagnostic,
+    },
+    file::File,
+    ingot::IngotBaseUrl,
+    InputDb,
+};
+use parser::{TextRange, TextSize};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use url::Url;
+
+use crate::{db::DiagnosticsCollection, DriverDataBase};
+
+/// The cache directory, relative to the checked ingot or workspace.
+pub const CACHE_DIR: &str = ".fe/cache";
+
+/// Bumped whenever the format of the cache entries changes.
+const FORMAT_VERSION: u32 = 2;
+
+/// A hash of the contents of an ingot and of its dependencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fingerprint(String);
+
+/// Returns the fingerprint of the URLs and contents of the files of the ingot
+/// at `ingot_url` and of the ingots it depends on, directly or indirectly.
+pub fn fingerprint(db: &DriverDataBase, ingot_url: &Url) -> Fingerprint {
+    // The ingots are hashed in the order of their URLs, so that the
+    // fingerprint doesn't depend on the order of the dependencies.
+    let mut ingot_urls = BTreeSet::new();
+    let mut pending = vec![ingot_url.clone()];
+    while let Some(url) = pending.pop() {
+        if let Some(ingot) = url.ingot(db) {
+            pending.extend(
+                ingot
+                    .dependencies(db)
+                    .into_iter()
+                    .map(|(_, url)| url)
+                    .filter(|url| !ingot_urls.contains(url)),
+            );
+        }
+        ingot_urls.insert(url);
+    }
+
+    let mut hasher = Sha256::new();
+    for ingot_url in &ingot_urls {
+        let Some(ingot) = ingot_url.ingot(db) else {
+            continue;
+        };
+        let mut files: Vec<_> = ingot.files(db).iter().collect();
+        files.sort_by(|(lhs, _), (rhs, _)| lhs.as_str().cmp(rhs.as_str()));
+        for (url, file) in files {
+            let text = file.text(db);
+            hasher.update(url.as_str().len().to_le_bytes());
+            hasher.update(url.as_str());
+            hasher.update(text.len().to_le_bytes());
+            hasher.update(text);
+        }
+    }
+    Fingerprint(format!("{:x}", hasher.finalize()))
+}
+
+pub struct AnalysisCache {
+    dir: Utf8PathBuf,
+    /// The ingots whose diagnostics were loaded since the last
+    /// [`Self::take_hits`].
+    hits: RefCell<Vec<Url>>,
+}
+
+impl AnalysisCache {
+    pub fn new(dir: impl Into<Utf8PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            hits: RefCell::default(),
+        }
+    }
+
+    /// Returns the URLs of the ingots whose diagnostics were loaded from the
+    /// cache since the last call.
+    pub fn take_hits(&self) -> Vec<Url> {
+        self.hits.take()
+    }
+
+    /// Returns the diagnostics of each module of the ingot at `url`, if they
+    /// were stored for the same `inputs` by the same version of the compiler.
+    pub fn load(
+        &self,
+        db: &DriverDataBase,
+        url: &Url,
+        inputs: &Fingerprint,
+    ) -> Option<Vec<(File, DiagnosticsCollection)>> {
+        let content = std::fs::read_to_string(self.entry_path(url)).ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+        if entry.url != url.as_str() || Some(entry.key) != key(inputs) {
+            return None;
+        }
+        let modules = entry
+            .modules
+            .into_iter()
+            .map(|module| module.into_module(db))
+            .collect::<Option<_>>()?;
+        self.hits.borrow_mut().push(url.clone());
+        Some(modules)
+    }
+
+    /// Stores the diagnostics of each module of the ingot at `url`, replacing
+    /// any earlier entry of the ingot.
+    ///
+    /// The cache is only an optimization, so failures to write it are ignored.
+    pub fn store(
+        &self,
+        db: &DriverDataBase,
+        url: &Url,
+        inputs: &Fingerprint,
+        modules: &[(File, DiagnosticsCollection)],
+    ) {
+        let Some(key) = key(inputs) else {
+            return;
+        };
+        let Some(modules) = modules
+            .iter()
+            .map(|(file, diags)| CachedModule::from_module(db, *file, diags))
+            .collect::<Option<_>>()
+        else {
+            return;
+        };
+        let entry = CacheEntry {
+            url: url.to_string(),
+            key,
+            modules,
+        };
+        let content = serde_json::to_string(&entry).expect("cache entry should be serializable");
+
+        // Write to a temporary file first, so that an interrupted run doesn't
+        // leave a truncated entry behind.
+        let path = self.entry_path(url);
+        let tmp_path = path.with_extension("tmp");
+        let _ = std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(&tmp_path, content))
+            .and_then(|_| std::fs::rename(&tmp_path, &path));
+    }
+
+    /// Each ingot has a single entry holding all its modules, so that the cache
+    /// doesn't grow with every change.
+    fn entry_path(&self, url: &Url) -> Utf8PathBuf {
+        let name = format!("{:x}", Sha256::digest(url.as_str()));
+        self.dir.join(name).with_extension("json")
+    }
+}
+
+/// Returns the key that the diagnostics of a run with `inputs` are stored with,
+/// or `None` if the compiler binary can't be read, in which case nothing is
+/// cached.
+fn key(inputs: &Fingerprint) -> Option<String> {
+    Some(format!(
+        "{FORMAT_VERSION}-{}-{}-{}",
+        env!("CARGO_PKG_VERSION"),
+        compiler_hash()?,
+        inputs.0
+    ))
+}
+
+/// Returns a hash of the running compiler binary, so that entries aren't
+/// reused by a compiler that was rebuilt without a version change.
+fn compiler_hash() -> Option<&'static str> {
+    static HASH: OnceLock<Option<String>> = OnceLock::new();
+    HASH.get_or_init(|| {
+        let mut binary = std::fs::File::open(std::env::current_exe().ok()?).ok()?;
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut binary, &mut hasher).ok()?;
+        Some(format!("{:x}", hasher.finalize()))
+    })
+    .as_deref()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    key: String,
+    modules: Vec<CachedModule>,
+}
+
+/// The diagnostics reported for a module, whose file is referred to by its
+/// URL.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedModule {
+    url: String,
+    diagnostics: Vec<CachedDiagnostic>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedDiagnostic {
+    severity: String,
+    message: String,
+    sub_diagnostics: Vec<CachedSubDiagnostic>,
+    notes: Vec<String>,
+    pass: u16,
+    local_code: u16,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedSubDiagnostic {
+    primary: bool,
+    message: String,
+    span: Option<CachedSpan>,
+}
+
+/// A span whose file is referred to by its URL, since the IDs of the `File`
+/// inputs differ between runs.
+#[derive(Debug, Serialize, Deserialize)]
+struct CachedSpan {
+    url: String,
+    range: (u32, u32),
+    kind: CachedSpanKind,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum CachedSpanKind {
+    Original,
+    Expanded { def: Option<(u32, u32)> },
+    NotFound,
+}
+
+impl CachedModule {
+    /// Returns `None` if any of the diagnostics can't be cached.
+    fn from_module(db: &DriverDataBase, file: File, diags: &DiagnosticsCollection) -> Option<Self> {
+        Some(Self {
+            url: file.url(db)?.to_string(),
+            diagnostics: diags
+                .finalize()
+                .iter()
+                .map(|diag| CachedDiagnostic::from_complete(db, diag))
+                .collect::<Option<_>>()?,
+        })
+    }
+
+    /// Returns `None` if the module or any of the diagnostics is invalid or
+    /// refers to a file that isn't in the workspace.
+    fn into_module(self, db: &DriverDataBase) -> Option<(File, DiagnosticsCollection)> {
+        let file = db.workspace().get(db, &Url::parse(&self.url).ok()?)?;
+        let diags = self
+            .diagnostics
+            .into_iter()
+            .map(|diag| diag.into_complete(db))
+            .collect::<Option<_>>()?;
+        Some((file, DiagnosticsCollection::sorted(diags)))
+    }
+}
+
+impl CachedDiagnostic {
+    /// Returns `None` if the diagnostic can't be cached, e.g. because it
+    /// comes from an external analysis.
+    fn from_complete(db: &DriverDataBase, diag: &CompleteDiagnostic) -> Option<Self> {
+        if matches!(diag.error_code.pass, DiagnosticPass::ExternalAnalysis(_)) {
+            return None;
+        }
+        let sub_diagnostics = diag
+            .sub_diagnostics
+            .iter()
+            .map(|sub| {
+                let span = match &sub.span {
+                    Some(span) => Some(CachedSpan::from_span(db, span)?),
+                    None => None,
+                };
+                Some(CachedSubDiagnostic {
+                    primary: sub.is_primary(),
+                    message: sub.message.clone(),
+                    span,
+                })
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Self {
+            severity: match diag.severity {
+                Severity::Error => "error",
+                Severity::Warning => "warning",
+                Severity::Note => "note",
+            }
+            .to_string(),
+            message: diag.message.clone(),
+            sub_diagnostics,
+            notes: diag.notes.clone(),
+            pass: diag.error_code.pass.code(),
+            local_code: diag.error_code.local_code,
+        })
+    }
+
+    /// Returns `None` if the entry is invalid or refers to a file that isn't
+    /// in the workspace.
+    fn into_complete(self, db: &DriverDataBase) -> Option<CompleteDiagnostic> {
+        let severity = match self.severity.as_str() {
+            "error" => Severity::Error,
+            "warning" => Severity::Warning,
+            "note" => Severity::Note,
+            _ => return None,
+        };
+        let sub_diagnostics = self
+            .sub_diagnostics
+            .into_iter()
+            .map(|sub| {
+                let span = match sub.span {
+                    Some(span) => Some(span.into_span(db)?),
+                    None => None,
+                };
+                let style = if sub.primary {
+                    LabelStyle::Primary
+                } else {
+                    LabelStyle::Secondary
+                };
+                Some(SubDiagnostic::new(style, sub.message, span))
+            })
+            .collect::<Option<_>>()?;
+
+        Some(CompleteDiagnostic::new(
+            severity,
+            self.message,
+            sub_diagnostics,
+            self.notes,
+            GlobalErrorCode::new(pass_from_code(self.pass)?, self.local_code),
+        ))
+    }
+}
+
+impl CachedSpan {
+    fn from_span(db: &DriverDataBase, span: &Span) -> Option<Self> {
+        Some(Self {
+            url: span.file.url(db)?.to_string(),
+            range: range_to_pair(span.range),
+            kind: match span.kind {
+                SpanKind::Original => CachedSpanKind::Original,
+                SpanKind::Expanded { def } => CachedSpanKind::Expanded {
+                    def: def.map(range_to_pair),
+                },
+                SpanKind::NotFound => CachedSpanKind::NotFound,
+            },
+        })
+    }
+
+    fn into_span(self, db: &DriverDataBase) -> Option<Span> {
+        let url = Url::parse(&self.url).ok()?;
+        let file = db.workspace().get(db, &url)?;
+        let kind = match self.kind {
+            CachedSpanKind::Original => SpanKind::Original,
+            CachedSpanKind::Expanded { def } => SpanKind::Expanded {
+                def: def.map(pair_to_range),
+            },
+            CachedSpanKind::NotFound => SpanKind::NotFound,
+        };
+        Some(Span::new(file, pair_to_range(self.range), kind))
+    }
+}
+
+fn range_to_pair(range: TextRange) -> (u32, u32) {
+    (range.start().into(), range.end().into())
+}
+
+fn pair_to_range((start, end): (u32, u32)) -> TextRange {
+    TextRange::new(TextSize::from(start), TextSize::from(end))
+}
+
+fn pass_from_code(code: u16) -> Option<DiagnosticPass> {
+    Some(match code {
+        1 => DiagnosticPass::Parse,
+        2 => DiagnosticPass::NameResolution,
+        3 => DiagnosticPass::TypeDefinition,
+        4 => DiagnosticPass::TraitDefinition,
+        5 => DiagnosticPass::ImplTraitDefinition,
+        6 => DiagnosticPass::TraitSatisfaction,
+        7 => DiagnosticPass::MethodDefinition,
+        8 => DiagnosticPass::TyCheck,
+        9 => DiagnosticPass::Lint,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use salsa::Setter;
+
+    use super::*;
+
+    const SOURCE: &str = "pub fn foo() -> u256 { true }";
+
+    fn load_ingot(db: &mut DriverDataBase, base_url: &Url, name: &str, dependencies: &str) {
+        let config = format!(
+            "[ingot]\nname = \"{name}\"\nversion = \"0.1.0\"\n\n[dependencies]\n{dependencies}"
+        );
+        let index = db.workspace();
+        index.touch_ingot(db, base_url, Some(config));
+        index.touch(
+            db,
+            base_url.join("src/lib.fe").unwrap(),
+            Some(SOURCE.to_string()),
+        );
+    }
+
+    fn set_source(db: &mut DriverDataBase, base_url: &Url, source: &str) {
+        let file = db
+            .workspace()
+            .get(db, &base_url.join("src/lib.fe").unwrap())
+            .unwrap();
+        file.set_text(db).to(source.to_string());
+    }
+
+    #[test]
+    fn invalidation() {
+        let dir = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fe-cache-{}", std::process::id()));
+        let cache = AnalysisCache::new(&dir);
+        let base_url = Url::parse("file:///app/").unwrap();
+
+        let mut db = DriverDataBase::default();
+        load_ingot(&mut db, &base_url, "app", "");
+        let inputs = fingerprint(&db, &base_url);
+        assert!(cache.load(&db, &base_url, &inputs).is_none());
+        let modules = db.run_on_ingot_by_module(base_url.ingot(&db).unwrap(), None);
+        assert!(modules.iter().any(|(_, diags)| diags.has_errors()));
+        cache.store(&db, &base_url, &inputs, &modules);
+        let expected: Vec<_> = modules
+            .iter()
+            .map(|(file, diags)| (file.url(&db).unwrap(), diags.format_diags(&db)))
+            .collect();
+
+        // A new run with the same files reuses the diagnostics of each module.
+        let mut db = DriverDataBase::default();
+        load_ingot(&mut db, &base_url, "app", "");
+        assert_eq!(fingerprint(&db, &base_url), inputs);
+        let cached: Vec<_> = cache
+            .load(&db, &base_url, &inputs)
+            .unwrap()
+            .iter()
+            .map(|(file, diags)| (file.url(&db).unwrap(), diags.format_diags(&db)))
+            .collect();
+        assert_eq!(cached, expected);
+
+        // Changing a file changes the fingerprint, so the entry is stale.
+        set_source(&mut db, &base_url, "pub fn foo() -> u256 { 0 }");
+        let changed_inputs = fingerprint(&db, &base_url);
+        assert_ne!(changed_inputs, inputs);
+        assert!(cache.load(&db, &base_url, &changed_inputs).is_none());
+
+        // Entries of other compiler versions or binaries are ignored.
+        let path = cache.entry_path(&base_url);
+        let content = std::fs::read_to_string(&path).unwrap();
+        let stale = content.replace(
+            &key(&inputs).unwrap(),
+            &format!(
+                "{FORMAT_VERSION}-{}-0-{}",
+                env!("CARGO_PKG_VERSION"),
+                inputs.0
+            ),
+        );
+        std::fs::write(&path, stale).unwrap();
+        assert!(cache.load(&db, &base_url, &inputs).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fingerprint_covers_dependencies_only() {
+        let lib_url = Url::parse("file:///workspace/lib/").unwrap();
+        let app_url = Url::parse("file:///workspace/app/").unwrap();
+        let other_url = Url::parse("file:///workspace/other/").unwrap();
+
+        let mut db = DriverDataBase::default();
+        load_ingot(&mut db, &lib_url, "lib", "");
+        load_ingot(&mut db, &app_url, "app", "lib = \"../lib\"\n");
+        load_ingot(&mut db, &other_url, "other", "");
+        let fingerprints =
+            |db: &DriverDataBase| [&lib_url, &app_url, &other_url].map(|url| fingerprint(db, url));
+        let [lib, app, other] = fingerprints(&db);
+
+        // Changing a dependency invalidates the ingots that depend on it, but
+        // not the unrelated ones.
+        set_source(&mut db, &lib_url, "pub fn foo() -> u256 { 0 }");
+        let [changed_lib, changed_app, unchanged_other] = fingerprints(&db);
+        assert_ne!(changed_lib, lib);
+        assert_ne!(changed_app, app);
+        assert_eq!(unchanged_other, other);
+
+        // Changing an ingot doesn't invalidate its dependencies.
+        set_source(&mut db, &app_url, "pub fn foo() -> u256 { 1 }");
+        let [unchanged_lib, _, _] = fingerprints(&db);
+        assert_eq!(unchanged_lib, changed_lib);
+    }
+}
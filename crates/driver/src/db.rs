@@ -1,19 +1,29 @@
-use crate::diagnostics::CsDbWrapper;
+use std::sync::{Arc, Mutex};
+
+use crate::{
+    diagnostics::{format_json, format_sarif, CsDbWrapper},
+    timings::{QueryStats, Timings},
+};
 use codespan_reporting::term::{
     self,
     termcolor::{BufferWriter, ColorChoice},
 };
-use common::file::File;
-use common::{define_input_db, diagnostics::CompleteDiagnostic};
+use common::file::{File, Workspace};
+use common::{
+    core::HasBuiltinCore,
+    diagnostics::{CompleteDiagnostic, Severity},
+    impl_input_db,
+};
 use hir::{
     hir_def::TopLevelMod,
     lower::{map_file_to_mod, module_tree},
     Ingot,
 };
 use hir_analysis::{
-    analysis_pass::{AnalysisPassManager, ParsingPass},
+    analysis_pass::{run_on_modules_in_parallel, AnalysisPassManager, ParsingPass, PassTiming},
     diagnostics::DiagnosticVoucher,
-    name_resolution::ImportAnalysisPass,
+    lint::LintAnalysisPass,
+    name_resolution::{ImportAnalysisPass, VisibilityAnalysisPass},
     ty::{
         AdtDefAnalysisPass, BodyAnalysisPass, DefConflictAnalysisPass, FuncAnalysisPass,
         ImplAnalysisPass, ImplTraitAnalysisPass, TraitAnalysisPass, TypeAliasAnalysisPass,
@@ -22,11 +32,47 @@ use hir_analysis::{
 
 use crate::diagnostics::ToCsDiag;
 
-define_input_db!(DriverDataBase);
+#[derive(Clone)]
+#[salsa::db]
+pub struct DriverDataBase {
+    storage: salsa::Storage<Self>,
+    index: Option<Workspace>,
+    /// Shared with the clones of the database, so that queries executed by any
+    /// of them are counted.
+    query_stats: Option<Arc<Mutex<QueryStats>>>,
+}
+
+#[salsa::db]
+impl salsa::Database for DriverDataBase {
+    fn salsa_event(&self, event: &dyn Fn() -> salsa::Event) {
+        let Some(query_stats) = &self.query_stats else {
+            return;
+        };
+        if let salsa::EventKind::WillExecute { database_key } = event().kind {
+            query_stats.lock().unwrap().record(database_key);
+        }
+    }
+}
+
+impl_input_db!(DriverDataBase);
+
+impl Default for DriverDataBase {
+    fn default() -> Self {
+        let mut db = Self {
+            storage: salsa::Storage::default(),
+            index: None,
+            query_stats: None,
+        };
+        let index = Workspace::default(&db);
+        db.index = Some(index);
+        db.initialize_builtin_core();
+        db
+    }
+}
 
 impl DriverDataBase {
     // TODO: An temporary implementation for ui testing.
-    pub fn run_on_top_mod<'db>(&'db self, top_mod: TopLevelMod<'db>) -> DiagnosticsCollection<'db> {
+    pub fn run_on_top_mod<'db>(&'db self, top_mod: TopLevelMod<'db>) -> DiagnosticsCollection {
         self.run_on_file_with_pass_manager(top_mod, initialize_analysis_pass())
     }
 
@@ -34,40 +80,117 @@ impl DriverDataBase {
         &'db self,
         top_mod: TopLevelMod<'db>,
         mut pass_manager: AnalysisPassManager,
-    ) -> DiagnosticsCollection<'db> {
-        DiagnosticsCollection(pass_manager.run_on_module(self, top_mod))
+    ) -> DiagnosticsCollection {
+        DiagnosticsCollection::new(self, &pass_manager.run_on_module(self, top_mod))
     }
 
-    pub fn run_on_ingot<'db>(&'db self, ingot: Ingot<'db>) -> DiagnosticsCollection<'db> {
-        self.run_on_ingot_with_pass_manager(ingot, initialize_analysis_pass())
+    /// Analyzes the modules of the ingot in parallel.
+    pub fn run_on_ingot(&self, ingot: Ingot) -> DiagnosticsCollection {
+        self.run_on_ingot_with_pass_manager(ingot, initialize_ingot_analysis_pass)
     }
 
-    pub fn run_on_ingot_with_pass_manager<'db>(
-        &'db self,
-        ingot: Ingot<'db>,
-        mut pass_manager: AnalysisPassManager,
-    ) -> DiagnosticsCollection<'db> {
-        let tree = module_tree(self, ingot);
-        DiagnosticsCollection(pass_manager.run_on_module_tree(self, tree))
+    /// Like [`Self::run_on_ingot`], but keeps the diagnostics of each module
+    /// apart, and records how long each pass takes on each module if
+    /// `timings` is given.
+    pub fn run_on_ingot_by_module(
+        &self,
+        ingot: Ingot,
+        timings: Option<&mut Timings>,
+    ) -> Vec<(File, DiagnosticsCollection)> {
+        let record_timings = timings.is_some();
+        let (diags, pass_timings) = self.run_on_modules(ingot, || {
+            let mut pass_manager = initialize_ingot_analysis_pass();
+            if record_timings {
+                pass_manager.record_timings();
+            }
+            pass_manager
+        });
+        if let Some(timings) = timings {
+            timings.extend(pass_timings);
+        }
+        diags
+    }
+
+    /// Analyzes the modules of the ingot in parallel, using a pass manager
+    /// created by `init_pass_manager` for each thread.
+    pub fn run_on_ingot_with_pass_manager(
+        &self,
+        ingot: Ingot,
+        init_pass_manager: impl Fn() -> AnalysisPassManager + Sync,
+    ) -> DiagnosticsCollection {
+        let (diags, _) = self.run_on_modules(ingot, init_pass_manager);
+        DiagnosticsCollection::merge(diags.into_iter().map(|(_, diags)| diags))
+    }
+
+    fn run_on_modules(
+        &self,
+        ingot: Ingot,
+        init_pass_manager: impl Fn() -> AnalysisPassManager + Sync,
+    ) -> (Vec<(File, DiagnosticsCollection)>, Vec<PassTiming>) {
+        let modules: Vec<_> = module_tree(self, ingot).all_modules().collect();
+        let (diags, timings) =
+            run_on_modules_in_parallel(self, &modules, init_pass_manager, |db, diags| {
+                DiagnosticsCollection::new(db, &diags)
+            });
+        let files = modules.iter().map(|top_mod| top_mod.file(self));
+        (files.zip(diags).collect(), timings)
     }
 
     pub fn top_mod(&self, input: File) -> TopLevelMod {
         map_file_to_mod(self, input)
     }
+
+    /// Starts counting how many times each query is executed. The counts are
+    /// collected with [`Self::take_query_stats`].
+    pub fn record_query_stats(&mut self) {
+        self.query_stats.get_or_insert_with(Default::default);
+    }
+
+    /// Returns the query counts since the last call, or `None` if counting
+    /// isn't enabled.
+    pub fn take_query_stats(&self) -> Option<QueryStats> {
+        let query_stats = self.query_stats.as_ref()?;
+        Some(std::mem::take(&mut *query_stats.lock().unwrap()))
+    }
 }
 
-pub struct DiagnosticsCollection<'db>(Vec<Box<dyn DiagnosticVoucher + 'db>>);
-impl DiagnosticsCollection<'_> {
+/// The diagnostics of an analysis, sorted by error code and span.
+pub struct DiagnosticsCollection(Vec<CompleteDiagnostic>);
+impl DiagnosticsCollection {
+    fn new(db: &DriverDataBase, diags: &[Box<dyn DiagnosticVoucher + '_>]) -> Self {
+        Self::sorted(diags.iter().map(|d| d.to_complete(db)).collect())
+    }
+
+    /// Merges the diagnostics of several analyses, e.g. of the modules of an
+    /// ingot.
+    pub fn merge(collections: impl IntoIterator<Item = Self>) -> Self {
+        Self::sorted(collections.into_iter().flat_map(|diags| diags.0).collect())
+    }
+
+    pub(crate) fn sorted(mut diags: Vec<CompleteDiagnostic>) -> Self {
+        diags.sort_by(|lhs, rhs| match lhs.error_code.cmp(&rhs.error_code) {
+            std::cmp::Ordering::Equal => lhs.primary_span().cmp(&rhs.primary_span()),
+            ord => ord,
+        });
+        Self(diags)
+    }
+
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 
+    /// Returns `true` if any of the diagnostics is an error, as opposed to a
+    /// warning.
+    pub fn has_errors(&self) -> bool {
+        self.0.iter().any(|d| d.severity == Severity::Error)
+    }
+
     pub fn emit(&self, db: &DriverDataBase) {
         let writer = BufferWriter::stderr(ColorChoice::Auto);
         let mut buffer = writer.buffer();
         let config = term::Config::default();
 
-        for diag in self.finalize(db) {
+        for diag in &self.0 {
             term::emit(&mut buffer, &config, &CsDbWrapper(db), &diag.to_cs(db)).unwrap();
         }
 
@@ -82,20 +205,26 @@ impl DiagnosticsCollection<'_> {
         let mut buffer = writer.buffer();
         let config = term::Config::default();
 
-        for diag in self.finalize(db) {
+        for diag in &self.0 {
             term::emit(&mut buffer, &config, &CsDbWrapper(db), &diag.to_cs(db)).unwrap();
         }
 
         std::str::from_utf8(buffer.as_slice()).unwrap().to_string()
     }
 
-    fn finalize(&self, db: &DriverDataBase) -> Vec<CompleteDiagnostic> {
-        let mut diags: Vec<_> = self.0.iter().map(|d| d.as_ref().to_complete(db)).collect();
-        diags.sort_by(|lhs, rhs| match lhs.error_code.cmp(&rhs.error_code) {
-            std::cmp::Ordering::Equal => lhs.primary_span().cmp(&rhs.primary_span()),
-            ord => ord,
-        });
-        diags
+    /// Format the accumulated diagnostics as JSON lines.
+    pub fn format_json(&self, db: &DriverDataBase) -> String {
+        format_json(db, &self.0)
+    }
+
+    /// Format the accumulated diagnostics as a SARIF log.
+    pub fn format_sarif(&self, db: &DriverDataBase) -> String {
+        format_sarif(db, &self.0)
+    }
+
+    /// Returns the complete diagnostics, sorted by error code and span.
+    pub fn finalize(&self) -> Vec<CompleteDiagnostic> {
+        self.0.clone()
     }
 }
 
@@ -104,6 +233,7 @@ fn initialize_analysis_pass() -> AnalysisPassManager {
     pass_manager.add_module_pass(Box::new(ParsingPass {}));
     pass_manager.add_module_pass(Box::new(DefConflictAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(ImportAnalysisPass {}));
+    pass_manager.add_module_pass(Box::new(VisibilityAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(AdtDefAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(TypeAliasAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(TraitAnalysisPass {}));
@@ -113,3 +243,57 @@ fn initialize_analysis_pass() -> AnalysisPassManager {
     pass_manager.add_module_pass(Box::new(BodyAnalysisPass {}));
     pass_manager
 }
+
+fn initialize_ingot_analysis_pass() -> AnalysisPassManager {
+    let mut pass_manager = initialize_analysis_pass();
+    pass_manager.add_module_pass(Box::new(LintAnalysisPass::with_builtin_lints()));
+    pass_manager
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{ingot::IngotBaseUrl, InputDb};
+    use url::Url;
+
+    use super::*;
+
+    #[test]
+    fn parallel_analysis_matches_serial_analysis() {
+        let mut db = DriverDataBase::default();
+        let base_url = Url::parse("file:///app/").unwrap();
+        let index = db.workspace();
+        index.touch_ingot(
+            &mut db,
+            &base_url,
+            Some("[ingot]\nname = \"app\"\nversion = \"0.1.0\"\n".to_string()),
+        );
+        let mut urls = vec![];
+        for (path, source) in [
+            ("src/lib.fe", "pub fn lib() -> u256 { true }"),
+            (
+                "src/a.fe",
+                "pub fn a() -> bool { 1 }\npub fn b() -> u8 { false }",
+            ),
+            ("src/b.fe", "pub fn c() -> Unknown { 0 }"),
+            ("src/c.fe", "pub fn d() -> u256 { 0 }"),
+        ] {
+            let url = base_url.join(path).unwrap();
+            index.touch(&mut db, url.clone(), Some(source.to_string()));
+            urls.push(url);
+        }
+
+        let mut serial = vec![];
+        for url in &urls {
+            let top_mod = db.top_mod(index.get(&db, url).unwrap());
+            serial.extend(
+                db.run_on_file_with_pass_manager(top_mod, initialize_ingot_analysis_pass())
+                    .finalize(),
+            );
+        }
+        let serial = DiagnosticsCollection::sorted(serial).finalize();
+
+        let parallel = db.run_on_ingot(base_url.ingot(&db).unwrap()).finalize();
+        assert!(!parallel.is_empty());
+        assert_eq!(parallel, serial);
+    }
+}
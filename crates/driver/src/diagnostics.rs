@@ -3,12 +3,14 @@ use std::ops::Range;
 use camino::Utf8Path;
 use codespan_reporting as cs;
 use common::{
-    diagnostics::{LabelStyle, Severity},
+    diagnostics::{CompleteDiagnostic, LabelStyle, Severity, SpanKind},
     file::File,
     InputDb,
 };
 use cs::{diagnostic as cs_diag, files as cs_files};
 use hir_analysis::diagnostics::{DiagnosticVoucher, SpannedHirAnalysisDb};
+use parser::TextRange;
+use serde::Serialize;
 
 pub trait ToCsDiag {
     fn to_cs(&self, db: &dyn SpannedInputDb) -> cs_diag::Diagnostic<File>;
@@ -22,38 +24,66 @@ where
     T: DiagnosticVoucher,
 {
     fn to_cs(&self, db: &dyn SpannedInputDb) -> cs_diag::Diagnostic<File> {
-        let complete = self.to_complete(db);
-
-        let severity = convert_severity(complete.severity);
-        let code = Some(complete.error_code.to_string());
-        let message = complete.message;
-
-        let labels = complete
-            .sub_diagnostics
-            .into_iter()
-            .filter_map(|sub_diag| {
-                let span = sub_diag.span?;
-                match sub_diag.style {
-                    LabelStyle::Primary => {
-                        cs_diag::Label::new(cs_diag::LabelStyle::Primary, span.file, span.range)
-                    }
-                    LabelStyle::Secondary => {
-                        cs_diag::Label::new(cs_diag::LabelStyle::Secondary, span.file, span.range)
-                    }
-                }
-                .with_message(sub_diag.message)
-                .into()
-            })
-            .collect();
-
-        cs_diag::Diagnostic {
-            severity,
-            code,
-            message,
-            labels,
-            notes: complete.notes,
+        complete_to_cs(&self.to_complete(db))
+    }
+}
+
+fn complete_to_cs(complete: &CompleteDiagnostic) -> cs_diag::Diagnostic<File> {
+    let labels = labels(complete)
+        .into_iter()
+        .map(|label| {
+            let style = match label.style {
+                LabelStyle::Primary => cs_diag::LabelStyle::Primary,
+                LabelStyle::Secondary => cs_diag::LabelStyle::Secondary,
+            };
+            cs_diag::Label::new(style, label.file, label.range).with_message(label.message)
+        })
+        .collect();
+
+    cs_diag::Diagnostic {
+        severity: convert_severity(complete.severity),
+        code: Some(complete.error_code.to_string()),
+        message: complete.message.clone(),
+        labels,
+        notes: complete.notes.clone(),
+    }
+}
+
+struct Label {
+    style: LabelStyle,
+    file: File,
+    range: TextRange,
+    message: String,
+}
+
+/// Returns the labels of the sub-diagnostics that have a span.
+fn labels(complete: &CompleteDiagnostic) -> Vec<Label> {
+    let mut labels = vec![];
+    for sub_diag in &complete.sub_diagnostics {
+        let Some(span) = &sub_diag.span else {
+            continue;
+        };
+        labels.push(Label {
+            style: sub_diag.style,
+            file: span.file,
+            range: span.range,
+            message: sub_diag.message.clone(),
+        });
+
+        // Points to the part of the macro definition the primary label
+        // is expanded from.
+        if let (SpanKind::Expanded { def: Some(def) }, LabelStyle::Primary) =
+            (span.kind, sub_diag.style)
+        {
+            labels.push(Label {
+                style: LabelStyle::Secondary,
+                file: span.file,
+                range: def,
+                message: "in this macro expansion".to_string(),
+            });
         }
     }
+    labels
 }
 
 fn convert_severity(severity: Severity) -> cs_diag::Severity {
@@ -64,6 +94,243 @@ fn convert_severity(severity: Severity) -> cs_diag::Severity {
     }
 }
 
+fn severity_str(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Note => "note",
+    }
+}
+
+/// A diagnostic in the `--format json` output.
+#[derive(Debug, Serialize)]
+struct JsonDiagnostic {
+    severity: &'static str,
+    code: String,
+    message: String,
+    primary: Option<JsonLabel>,
+    secondary: Vec<JsonLabel>,
+    notes: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonLabel {
+    message: String,
+    file: String,
+    start: Position,
+    end: Position,
+}
+
+/// A one-based line and column, where columns are counted in characters.
+#[derive(Debug, Clone, Copy, Serialize)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+/// Renders the diagnostics as JSON lines, with one object per diagnostic.
+pub fn format_json(db: &dyn SpannedHirAnalysisDb, diags: &[CompleteDiagnostic]) -> String {
+    diags
+        .iter()
+        .map(|diag| {
+            let mut primary = None;
+            let mut secondary = vec![];
+            for label in labels(diag) {
+                let (start, end) = positions(db, label.file, label.range);
+                let json_label = JsonLabel {
+                    message: label.message,
+                    file: file_name(db, label.file),
+                    start,
+                    end,
+                };
+                match label.style {
+                    LabelStyle::Primary if primary.is_none() => primary = Some(json_label),
+                    _ => secondary.push(json_label),
+                }
+            }
+
+            let json = JsonDiagnostic {
+                severity: severity_str(diag.severity),
+                code: diag.error_code.to_string(),
+                message: diag.message.clone(),
+                primary,
+                secondary,
+                notes: diag.notes.clone(),
+            };
+            serde_json::to_string(&json).expect("diagnostics should be serializable") + "\n"
+        })
+        .collect()
+}
+
+/// A SARIF 2.1.0 log, see <https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html>.
+#[derive(Debug, Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<SarifRun>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    information_uri: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    level: &'static str,
+    message: SarifMessage,
+    locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<SarifLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    properties: Option<SarifProperties>,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+    message: SarifMessage,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    region: SarifRegion,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct SarifProperties {
+    notes: Vec<String>,
+}
+
+/// Renders the diagnostics as a SARIF 2.1.0 log with a single run.
+pub fn format_sarif(db: &dyn SpannedHirAnalysisDb, diags: &[CompleteDiagnostic]) -> String {
+    let results = diags
+        .iter()
+        .map(|diag| {
+            let mut locations = vec![];
+            let mut related_locations = vec![];
+            for label in labels(diag) {
+                let (start, end) = positions(db, label.file, label.range);
+                let location = SarifLocation {
+                    physical_location: SarifPhysicalLocation {
+                        artifact_location: SarifArtifactLocation {
+                            uri: file_name(db, label.file),
+                        },
+                        region: SarifRegion {
+                            start_line: start.line,
+                            start_column: start.column,
+                            end_line: end.line,
+                            end_column: end.column,
+                        },
+                    },
+                    message: SarifMessage {
+                        text: label.message,
+                    },
+                };
+                match label.style {
+                    LabelStyle::Primary if locations.is_empty() => locations.push(location),
+                    _ => related_locations.push(location),
+                }
+            }
+
+            SarifResult {
+                rule_id: diag.error_code.to_string(),
+                level: severity_str(diag.severity),
+                message: SarifMessage {
+                    text: diag.message.clone(),
+                },
+                locations,
+                related_locations,
+                properties: (!diag.notes.is_empty()).then(|| SarifProperties {
+                    notes: diag.notes.clone(),
+                }),
+            }
+        })
+        .collect();
+
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: vec![SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "fe",
+                    information_uri: "https://github.com/ethereum/fe",
+                },
+            },
+            column_kind: "unicodeCodePoints",
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&log).expect("diagnostics should be serializable") + "\n"
+}
+
+fn file_name(db: &dyn SpannedHirAnalysisDb, file: File) -> String {
+    match file.path(db) {
+        Some(path) => path.to_string(),
+        None => file.url(db).map(|url| url.to_string()).unwrap_or_default(),
+    }
+}
+
+fn positions(db: &dyn SpannedHirAnalysisDb, file: File, range: TextRange) -> (Position, Position) {
+    (
+        position(db, file, range.start().into()),
+        position(db, file, range.end().into()),
+    )
+}
+
+fn position(db: &dyn SpannedHirAnalysisDb, file: File, offset: usize) -> Position {
+    let starts = file_line_starts(db, file);
+    let line = starts
+        .binary_search(&offset)
+        .unwrap_or_else(|next_line| next_line - 1);
+    let column = file.text(db)[starts[line]..offset].chars().count() + 1;
+    Position {
+        line: line + 1,
+        column,
+    }
+}
+
 #[salsa::tracked(return_ref)]
 pub fn file_line_starts(db: &dyn SpannedHirAnalysisDb, file: File) -> Vec<usize> {
     cs::files::line_starts(file.text(db)).collect()
@@ -0,0 +1,77 @@
+use super::{ErrorCodeDoc, Examples};
+
+pub(super) const ERROR_CODES: &[ErrorCodeDoc] = &[
+    ErrorCodeDoc {
+        code: "5-0000",
+        title: "external trait is implemented for an external type",
+        explanation: r#"
+A trait can only be implemented in the ingot that defines either the trait or
+the type. Wrap the external type in a struct of the current ingot and implement
+the trait for the wrapper instead.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+use core::Clone
+
+impl Clone for u256 {
+    fn clone(self) -> Self {
+        self
+    }
+}
+"#,
+            corrected: r#"
+use core::Clone
+
+pub struct Amount {
+    value: u256,
+}
+
+impl Clone for Amount {
+    fn clone(self) -> Self {
+        Amount { value: self.value }
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "5-0001",
+        title: "conflicting trait implementations",
+        explanation: r#"
+A trait is implemented more than once for the same type, so it's unclear which
+implementation to use. This also happens when a generic implementation overlaps
+with a more specific one.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Describe {}
+
+impl Describe for i32 {}
+impl<T> Describe for T {}
+"#,
+            corrected: r#"
+pub trait Describe {}
+
+impl Describe for i32 {}
+impl Describe for u32 {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "5-0002",
+        title: "cyclic super traits",
+        explanation: r#"
+A trait is its own super trait, directly or through other traits.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape: Drawable {}
+pub trait Drawable: Shape {}
+"#,
+            corrected: r#"
+pub trait Shape: Drawable {}
+pub trait Drawable {}
+"#,
+        }),
+    },
+];
@@ -0,0 +1,196 @@
+//! Long-form explanations of the error codes reported by the compiler, shown
+//! by `fe explain <code>`.
+//!
+//! Every explanation comes with an erroneous example that produces the error
+//! and a corrected example that doesn't. Codes that are reserved but not
+//! currently emitted by the compiler have no examples, and their explanations
+//! say which code is reported instead.
+
+mod impl_trait_definition;
+mod name_resolution;
+mod parse;
+mod trait_satisfaction;
+mod ty_check;
+mod type_definition;
+
+use std::fmt;
+
+use hir_analysis::lint::{Lint, LintAnalysisPass};
+
+/// The documentation of an error code.
+#[derive(Debug)]
+pub struct ErrorCodeDoc {
+    /// The code as rendered by `GlobalErrorCode`, e.g. `2-0002`.
+    pub code: &'static str,
+    pub title: &'static str,
+    pub explanation: &'static str,
+    /// `None` if the error is not currently emitted by the compiler.
+    pub examples: Option<Examples>,
+}
+
+#[derive(Debug)]
+pub struct Examples {
+    pub erroneous: &'static str,
+    pub corrected: &'static str,
+}
+
+/// Returns the documentation of all error codes, ordered by code.
+pub fn error_codes() -> impl Iterator<Item = &'static ErrorCodeDoc> {
+    [
+        parse::ERROR_CODES,
+        name_resolution::ERROR_CODES,
+        type_definition::ERROR_CODES,
+        impl_trait_definition::ERROR_CODES,
+        trait_satisfaction::ERROR_CODES,
+        ty_check::ERROR_CODES,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Returns the explanation of `code`, which is either an error code or the
+/// code of a lint.
+pub fn explain(code: &str) -> Option<String> {
+    if let Some(doc) = error_codes().find(|doc| doc.code == code) {
+        return Some(doc.to_string());
+    }
+
+    LintAnalysisPass::with_builtin_lints()
+        .lints()
+        .find(|lint| lint_code(lint) == code)
+        .map(explain_lint)
+}
+
+fn lint_code(lint: &Lint) -> String {
+    format!("9-{:04}", lint.code)
+}
+
+fn explain_lint(lint: &Lint) -> String {
+    format!(
+        "{code}: the `{name}` lint\n\n\
+         This lint {description}. It's set to `{level}` by default, which can be \
+         overridden with `#allow({name})`, `#warn({name})` or `#deny({name})` \
+         attributes, or with the `[lints]` table of `fe.toml`.\n",
+        code = lint_code(lint),
+        name = lint.name,
+        description = lint.description,
+        level = lint.default_level,
+    )
+}
+
+impl fmt::Display for ErrorCodeDoc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}: {}\n", self.code, self.title)?;
+        writeln!(f, "{}", self.explanation.trim())?;
+
+        match &self.examples {
+            Some(examples) => {
+                writeln!(f, "\nErroneous example:\n")?;
+                write_example(f, examples.erroneous)?;
+                writeln!(f, "\nCorrected example:\n")?;
+                write_example(f, examples.corrected)
+            }
+            None => writeln!(f, "\nThis error is not currently emitted by the compiler."),
+        }
+    }
+}
+
+fn write_example(f: &mut fmt::Formatter<'_>, example: &str) -> fmt::Result {
+    for line in example.trim().lines() {
+        if line.is_empty() {
+            writeln!(f)?;
+        } else {
+            writeln!(f, "    {line}")?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use common::InputDb;
+    use url::Url;
+
+    use super::*;
+    use crate::DriverDataBase;
+
+    fn emitted_codes(source: &str) -> Vec<String> {
+        let mut db = DriverDataBase::default();
+        let file = db.workspace().touch(
+            &mut db,
+            Url::parse("file:///explain.fe").unwrap(),
+            Some(source.to_string()),
+        );
+        let top_mod = db.top_mod(file);
+        db.run_on_top_mod(top_mod)
+            .finalize()
+            .iter()
+            .map(|diag| diag.error_code.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn codes_are_sorted_and_unique() {
+        let codes: Vec<_> = error_codes().map(|doc| doc.code).collect();
+        let mut sorted = codes.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(codes, sorted);
+    }
+
+    #[test]
+    fn emittable_codes_are_documented() {
+        let mut codes: Vec<_> = hir_analysis::diagnostics::error_codes()
+            .map(|code| code.to_string())
+            .collect();
+        codes.sort();
+        let count = codes.len();
+        codes.dedup();
+        assert_eq!(codes.len(), count, "an error code is used more than once");
+
+        let documented: Vec<_> = error_codes().map(|doc| doc.code.to_string()).collect();
+        assert_eq!(codes, documented);
+
+        let mut lint_codes: Vec<_> = LintAnalysisPass::with_builtin_lints()
+            .lints()
+            .map(lint_code)
+            .collect();
+        lint_codes.sort();
+        let count = lint_codes.len();
+        lint_codes.dedup();
+        assert_eq!(
+            lint_codes.len(),
+            count,
+            "a lint code is used more than once"
+        );
+        for code in lint_codes {
+            assert!(explain(&code).is_some(), "`{code}` has no explanation");
+        }
+    }
+
+    #[test]
+    fn examples() {
+        for doc in error_codes() {
+            let Some(examples) = &doc.examples else {
+                continue;
+            };
+            assert!(
+                emitted_codes(examples.erroneous).contains(&doc.code.to_string()),
+                "the erroneous example of {} doesn't produce the error",
+                doc.code
+            );
+            assert!(
+                !emitted_codes(examples.corrected).contains(&doc.code.to_string()),
+                "the corrected example of {} produces the error",
+                doc.code
+            );
+        }
+    }
+
+    #[test]
+    fn explain_lint() {
+        let explanation = explain("9-0002").unwrap();
+        assert!(explanation.contains("`unused_imports`"));
+        assert!(explain("9-9999").is_none());
+    }
+}
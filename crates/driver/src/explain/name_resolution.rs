@@ -0,0 +1,228 @@
+use super::{ErrorCodeDoc, Examples};
+
+pub(super) const ERROR_CODES: &[ErrorCodeDoc] = &[
+    ErrorCodeDoc {
+        code: "2-0001",
+        title: "conflicting imports",
+        explanation: r#"
+Two imports bring different items with the same name into the same scope. Give
+one of them another name with `as`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+use foo1::S
+use foo2::S
+
+pub mod foo1 {
+    pub struct S {}
+}
+
+pub mod foo2 {
+    pub struct S {}
+}
+"#,
+            corrected: r#"
+use foo1::S
+use foo2::S as S2
+
+pub mod foo1 {
+    pub struct S {}
+}
+
+pub mod foo2 {
+    pub struct S {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0002",
+        title: "name is not found",
+        explanation: r#"
+A path refers to a name that isn't defined or imported in the current scope.
+Check the spelling of the name, or import it with `use`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wallet {
+    balance: Balance,
+}
+"#,
+            corrected: r#"
+pub struct Balance {
+    amount: u256,
+}
+
+pub struct Wallet {
+    balance: Balance,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0003",
+        title: "item is not visible",
+        explanation: r#"
+A path refers to an item that is private to another module. Items are only
+visible outside of the module that defines them if they're marked with `pub`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+use tokens::Token
+
+mod tokens {
+    struct Token {}
+}
+"#,
+            corrected: r#"
+use tokens::Token
+
+mod tokens {
+    pub struct Token {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0004",
+        title: "name is ambiguous",
+        explanation: r#"
+A name refers to more than one item, e.g. because two glob imports bring items
+with the same name into scope. Import the intended item explicitly.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub use inner1::*
+pub use inner2::*
+pub use S
+
+pub mod inner1 {
+    pub struct S {}
+}
+
+mod inner2 {
+    pub struct S {}
+}
+"#,
+            corrected: r#"
+pub use inner1::S
+
+pub mod inner1 {
+    pub struct S {}
+}
+
+mod inner2 {
+    pub struct S {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0005",
+        title: "invalid middle segment of a path",
+        explanation: r#"
+Only modules, enums and traits can contain items that are reachable by a path.
+Other items, e.g. structs and functions, can't be used as a middle segment of a
+path.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+use Point::x
+
+pub struct Point {
+    pub x: i32,
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    pub x: i32,
+}
+
+pub fn x_of(p: Point) -> i32 {
+    p.x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0006",
+        title: "expected a type",
+        explanation: r#"
+A path in a type position refers to an item that isn't a type, e.g. a trait or a
+value. To accept any type that implements a trait, use a generic parameter that
+is bounded by the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {}
+
+pub fn draw(shape: Shape) {}
+"#,
+            corrected: r#"
+pub trait Shape {}
+
+pub fn draw<S: Shape>(shape: S) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0007",
+        title: "expected a trait",
+        explanation: r#"
+A path in a trait bound refers to an item that isn't a trait, e.g. a struct.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Shape {}
+
+pub fn draw<S: Shape>(shape: S) {}
+"#,
+            corrected: r#"
+pub trait Shape {}
+
+pub fn draw<S: Shape>(shape: S) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "2-0008",
+        title: "expected a value",
+        explanation: r#"
+A path in a value position refers to an item that isn't a value, e.g. a type or a
+trait.
+
+Value paths are only resolved in function bodies, where the type checker reports
+such paths as 8-0030 instead.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "2-0009",
+        title: "too many generic arguments in a path",
+        explanation: r#"
+A segment of a path is given more generic arguments than the item it refers to
+declares.
+
+The number of generic arguments is checked when the path is lowered to a type,
+which reports 3-0016 instead.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "2-0010",
+        title: "invalid visibility restriction",
+        explanation: r#"
+The path of a `pub(in path)` visibility must name a module that is an ancestor of
+the module containing the item, and `pub(super)` can't be used in the root
+module of an ingot since it has no parent module.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub(super) fn helper() {}
+"#,
+            corrected: r#"
+pub(ingot) fn helper() {}
+"#,
+        }),
+    },
+];
@@ -0,0 +1,54 @@
+use super::{ErrorCodeDoc, Examples};
+
+pub(super) const ERROR_CODES: &[ErrorCodeDoc] = &[
+    ErrorCodeDoc {
+        code: "1-0001",
+        title: "syntax error",
+        explanation: r#"
+The source code doesn't follow the Fe grammar. The error message describes
+what the parser expected at the position of the error, e.g. an expression after
+a binary operator or a closing delimiter.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn add(x: u256, y: u256) -> u256 {
+    x +
+}
+"#,
+            corrected: r#"
+pub fn add(x: u256, y: u256) -> u256 {
+    x + y
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "1-0002",
+        title: "macro expansion error",
+        explanation: r#"
+A macro invocation can't be expanded, e.g. because it matches none of the rules
+of the macro, the macro isn't defined in the file, or the expansion isn't valid
+syntax where the invocation is written.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+macro double {
+    ($x:expr) => { $x * 2 }
+}
+
+pub fn four() -> u256 {
+    double!()
+}
+"#,
+            corrected: r#"
+macro double {
+    ($x:expr) => { $x * 2 }
+}
+
+pub fn four() -> u256 {
+    double!(2)
+}
+"#,
+        }),
+    },
+];
@@ -0,0 +1,691 @@
+use super::{ErrorCodeDoc, Examples};
+
+pub(super) const ERROR_CODES: &[ErrorCodeDoc] = &[
+    ErrorCodeDoc {
+        code: "6-0000",
+        title: "trait is implemented for a type of the wrong kind",
+        explanation: r#"
+The kind of the implementor doesn't match the kind that the trait requires of
+`Self`.
+
+The implementor is checked along with the other trait arguments, which reports
+a kind mismatch as 6-0002 instead.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "6-0001",
+        title: "wrong number of trait arguments",
+        explanation: r#"
+A trait is given a different number of generic arguments than it declares
+generic parameters.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Converter<T> {}
+
+impl Converter for u32 {}
+"#,
+            corrected: r#"
+pub trait Converter<T> {}
+
+impl Converter<u256> for u32 {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0002",
+        title: "trait argument kind mismatch",
+        explanation: r#"
+The kind of a type doesn't match the kind that a trait requires of `Self` or of
+one of its generic parameters. Add a kind bound to the type parameter.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Functor
+where Self: * -> *
+{}
+
+pub struct Holder<F: Functor> {}
+"#,
+            corrected: r#"
+pub trait Functor
+where Self: * -> *
+{}
+
+pub struct Holder<F: * -> *>
+where F: Functor
+{}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0003",
+        title: "trait bound is not satisfied",
+        explanation: r#"
+A type is used where it has to implement a trait, e.g. as an argument of a
+bounded generic parameter, but the trait isn't implemented for it. Either
+implement the trait for the type, or use a type that implements it.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {}
+
+pub struct Circle {}
+
+pub struct Canvas<S: Shape> {
+    shape: S,
+}
+
+pub struct Drawing {
+    canvas: Canvas<Circle>,
+}
+"#,
+            corrected: r#"
+pub trait Shape {}
+
+pub struct Circle {}
+
+impl Shape for Circle {}
+
+pub struct Canvas<S: Shape> {
+    shape: S,
+}
+
+pub struct Drawing {
+    canvas: Canvas<Circle>,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0004",
+        title: "infinite trait bound recursion",
+        explanation: r#"
+Proving that a trait bound is satisfied requires proving the same bound again
+for an ever growing type, so the solver gives up.
+
+When the solver gives up, the bound is reported as not satisfied with 6-0003
+instead.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "6-0005",
+        title: "trait bound on a concrete type",
+        explanation: r#"
+A `where` clause bounds a type that doesn't contain any generic parameters.
+Such a bound is either always or never satisfied, so it's not allowed.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {}
+
+pub struct Circle {}
+
+pub fn draw()
+where Circle: Shape
+{}
+"#,
+            corrected: r#"
+pub trait Shape {}
+
+pub fn draw<S>()
+where S: Shape
+{}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0006",
+        title: "trait bound on a const parameter",
+        explanation: r#"
+A const generic parameter stands for a value rather than a type, so it can't be
+bounded by a trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {}
+
+pub struct Buffer<const N: u32>
+where N: Shape
+{}
+"#,
+            corrected: r#"
+pub struct Buffer<const N: u32> {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0007",
+        title: "method argument type mismatch",
+        explanation: r#"
+An argument of a method of an `impl trait` block has a different type than the
+corresponding argument of the method of the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Scale {
+    fn scale(self, factor: u256)
+}
+
+impl Scale for u256 {
+    fn scale(self, factor: u8) {}
+}
+"#,
+            corrected: r#"
+pub trait Scale {
+    fn scale(self, factor: u256)
+}
+
+impl Scale for u256 {
+    fn scale(self, factor: u256) {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0008",
+        title: "method return type mismatch",
+        explanation: r#"
+A method of an `impl trait` block returns a different type than the method of
+the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {
+    fn area(self) -> u256
+}
+
+impl Shape for u256 {
+    fn area(self) -> u8 {
+        0
+    }
+}
+"#,
+            corrected: r#"
+pub trait Shape {
+    fn area(self) -> u256
+}
+
+impl Shape for u256 {
+    fn area(self) -> u256 {
+        0
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0009",
+        title: "method has stricter bounds than the trait",
+        explanation: r#"
+A method of an `impl trait` block requires trait bounds that the method of the
+trait doesn't. Callers only know the bounds of the trait, so the implementation
+can't require more.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {}
+
+pub trait Canvas {
+    fn draw<S>(self, shape: S)
+}
+
+impl Canvas for u256 {
+    fn draw<S: Shape>(self, shape: S) {}
+}
+"#,
+            corrected: r#"
+pub trait Shape {}
+
+pub trait Canvas {
+    fn draw<S: Shape>(self, shape: S)
+}
+
+impl Canvas for u256 {
+    fn draw<S: Shape>(self, shape: S) {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0010",
+        title: "invalid type of `self`",
+        explanation: r#"
+The type of a `self` argument must be `Self`, or start with `Self` such as
+`Self<T>`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Counter {
+    value: u256,
+}
+
+impl Counter {
+    fn get(self: u256) -> u256 {
+        self
+    }
+}
+"#,
+            corrected: r#"
+pub struct Counter {
+    value: u256,
+}
+
+impl Counter {
+    fn get(self) -> u256 {
+        self.value
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0011",
+        title: "invalid inherent implementation",
+        explanation: r#"
+Inherent `impl` blocks are only allowed for types that are defined in the
+current ingot. To add methods to other types, declare a trait and implement it
+for the type.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+impl u256 {
+    fn double(self) -> u256 {
+        self * 2
+    }
+}
+"#,
+            corrected: r#"
+pub trait Double {
+    fn double(self) -> u256
+}
+
+impl Double for u256 {
+    fn double(self) -> u256 {
+        self * 2
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0012",
+        title: "associated type is not a member of the trait",
+        explanation: r#"
+An `impl trait` block defines an associated type that the trait doesn't declare.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Container {
+    type Item
+}
+
+impl Container for u256 {
+    type Item = u8
+    type Key = u8
+}
+"#,
+            corrected: r#"
+pub trait Container {
+    type Item
+}
+
+impl Container for u256 {
+    type Item = u8
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0013",
+        title: "not all associated types are defined",
+        explanation: r#"
+An `impl trait` block must define every associated type of the trait that
+doesn't have a default.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Container {
+    type Item
+}
+
+impl Container for u256 {}
+"#,
+            corrected: r#"
+pub trait Container {
+    type Item
+}
+
+impl Container for u256 {
+    type Item = u8
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0014",
+        title: "associated const is not a member of the trait",
+        explanation: r#"
+An `impl trait` block defines an associated const that the trait doesn't
+declare.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Bounded {
+    const MAX: u256
+}
+
+impl Bounded for u8 {
+    const MAX: u256 = 255
+    const MIN: u256 = 0
+}
+"#,
+            corrected: r#"
+pub trait Bounded {
+    const MAX: u256
+}
+
+impl Bounded for u8 {
+    const MAX: u256 = 255
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0015",
+        title: "not all associated consts are defined",
+        explanation: r#"
+An `impl trait` block must define every associated const of the trait that
+doesn't have a default value.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Bounded {
+    const MAX: u256
+}
+
+impl Bounded for u8 {}
+"#,
+            corrected: r#"
+pub trait Bounded {
+    const MAX: u256
+}
+
+impl Bounded for u8 {
+    const MAX: u256 = 255
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0016",
+        title: "associated const type mismatch",
+        explanation: r#"
+An associated const of an `impl trait` block has a different type than the
+const declared in the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Bounded {
+    const MAX: u256
+}
+
+impl Bounded for u8 {
+    const MAX: bool = true
+}
+"#,
+            corrected: r#"
+pub trait Bounded {
+    const MAX: u256
+}
+
+impl Bounded for u8 {
+    const MAX: u256 = 255
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0017",
+        title: "conflicting method implementations",
+        explanation: r#"
+Two `impl` blocks define a method with the same name for the same type, or for
+overlapping instantiations of a generic type.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Counter {
+    value: u256,
+}
+
+impl Counter {
+    fn get(self) -> u256 {
+        self.value
+    }
+}
+
+impl Counter {
+    fn get(self) -> u256 {
+        self.value
+    }
+}
+"#,
+            corrected: r#"
+pub struct Counter {
+    value: u256,
+}
+
+impl Counter {
+    fn get(self) -> u256 {
+        self.value
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0018",
+        title: "method is not a member of the trait",
+        explanation: r#"
+An `impl trait` block defines a method that the trait doesn't declare. Move the
+method to an inherent `impl` block of the type.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {
+    fn area(self) -> u256
+}
+
+pub struct Square {
+    side: u256,
+}
+
+impl Shape for Square {
+    fn area(self) -> u256 {
+        self.side * self.side
+    }
+
+    fn perimeter(self) -> u256 {
+        4 * self.side
+    }
+}
+"#,
+            corrected: r#"
+pub trait Shape {
+    fn area(self) -> u256
+}
+
+pub struct Square {
+    side: u256,
+}
+
+impl Shape for Square {
+    fn area(self) -> u256 {
+        self.side * self.side
+    }
+}
+
+impl Square {
+    fn perimeter(self) -> u256 {
+        4 * self.side
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0019",
+        title: "not all trait methods are implemented",
+        explanation: r#"
+An `impl trait` block must implement every method of the trait that doesn't
+have a default implementation.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Shape {
+    fn area(self) -> u256
+}
+
+pub struct Square {
+    side: u256,
+}
+
+impl Shape for Square {}
+"#,
+            corrected: r#"
+pub trait Shape {
+    fn area(self) -> u256
+}
+
+pub struct Square {
+    side: u256,
+}
+
+impl Shape for Square {
+    fn area(self) -> u256 {
+        self.side * self.side
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0020",
+        title: "method type parameter count mismatch",
+        explanation: r#"
+A method of an `impl trait` block declares a different number of generic
+parameters than the method of the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Converter {
+    fn convert<T>(self, value: T)
+}
+
+impl Converter for u256 {
+    fn convert(self, value: u256) {}
+}
+"#,
+            corrected: r#"
+pub trait Converter {
+    fn convert<T>(self, value: T)
+}
+
+impl Converter for u256 {
+    fn convert<T>(self, value: T) {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0021",
+        title: "method type parameter kind mismatch",
+        explanation: r#"
+A generic parameter of a method of an `impl trait` block has a different kind
+than the corresponding parameter of the method of the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Mapper {
+    fn map<F: * -> *>(self, value: F<i32>)
+}
+
+impl Mapper for u256 {
+    fn map<F>(self, value: F) {}
+}
+"#,
+            corrected: r#"
+pub trait Mapper {
+    fn map<F: * -> *>(self, value: F<i32>)
+}
+
+impl Mapper for u256 {
+    fn map<F: * -> *>(self, value: F<i32>) {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0022",
+        title: "method argument count mismatch",
+        explanation: r#"
+A method of an `impl trait` block takes a different number of arguments than the
+method of the trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Scale {
+    fn scale(self, factor: u256) -> u256
+}
+
+impl Scale for u256 {
+    fn scale(self) -> u256 {
+        self
+    }
+}
+"#,
+            corrected: r#"
+pub trait Scale {
+    fn scale(self, factor: u256) -> u256
+}
+
+impl Scale for u256 {
+    fn scale(self, factor: u256) -> u256 {
+        self * factor
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "6-0023",
+        title: "method argument label mismatch",
+        explanation: r#"
+An argument of a method of an `impl trait` block has a different label than the
+corresponding argument of the method of the trait. Callers use the labels of the
+trait, so they have to agree.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Transfer {
+    fn transfer(self, to recipient: u256, amount: u256)
+}
+
+impl Transfer for u256 {
+    fn transfer(self, recipient: u256, amount: u256) {}
+}
+"#,
+            corrected: r#"
+pub trait Transfer {
+    fn transfer(self, to recipient: u256, amount: u256)
+}
+
+impl Transfer for u256 {
+    fn transfer(self, to recipient: u256, amount: u256) {}
+}
+"#,
+        }),
+    },
+];
@@ -0,0 +1,1468 @@
+use super::{ErrorCodeDoc, Examples};
+
+pub(super) const ERROR_CODES: &[ErrorCodeDoc] = &[
+    ErrorCodeDoc {
+        code: "8-0000",
+        title: "type mismatch",
+        explanation: r#"
+An expression has a different type than the context expects, e.g. than the type
+annotation of a `let` binding. Fe doesn't convert between types implicitly.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn is_empty(len: u256) -> bool {
+    let empty: bool = len
+    empty
+}
+"#,
+            corrected: r#"
+pub fn is_empty(len: u256) -> bool {
+    let empty: bool = len == 0
+    empty
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0001",
+        title: "infinite type",
+        explanation: r#"
+Inferring the type of an expression requires a type to contain itself, e.g.
+because a value is wrapped into a value of the same variable.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum List<T> {
+    Nil,
+    Cons(T),
+}
+
+pub fn build() {
+    let mut list = List::Nil
+    list = List::Cons(list)
+}
+"#,
+            corrected: r#"
+pub enum List<T> {
+    Nil,
+    Cons(T),
+}
+
+pub fn build() {
+    let mut list: List<u256> = List::Nil
+    list = List::Cons(1)
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0002",
+        title: "duplicate `..` in a pattern",
+        explanation: r#"
+A rest pattern `..` can be used at most once in a tuple or record pattern.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+pub fn first(p: Point) -> i32 {
+    let Point { x, .., z, .. } = p
+    x
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+pub fn first(p: Point) -> i32 {
+    let Point { x, .. } = p
+    x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0003",
+        title: "invalid path in a pattern",
+        explanation: r#"
+A path in a pattern refers to an item that can't be matched against, e.g. a
+function.
+
+The type checker reports a path to a trait or a module in a pattern as 8-0030,
+and a path to a record type or variant as 8-0004, so this code isn't used.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "8-0004",
+        title: "expected a unit variant",
+        explanation: r#"
+A path that refers to a struct or to a variant with fields is used as a value or
+a pattern on its own. The fields must be given, e.g. `Point { x, y }`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Config {
+    limit: u256,
+}
+
+pub fn default_config() -> Config {
+    Config
+}
+"#,
+            corrected: r#"
+pub struct Config {
+    limit: u256,
+}
+
+pub fn default_config() -> Config {
+    Config { limit: 100 }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0005",
+        title: "expected a tuple variant",
+        explanation: r#"
+A tuple pattern such as `Shape::Circle(r)` refers to something that isn't a
+tuple variant, e.g. a unit variant.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Shape {
+    Empty,
+    Circle(u256),
+}
+
+pub fn radius(s: Shape) -> u256 {
+    match s {
+        Shape::Empty(r) => r
+        Shape::Circle(r) => r
+    }
+}
+"#,
+            corrected: r#"
+pub enum Shape {
+    Empty,
+    Circle(u256),
+}
+
+pub fn radius(s: Shape) -> u256 {
+    match s {
+        Shape::Empty => 0
+        Shape::Circle(r) => r
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0006",
+        title: "expected a record",
+        explanation: r#"
+A record expression or pattern such as `Shape::Circle { radius }` refers to
+something that doesn't have named fields, e.g. a tuple variant.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Shape {
+    Circle(u256),
+}
+
+pub fn unit_circle() -> Shape {
+    Shape::Circle { radius: 1 }
+}
+"#,
+            corrected: r#"
+pub enum Shape {
+    Circle(u256),
+}
+
+pub fn unit_circle() -> Shape {
+    Shape::Circle(1)
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0007",
+        title: "field count mismatch",
+        explanation: r#"
+A tuple pattern has a different number of elements than the tuple variant it
+matches. Use `..` to skip elements.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Shape {
+    Rect(u256, u256),
+}
+
+pub fn area(s: Shape) -> u256 {
+    let Shape::Rect(w, h, d) = s
+    w * h
+}
+"#,
+            corrected: r#"
+pub enum Shape {
+    Rect(u256, u256),
+}
+
+pub fn area(s: Shape) -> u256 {
+    let Shape::Rect(w, h) = s
+    w * h
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0008",
+        title: "duplicate field in a record pattern",
+        explanation: r#"
+A record pattern binds the same field more than once.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn sum(p: Point) -> i32 {
+    let Point { x, x } = p
+    x
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn sum(p: Point) -> i32 {
+    let Point { x, y } = p
+    x + y
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0009",
+        title: "field is not found",
+        explanation: r#"
+A record expression or pattern refers to a field that the struct or variant
+doesn't have.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn sum(p: Point) -> i32 {
+    let Point { x, z } = p
+    x + z
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn sum(p: Point) -> i32 {
+    let Point { x, y } = p
+    x + y
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0010",
+        title: "explicit field label is required",
+        explanation: r#"
+A field of a record expression or pattern must be labeled with the name of the
+field, unless it's a plain name that matches the field, as in `Point { x, y }`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Direction {
+    Up,
+    Down,
+}
+
+pub struct Move {
+    direction: Direction,
+    steps: u256,
+}
+
+pub fn steps_up(m: Move) -> u256 {
+    match m {
+        Move { Direction::Up, steps } => steps
+        _ => 0
+    }
+}
+"#,
+            corrected: r#"
+pub enum Direction {
+    Up,
+    Down,
+}
+
+pub struct Move {
+    direction: Direction,
+    steps: u256,
+}
+
+pub fn steps_up(m: Move) -> u256 {
+    match m {
+        Move { direction: Direction::Up, steps } => steps
+        _ => 0
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0011",
+        title: "missing fields in a record pattern",
+        explanation: r#"
+A record pattern must mention every field of the struct or variant. Use `..` to
+ignore the remaining fields.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn x_of(p: Point) -> i32 {
+    let Point { x } = p
+    x
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn x_of(p: Point) -> i32 {
+    let Point { x, .. } = p
+    x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0012",
+        title: "undefined variable",
+        explanation: r#"
+An expression refers to a variable that isn't defined in the current scope.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn double(x: u256) -> u256 {
+    y * 2
+}
+"#,
+            corrected: r#"
+pub fn double(x: u256) -> u256 {
+    x * 2
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0013",
+        title: "returned type mismatch",
+        explanation: r#"
+The value of a `return` expression has a different type than the return type
+of the function. A function without a return type returns `()`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn is_zero(x: u256) -> bool {
+    return x
+}
+"#,
+            corrected: r#"
+pub fn is_zero(x: u256) -> bool {
+    return x == 0
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0014",
+        title: "type must be known",
+        explanation: r#"
+The type of an expression must be known at this point, e.g. to access its
+fields or call its methods, but it hasn't been inferred yet. Add a type
+annotation.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn first() -> i32 {
+    let pair
+    pair.0
+}
+"#,
+            corrected: r#"
+pub fn first() -> i32 {
+    let pair: (i32, i32) = (1, 2)
+    pair.0
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0015",
+        title: "field is not found",
+        explanation: r#"
+A field access refers to a field, or a tuple index, that the type doesn't have.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn y_of(p: Point) -> i32 {
+    p.z
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub fn y_of(p: Point) -> i32 {
+    p.y
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0016",
+        title: "operator is not implemented for the type",
+        explanation: r#"
+An operator is applied to a type that doesn't implement the corresponding trait
+of `std::ops`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Amount {
+    value: u256,
+}
+
+pub fn total(a: Amount, b: Amount) -> u256 {
+    a + b
+}
+"#,
+            corrected: r#"
+pub struct Amount {
+    value: u256,
+}
+
+pub fn total(a: Amount, b: Amount) -> u256 {
+    a.value + b.value
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0017",
+        title: "left-hand side can't be assigned to",
+        explanation: r#"
+Only variables, fields and indexed elements can be assigned to. Other
+expressions, e.g. calls and literals, don't denote a place to store the value.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn counter() -> u256 {
+    0
+}
+
+pub fn reset() {
+    counter() = 0
+}
+"#,
+            corrected: r#"
+pub fn reset() -> u256 {
+    let mut counter: u256 = 1
+    counter = 0
+    counter
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0018",
+        title: "assignment to an immutable binding",
+        explanation: r#"
+Bindings and arguments are immutable unless they're declared with `mut`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn increment(x: u256) -> u256 {
+    x += 1
+    x
+}
+"#,
+            corrected: r#"
+pub fn increment(mut x: u256) -> u256 {
+    x += 1
+    x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0019",
+        title: "`break` or `continue` outside of a loop",
+        explanation: r#"
+`break` and `continue` can only be used inside of a loop. To leave a function
+early, use `return`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn check(x: u256) -> u256 {
+    if x == 0 {
+        break
+    }
+    x
+}
+"#,
+            corrected: r#"
+pub fn check(x: u256) -> u256 {
+    if x == 0 {
+        return 0
+    }
+    x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0020",
+        title: "trait is not implemented",
+        explanation: r#"
+A language construct requires a type to implement a trait that it doesn't, e.g.
+a `for` loop iterates over a value that isn't an array.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Range {
+    start: u256,
+    end: u256,
+}
+
+pub fn sum(r: Range) -> u256 {
+    let mut total: u256 = 0
+    for i in r {
+        total += i
+    }
+    total
+}
+"#,
+            corrected: r#"
+pub fn sum(values: [u256; 3]) -> u256 {
+    let mut total: u256 = 0
+    for value in values {
+        total += value
+    }
+    total
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0021",
+        title: "expression is not callable",
+        explanation: r#"
+A call expression calls a value that isn't a function.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn apply(rate: u256, amount: u256) -> u256 {
+    rate(amount)
+}
+"#,
+            corrected: r#"
+pub fn apply(rate: u256, amount: u256) -> u256 {
+    rate * amount
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0022",
+        title: "generic argument count mismatch",
+        explanation: r#"
+A call gives a different number of generic arguments than the function declares
+generic parameters. The arguments can also be left out to infer them.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn make_pair<T, U>(_ t: T, _ u: U) -> (T, U) {
+    (t, u)
+}
+
+pub fn pair() -> (u8, u16) {
+    make_pair<u8>(1, 2)
+}
+"#,
+            corrected: r#"
+pub fn make_pair<T, U>(_ t: T, _ u: U) -> (T, U) {
+    (t, u)
+}
+
+pub fn pair() -> (u8, u16) {
+    make_pair<u8, u16>(1, 2)
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0023",
+        title: "argument count mismatch",
+        explanation: r#"
+A call gives a different number of arguments than the function takes.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn add(_ x: u256, _ y: u256) -> u256 {
+    x + y
+}
+
+pub fn increment(x: u256) -> u256 {
+    add(x)
+}
+"#,
+            corrected: r#"
+pub fn add(_ x: u256, _ y: u256) -> u256 {
+    x + y
+}
+
+pub fn increment(x: u256) -> u256 {
+    add(x, 1)
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0024",
+        title: "argument label mismatch",
+        explanation: r#"
+Arguments must be given with the labels of the function's parameters. A
+parameter is labeled with its name unless it's declared with a different label,
+or with `_` to take the argument without a label.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn transfer(to: u256, amount: u256) {}
+
+pub fn pay(recipient: u256) {
+    transfer(recipient, 10)
+}
+"#,
+            corrected: r#"
+pub fn transfer(to: u256, amount: u256) {}
+
+pub fn pay(recipient: u256) {
+    transfer(to: recipient, amount: 10)
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0025",
+        title: "ambiguous method call",
+        explanation: r#"
+More than one inherent `impl` block defines a method with the called name for
+the receiver, because the type of the receiver isn't known precisely enough.
+Add a type annotation.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new() -> Self {
+        unsafe { todo() }
+    }
+}
+
+impl Wrapper<i32> {
+    fn get(self) -> i32 {
+        self.value
+    }
+}
+
+impl Wrapper<u32> {
+    fn get(self) -> u32 {
+        self.value
+    }
+}
+
+extern {
+    fn todo() -> !
+}
+
+pub fn run() {
+    let w = Wrapper::new()
+    let value = w.get()
+}
+"#,
+            corrected: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new() -> Self {
+        unsafe { todo() }
+    }
+}
+
+impl Wrapper<i32> {
+    fn get(self) -> i32 {
+        self.value
+    }
+}
+
+impl Wrapper<u32> {
+    fn get(self) -> u32 {
+        self.value
+    }
+}
+
+extern {
+    fn todo() -> !
+}
+
+pub fn run() {
+    let w: Wrapper<i32> = Wrapper::new()
+    let value = w.get()
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0026",
+        title: "multiple trait candidates for a method",
+        explanation: r#"
+More than one trait in scope provides a method with the called name for the
+receiver, because the type of the receiver isn't known precisely enough. Add a
+type annotation.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new() -> Self {
+        unsafe { todo() }
+    }
+}
+
+trait Getter {
+    fn get(self) -> u32
+}
+
+trait Fetcher {
+    fn get(self) -> i32
+}
+
+impl Getter for Wrapper<u32> {
+    fn get(self) -> u32 {
+        self.value
+    }
+}
+
+impl Fetcher for Wrapper<i32> {
+    fn get(self) -> i32 {
+        self.value
+    }
+}
+
+extern {
+    fn todo() -> !
+}
+
+pub fn run() {
+    let w = Wrapper::new()
+    let value = w.get()
+}
+"#,
+            corrected: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn new() -> Self {
+        unsafe { todo() }
+    }
+}
+
+trait Getter {
+    fn get(self) -> u32
+}
+
+trait Fetcher {
+    fn get(self) -> i32
+}
+
+impl Getter for Wrapper<u32> {
+    fn get(self) -> u32 {
+        self.value
+    }
+}
+
+impl Fetcher for Wrapper<i32> {
+    fn get(self) -> i32 {
+        self.value
+    }
+}
+
+extern {
+    fn todo() -> !
+}
+
+pub fn run() {
+    let w: Wrapper<u32> = Wrapper::new()
+    let value = w.get()
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0027",
+        title: "ambiguous trait implementation",
+        explanation: r#"
+More than one implementation of a generic trait applies to a method call, e.g.
+because the trait is implemented with different arguments for the same type and
+the result type isn't known. Add a type annotation.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+trait Convert<T> {
+    fn convert(self) -> T
+}
+
+impl Convert<i32> for Wrapper<u32> {
+    fn convert(self) -> i32 {
+        0
+    }
+}
+
+impl Convert<u32> for Wrapper<u32> {
+    fn convert(self) -> u32 {
+        self.value
+    }
+}
+
+pub fn run(x: u32) {
+    let w = Wrapper { value: x }
+    let y = w.convert()
+}
+"#,
+            corrected: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+trait Convert<T> {
+    fn convert(self) -> T
+}
+
+impl Convert<i32> for Wrapper<u32> {
+    fn convert(self) -> i32 {
+        0
+    }
+}
+
+impl Convert<u32> for Wrapper<u32> {
+    fn convert(self) -> u32 {
+        self.value
+    }
+}
+
+pub fn run(x: u32) {
+    let w = Wrapper { value: x }
+    let y: u32 = w.convert()
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0028",
+        title: "trait is not in scope",
+        explanation: r#"
+The called method is provided by a trait that isn't in scope. Import the trait
+with `use` to call its methods.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn encode(flag: bool) -> u256 {
+    flag.to_word()
+}
+
+mod convert {
+    pub trait ToWord {
+        fn to_word(self) -> u256
+    }
+
+    impl ToWord for bool {
+        fn to_word(self) -> u256 {
+            1
+        }
+    }
+}
+"#,
+            corrected: r#"
+use convert::ToWord
+
+pub fn encode(flag: bool) -> u256 {
+    flag.to_word()
+}
+
+mod convert {
+    pub trait ToWord {
+        fn to_word(self) -> u256
+    }
+
+    impl ToWord for bool {
+        fn to_word(self) -> u256 {
+            1
+        }
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0029",
+        title: "method is not found",
+        explanation: r#"
+Neither an inherent `impl` block of the type nor a trait in scope defines a
+method or associated function with the given name.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Counter {
+    value: u256,
+}
+
+pub fn make() -> Counter {
+    Counter::new()
+}
+"#,
+            corrected: r#"
+pub struct Counter {
+    value: u256,
+}
+
+impl Counter {
+    fn new() -> Self {
+        Counter { value: 0 }
+    }
+}
+
+pub fn make() -> Counter {
+    Counter::new()
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0030",
+        title: "expected a value",
+        explanation: r#"
+An expression refers to an item that isn't a value, e.g. an enum type instead of
+one of its variants, or a trait.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Direction {
+    Up,
+    Down,
+}
+
+pub fn default_direction() -> Direction {
+    Direction
+}
+"#,
+            corrected: r#"
+pub enum Direction {
+    Up,
+    Down,
+}
+
+pub fn default_direction() -> Direction {
+    Direction::Up
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0031",
+        title: "type annotation is needed",
+        explanation: r#"
+The type of an expression can't be inferred from its uses. Integer literals
+don't have a default type, so a binding that is initialized with a literal
+and never constrained otherwise needs a type annotation.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn init() {
+    let count = 0
+}
+"#,
+            corrected: r#"
+pub fn init() {
+    let count: u256 = 0
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0032",
+        title: "duplicate binding in a pattern",
+        explanation: r#"
+A pattern binds the same name more than once.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn sum(pair: (u256, u256)) -> u256 {
+    let (x, x) = pair
+    x
+}
+"#,
+            corrected: r#"
+pub fn sum(pair: (u256, u256)) -> u256 {
+    let (x, y) = pair
+    x + y
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0033",
+        title: "associated function is called as a method",
+        explanation: r#"
+Method call syntax requires the function to take a `self` parameter. Call an
+associated function without `self` by its path instead.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Adder {}
+
+impl Adder {
+    fn add(_ a: u256, _ b: u256) -> u256 {
+        a + b
+    }
+}
+
+pub fn run(adder: Adder) -> u256 {
+    adder.add(1, 2)
+}
+"#,
+            corrected: r#"
+pub struct Adder {}
+
+impl Adder {
+    fn add(_ a: u256, _ b: u256) -> u256 {
+        a + b
+    }
+}
+
+pub fn run(adder: Adder) -> u256 {
+    Adder::add(1, 2)
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0034",
+        title: "non-exhaustive patterns",
+        explanation: r#"
+The arms of a `match` expression don't cover every possible value of the
+scrutinee. Add arms for the missing values, or a wildcard arm `_`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+pub fn code(c: Color) -> u8 {
+    match c {
+        Color::Red => 0
+        Color::Green => 1
+    }
+}
+"#,
+            corrected: r#"
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+pub fn code(c: Color) -> u8 {
+    match c {
+        Color::Red => 0
+        Color::Green => 1
+        Color::Blue => 2
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0035",
+        title: "unreachable pattern",
+        explanation: r#"
+An arm of a `match` expression can never be taken, because the arms before it
+already cover all values that it matches.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+pub fn code(c: Color) -> u8 {
+    match c {
+        Color::Red => 0
+        _ => 1
+        Color::Blue => 2
+    }
+}
+"#,
+            corrected: r#"
+pub enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+pub fn code(c: Color) -> u8 {
+    match c {
+        Color::Red => 0
+        Color::Blue => 2
+        _ => 1
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0036",
+        title: "refutable pattern in a `let` binding",
+        explanation: r#"
+The pattern of a `let` binding must match every possible value. Use
+`let .. else` to handle the values that don't match.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Lookup {
+    Found(u256),
+    Missing,
+}
+
+pub fn value(l: Lookup) -> u256 {
+    let Lookup::Found(x) = l
+    x
+}
+"#,
+            corrected: r#"
+pub enum Lookup {
+    Found(u256),
+    Missing,
+}
+
+pub fn value(l: Lookup) -> u256 {
+    let Lookup::Found(x) = l else {
+        return 0
+    }
+    x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0037",
+        title: "`else` block of `let .. else` doesn't diverge",
+        explanation: r#"
+The `else` block of `let .. else` runs when the pattern doesn't match, so there
+are no values for the bindings afterwards. The block must not complete
+normally, e.g. it must `return`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Lookup {
+    Found(u256),
+    Missing,
+}
+
+pub fn value(l: Lookup) -> u256 {
+    let Lookup::Found(x) = l else {}
+    x
+}
+"#,
+            corrected: r#"
+pub enum Lookup {
+    Found(u256),
+    Missing,
+}
+
+pub fn value(l: Lookup) -> u256 {
+    let Lookup::Found(x) = l else {
+        return 0
+    }
+    x
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0038",
+        title: "invalid range pattern",
+        explanation: r#"
+The bounds of a range pattern must be integer literals, and the lower bound
+can't be greater than the upper bound.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn bucket(x: u8) -> u8 {
+    match x {
+        9..=0 => 0
+        _ => 1
+    }
+}
+"#,
+            corrected: r#"
+pub fn bucket(x: u8) -> u8 {
+    match x {
+        0..=9 => 0
+        _ => 1
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0039",
+        title: "undeclared label",
+        explanation: r#"
+`break` or `continue` refers to a label that isn't declared by any of the
+enclosing loops.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn spin() {
+    'outer: loop {
+        loop {
+            break 'outr
+        }
+    }
+}
+"#,
+            corrected: r#"
+pub fn spin() {
+    'outer: loop {
+        loop {
+            break 'outer
+        }
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0040",
+        title: "`break` with a value outside of `loop`",
+        explanation: r#"
+Only a `loop` can be broken out of with a value. `for` and `while` loops may
+not run their body at all, so they can't produce a value.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn first_large(values: [u256; 3]) -> u256 {
+    for value in values {
+        if value > 10 {
+            break value
+        }
+    }
+    0
+}
+"#,
+            corrected: r#"
+pub fn first_large(values: [u256; 3]) -> u256 {
+    for value in values {
+        if value > 10 {
+            return value
+        }
+    }
+    0
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0041",
+        title: "not all paths return a value",
+        explanation: r#"
+A function with a return type has a path through its body that ends without
+producing a value, e.g. an `if` without an `else` or a `while` loop.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn sign(b: bool) -> u256 {
+    if b {
+        return 1
+    }
+}
+"#,
+            corrected: r#"
+pub fn sign(b: bool) -> u256 {
+    if b {
+        return 1
+    }
+    0
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0042",
+        title: "use of an uninitialized binding",
+        explanation: r#"
+A binding that is declared without an initializer is used before a value is
+assigned to it on every path.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn fee(premium: bool) -> u256 {
+    let fee: u256
+    if premium {
+        fee = 1
+    }
+    fee
+}
+"#,
+            corrected: r#"
+pub fn fee(premium: bool) -> u256 {
+    let fee: u256
+    if premium {
+        fee = 1
+    } else {
+        fee = 2
+    }
+    fee
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0043",
+        title: "immutable binding is assigned twice",
+        explanation: r#"
+An immutable binding that is declared without an initializer can be assigned
+only once. Declare it with `mut` to assign it again.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn fee() -> u256 {
+    let fee: u256
+    fee = 1
+    fee = 2
+    fee
+}
+"#,
+            corrected: r#"
+pub fn fee() -> u256 {
+    let mut fee: u256
+    fee = 1
+    fee = 2
+    fee
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0044",
+        title: "literal out of range",
+        explanation: r#"
+An integer literal doesn't fit into its type.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn limit() -> u8 {
+    256
+}
+"#,
+            corrected: r#"
+pub fn limit() -> u16 {
+    256
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "8-0045",
+        title: "call to an unsafe function outside of `unsafe`",
+        explanation: r#"
+Functions declared with `unsafe fn`, and extern functions that aren't marked
+with `#safe`, can only be called inside of an `unsafe` block or an unsafe
+function.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+extern {
+    fn read_word(_ addr: u256) -> u256
+}
+
+pub fn load(addr: u256) -> u256 {
+    read_word(addr)
+}
+"#,
+            corrected: r#"
+extern {
+    fn read_word(_ addr: u256) -> u256
+}
+
+pub fn load(addr: u256) -> u256 {
+    unsafe { read_word(addr) }
+}
+"#,
+        }),
+    },
+];
@@ -0,0 +1,573 @@
+use super::{ErrorCodeDoc, Examples};
+
+pub(super) const ERROR_CODES: &[ErrorCodeDoc] = &[
+    ErrorCodeDoc {
+        code: "3-0000",
+        title: "type is not fully applied",
+        explanation: r#"
+A generic type is used where a concrete type is expected, but not all of its
+generic parameters are given. Types of fields, function arguments and local
+variables must have the `*` kind, i.e. all generic arguments must be given.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+pub struct Wallet {
+    balances: Pair<u256>,
+}
+"#,
+            corrected: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+pub struct Wallet {
+    balances: Pair<u256, u256>,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0001",
+        title: "invalid type argument kind",
+        explanation: r#"
+The kind of a generic argument doesn't match the kind of the corresponding
+generic parameter, e.g. a generic type that isn't fully applied is given to a
+parameter of the `*` kind.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+pub struct Nested {
+    inner: Pair<Pair, u8>,
+}
+"#,
+            corrected: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+pub struct Nested {
+    inner: Pair<Pair<u8, u8>, u8>,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0002",
+        title: "recursive type definition",
+        explanation: r#"
+A struct or an enum contains itself, directly or through other types, so its size
+would be infinite. Store an index or a key that refers to the other value
+instead.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Node {
+    value: u256,
+    next: Node,
+}
+"#,
+            corrected: r#"
+pub struct Node {
+    value: u256,
+    next_index: usize,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0003",
+        title: "type alias parameter is not given",
+        explanation: r#"
+Unlike generic types, a generic type alias can't be partially applied. All of
+its generic parameters must be given when it's used.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+type PairOf<T, U> = Pair<T, U>
+
+pub struct Wallet {
+    balances: PairOf<u256>,
+}
+"#,
+            corrected: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+
+type PairOf<T, U> = Pair<T, U>
+
+pub struct Wallet {
+    balances: PairOf<u256, u256>,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0004",
+        title: "type alias cycle",
+        explanation: r#"
+A type alias refers to itself, directly or through other type aliases, so it
+never resolves to a type.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+type Amount = Balance
+type Balance = Amount
+"#,
+            corrected: r#"
+type Amount = u256
+type Balance = Amount
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0005",
+        title: "inconsistent kind bounds",
+        explanation: r#"
+A generic parameter is given more than one kind bound, and the bounds disagree.
+A parameter can only have a single kind.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Functor<F>
+where
+    F: * -> *,
+    F: (* -> *) -> *
+{}
+"#,
+            corrected: r#"
+pub trait Functor<F>
+where
+    F: * -> *
+{}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0006",
+        title: "kind bound is not allowed",
+        explanation: r#"
+A kind bound is given in a position where kinds are not allowed.
+
+Kind bounds are currently allowed on every generic parameter, and only kind
+bounds that contradict each other are reported, as 3-0005.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "3-0007",
+        title: "generic parameter is already defined in the parent item",
+        explanation: r#"
+A method declares a generic parameter with the same name as a generic parameter
+of the enclosing `impl`, `impl trait` or `trait`. The parameter of the parent is
+already in scope, so give the new parameter another name.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn replace<T>(self, value: T) {}
+}
+"#,
+            corrected: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+impl<T> Wrapper<T> {
+    fn replace<U>(self, value: U) {}
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0008",
+        title: "duplicate argument name",
+        explanation: r#"
+Two arguments of a function have the same name. Note that the label of an
+argument, e.g. `to` in `to x: u256`, is separate from its name.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn swap(x: u256, y x: u256) {}
+"#,
+            corrected: r#"
+pub fn swap(x: u256, y: u256) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0009",
+        title: "invalid const parameter type",
+        explanation: r#"
+The type of a const generic parameter must be an integer type or `bool`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub struct Buffer<const N: Point> {}
+"#,
+            corrected: r#"
+pub struct Buffer<const N: usize> {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0010",
+        title: "recursive const parameter type",
+        explanation: r#"
+The type of a const generic parameter refers to the item that declares the
+parameter.
+
+Const parameters can only have integer or `bool` types, which never refer to
+the declaring item, so such a type is reported as 3-0009 instead.
+"#,
+        examples: None,
+    },
+    ErrorCodeDoc {
+        code: "3-0011",
+        title: "const argument type mismatch",
+        explanation: r#"
+The type of a const generic argument doesn't match the type of the
+corresponding const parameter.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Buffer<const N: u32> {}
+
+pub fn clear(buf: Buffer<true>) {}
+"#,
+            corrected: r#"
+pub struct Buffer<const N: u32> {}
+
+pub fn clear(buf: Buffer<32>) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0012",
+        title: "expected a const argument",
+        explanation: r#"
+A type is given to a const generic parameter. Const parameters only accept
+values, e.g. integer literals.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Buffer<const N: u256> {}
+
+pub fn clear(buf: Buffer<u32>) {}
+"#,
+            corrected: r#"
+pub struct Buffer<const N: u256> {}
+
+pub fn clear(buf: Buffer<32>) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0013",
+        title: "expected a type argument",
+        explanation: r#"
+A value is given to a generic parameter that expects a type. To accept values,
+declare the parameter with `const`.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+pub fn unwrap(w: Wrapper<1>) {}
+"#,
+            corrected: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+pub fn unwrap(w: Wrapper<u256>) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0014",
+        title: "associated type is not found",
+        explanation: r#"
+A path refers to an associated type that none of the traits implemented by, or
+bounding, the type declares.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Container {}
+
+pub fn take<C: Container>(item: C::Item) {}
+"#,
+            corrected: r#"
+pub trait Container {
+    type Item
+}
+
+pub fn take<C: Container>(item: C::Item) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0015",
+        title: "invalid const expression in a type",
+        explanation: r#"
+Only literals and paths to consts can be used as const arguments and array
+lengths. Other expressions, e.g. arithmetic, are not evaluated in types.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Buffer {
+    data: [u8; 2 * 16],
+}
+"#,
+            corrected: r#"
+pub struct Buffer {
+    data: [u8; 32],
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0016",
+        title: "too many generic arguments",
+        explanation: r#"
+A type is given more generic arguments than it declares generic parameters.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+pub fn unwrap(w: Wrapper<i32, u32>) {}
+"#,
+            corrected: r#"
+pub struct Wrapper<T> {
+    value: T,
+}
+
+pub fn unwrap(w: Wrapper<i32>) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0017",
+        title: "duplicate field name",
+        explanation: r#"
+Two fields of a struct, or of a record variant of an enum, have the same name.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Point {
+    x: i32,
+    x: i32,
+}
+"#,
+            corrected: r#"
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0018",
+        title: "duplicate variant name",
+        explanation: r#"
+Two variants of an enum have the same name.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub enum Direction {
+    Up,
+    Down,
+    Up,
+}
+"#,
+            corrected: r#"
+pub enum Direction {
+    Up,
+    Down,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0019",
+        title: "duplicate generic parameter name",
+        explanation: r#"
+Two generic parameters of a struct, enum or contract have the same name.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub struct Pair<T, T> {
+    first: T,
+    second: T,
+}
+"#,
+            corrected: r#"
+pub struct Pair<T, U> {
+    first: T,
+    second: U,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0020",
+        title: "duplicate argument label",
+        explanation: r#"
+Two arguments of a function have the same label, so a call can't tell them
+apart.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn transfer(to x: u256, to y: u256) {}
+"#,
+            corrected: r#"
+pub fn transfer(from x: u256, to y: u256) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0021",
+        title: "ambiguous associated type",
+        explanation: r#"
+More than one trait bounding a type declares an associated type with the given
+name, so it's unclear which one is meant.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub trait Container {
+    type Item
+}
+
+pub trait Stream {
+    type Item
+}
+
+pub fn take<T: Container + Stream>(item: T::Item) {}
+"#,
+            corrected: r#"
+pub trait Container {
+    type Item
+}
+
+pub trait Stream {
+    type Element
+}
+
+pub fn take<T: Container + Stream>(item: T::Item) {}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0022",
+        title: "trait can't be derived",
+        explanation: r#"
+Only `Clone`, `Default` and `Eq` can be given to `#derive(..)`. Other traits
+have to be implemented with an `impl` block.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+#derive(Eq, Hash)
+pub enum Direction {
+    Up,
+    Down,
+}
+"#,
+            corrected: r#"
+#derive(Eq)
+pub enum Direction {
+    Up,
+    Down,
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0023",
+        title: "`Default` can't be derived for an enum",
+        explanation: r#"
+A derived `Default` implementation constructs the default value of each field,
+which doesn't work for enums since there's no obvious default variant.
+Implement `Default` by hand instead.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+#derive(Default)
+pub enum Toggle {
+    On,
+    Off,
+}
+"#,
+            corrected: r#"
+use core::Default
+
+pub enum Toggle {
+    On,
+    Off,
+}
+
+impl Default for Toggle {
+    fn default() -> Self {
+        Toggle::Off
+    }
+}
+"#,
+        }),
+    },
+    ErrorCodeDoc {
+        code: "3-0100",
+        title: "conflicting definitions",
+        explanation: r#"
+Two items in the same scope have the same name and live in the same namespace,
+e.g. a function and a const.
+"#,
+        examples: Some(Examples {
+            erroneous: r#"
+pub fn limit() -> u256 {
+    100
+}
+
+pub const limit: u256 = 100
+"#,
+            corrected: r#"
+pub fn limit() -> u256 {
+    LIMIT
+}
+
+pub const LIMIT: u256 = 100
+"#,
+        }),
+    },
+];
@@ -1,18 +1,33 @@
+pub mod cache;
 pub mod db;
 pub mod diagnostics;
+pub mod explain;
 pub mod files;
+pub mod new;
+pub mod timings;
+pub mod watch;
 
-use camino::Utf8PathBuf;
+use camino::{Utf8Path, Utf8PathBuf};
+use common::config::Config;
 use common::core::HasBuiltinCore;
 use common::ingot::IngotBaseUrl;
 
+use cache::{AnalysisCache, CACHE_DIR};
+use common::diagnostics::CompleteDiagnostic;
 use common::InputDb;
+use db::DiagnosticsCollection;
 pub use db::DriverDataBase;
+use timings::Timings;
 
-use clap::{Parser, Subcommand};
+use clap::{Parser, Subcommand, ValueEnum};
 use hir::hir_def::TopLevelMod;
 use resolver::{
-    ingot::{source_files::SourceFiles, Ingot, IngotResolver},
+    ingot::{
+        dependency::{DependencyResolver, DependencySource, ResolvedDependency},
+        lock::{Lockfile, LOCKFILE_NAME},
+        source_files::SourceFiles,
+        Ingot, IngotResolver,
+    },
     Resolver,
 };
 use url::Url;
@@ -20,7 +35,19 @@ use url::Url;
 pub fn run(opts: &Options) {
     match &opts.command {
         Command::Build => eprintln!("`fe build` doesn't work at the moment"),
-        Command::Check { path, core } => {
+        Command::Check {
+            path,
+            core,
+            deny_warnings,
+            locked,
+            registry,
+            format,
+            watch,
+            timings,
+            trace,
+            query_stats,
+            no_cache,
+        } => {
             let mut db = DriverDataBase::default();
             let mut ingot_resolver = IngotResolver::default();
 
@@ -112,102 +139,110 @@ pub fn run(opts: &Options) {
                 db.builtin_core().base(&db)
             };
 
-            if !path.exists() {
-                eprintln!("the path `{path}` does not exist");
-                std::process::exit(1)
-            }
+            let ingots = load_ingots(
+                &mut db,
+                &mut ingot_resolver,
+                path,
+                core_url,
+                registry.as_ref(),
+                *locked,
+            );
 
-            let path_url = match path.canonicalize_utf8() {
-                Ok(canonical_path) => {
-                    if canonical_path.is_file() {
-                        Url::from_file_path(canonical_path)
-                            .expect("unable to create file url from directory path ")
-                    } else {
-                        Url::from_directory_path(canonical_path)
-                            .expect("unable to create directory url from canonical path")
-                    }
-                }
-                Err(err) => {
-                    eprintln!("failed to canonicalize path `{path}`: {err}");
-                    std::process::exit(1)
-                }
-            };
+            let cache = (!*no_cache).then(|| {
+                let root = if path.is_file() {
+                    path.parent().unwrap_or(Utf8Path::new(""))
+                } else {
+                    path.as_path()
+                };
+                AnalysisCache::new(root.join(CACHE_DIR))
+            });
 
-            let local_url = match ingot_resolver.resolve(&path_url) {
-                Ok(Ingot::Folder {
-                    config,
-                    source_files:
-                        Some(SourceFiles {
-                            root: Some(_root),
-                            files,
-                        }),
-                }) => {
-                    let base_url = Url::from_directory_path(path.canonicalize_utf8().unwrap())
-                        .expect("failed to parse base URL");
-
-                    let diagnostics = ingot_resolver.take_diagnostics();
-                    if !diagnostics.is_empty() {
-                        eprintln!("an error was encountered while resolving `{path}`");
-                        for diagnostic in diagnostics {
-                            eprintln!("{diagnostic}")
-                        }
-                        std::process::exit(1)
+            if *query_stats {
+                db.record_query_stats();
+            }
+            let record_timings = *timings || trace.is_some();
+            let check = |db: &DriverDataBase| {
+                let mut pass_timings = record_timings.then(Timings::new);
+                let mut reporter = Reporter::new(*format);
+                let failed = check_ingots(
+                    db,
+                    &ingots,
+                    &mut reporter,
+                    *deny_warnings,
+                    pass_timings.as_mut(),
+                    cache.as_ref(),
+                );
+                reporter.finish(db);
+                if let Some(pass_timings) = pass_timings {
+                    if *timings {
+                        eprint!("\n{}", pass_timings.format_table());
                     }
-                    let index = db.workspace();
-                    if let Some(config) = config {
-                        let config_url = config.url;
-                        index.touch_ingot(&mut db, &base_url, Some(config.content));
-                        let config = base_url
-                            .ingot(&db)
-                            .expect("local ingot should exist")
-                            .config(&db)
-                            .expect("local ingot config should exist");
-                        if let Some(diagnostics) = config.formatted_diagnostics() {
-                            eprintln!("there are issues with the local fe.toml file {config_url}",);
-                            eprintln!("{diagnostics}");
-                            std::process::exit(1)
+                    if let Some(trace) = trace {
+                        if let Err(err) = std::fs::write(trace, pass_timings.chrome_trace()) {
+                            eprintln!("failed to write `{trace}`: {err}");
                         }
-                    } else {
-                        index.touch_ingot(&mut db, &base_url, None);
-                    };
-
-                    for (file_url, content) in files {
-                        index.touch(&mut db, file_url, Some(content));
                     }
-                    base_url
                 }
-                Ok(Ingot::SingleFile { url, content }) => {
-                    db.workspace().touch(&mut db, url.clone(), Some(content));
-                    url
+                if let Some(query_stats) = db.take_query_stats() {
+                    eprint!("\n{}", query_stats.format_table());
                 }
-                Ok(_) => {
-                    for diagnostic in ingot_resolver.take_diagnostics() {
-                        eprintln!("{diagnostic}")
+                let hits = cache.as_ref().map(AnalysisCache::take_hits);
+                if record_timings || *query_stats {
+                    for url in hits.into_iter().flatten() {
+                        eprintln!(
+                            "note: `{url}` was loaded from the analysis cache and isn't \
+                             included in the measurements; pass `--no-cache` to analyze it"
+                        );
                     }
-                    std::process::exit(1)
-                }
-                Err(error) => {
-                    eprintln!("{error}: {path}");
-                    std::process::exit(1)
                 }
+                failed
             };
 
-            let core_source_diags =
-                db.run_on_ingot(core_url.ingot(&db).expect("core ingot should exist"));
-            if !core_source_diags.is_empty() {
-                eprintln!("errors in {core_url}\n");
-                core_source_diags.emit(&db);
-                std::process::exit(1);
+            if !*watch {
+                if check(&db) {
+                    std::process::exit(1);
+                }
+                return;
             }
 
-            let local_source_diags = db.run_on_ingot(local_url.ingot(&db).unwrap());
-            if !local_source_diags.is_empty() {
-                eprintln!("errors in {local_url}\n");
-                local_source_diags.emit(&db);
-                std::process::exit(1);
+            let mut roots: Vec<_> = ingots
+                .locals
+                .iter()
+                .map(|url| watch::WatchRoot {
+                    path: url
+                        .to_file_path()
+                        .ok()
+                        .and_then(|path| Utf8PathBuf::from_path_buf(path).ok())
+                        .expect("local ingot URL should be a UTF-8 file path"),
+                    url: url.clone(),
+                })
+                .collect();
+            if let Some(core_path) = core {
+                roots.push(watch::WatchRoot {
+                    path: core_path
+                        .canonicalize_utf8()
+                        .expect("core path was canonicalized before"),
+                    url: ingots.core.clone(),
+                });
+            }
+            watch::watch(&mut db, &roots, |db| {
+                check(db);
+            })
+        }
+        Command::New { path, lib } => {
+            if let Err(err) = new::new_ingot(path, *lib) {
+                eprintln!("{err}");
+                std::process::exit(1)
             }
+            eprintln!("created ingot at `{path}`");
         }
-        Command::New => eprintln!("`fe new` doesn't work at the moment"),
+        Command::Explain { code } => match explain::explain(code) {
+            Some(explanation) => print!("{explanation}"),
+            None => {
+                eprintln!("no explanation for `{code}`");
+                std::process::exit(1)
+            }
+        },
     }
 }
 
@@ -226,8 +261,381 @@ pub enum Command {
         path: Utf8PathBuf,
         #[arg(short, long)]
         core: Option<Utf8PathBuf>,
+        /// Exit with an error if there are any warnings.
+        #[arg(long)]
+        deny_warnings: bool,
+        /// Exit with an error if `fe.lock` is missing or out of date instead
+        /// of updating it.
+        #[arg(long)]
+        locked: bool,
+        /// The registry directory that versioned dependencies are looked up in.
+        #[arg(long)]
+        registry: Option<Utf8PathBuf>,
+        /// The format diagnostics are reported in.
+        #[arg(long, value_enum, default_value_t = OutputFormat::Human)]
+        format: OutputFormat,
+        /// Re-check the ingots whenever their files change.
+        #[arg(long)]
+        watch: bool,
+        /// Print how long each analysis pass and each module took.
+        #[arg(long)]
+        timings: bool,
+        /// Write the pass timings as a Chrome trace to the given file.
+        #[arg(long, value_name = "FILE")]
+        trace: Option<Utf8PathBuf>,
+        /// Print how many times each query was executed.
+        #[arg(long)]
+        query_stats: bool,
+        /// Don't read or write the analysis cache in `.fe/cache`.
+        #[arg(long)]
+        no_cache: bool,
     },
-    New,
+    /// Create a new ingot.
+    New {
+        path: Utf8PathBuf,
+        /// Create a library ingot without a sample contract.
+        #[arg(long)]
+        lib: bool,
+    },
+    /// Explain an error code.
+    Explain {
+        /// The error code, e.g. `8-0000`.
+        code: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human readable diagnostics, written to stderr.
+    Human,
+    /// One JSON object per diagnostic and line, written to stdout.
+    Json,
+    /// A SARIF 2.1.0 log, written to stdout.
+    Sarif,
+}
+
+/// Reports the diagnostics of the checked ingots in the requested format.
+///
+/// Human readable diagnostics are emitted right away, while the machine
+/// readable formats are collected and written as a whole by `finish`.
+struct Reporter {
+    format: OutputFormat,
+    diagnostics: Vec<CompleteDiagnostic>,
+}
+
+impl Reporter {
+    fn new(format: OutputFormat) -> Self {
+        Self {
+            format,
+            diagnostics: vec![],
+        }
+    }
+
+    fn report(&mut self, db: &DriverDataBase, header: &str, diags: &DiagnosticsCollection) {
+        match self.format {
+            OutputFormat::Human => {
+                eprintln!("{header}\n");
+                diags.emit(db);
+            }
+            OutputFormat::Json | OutputFormat::Sarif => self.diagnostics.extend(diags.finalize()),
+        }
+    }
+
+    fn finish(self, db: &DriverDataBase) {
+        match self.format {
+            OutputFormat::Human => {}
+            OutputFormat::Json => print!("{}", diagnostics::format_json(db, &self.diagnostics)),
+            OutputFormat::Sarif => print!("{}", diagnostics::format_sarif(db, &self.diagnostics)),
+        }
+    }
+}
+
+/// The ingots analyzed by `fe check`.
+struct CheckedIngots {
+    core: Url,
+    dependencies: Vec<Url>,
+    locals: Vec<Url>,
+}
+
+/// Checks the core ingot, the dependencies and the local ingots, and reports
+/// their diagnostics to `reporter`.
+///
+/// The local ingots are only checked if the core ingot and the dependencies
+/// are free of errors. Ingots whose diagnostics are in the `cache` aren't
+/// analyzed again. Returns whether the check failed.
+fn check_ingots(
+    db: &DriverDataBase,
+    ingots: &CheckedIngots,
+    reporter: &mut Reporter,
+    deny_warnings: bool,
+    mut timings: Option<&mut Timings>,
+    cache: Option<&AnalysisCache>,
+) -> bool {
+    let mut run_on_ingot = |url: &Url| {
+        let inputs = cache.map(|_| cache::fingerprint(db, url));
+        let cache = cache.zip(inputs.as_ref());
+        let modules = match cache.and_then(|(cache, inputs)| cache.load(db, url, inputs)) {
+            Some(modules) => modules,
+            None => {
+                let ingot = url.ingot(db).expect("checked ingot should exist");
+                let modules = db.run_on_ingot_by_module(ingot, timings.as_deref_mut());
+                if let Some((cache, inputs)) = cache {
+                    cache.store(db, url, inputs, &modules);
+                }
+                modules
+            }
+        };
+        DiagnosticsCollection::merge(modules.into_iter().map(|(_, diags)| diags))
+    };
+
+    let core_url = &ingots.core;
+    let core_source_diags = run_on_ingot(core_url);
+    if core_source_diags.has_errors() {
+        reporter.report(db, &format!("errors in {core_url}"), &core_source_diags);
+        return true;
+    }
+
+    for dependency_url in &ingots.dependencies {
+        let dependency_diags = run_on_ingot(dependency_url);
+        if dependency_diags.has_errors() {
+            reporter.report(
+                db,
+                &format!("errors in {dependency_url}"),
+                &dependency_diags,
+            );
+            return true;
+        }
+    }
+
+    let mut failed = false;
+    for local_url in &ingots.locals {
+        let local_source_diags = run_on_ingot(local_url);
+        if local_source_diags.is_empty() {
+            continue;
+        }
+        let has_errors = local_source_diags.has_errors();
+        let header = if has_errors {
+            format!("errors in {local_url}")
+        } else {
+            format!("warnings in {local_url}")
+        };
+        reporter.report(db, &header, &local_source_diags);
+        failed |= has_errors || deny_warnings;
+    }
+    failed
+}
+
+/// Loads the ingot at `path`, or every member ingot if `path` is a workspace
+/// root, into the database together with their dependencies.
+fn load_ingots(
+    db: &mut DriverDataBase,
+    ingot_resolver: &mut IngotResolver,
+    path: &Utf8Path,
+    core: Url,
+    registry: Option<&Utf8PathBuf>,
+    locked: bool,
+) -> CheckedIngots {
+    let members = workspace_members(path).unwrap_or_else(|| vec![path.to_path_buf()]);
+    let mut local_urls = vec![];
+    let mut dependency_urls = vec![];
+    for member in &members {
+        let (local_url, member_dependency_urls) =
+            load_local_ingot(db, ingot_resolver, member, registry, locked);
+        local_urls.push(local_url);
+        for url in member_dependency_urls {
+            if !dependency_urls.contains(&url) {
+                dependency_urls.push(url);
+            }
+        }
+    }
+    // Members that depend on each other are reported as members.
+    dependency_urls.retain(|url| !local_urls.contains(url));
+    CheckedIngots {
+        core,
+        dependencies: dependency_urls,
+        locals: local_urls,
+    }
+}
+
+/// Returns the member ingots of the workspace at `path`, or `None` if `path`
+/// isn't a workspace root.
+///
+/// The root itself is a member if its `fe.toml` also describes an ingot.
+fn workspace_members(path: &Utf8Path) -> Option<Vec<Utf8PathBuf>> {
+    let config_path = path.join("fe.toml");
+    let content = std::fs::read_to_string(&config_path).ok()?;
+    let config = Config::parse(&content).ok()?;
+    let workspace = config.workspace.as_ref()?;
+    if let Some(diagnostics) = config.formatted_diagnostics() {
+        eprintln!("there are issues with the workspace fe.toml file {config_path}");
+        eprintln!("{diagnostics}");
+        std::process::exit(1)
+    }
+
+    let mut members = vec![];
+    if config.metadata.name.is_some() {
+        members.push(path.to_path_buf());
+    }
+    members.extend(workspace.members.iter().map(|member| path.join(member)));
+    Some(members)
+}
+
+/// Loads the ingot at `path` and its dependencies into the database.
+///
+/// Returns the URL of the ingot and the URLs of its dependencies.
+fn load_local_ingot(
+    db: &mut DriverDataBase,
+    ingot_resolver: &mut IngotResolver,
+    path: &Utf8Path,
+    registry: Option<&Utf8PathBuf>,
+    locked: bool,
+) -> (Url, Vec<Url>) {
+    if !path.exists() {
+        eprintln!("the path `{path}` does not exist");
+        std::process::exit(1)
+    }
+
+    let path_url = match path.canonicalize_utf8() {
+        Ok(canonical_path) => {
+            if canonical_path.is_file() {
+                Url::from_file_path(canonical_path)
+                    .expect("unable to create file url from directory path ")
+            } else {
+                Url::from_directory_path(canonical_path)
+                    .expect("unable to create directory url from canonical path")
+            }
+        }
+        Err(err) => {
+            eprintln!("failed to canonicalize path `{path}`: {err}");
+            std::process::exit(1)
+        }
+    };
+
+    let mut dependency_urls = vec![];
+    let local_url = match ingot_resolver.resolve(&path_url) {
+        Ok(Ingot::Folder {
+            config,
+            source_files:
+                Some(SourceFiles {
+                    root: Some(_root),
+                    files,
+                }),
+        }) => {
+            let base_url = Url::from_directory_path(path.canonicalize_utf8().unwrap())
+                .expect("failed to parse base URL");
+
+            let diagnostics = ingot_resolver.take_diagnostics();
+            if !diagnostics.is_empty() {
+                eprintln!("an error was encountered while resolving `{path}`");
+                for diagnostic in diagnostics {
+                    eprintln!("{diagnostic}")
+                }
+                std::process::exit(1)
+            }
+            let index = db.workspace();
+            if let Some(config) = config {
+                let config_url = config.url;
+                index.touch_ingot(db, &base_url, Some(config.content));
+                let config = base_url
+                    .ingot(db)
+                    .expect("local ingot should exist")
+                    .config(db)
+                    .expect("local ingot config should exist");
+                if let Some(diagnostics) = config.formatted_diagnostics() {
+                    eprintln!("there are issues with the local fe.toml file {config_url}",);
+                    eprintln!("{diagnostics}");
+                    std::process::exit(1)
+                }
+            } else {
+                index.touch_ingot(db, &base_url, None);
+            };
+
+            for (file_url, content) in files {
+                index.touch(db, file_url, Some(content));
+            }
+
+            let mut dependency_resolver = DependencyResolver::default();
+            if let Some(registry) = registry {
+                dependency_resolver = dependency_resolver.with_registry(registry.clone());
+            }
+            let dependencies = match dependency_resolver.resolve(&base_url) {
+                Ok(dependencies) => dependencies,
+                Err(error) => {
+                    eprintln!("{error}");
+                    std::process::exit(1)
+                }
+            };
+            let diagnostics = dependency_resolver.take_diagnostics();
+            if !diagnostics.is_empty() {
+                eprintln!("an error was encountered while resolving the dependencies of `{path}`");
+                for diagnostic in diagnostics {
+                    eprintln!("{diagnostic}")
+                }
+                std::process::exit(1)
+            }
+            if let Err(err) = sync_lockfile(path, &base_url, &dependencies, locked) {
+                eprintln!("{err}");
+                std::process::exit(1)
+            }
+            for dependency in dependencies {
+                if let (DependencySource::Vendor | DependencySource::Registry, Some(name)) =
+                    (dependency.source, &dependency.metadata.name)
+                {
+                    index.register_ingot(db, name.clone(), dependency.url.clone());
+                }
+                index.touch_ingot(db, &dependency.url, Some(dependency.config.content));
+                for (file_url, content) in dependency.files {
+                    index.touch(db, file_url, Some(content));
+                }
+                dependency_urls.push(dependency.url);
+            }
+            base_url
+        }
+        Ok(Ingot::SingleFile { url, content }) => {
+            db.workspace().touch(db, url.clone(), Some(content));
+            url
+        }
+        Ok(_) => {
+            for diagnostic in ingot_resolver.take_diagnostics() {
+                eprintln!("{diagnostic}")
+            }
+            std::process::exit(1)
+        }
+        Err(error) => {
+            eprintln!("{error}: {path}");
+            std::process::exit(1)
+        }
+    };
+
+    (local_url, dependency_urls)
+}
+
+/// Writes the lockfile of the ingot at `path`, or checks that it is up to date
+/// if `locked` is set.
+fn sync_lockfile(
+    path: &Utf8Path,
+    base_url: &Url,
+    dependencies: &[ResolvedDependency],
+    locked: bool,
+) -> Result<(), String> {
+    let lockfile_path = path.join(LOCKFILE_NAME);
+    let lockfile = Lockfile::new(base_url, dependencies);
+    let up_to_date = match std::fs::read_to_string(&lockfile_path) {
+        Ok(content) => Lockfile::parse(&content).is_ok_and(|existing| existing == lockfile),
+        // An ingot without dependencies doesn't need a lockfile.
+        Err(_) => dependencies.is_empty(),
+    };
+    if up_to_date {
+        return Ok(());
+    }
+
+    if locked {
+        return Err(format!(
+            "the lockfile `{lockfile_path}` is out of date, run `fe check` without `--locked` to update it"
+        ));
+    }
+    std::fs::write(&lockfile_path, lockfile.to_string())
+        .map_err(|err| format!("failed to write `{lockfile_path}`: {err}"))
 }
 
 fn _dump_scope_graph(db: &DriverDataBase, top_mod: TopLevelMod) -> String {
@@ -237,3 +645,119 @@ fn _dump_scope_graph(db: &DriverDataBase, top_mod: TopLevelMod) -> String {
 }
 
 // Maybe the driver should eventually only support WASI?
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_ingot(path: &Utf8Path, config: &str) {
+        std::fs::create_dir_all(path.join("src")).unwrap();
+        std::fs::write(path.join("fe.toml"), config).unwrap();
+        std::fs::write(path.join("src/lib.fe"), "").unwrap();
+    }
+
+    fn resolve(path: &Utf8Path) -> (Url, Vec<ResolvedDependency>) {
+        let base_url = Url::from_directory_path(path).unwrap();
+        let mut resolver = DependencyResolver::default();
+        let dependencies = resolver.resolve(&base_url).unwrap();
+        assert!(resolver.take_diagnostics().is_empty());
+        (base_url, dependencies)
+    }
+
+    #[test]
+    fn stale_lockfile() {
+        let root = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fe-lockfile-{}", std::process::id()));
+        let app = root.join("app");
+        write_ingot(
+            &app,
+            "[ingot]\nname = \"app\"\nversion = \"0.1.0\"\n[dependencies]\nlib = \"../lib\"\n",
+        );
+        write_ingot(
+            &root.join("lib"),
+            "[ingot]\nname = \"lib\"\nversion = \"1.0.0\"\n",
+        );
+
+        // A missing lockfile is stale, and is only written without `--locked`.
+        let (base_url, dependencies) = resolve(&app);
+        assert!(sync_lockfile(&app, &base_url, &dependencies, true).is_err());
+        assert!(!app.join(LOCKFILE_NAME).exists());
+        sync_lockfile(&app, &base_url, &dependencies, false).unwrap();
+        sync_lockfile(&app, &base_url, &dependencies, true).unwrap();
+
+        // Changing a dependency makes the lockfile stale.
+        std::fs::write(root.join("lib/src/lib.fe"), "pub fn foo() {}").unwrap();
+        let (base_url, dependencies) = resolve(&app);
+        let err = sync_lockfile(&app, &base_url, &dependencies, true).unwrap_err();
+        assert!(err.contains("is out of date"));
+        sync_lockfile(&app, &base_url, &dependencies, false).unwrap();
+        sync_lockfile(&app, &base_url, &dependencies, true).unwrap();
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn workspace() {
+        let root = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fe-workspace-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(
+            root.join("fe.toml"),
+            "[workspace]\nmembers = [\"a\", \"b\"]\n",
+        )
+        .unwrap();
+        write_ingot(
+            &root.join("a"),
+            "[ingot]\nname = \"a\"\nversion = \"0.1.0\"\n",
+        );
+        write_ingot(
+            &root.join("b"),
+            "[ingot]\nname = \"b\"\nversion = \"0.1.0\"\n[dependencies]\na = \"../a\"\n",
+        );
+        for member in ["a", "b"] {
+            std::fs::write(
+                root.join(member).join("src/lib.fe"),
+                "pub fn foo() -> u256 { true }",
+            )
+            .unwrap();
+        }
+
+        let mut db = DriverDataBase::default();
+        let core = db.builtin_core().base(&db);
+        let ingots = load_ingots(
+            &mut db,
+            &mut IngotResolver::default(),
+            &root,
+            core,
+            None,
+            false,
+        );
+
+        // `b` depends on `a`, which is still checked as a member.
+        let member_url = |name: &str| {
+            Url::from_directory_path(root.join(name).canonicalize_utf8().unwrap()).unwrap()
+        };
+        assert_eq!(ingots.locals, [member_url("a"), member_url("b")]);
+        assert!(ingots.dependencies.is_empty());
+
+        // Each member is analyzed once, and its error is reported for it.
+        let mut reporter = Reporter::new(OutputFormat::Json);
+        assert!(check_ingots(&db, &ingots, &mut reporter, false, None, None));
+        let files: Vec<_> = reporter
+            .diagnostics
+            .iter()
+            .map(|diag| diag.primary_span().file.url(&db).unwrap())
+            .collect();
+        assert_eq!(
+            files,
+            [
+                member_url("a").join("src/lib.fe").unwrap(),
+                member_url("b").join("src/lib.fe").unwrap(),
+            ]
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,145 @@
+use std::{fmt, fs, io};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use common::config::is_valid_name;
+
+const INITIAL_VERSION: &str = "0.1.0";
+
+const CONTRACT_TEMPLATE: &str = r#"pub contract Counter {
+    value: u256,
+}
+
+pub fn increment(value: u256) -> u256 {
+    value + 1
+}
+"#;
+
+const LIB_TEMPLATE: &str = r#"pub fn add(x: u256, y: u256) -> u256 {
+    x + y
+}
+"#;
+
+#[derive(Debug)]
+pub enum NewIngotError {
+    InvalidName(String),
+    AlreadyExists(Utf8PathBuf),
+    Io(Utf8PathBuf, io::Error),
+}
+
+impl fmt::Display for NewIngotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(
+                f,
+                "invalid ingot name `{name}`, names may only contain alphanumeric characters and `-`"
+            ),
+            Self::AlreadyExists(path) => write!(f, "the path `{path}` already exists"),
+            Self::Io(path, err) => write!(f, "failed to write `{path}`: {err}"),
+        }
+    }
+}
+
+/// Creates a new ingot at `path`, named after the last component of the path.
+///
+/// The ingot contains an `fe.toml` and a `src/lib.fe` with a sample contract,
+/// or a sample function if `lib` is set.
+pub fn new_ingot(path: &Utf8Path, lib: bool) -> Result<(), NewIngotError> {
+    let name = path.file_name().unwrap_or_default();
+    if !is_valid_name(name) {
+        return Err(NewIngotError::InvalidName(name.to_string()));
+    }
+    if path.exists() {
+        return Err(NewIngotError::AlreadyExists(path.to_path_buf()));
+    }
+
+    let src = path.join("src");
+    fs::create_dir_all(&src).map_err(|err| NewIngotError::Io(src.clone(), err))?;
+
+    let files = [
+        (path.join("fe.toml"), config_content(name)),
+        (
+            src.join("lib.fe"),
+            if lib { LIB_TEMPLATE } else { CONTRACT_TEMPLATE }.to_string(),
+        ),
+    ];
+    for (file, content) in files {
+        fs::write(&file, content).map_err(|err| NewIngotError::Io(file, err))?;
+    }
+
+    Ok(())
+}
+
+fn config_content(name: &str) -> String {
+    format!("[ingot]\nname = \"{name}\"\nversion = \"{INITIAL_VERSION}\"\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{config::Config, ingot::IngotBaseUrl, InputDb};
+    use url::Url;
+
+    use super::*;
+    use crate::DriverDataBase;
+
+    fn temp_path(name: &str) -> Utf8PathBuf {
+        Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fe-new-{}", std::process::id()))
+            .join(name)
+    }
+
+    /// Loads the ingot at `path` from disk and checks it.
+    fn check(path: &Utf8Path) -> String {
+        let mut db = DriverDataBase::default();
+        let base_url = Url::from_directory_path(path.canonicalize_utf8().unwrap()).unwrap();
+        let index = db.workspace();
+        let config = fs::read_to_string(path.join("fe.toml")).unwrap();
+        index.touch_ingot(&mut db, &base_url, Some(config));
+        let source = fs::read_to_string(path.join("src/lib.fe")).unwrap();
+        index.touch(&mut db, base_url.join("src/lib.fe").unwrap(), Some(source));
+
+        let diags = db.run_on_ingot(base_url.ingot(&db).unwrap());
+        diags.format_diags(&db)
+    }
+
+    #[test]
+    fn samples_check() {
+        for (name, lib) in [("contract-ingot", false), ("lib-ingot", true)] {
+            let path = temp_path(name);
+            new_ingot(&path, lib).unwrap();
+            assert!(path.join("fe.toml").is_file());
+            assert!(path.join("src/lib.fe").is_file());
+            assert_eq!(check(&path), "", "the `{name}` sample has diagnostics");
+            fs::remove_dir_all(&path).unwrap();
+        }
+    }
+
+    #[test]
+    fn existing_path() {
+        let path = temp_path("existing-ingot");
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("fe.toml"), "keep").unwrap();
+        assert!(matches!(
+            new_ingot(&path, false),
+            Err(NewIngotError::AlreadyExists(_))
+        ));
+        assert_eq!(fs::read_to_string(path.join("fe.toml")).unwrap(), "keep");
+        assert!(!path.join("src").exists());
+        fs::remove_dir_all(&path).unwrap();
+    }
+
+    #[test]
+    fn config_is_valid() {
+        let config = Config::parse(&config_content("my-ingot")).unwrap();
+        assert!(config.diagnostics.is_empty());
+        assert_eq!(config.metadata.name.as_deref(), Some("my-ingot"));
+    }
+
+    #[test]
+    fn invalid_name() {
+        assert!(matches!(
+            new_ingot(Utf8Path::new("my_ingot"), false),
+            Err(NewIngotError::InvalidName(_))
+        ));
+    }
+}
@@ -0,0 +1,266 @@
+//! `fe check --timings` and `--query-stats`: reports where the time of a check
+//! is spent.
+
+use std::{
+    collections::HashMap,
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use hir_analysis::analysis_pass::PassTiming;
+use salsa::DatabaseKeyIndex;
+use serde::Serialize;
+
+/// The pass timings of a check, see [`crate::DriverDataBase::run_on_ingot_by_module`].
+#[derive(Debug, Clone)]
+pub struct Timings {
+    start: Instant,
+    passes: Vec<PassTiming>,
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Timings {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            passes: vec![],
+        }
+    }
+
+    pub fn extend(&mut self, timings: impl IntoIterator<Item = PassTiming>) {
+        self.passes.extend(timings);
+    }
+
+    /// Formats the total time of each pass and of each module as tables,
+    /// sorted from the slowest to the fastest. The modules are analyzed in
+    /// parallel, so the total is the time summed over all threads.
+    pub fn format_table(&self) -> String {
+        let total: Duration = self.passes.iter().map(|timing| timing.duration).sum();
+        let mut table = String::new();
+        for (header, rows) in [
+            ("pass", self.totals(|timing| timing.pass)),
+            ("module", self.totals(|timing| timing.module.as_str())),
+        ] {
+            let width = rows
+                .iter()
+                .map(|(name, _)| name.len())
+                .chain([header.len(), "total".len()])
+                .max()
+                .unwrap_or_default();
+
+            writeln!(table, "{header:<width$}  {:>10}  {:>6}", "time", "share").unwrap();
+            for (name, duration) in rows {
+                let share = if total.is_zero() {
+                    0.0
+                } else {
+                    duration.as_secs_f64() / total.as_secs_f64() * 100.0
+                };
+                writeln!(
+                    table,
+                    "{name:<width$}  {:>10}  {share:>5.1}%",
+                    format!("{duration:.2?}")
+                )
+                .unwrap();
+            }
+            writeln!(
+                table,
+                "{:<width$}  {:>10}\n",
+                "total",
+                format!("{total:.2?}")
+            )
+            .unwrap();
+        }
+        table
+    }
+
+    /// Returns the total duration of the timings with the same `key`.
+    fn totals<'a>(&'a self, key: impl Fn(&'a PassTiming) -> &'a str) -> Vec<(&'a str, Duration)> {
+        let mut totals: HashMap<&str, Duration> = HashMap::new();
+        for timing in &self.passes {
+            *totals.entry(key(timing)).or_default() += timing.duration;
+        }
+        sorted_desc(totals)
+    }
+
+    /// Formats the timings as a Chrome trace, which can be opened in
+    /// `chrome://tracing` or <https://ui.perfetto.dev>.
+    pub fn chrome_trace(&self) -> String {
+        // Threads are numbered in the order in which they first ran a pass.
+        let mut threads = vec![];
+        for timing in &self.passes {
+            if !threads.contains(&timing.thread) {
+                threads.push(timing.thread);
+            }
+        }
+        let tid = |timing: &PassTiming| {
+            threads
+                .iter()
+                .position(|thread| *thread == timing.thread)
+                .unwrap()
+                + 1
+        };
+
+        let trace = ChromeTrace {
+            trace_events: self
+                .passes
+                .iter()
+                .map(|timing| TraceEvent {
+                    name: timing.pass,
+                    cat: "pass",
+                    ph: "X",
+                    ts: micros(timing.start.saturating_duration_since(self.start)),
+                    dur: micros(timing.duration),
+                    pid: 1,
+                    tid: tid(timing),
+                    args: TraceArgs {
+                        module: timing.module.clone(),
+                    },
+                })
+                .collect(),
+            display_time_unit: "ms",
+        };
+        serde_json::to_string(&trace).expect("trace should be serializable")
+    }
+}
+
+/// A trace in the Chrome trace event format, see
+/// <https://docs.google.com/document/d/1CvAClvFfyA5R-PhYUmn5OOQtYMH4h6I0nSsKchNAySU>.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ChromeTrace<'a> {
+    trace_events: Vec<TraceEvent<'a>>,
+    display_time_unit: &'static str,
+}
+
+#[derive(Debug, Serialize)]
+struct TraceEvent<'a> {
+    name: &'a str,
+    cat: &'static str,
+    ph: &'static str,
+    ts: f64,
+    dur: f64,
+    pid: u32,
+    tid: usize,
+    args: TraceArgs,
+}
+
+#[derive(Debug, Serialize)]
+struct TraceArgs {
+    module: String,
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+/// The number of times each salsa query was executed, as opposed to reusing a
+/// memoized result.
+#[derive(Debug, Clone, Default)]
+pub struct QueryStats {
+    executions: HashMap<String, usize>,
+}
+
+impl QueryStats {
+    pub(crate) fn record(&mut self, database_key: DatabaseKeyIndex) {
+        // The debug representation of a key is `query_name(id)` while the
+        // database is attached, which it is while a query executes.
+        let key = format!("{database_key:?}");
+        let query = key.split_once('(').map_or(key.as_str(), |(name, _)| name);
+        *self.executions.entry(query.to_string()).or_default() += 1;
+    }
+
+    /// Formats the execution counts as a table, sorted from the most to the
+    /// least executed query.
+    pub fn format_table(&self) -> String {
+        let rows = sorted_desc(
+            self.executions
+                .iter()
+                .map(|(query, count)| (query.as_str(), *count)),
+        );
+        let width = rows
+            .iter()
+            .map(|(query, _)| query.len())
+            .chain(["query".len()])
+            .max()
+            .unwrap_or_default();
+        let total: usize = rows.iter().map(|(_, count)| count).sum();
+
+        let mut table = String::new();
+        writeln!(table, "{:<width$}  {:>10}", "query", "executions").unwrap();
+        for (query, count) in rows {
+            writeln!(table, "{query:<width$}  {count:>10}").unwrap();
+        }
+        writeln!(table, "{:<width$}  {total:>10}", "total").unwrap();
+        table
+    }
+}
+
+/// Sorts the rows by descending value, and then by name.
+fn sorted_desc<'a, T: Ord>(rows: impl IntoIterator<Item = (&'a str, T)>) -> Vec<(&'a str, T)> {
+    let mut rows: Vec<_> = rows.into_iter().collect();
+    rows.sort_by(|lhs, rhs| rhs.1.cmp(&lhs.1).then(lhs.0.cmp(rhs.0)));
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timings() -> Timings {
+        let mut timings = Timings::new();
+        let start = timings.start;
+        let timing = |pass, module: &str, start_ms, duration_ms| PassTiming {
+            pass,
+            module: module.to_string(),
+            thread: std::thread::current().id(),
+            start: start + Duration::from_millis(start_ms),
+            duration: Duration::from_millis(duration_ms),
+        };
+        timings.extend([
+            timing("ParsingPass", "app", 0, 1),
+            timing("BodyAnalysisPass", "app", 1, 6),
+            timing("ParsingPass", "app::foo", 7, 1),
+            timing("BodyAnalysisPass", "app::foo", 8, 2),
+        ]);
+        timings
+    }
+
+    #[test]
+    fn format_table() {
+        let table = timings().format_table();
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(
+            lines,
+            [
+                "pass                    time   share",
+                "BodyAnalysisPass      8.00ms   80.0%",
+                "ParsingPass           2.00ms   20.0%",
+                "total                10.00ms",
+                "",
+                "module          time   share",
+                "app           7.00ms   70.0%",
+                "app::foo      3.00ms   30.0%",
+                "total        10.00ms",
+                "",
+            ]
+        );
+    }
+
+    #[test]
+    fn chrome_trace() {
+        let trace: serde_json::Value = serde_json::from_str(&timings().chrome_trace()).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        assert_eq!(events.len(), 4);
+        assert_eq!(events[1]["name"], "BodyAnalysisPass");
+        assert_eq!(events[1]["ph"], "X");
+        assert_eq!(events[1]["ts"], 1000.0);
+        assert_eq!(events[1]["dur"], 6000.0);
+        assert_eq!(events[1]["tid"], 1);
+        assert_eq!(events[1]["args"]["module"], "app");
+    }
+}
@@ -0,0 +1,220 @@
+//! `fe check --watch`: keeps the database alive and re-checks the ingots
+//! whenever their files change on disk.
+//!
+//! Only the changed `File` inputs are updated, so the re-check reuses every
+//! query result that doesn't depend on them.
+
+use std::{
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use common::InputDb;
+use notify::{RecursiveMode, Watcher};
+use salsa::Setter;
+use url::Url;
+
+use crate::DriverDataBase;
+
+/// Editors often write a file in several steps, so events that arrive within
+/// this interval of each other are handled by a single re-check.
+const DEBOUNCE: Duration = Duration::from_millis(50);
+
+/// A directory or a single file whose changes are applied to the database.
+#[derive(Debug, Clone)]
+pub struct WatchRoot {
+    /// The canonical path of the root.
+    pub path: Utf8PathBuf,
+    /// The URL of the root in the workspace, which differs from the file URL
+    /// of `path` for the core ingot.
+    pub url: Url,
+}
+
+impl WatchRoot {
+    /// Returns the workspace URL of the file at `path`, or `None` if `path`
+    /// isn't under the root.
+    fn url_of(&self, path: &Utf8Path) -> Option<Url> {
+        if path == self.path {
+            return Some(self.url.clone());
+        }
+        let relative = path.strip_prefix(&self.path).ok()?;
+        self.url.join(relative.as_str()).ok()
+    }
+}
+
+/// Checks the ingots with `check`, and then re-checks them whenever a file
+/// under one of the `roots` changes. Never returns.
+///
+/// Changes to `fe.toml` files update the ingot configs, but dependencies are
+/// only resolved when `fe check` starts.
+pub fn watch(
+    db: &mut DriverDataBase,
+    roots: &[WatchRoot],
+    mut check: impl FnMut(&DriverDataBase),
+) -> ! {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = match notify::recommended_watcher(sender) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("failed to start watching: {err}");
+            std::process::exit(1)
+        }
+    };
+    for root in roots {
+        let mode = if root.path.is_dir() {
+            RecursiveMode::Recursive
+        } else {
+            RecursiveMode::NonRecursive
+        };
+        if let Err(err) = watcher.watch(root.path.as_std_path(), mode) {
+            eprintln!("failed to watch `{}`: {err}", root.path);
+            std::process::exit(1)
+        }
+    }
+
+    timed_check(db, &mut check);
+    loop {
+        let Ok(first) = receiver.recv() else {
+            eprintln!("stopped watching");
+            std::process::exit(1)
+        };
+        let mut results = vec![first];
+        while let Ok(result) = receiver.recv_timeout(DEBOUNCE) {
+            results.push(result);
+        }
+
+        let mut paths = vec![];
+        for result in results {
+            match result {
+                Ok(event) => paths.extend(
+                    event
+                        .paths
+                        .into_iter()
+                        .filter_map(|path| Utf8PathBuf::from_path_buf(path).ok()),
+                ),
+                Err(err) => eprintln!("watch error: {err}"),
+            }
+        }
+
+        let changed = apply_changes(db, roots, paths);
+        if changed == 0 {
+            continue;
+        }
+        let files = if changed == 1 { "file" } else { "files" };
+        eprintln!("\n{changed} {files} changed, re-checking\n");
+        timed_check(db, &mut check);
+    }
+}
+
+fn timed_check(db: &DriverDataBase, check: &mut impl FnMut(&DriverDataBase)) {
+    let start = Instant::now();
+    check(db);
+    eprintln!("checked in {:.2?}", start.elapsed());
+}
+
+/// Updates the `File` inputs of the changed `paths`: files that still exist
+/// are created or updated with their current content, and the others are
+/// removed. Paths that aren't Fe sources or `fe.toml` files, or that aren't
+/// under any of the `roots`, are ignored.
+///
+/// Returns the number of inputs that changed.
+pub fn apply_changes(
+    db: &mut DriverDataBase,
+    roots: &[WatchRoot],
+    paths: impl IntoIterator<Item = Utf8PathBuf>,
+) -> usize {
+    let mut urls = vec![];
+    for path in paths {
+        if path.extension() != Some("fe") && path.file_name() != Some("fe.toml") {
+            continue;
+        }
+        if let Some(url) = roots.iter().find_map(|root| root.url_of(&path)) {
+            if !urls.iter().any(|(existing, _)| existing == &url) {
+                urls.push((url, path));
+            }
+        }
+    }
+
+    let mut changed = 0;
+    for (url, path) in urls {
+        let index = db.workspace();
+        match (std::fs::read_to_string(&path), index.get(db, &url)) {
+            (Ok(content), Some(file)) => {
+                if *file.text(db) != content {
+                    file.set_text(db).to(content);
+                    changed += 1;
+                }
+            }
+            (Ok(content), None) => {
+                index.touch(db, url, Some(content));
+                changed += 1;
+            }
+            (Err(_), Some(_)) => {
+                index.remove(db, &url);
+                changed += 1;
+            }
+            (Err(_), None) => {}
+        }
+    }
+    changed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_of() {
+        let root = WatchRoot {
+            path: Utf8PathBuf::from("/work/core"),
+            url: Url::parse("core-ingot:///").unwrap(),
+        };
+        assert_eq!(
+            root.url_of(Utf8Path::new("/work/core/src/lib.fe")),
+            Some(Url::parse("core-ingot:///src/lib.fe").unwrap())
+        );
+        assert_eq!(root.url_of(Utf8Path::new("/work/other/src/lib.fe")), None);
+
+        let root = WatchRoot {
+            path: Utf8PathBuf::from("/work/main.fe"),
+            url: Url::parse("file:///work/main.fe").unwrap(),
+        };
+        assert_eq!(
+            root.url_of(Utf8Path::new("/work/main.fe")),
+            Some(root.url.clone())
+        );
+    }
+
+    #[test]
+    fn apply_changes_updates_inputs() {
+        let dir = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+            .unwrap()
+            .join(format!("fe-watch-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        let path = dir.join("src/lib.fe");
+        let root = WatchRoot {
+            url: Url::from_directory_path(&dir).unwrap(),
+            path: dir.clone(),
+        };
+        let url = Url::from_file_path(&path).unwrap();
+        let mut db = DriverDataBase::default();
+
+        std::fs::write(&path, "pub fn foo() {}").unwrap();
+        assert_eq!(apply_changes(&mut db, &[root.clone()], [path.clone()]), 1);
+        let file = db.workspace().get(&db, &url).unwrap();
+        assert_eq!(file.text(&db), "pub fn foo() {}");
+
+        std::fs::write(&path, "pub fn bar() {}").unwrap();
+        std::fs::write(dir.join("notes.txt"), "").unwrap();
+        let paths = [path.clone(), path.clone(), dir.join("notes.txt")];
+        assert_eq!(apply_changes(&mut db, &[root.clone()], paths), 1);
+        assert_eq!(file.text(&db), "pub fn bar() {}");
+
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(apply_changes(&mut db, &[root], [path]), 1);
+        assert!(db.workspace().get(&db, &url).is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
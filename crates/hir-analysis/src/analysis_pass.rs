@@ -1,8 +1,15 @@
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread::ThreadId,
+    time::{Duration, Instant},
+};
+
 use crate::{diagnostics::DiagnosticVoucher, HirAnalysisDb};
 use hir::{
     hir_def::{ModuleTree, TopLevelMod},
-    lower::parse_file_impl,
-    ParserError,
+    lower::{expand_macros_impl, parse_file_impl},
+    MacroExpansionError, ParserError,
 };
 
 /// All analysis passes that run analysis on the HIR top level module
@@ -13,11 +20,30 @@ pub trait ModuleAnalysisPass {
         db: &'db dyn HirAnalysisDb,
         top_mod: TopLevelMod<'db>,
     ) -> Vec<Box<dyn DiagnosticVoucher + 'db>>;
+
+    /// The name of the pass, used when reporting its timings.
+    fn name(&self) -> &'static str {
+        let name = std::any::type_name::<Self>();
+        name.rsplit("::").next().unwrap_or(name)
+    }
+}
+
+/// The wall time that a pass took to run on a top level module.
+#[derive(Debug, Clone)]
+pub struct PassTiming {
+    pub pass: &'static str,
+    /// The path of the module, e.g. `my_ingot::foo`.
+    pub module: String,
+    /// The thread that ran the pass.
+    pub thread: ThreadId,
+    pub start: Instant,
+    pub duration: Duration,
 }
 
 #[derive(Default)]
 pub struct AnalysisPassManager {
     module_passes: Vec<Box<dyn ModuleAnalysisPass>>,
+    timings: Option<Vec<PassTiming>>,
 }
 
 impl AnalysisPassManager {
@@ -29,15 +55,28 @@ impl AnalysisPassManager {
         self.module_passes.push(pass);
     }
 
+    /// Starts recording how long each pass takes on each module. The timings
+    /// are collected with [`Self::take_timings`].
+    pub fn record_timings(&mut self) {
+        self.timings.get_or_insert_with(Vec::new);
+    }
+
+    /// Returns the timings recorded since the last call, or an empty list if
+    /// recording isn't enabled.
+    pub fn take_timings(&mut self) -> Vec<PassTiming> {
+        self.timings
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
     pub fn run_on_module<'db>(
         &mut self,
         db: &'db dyn HirAnalysisDb,
         top_mod: TopLevelMod<'db>,
     ) -> Vec<Box<dyn DiagnosticVoucher + 'db>> {
         let mut diags = vec![];
-        for pass in self.module_passes.iter_mut() {
-            diags.extend(pass.run_on_module(db, top_mod));
-        }
+        self.run_passes(db, top_mod, &mut diags);
         diags
     }
 
@@ -48,11 +87,37 @@ impl AnalysisPassManager {
     ) -> Vec<Box<dyn DiagnosticVoucher + 'db>> {
         let mut diags = vec![];
         for module in tree.all_modules() {
+            self.run_passes(db, module, &mut diags);
+        }
+        diags
+    }
+
+    fn run_passes<'db>(
+        &mut self,
+        db: &'db dyn HirAnalysisDb,
+        top_mod: TopLevelMod<'db>,
+        diags: &mut Vec<Box<dyn DiagnosticVoucher + 'db>>,
+    ) {
+        let Some(timings) = &mut self.timings else {
             for pass in self.module_passes.iter_mut() {
-                diags.extend(pass.run_on_module(db, module));
+                diags.extend(pass.run_on_module(db, top_mod));
             }
+            return;
+        };
+
+        let module = top_mod.scope().pretty_path(db).unwrap_or_default();
+        let thread = std::thread::current().id();
+        for pass in self.module_passes.iter_mut() {
+            let start = Instant::now();
+            diags.extend(pass.run_on_module(db, top_mod));
+            timings.push(PassTiming {
+                pass: pass.name(),
+                module: module.clone(),
+                thread,
+                start,
+                duration: start.elapsed(),
+            });
         }
-        diags
     }
 }
 
@@ -65,9 +130,94 @@ impl ModuleAnalysisPass for ParsingPass {
         db: &'db dyn HirAnalysisDb,
         top_mod: TopLevelMod<'db>,
     ) -> Vec<Box<dyn DiagnosticVoucher>> {
-        parse_file_impl::accumulated::<ParserError>(db, top_mod)
+        let parse_errors = parse_file_impl::accumulated::<ParserError>(db, top_mod);
+        let expansion_errors = expand_macros_impl::accumulated::<MacroExpansionError>(db, top_mod);
+        let mut diags: Vec<Box<dyn DiagnosticVoucher>> = parse_errors
             .into_iter()
             .map(|d| Box::new(d.clone()) as _)
-            .collect::<Vec<_>>()
+            .collect();
+        diags.extend(
+            expansion_errors
+                .into_iter()
+                .map(|d| Box::new(d.clone()) as _),
+        );
+        diags
+    }
+}
+
+/// Runs the passes on the `modules` in parallel, each thread with its own clone
+/// of `db` and its own pass manager created by `init_pass_manager`.
+///
+/// The diagnostics of each module are turned into the result of the module by
+/// `finalize` on the thread that analyzed it, since they can't outlive the
+/// clone of the database. The results are returned in the order of `modules`,
+/// together with the timings recorded by the pass managers.
+///
+/// If a thread panics, e.g. because a query was cancelled, the panic is
+/// resumed on the calling thread.
+pub fn run_on_modules_in_parallel<'db, DB, T>(
+    db: &DB,
+    modules: &[TopLevelMod<'db>],
+    init_pass_manager: impl Fn() -> AnalysisPassManager + Sync,
+    finalize: impl for<'a> Fn(&'a DB, Vec<Box<dyn DiagnosticVoucher + 'a>>) -> T + Sync,
+) -> (Vec<T>, Vec<PassTiming>)
+where
+    DB: HirAnalysisDb + Clone + Send,
+    T: Send,
+{
+    let threads = std::thread::available_parallelism()
+        .map_or(1, NonZeroUsize::get)
+        .min(modules.len());
+    if threads <= 1 {
+        let mut pass_manager = init_pass_manager();
+        let results = modules
+            .iter()
+            .map(|&module| finalize(db, pass_manager.run_on_module(db, module)))
+            .collect();
+        return (results, pass_manager.take_timings());
+    }
+
+    // Modules are handed out one at a time, so that a thread that got a large
+    // module doesn't hold up the others.
+    let next = AtomicUsize::new(0);
+    let worker = |db: DB| {
+        let mut pass_manager = init_pass_manager();
+        let mut results = vec![];
+        loop {
+            let idx = next.fetch_add(1, Ordering::Relaxed);
+            let Some(&module) = modules.get(idx) else {
+                break;
+            };
+            results.push((idx, finalize(&db, pass_manager.run_on_module(&db, module))));
+        }
+        (results, pass_manager.take_timings())
+    };
+
+    let joined: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|_| {
+                let db = db.clone();
+                scope.spawn(|| worker(db))
+            })
+            .collect();
+        handles.into_iter().map(|handle| handle.join()).collect()
+    });
+
+    let mut results = vec![];
+    let mut timings = vec![];
+    for thread_result in joined {
+        match thread_result {
+            Ok((thread_results, thread_timings)) => {
+                results.extend(thread_results);
+                timings.extend(thread_timings);
+            }
+            Err(payload) => std::panic::resume_unwind(payload),
+        }
     }
+    results.sort_by_key(|(idx, _)| *idx);
+    timings.sort_by_key(|timing| timing.start);
+    (
+        results.into_iter().map(|(_, result)| result).collect(),
+        timings,
+    )
 }
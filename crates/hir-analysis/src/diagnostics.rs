@@ -4,7 +4,10 @@
 //! [`CompleteDiagnostic`].
 
 use crate::{
-    name_resolution::diagnostics::NameResDiag,
+    name_resolution::{
+        diagnostics::{NameResDiag, VisRestrictionError},
+        restricted_vis_scope,
+    },
     ty::{
         diagnostics::{
             BodyDiag, DefConflictError, FuncBodyDiag, ImplDiag, TraitConstraintDiag,
@@ -12,7 +15,7 @@ use crate::{
         },
         trait_def::TraitDef,
         ty_check::RecordLike,
-        ty_def::{TyData, TyVarSort},
+        ty_def::{TyBase, TyData, TyVarSort},
     },
     HirAnalysisDb,
 };
@@ -21,7 +24,11 @@ use common::diagnostics::{
     SubDiagnostic,
 };
 use either::Either;
-use hir::{hir_def::FieldIndex, span::LazySpan, ParserError, SpannedHirDb};
+use hir::{
+    hir_def::{scope_graph::ScopeId, DerivableTrait, FieldIndex, Visibility},
+    span::LazySpan,
+    MacroExpansionError, ParserError, SpannedHirDb,
+};
 use itertools::Itertools;
 
 /// All diagnostics accumulated in salsa-db should implement
@@ -57,6 +64,32 @@ pub trait SpannedHirAnalysisDb:
 #[salsa::db]
 impl<T> SpannedHirAnalysisDb for T where T: HirAnalysisDb + SpannedHirDb {}
 
+/// Returns the codes of all the errors that the analysis passes can emit.
+///
+/// Lints aren't included; their codes are given by
+/// [`LintAnalysisPass::lints`](crate::lint::LintAnalysisPass::lints).
+pub fn error_codes() -> impl Iterator<Item = GlobalErrorCode> {
+    [
+        (DiagnosticPass::Parse, 1..=2),
+        (DiagnosticPass::NameResolution, NameResDiag::LOCAL_CODES),
+        (DiagnosticPass::TypeDefinition, TyLowerDiag::LOCAL_CODES),
+        // `DefConflictError`
+        (DiagnosticPass::TypeDefinition, 100..=100),
+        (
+            DiagnosticPass::ImplTraitDefinition,
+            TraitLowerDiag::LOCAL_CODES,
+        ),
+        (
+            DiagnosticPass::TraitSatisfaction,
+            TraitConstraintDiag::LOCAL_CODES,
+        ),
+        (DiagnosticPass::TraitSatisfaction, ImplDiag::LOCAL_CODES),
+        (DiagnosticPass::TyCheck, BodyDiag::LOCAL_CODES),
+    ]
+    .into_iter()
+    .flat_map(|(pass, codes)| codes.map(move |code| GlobalErrorCode::new(pass.clone(), code)))
+}
+
 // `ParseError` has span information, but this is not a problem because the
 // parsing procedure itself depends on the file content, and thus span
 // information.
@@ -78,6 +111,24 @@ impl DiagnosticVoucher for ParserError {
     }
 }
 
+impl DiagnosticVoucher for MacroExpansionError {
+    fn to_complete(&self, _db: &dyn SpannedHirAnalysisDb) -> CompleteDiagnostic {
+        let error_code = GlobalErrorCode::new(DiagnosticPass::Parse, 2);
+        let span = Span::new(self.file, self.error.range(), SpanKind::Original);
+        CompleteDiagnostic::new(
+            Severity::Error,
+            self.error.msg(),
+            vec![SubDiagnostic::new(
+                LabelStyle::Primary,
+                self.error.label(),
+                Some(span),
+            )],
+            vec![],
+            error_code,
+        )
+    }
+}
+
 pub trait LazyDiagnostic<'db> {
     fn to_complete(&self, db: &'db dyn SpannedHirAnalysisDb) -> CompleteDiagnostic;
 }
@@ -133,6 +184,25 @@ impl DiagnosticVoucher for TyDiagCollection<'_> {
     }
 }
 
+/// Returns a note explaining where `scope` with restricted visibility is
+/// visible from.
+fn restricted_vis_note(
+    db: &dyn SpannedHirAnalysisDb,
+    scope: ScopeId,
+    ident: &str,
+) -> Option<String> {
+    match scope.data(db).vis {
+        Visibility::Ingot => Some(format!(
+            "note: `{ident}` is `pub(ingot)` and only visible within its ingot"
+        )),
+        Visibility::Super | Visibility::In(_) => {
+            let module = restricted_vis_scope(db, scope)?.ok()?.pretty_path(db)?;
+            Some(format!("note: `{ident}` is only visible within `{module}`"))
+        }
+        Visibility::Public | Visibility::Private => None,
+    }
+}
+
 impl DiagnosticVoucher for NameResDiag<'_> {
     fn to_complete(&self, db: &dyn SpannedHirAnalysisDb) -> CompleteDiagnostic {
         let error_code = GlobalErrorCode::new(DiagnosticPass::NameResolution, self.local_code());
@@ -184,7 +254,7 @@ impl DiagnosticVoucher for NameResDiag<'_> {
                 }
             }
 
-            Self::Invisible(prim_span, ident, span) => {
+            Self::Invisible(prim_span, ident, span, vis_scope) => {
                 let ident = ident.data(db);
 
                 let mut sub_diagnostics = vec![SubDiagnostic {
@@ -200,11 +270,16 @@ impl DiagnosticVoucher for NameResDiag<'_> {
                     });
                 }
 
+                let notes = vis_scope
+                    .and_then(|scope| restricted_vis_note(db, scope, ident))
+                    .into_iter()
+                    .collect();
+
                 CompleteDiagnostic {
                     severity,
                     message: format!("`{ident}` is not visible"),
                     sub_diagnostics,
-                    notes: vec![],
+                    notes,
                     error_code,
                 }
             }
@@ -309,6 +384,44 @@ impl DiagnosticVoucher for NameResDiag<'_> {
                 }
             }
 
+            Self::InvalidVisRestriction(span, err) => {
+                let (message, label) = match err {
+                    VisRestrictionError::NoParentModule => (
+                        "`pub(super)` is used in the root module".to_string(),
+                        "the root module has no parent module".to_string(),
+                    ),
+                    VisRestrictionError::NotModule(segment) => {
+                        let name = segment
+                            .ident(db)
+                            .to_opt()
+                            .map_or_else(String::new, |ident| ident.data(db).to_string());
+                        (
+                            format!("`{name}` is not a module"),
+                            "expected an ancestor module".to_string(),
+                        )
+                    }
+                    VisRestrictionError::NotAncestor(module) => {
+                        let module = module.pretty_path(db).unwrap_or_default();
+                        (
+                            format!("`{module}` is not an ancestor of the current module"),
+                            "visibility can only be restricted to an ancestor module".to_string(),
+                        )
+                    }
+                };
+
+                CompleteDiagnostic {
+                    severity,
+                    message,
+                    sub_diagnostics: vec![SubDiagnostic {
+                        style: LabelStyle::Primary,
+                        message: label,
+                        span: span.resolve(db),
+                    }],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
             Self::TooManyGenericArgs {
                 span,
                 expected,
@@ -736,18 +849,59 @@ impl DiagnosticVoucher for TyLowerDiag<'_> {
                 error_code,
             },
 
-            Self::AssocTy(span) => CompleteDiagnostic {
+            Self::AssocTyNotFound { span, ty, name } => CompleteDiagnostic {
                 severity: Severity::Error,
-                message: "associated type is not supported ".to_string(),
+                message: format!("associated type `{}` not found", name.data(db)),
                 sub_diagnostics: vec![SubDiagnostic {
                     style: LabelStyle::Primary,
-                    message: "associated type is not implemented".to_string(),
+                    message: format!(
+                        "no trait bound of `{}` declares the associated type `{}`",
+                        ty.pretty_print(db),
+                        name.data(db)
+                    ),
                     span: span.resolve(db),
                 }],
                 notes: vec![],
                 error_code,
             },
 
+            Self::AmbiguousAssocTy {
+                span,
+                ty,
+                name,
+                cands,
+            } => {
+                let mut sub_diagnostics = vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: format!(
+                        "`{}` is declared in multiple traits bound by `{}`",
+                        name.data(db),
+                        ty.pretty_print(db)
+                    ),
+                    span: span.resolve(db),
+                }];
+
+                for cand in cands {
+                    sub_diagnostics.push(SubDiagnostic {
+                        style: LabelStyle::Secondary,
+                        message: format!("candidate: {}", cand.pretty_print(db, false)),
+                        span: cand.def(db).trait_(db).span().name().resolve(db),
+                    });
+                }
+
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("ambiguous associated type `{}`", name.data(db)),
+                    sub_diagnostics,
+                    notes: vec![format!(
+                        "use a qualified path like `<{} as Trait>::{}` to disambiguate",
+                        ty.pretty_print(db),
+                        name.data(db)
+                    )],
+                    error_code,
+                }
+            }
+
             Self::InvalidConstTyExpr(span) => CompleteDiagnostic {
                 severity: Severity::Error,
                 message: "the expression is not supported yet in a const type context".to_string(),
@@ -759,6 +913,36 @@ impl DiagnosticVoucher for TyLowerDiag<'_> {
                 notes: vec![],
                 error_code,
             },
+
+            Self::UnknownDerive { span, name } => {
+                let derivable = DerivableTrait::ALL
+                    .iter()
+                    .map(|trait_| format!("`{}`", trait_.name()))
+                    .join(", ");
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("cannot derive `{}`", name.data(db)),
+                    sub_diagnostics: vec![SubDiagnostic {
+                        style: LabelStyle::Primary,
+                        message: format!("`{}` is not a derivable trait", name.data(db)),
+                        span: span.resolve(db),
+                    }],
+                    notes: vec![format!("note: the derivable traits are {derivable}")],
+                    error_code,
+                }
+            }
+
+            Self::DefaultDeriveOnEnum(span) => CompleteDiagnostic {
+                severity: Severity::Error,
+                message: "cannot derive `Default` for an enum".to_string(),
+                sub_diagnostics: vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: "`Default` can only be derived for structs".to_string(),
+                    span: span.resolve(db),
+                }],
+                notes: vec![],
+                error_code,
+            },
         }
     }
 }
@@ -1258,6 +1442,194 @@ impl DiagnosticVoucher for BodyDiag<'_> {
                 }
             }
 
+            Self::UndeclaredLabel { primary, label } => {
+                let label = label.data(db);
+
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("use of undeclared label `{label}`"),
+                    sub_diagnostics: vec![SubDiagnostic {
+                        style: LabelStyle::Primary,
+                        message: format!("undeclared label `{label}`"),
+                        span: primary.resolve(db),
+                    }],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
+            Self::BreakWithValueInNonLoop(primary) => CompleteDiagnostic {
+                severity: Severity::Error,
+                message: "`break` with value in a `for` or `while` loop".to_string(),
+                sub_diagnostics: vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: "can only break with a value inside `loop`".to_string(),
+                    span: primary.resolve(db),
+                }],
+                notes: vec![],
+                error_code,
+            },
+
+            Self::MissingReturnValue {
+                primary,
+                label,
+                expected,
+                func,
+            } => {
+                let expected = expected.pretty_print(db);
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: "not all paths return a value".to_string(),
+                    sub_diagnostics: vec![
+                        SubDiagnostic {
+                            style: LabelStyle::Primary,
+                            message: label.clone(),
+                            span: primary.resolve(db),
+                        },
+                        SubDiagnostic {
+                            style: LabelStyle::Secondary,
+                            message: format!("this function expects `{expected}` to be returned"),
+                            span: func.span().ret_ty().resolve(db),
+                        },
+                    ],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
+            Self::UninitializedBinding {
+                primary,
+                binding: (name, def_span),
+                possibly,
+            } => {
+                let name = name.data(db);
+                let state = if *possibly {
+                    "is possibly-uninitialized"
+                } else {
+                    "isn't initialized"
+                };
+
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("used binding `{name}` {state}"),
+                    sub_diagnostics: vec![
+                        SubDiagnostic {
+                            style: LabelStyle::Primary,
+                            message: format!("`{name}` used here but it {state}"),
+                            span: primary.resolve(db),
+                        },
+                        SubDiagnostic {
+                            style: LabelStyle::Secondary,
+                            message: "binding declared here but left uninitialized".to_string(),
+                            span: def_span.resolve(db),
+                        },
+                    ],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
+            Self::ImmutableReassignment {
+                primary,
+                binding: (name, def_span),
+            } => {
+                let name = name.data(db);
+
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("cannot assign twice to immutable binding `{name}`"),
+                    sub_diagnostics: vec![
+                        SubDiagnostic {
+                            style: LabelStyle::Primary,
+                            message: "cannot assign twice to immutable binding".to_string(),
+                            span: primary.resolve(db),
+                        },
+                        SubDiagnostic {
+                            style: LabelStyle::Secondary,
+                            message: format!("try changing to `mut {name}`"),
+                            span: def_span.resolve(db),
+                        },
+                    ],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
+            Self::LiteralOutOfRange {
+                primary,
+                lit,
+                negated,
+                ty,
+            } => {
+                let span = primary.resolve(db);
+                // Show the literal as it's written, e.g. in hex, unless it
+                // comes from a macro expansion.
+                let lit = match &span {
+                    Some(span) if span.kind == SpanKind::Original => {
+                        span.file.text(db)[span.range].to_string()
+                    }
+                    _ => {
+                        let sign = if *negated { "-" } else { "" };
+                        format!("{sign}{}", lit.data(db))
+                    }
+                };
+                let ty_name = ty.pretty_print(db);
+
+                let range = match ty.data(db) {
+                    TyData::TyBase(TyBase::Prim(prim)) => prim.int_range(),
+                    _ => None,
+                };
+                // Only unsigned types without a fixed width, i.e. `usize`,
+                // don't have a range.
+                let note = match range {
+                    Some((min, max)) => format!("note: `{ty_name}` ranges from `{min}` to `{max}`"),
+                    None => format!("note: `{ty_name}` can't hold negative values"),
+                };
+
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("literal out of range for `{ty_name}`"),
+                    sub_diagnostics: vec![SubDiagnostic {
+                        style: LabelStyle::Primary,
+                        message: format!("the literal `{lit}` doesn't fit into `{ty_name}`"),
+                        span,
+                    }],
+                    notes: vec![note],
+                    error_code,
+                }
+            }
+
+            Self::UnsafeCallOutsideUnsafe { primary, func } => {
+                let name = func
+                    .name(db)
+                    .to_opt()
+                    .map_or_else(String::new, |name| name.data(db).to_string());
+                let notes = if func.is_extern(db) {
+                    vec!["note: extern functions are unsafe unless marked with `#safe`".to_string()]
+                } else {
+                    vec![]
+                };
+
+                CompleteDiagnostic {
+                    severity: Severity::Error,
+                    message: format!("call to unsafe function `{name}` requires an `unsafe` block"),
+                    sub_diagnostics: vec![
+                        SubDiagnostic {
+                            style: LabelStyle::Primary,
+                            message: "call to unsafe function".to_string(),
+                            span: primary.resolve(db),
+                        },
+                        SubDiagnostic {
+                            style: LabelStyle::Secondary,
+                            message: format!("`{name}` is defined here"),
+                            span: func.span().name().resolve(db),
+                        },
+                    ],
+                    notes,
+                    error_code,
+                }
+            }
+
             Self::TraitNotImplemented {
                 primary,
                 ty,
@@ -1695,6 +2067,61 @@ impl DiagnosticVoucher for BodyDiag<'_> {
                     error_code,
                 }
             }
+            BodyDiag::RefutablePatInLet {
+                primary,
+                missing_patterns,
+            } => {
+                let sub_diagnostics = vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: "pattern doesn't match all possible values".to_string(),
+                    span: primary.resolve(db),
+                }];
+                let mut notes = vec![];
+                if !missing_patterns.is_empty() {
+                    notes.push(format!("Not covered: `{}`", missing_patterns.join("`, `")));
+                }
+                notes.push(
+                    "consider using `let .. else` or `if let` to handle the other values"
+                        .to_string(),
+                );
+                CompleteDiagnostic {
+                    severity,
+                    message: "refutable pattern in `let` binding".to_string(),
+                    sub_diagnostics,
+                    notes,
+                    error_code,
+                }
+            }
+            BodyDiag::LetElseMustDiverge { primary, given } => {
+                let sub_diagnostics = vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: format!("expected `!`, but `{}` is given", given.pretty_print(db)),
+                    span: primary.resolve(db),
+                }];
+                CompleteDiagnostic {
+                    severity,
+                    message: "`else` block of `let .. else` must diverge".to_string(),
+                    sub_diagnostics,
+                    notes: vec![
+                        "try adding `return`, `break` or `continue` at the end of the block"
+                            .to_string(),
+                    ],
+                    error_code,
+                }
+            }
+
+            BodyDiag::InvalidRangePat(primary) => CompleteDiagnostic {
+                severity,
+                message: "invalid range pattern".to_string(),
+                sub_diagnostics: vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: "range bounds must be integers, and the start must not exceed the end"
+                        .to_string(),
+                    span: primary.resolve(db),
+                }],
+                notes: vec![],
+                error_code,
+            },
         }
     }
 }
@@ -2202,6 +2629,114 @@ impl DiagnosticVoucher for ImplDiag<'_> {
                     error_code,
                 }
             }
+
+            Self::AssocTyNotDefinedInTrait {
+                primary,
+                trait_,
+                name,
+            } => CompleteDiagnostic {
+                severity,
+                message: "associated type not defined in trait".to_string(),
+                sub_diagnostics: vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: format!(
+                        "associated type `{}` is not defined in trait `{}`",
+                        name.data(db),
+                        trait_.name(db).unwrap().data(db)
+                    ),
+                    span: primary.resolve(db),
+                }],
+                notes: vec![],
+                error_code,
+            },
+
+            Self::NotAllTraitAssocTysDefined {
+                primary,
+                not_defined,
+            } => {
+                let missing = not_defined
+                    .iter()
+                    .map(|id| id.data(db).as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                CompleteDiagnostic {
+                    severity,
+                    message: "not all trait associated types are defined".to_string(),
+                    sub_diagnostics: vec![SubDiagnostic {
+                        style: LabelStyle::Primary,
+                        message: format!("missing associated types: {missing}"),
+                        span: primary.resolve(db),
+                    }],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
+            Self::AssocConstNotDefinedInTrait {
+                primary,
+                trait_,
+                name,
+            } => CompleteDiagnostic {
+                severity,
+                message: "associated const not defined in trait".to_string(),
+                sub_diagnostics: vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: format!(
+                        "associated const `{}` is not defined in trait `{}`",
+                        name.data(db),
+                        trait_.name(db).unwrap().data(db)
+                    ),
+                    span: primary.resolve(db),
+                }],
+                notes: vec![],
+                error_code,
+            },
+
+            Self::NotAllTraitAssocConstsDefined {
+                primary,
+                not_defined,
+            } => {
+                let missing = not_defined
+                    .iter()
+                    .map(|id| id.data(db).as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                CompleteDiagnostic {
+                    severity,
+                    message: "not all trait associated consts are defined".to_string(),
+                    sub_diagnostics: vec![SubDiagnostic {
+                        style: LabelStyle::Primary,
+                        message: format!("missing associated consts: {missing}"),
+                        span: primary.resolve(db),
+                    }],
+                    notes: vec![],
+                    error_code,
+                }
+            }
+
+            Self::AssocConstTyMismatch {
+                primary,
+                name,
+                expected,
+                given,
+            } => CompleteDiagnostic {
+                severity,
+                message: "associated const type mismatch".to_string(),
+                sub_diagnostics: vec![SubDiagnostic {
+                    style: LabelStyle::Primary,
+                    message: format!(
+                        "expected `{}` type for `{}`, but the given type is `{}`",
+                        expected.pretty_print(db),
+                        name.data(db),
+                        given.pretty_print(db),
+                    ),
+                    span: primary.resolve(db),
+                }],
+                notes: vec![],
+                error_code,
+            },
         }
     }
 }
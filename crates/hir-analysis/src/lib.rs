@@ -1,6 +1,7 @@
 use hir::{span::DynLazySpan, HirDb};
 pub mod analysis_pass;
 pub mod diagnostics;
+pub mod lint;
 
 #[salsa::db]
 pub trait HirAnalysisDb: HirDb {}
@@ -0,0 +1,285 @@
+//! Lints report code that is valid but likely to be a mistake.
+//!
+//! Unlike other diagnostics, every lint has a level that decides whether it's
+//! reported as a warning, as an error, or not at all. The level of a lint is
+//! determined by, in order of precedence,
+//! 1. the innermost `#allow(..)`, `#warn(..)` or `#deny(..)` attribute that
+//!    names the lint,
+//! 2. the `[lints]` table of the ingot's `fe.toml`,
+//! 3. the default level of the lint.
+//!
+//! Lints are only reported for the ingots being developed, so external ingots
+//! and the core library are checked for errors only.
+
+pub mod unreachable;
+pub mod unsafe_;
+pub mod unused;
+pub mod visibility;
+
+use common::{
+    diagnostics::{
+        CompleteDiagnostic, DiagnosticPass, GlobalErrorCode, LabelStyle, LintLevel, SubDiagnostic,
+    },
+    ingot::IngotKind,
+};
+use hir::{
+    hir_def::{scope_graph::ScopeId, Attr, AttrListId, Partial, TopLevelMod},
+    span::{DynLazySpan, LazySpan},
+};
+
+use self::{
+    unreachable::UnreachableLintPass, unsafe_::UnsafeLintPass, unused::UnusedLintPass,
+    visibility::VisibilityLintPass,
+};
+use crate::{
+    analysis_pass::ModuleAnalysisPass,
+    diagnostics::{DiagnosticVoucher, SpannedHirAnalysisDb},
+    HirAnalysisDb,
+};
+
+/// The definition of a lint.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct Lint {
+    /// The name used to refer to the lint in attributes and in `fe.toml`.
+    pub name: &'static str,
+    /// The local code of the diagnostics reported by the lint.
+    pub code: u16,
+    pub default_level: LintLevel,
+    pub description: &'static str,
+}
+
+/// A pass that checks a module for one or more lints.
+pub trait LintPass {
+    /// Returns the lints reported by this pass.
+    fn lints(&self) -> &'static [&'static Lint];
+
+    fn check_module(&mut self, ctxt: &mut LintCtxt<'_>);
+}
+
+/// Runs [`LintPass`]es on a module and collects the lints they report.
+#[derive(Default)]
+pub struct LintAnalysisPass {
+    passes: Vec<Box<dyn LintPass>>,
+}
+
+impl LintAnalysisPass {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a pass that runs all the lints built into the compiler.
+    pub fn with_builtin_lints() -> Self {
+        let mut pass = Self::new();
+        pass.add_lint_pass(Box::new(UnusedLintPass {}));
+        pass.add_lint_pass(Box::new(UnreachableLintPass {}));
+        pass.add_lint_pass(Box::new(UnsafeLintPass {}));
+        pass.add_lint_pass(Box::new(VisibilityLintPass {}));
+        pass
+    }
+
+    pub fn add_lint_pass(&mut self, pass: Box<dyn LintPass>) {
+        self.passes.push(pass);
+    }
+
+    /// Returns all the lints reported by the registered passes.
+    pub fn lints(&self) -> impl Iterator<Item = &'static Lint> + '_ {
+        self.passes
+            .iter()
+            .flat_map(|pass| pass.lints().iter().copied())
+    }
+}
+
+impl ModuleAnalysisPass for LintAnalysisPass {
+    fn run_on_module<'db>(
+        &mut self,
+        db: &'db dyn HirAnalysisDb,
+        top_mod: TopLevelMod<'db>,
+    ) -> Vec<Box<dyn DiagnosticVoucher + 'db>> {
+        if matches!(
+            top_mod.ingot(db).kind(db),
+            IngotKind::External | IngotKind::Core
+        ) {
+            return Vec::new();
+        }
+
+        let mut ctxt = LintCtxt::new(db, top_mod);
+        for pass in self.passes.iter_mut() {
+            pass.check_module(&mut ctxt);
+        }
+
+        ctxt.diags
+            .into_iter()
+            .map(|diag| Box::new(diag) as _)
+            .collect()
+    }
+}
+
+pub struct LintCtxt<'db> {
+    db: &'db dyn HirAnalysisDb,
+    top_mod: TopLevelMod<'db>,
+    diags: Vec<LintDiag<'db>>,
+}
+
+impl<'db> LintCtxt<'db> {
+    fn new(db: &'db dyn HirAnalysisDb, top_mod: TopLevelMod<'db>) -> Self {
+        Self {
+            db,
+            top_mod,
+            diags: Vec::new(),
+        }
+    }
+
+    pub fn db(&self) -> &'db dyn HirAnalysisDb {
+        self.db
+    }
+
+    pub fn top_mod(&self) -> TopLevelMod<'db> {
+        self.top_mod
+    }
+
+    /// Returns the level of `lint` for the code in `scope`. Passes use this to
+    /// skip the analysis behind a lint that is allowed there.
+    pub fn level(&self, lint: &'static Lint, scope: ScopeId<'db>) -> LintLevel {
+        self.level_override(lint, scope)
+            .unwrap_or(lint.default_level)
+    }
+
+    /// Returns the level set for `lint` by an attribute or by `fe.toml`.
+    fn level_override(&self, lint: &'static Lint, scope: ScopeId<'db>) -> Option<LintLevel> {
+        let mut current = Some(scope);
+        while let Some(scope) = current {
+            if let Some(level) = scope
+                .attrs(self.db)
+                .and_then(|attrs| attr_lint_level(self.db, attrs, lint))
+            {
+                return Some(level);
+            }
+            current = scope.parent(self.db);
+        }
+
+        self.top_mod
+            .ingot(self.db)
+            .config(self.db)
+            .and_then(|config| config.lint_level(lint.name))
+    }
+
+    /// Reports `lint` on `primary` unless the lint is allowed in `scope`.
+    pub fn report(
+        &mut self,
+        lint: &'static Lint,
+        scope: ScopeId<'db>,
+        primary: DynLazySpan<'db>,
+        message: String,
+        label: String,
+    ) {
+        self.report_with_secondary(lint, scope, primary, message, label, None);
+    }
+
+    /// Same as [`Self::report`], but also labels the `secondary` span.
+    pub fn report_with_secondary(
+        &mut self,
+        lint: &'static Lint,
+        scope: ScopeId<'db>,
+        primary: DynLazySpan<'db>,
+        message: String,
+        label: String,
+        secondary: Option<(DynLazySpan<'db>, String)>,
+    ) {
+        let level_override = self.level_override(lint, scope);
+        let level = level_override.unwrap_or(lint.default_level);
+        if level == LintLevel::Allow {
+            return;
+        }
+
+        self.diags.push(LintDiag {
+            lint,
+            level,
+            by_default: level_override.is_none(),
+            primary,
+            message,
+            label,
+            secondary,
+        });
+    }
+}
+
+/// Returns the level set for `lint` by the attributes in `attrs`, if any.
+/// When several attributes name the lint, the last one wins.
+fn attr_lint_level<'db>(
+    db: &'db dyn HirAnalysisDb,
+    attrs: AttrListId<'db>,
+    lint: &Lint,
+) -> Option<LintLevel> {
+    attrs.data(db).iter().rev().find_map(|attr| {
+        let Attr::Normal(attr) = attr else {
+            return None;
+        };
+        let Partial::Present(name) = attr.name else {
+            return None;
+        };
+        let level = name.data(db).parse().ok()?;
+
+        attr.args
+            .iter()
+            .any(|arg| match arg.key {
+                Partial::Present(key) => !arg.value.is_present() && key.data(db) == lint.name,
+                Partial::Absent => false,
+            })
+            .then_some(level)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LintDiag<'db> {
+    lint: &'static Lint,
+    level: LintLevel,
+    /// `true` if the level is the default level of the lint.
+    by_default: bool,
+    primary: DynLazySpan<'db>,
+    message: String,
+    label: String,
+    secondary: Option<(DynLazySpan<'db>, String)>,
+}
+
+impl LintDiag<'_> {
+    pub fn lint(&self) -> &'static Lint {
+        self.lint
+    }
+
+    pub fn level(&self) -> LintLevel {
+        self.level
+    }
+}
+
+impl DiagnosticVoucher for LintDiag<'_> {
+    fn to_complete(&self, db: &dyn SpannedHirAnalysisDb) -> CompleteDiagnostic {
+        CompleteDiagnostic {
+            // Allowed lints are never reported.
+            severity: self.level.severity().unwrap(),
+            message: self.message.clone(),
+            sub_diagnostics: [SubDiagnostic {
+                style: LabelStyle::Primary,
+                message: self.label.clone(),
+                span: self.primary.resolve(db),
+            }]
+            .into_iter()
+            .chain(self.secondary.iter().map(|(span, label)| SubDiagnostic {
+                style: LabelStyle::Secondary,
+                message: label.clone(),
+                span: span.resolve(db),
+            }))
+            .collect(),
+            notes: self
+                .by_default
+                .then(|| {
+                    format!(
+                        "note: `#{}({})` is on by default",
+                        self.level, self.lint.name
+                    )
+                })
+                .into_iter()
+                .collect(),
+            error_code: GlobalErrorCode::new(DiagnosticPass::Lint, self.lint.code),
+        }
+    }
+}
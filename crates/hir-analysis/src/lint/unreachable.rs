@@ -0,0 +1,49 @@
+//! Lints for code that can never be executed.
+
+use common::diagnostics::LintLevel;
+
+use super::{Lint, LintCtxt, LintPass};
+use crate::ty::ty_check::check_func_body;
+
+pub const UNREACHABLE_CODE: Lint = Lint {
+    name: "unreachable_code",
+    code: 4,
+    default_level: LintLevel::Warn,
+    description: "detects statements that can never be executed",
+};
+
+/// Reports statements that can never be executed.
+pub struct UnreachableLintPass {}
+
+impl LintPass for UnreachableLintPass {
+    fn lints(&self) -> &'static [&'static Lint] {
+        &[&UNREACHABLE_CODE]
+    }
+
+    fn check_module(&mut self, ctxt: &mut LintCtxt<'_>) {
+        let db = ctxt.db();
+        for &func in ctxt.top_mod().all_funcs(db) {
+            let Some(body) = func.body(db) else {
+                continue;
+            };
+            if ctxt.level(&UNREACHABLE_CODE, func.scope()) == LintLevel::Allow {
+                continue;
+            }
+
+            let typed_body = &check_func_body(db, func).1;
+            for unreachable in typed_body.unreachable_stmts(db) {
+                ctxt.report_with_secondary(
+                    &UNREACHABLE_CODE,
+                    func.scope(),
+                    unreachable.stmt.span(body).into(),
+                    "unreachable statement".to_string(),
+                    "unreachable statement".to_string(),
+                    Some((
+                        unreachable.cause.span(body).into(),
+                        "any code following this statement is unreachable".to_string(),
+                    )),
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Lints for `unsafe` blocks.
+
+use common::diagnostics::LintLevel;
+
+use super::{Lint, LintCtxt, LintPass};
+use crate::ty::ty_check::check_func_body;
+
+pub const UNUSED_UNSAFE: Lint = Lint {
+    name: "unused_unsafe",
+    code: 5,
+    default_level: LintLevel::Warn,
+    description: "detects `unsafe` blocks that contain no unsafe operations",
+};
+
+/// Reports `unsafe` blocks that contain no operation requiring them.
+pub struct UnsafeLintPass {}
+
+impl LintPass for UnsafeLintPass {
+    fn lints(&self) -> &'static [&'static Lint] {
+        &[&UNUSED_UNSAFE]
+    }
+
+    fn check_module(&mut self, ctxt: &mut LintCtxt<'_>) {
+        let db = ctxt.db();
+        for &func in ctxt.top_mod().all_funcs(db) {
+            let Some(body) = func.body(db) else {
+                continue;
+            };
+            if ctxt.level(&UNUSED_UNSAFE, func.scope()) == LintLevel::Allow {
+                continue;
+            }
+
+            let typed_body = &check_func_body(db, func).1;
+            for expr in typed_body.unused_unsafe_blocks() {
+                ctxt.report(
+                    &UNUSED_UNSAFE,
+                    func.scope(),
+                    expr.span(body).into_unsafe_expr().unsafe_kw().into(),
+                    "unnecessary `unsafe` block".to_string(),
+                    "unnecessary `unsafe` block".to_string(),
+                );
+            }
+        }
+    }
+}
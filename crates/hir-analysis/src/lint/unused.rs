@@ -0,0 +1,500 @@
+//! Lints for code that is never used.
+
+use common::{diagnostics::LintLevel, ingot::Ingot};
+use hir::{
+    hir_def::{
+        scope_graph::ScopeId, Body, EnumVariant, Expr, ExprId, Func, IdentId, ItemKind, Partial,
+        Pat, PathId, Trait, Use, UseAlias,
+    },
+    span::DynLazySpan,
+    visitor::prelude::*,
+};
+use rustc_hash::FxHashSet;
+
+use super::{Lint, LintCtxt, LintPass};
+use crate::{
+    name_resolution::{
+        resolve_imports, resolve_path, resolve_path_imports, resolve_query, EarlyNameQueryId,
+        NameDerivation, NameRes, PathRes, QueryDirective, ResolvedImports,
+    },
+    ty::{
+        trait_lower::lower_impl_trait,
+        ty_check::{check_func_body, LocalBinding, TypedBody},
+    },
+    HirAnalysisDb,
+};
+
+pub const UNUSED_VARIABLES: Lint = Lint {
+    name: "unused_variables",
+    code: 1,
+    default_level: LintLevel::Warn,
+    description: "detects local variables and parameters that are never used",
+};
+
+pub const UNUSED_IMPORTS: Lint = Lint {
+    name: "unused_imports",
+    code: 2,
+    default_level: LintLevel::Warn,
+    description: "detects `use` imports that are never used",
+};
+
+pub const DEAD_CODE: Lint = Lint {
+    name: "dead_code",
+    code: 3,
+    default_level: LintLevel::Warn,
+    description: "detects private functions and types that are never used",
+};
+
+/// Reports unused variables, imports and private items.
+pub struct UnusedLintPass {}
+
+impl LintPass for UnusedLintPass {
+    fn lints(&self) -> &'static [&'static Lint] {
+        &[&UNUSED_VARIABLES, &UNUSED_IMPORTS, &DEAD_CODE]
+    }
+
+    fn check_module(&mut self, ctxt: &mut LintCtxt<'_>) {
+        check_unused_bindings(ctxt);
+
+        let db = ctxt.db();
+        let usage = ingot_usage(db, ctxt.top_mod().ingot(db));
+        check_unused_imports(ctxt, usage);
+        check_dead_code(ctxt, usage);
+    }
+}
+
+fn check_unused_bindings(ctxt: &mut LintCtxt<'_>) {
+    let db = ctxt.db();
+    for &func in ctxt.top_mod().all_funcs(db) {
+        // The bindings of generated code can't be renamed by the user.
+        let Some(body) = func.body(db).filter(|_| !func.is_expanded(db)) else {
+            continue;
+        };
+        if ctxt.level(&UNUSED_VARIABLES, func.scope()) == LintLevel::Allow {
+            continue;
+        }
+
+        let typed_body = &check_func_body(db, func).1;
+        for binding in typed_body.unused_bindings() {
+            let Some((name, span)) = binding_name_and_span(db, func, body, binding) else {
+                continue;
+            };
+
+            let name = name.data(db);
+            if name.starts_with('_') {
+                continue;
+            }
+
+            ctxt.report(
+                &UNUSED_VARIABLES,
+                func.scope(),
+                span,
+                format!("unused variable: `{name}`"),
+                format!("if this is intentional, prefix it with an underscore: `_{name}`"),
+            );
+        }
+    }
+}
+
+/// Returns the name of `binding` and the span it's defined at. Returns `None`
+/// for the `self` parameter, which is never reported as unused.
+fn binding_name_and_span<'db>(
+    db: &'db dyn HirAnalysisDb,
+    func: Func<'db>,
+    body: Body<'db>,
+    binding: LocalBinding<'db>,
+) -> Option<(IdentId<'db>, DynLazySpan<'db>)> {
+    match binding {
+        LocalBinding::Local { pat, .. } => match pat.data(db, body) {
+            Partial::Present(Pat::Binding(Partial::Present(name), ..)) => {
+                Some((*name, pat.span(body).into_binding_pat().name().into()))
+            }
+            Partial::Present(Pat::Path(Partial::Present(path), ..)) => Some((
+                path.ident(db).to_opt()?,
+                pat.span(body).into_path_pat().path().into(),
+            )),
+            _ => None,
+        },
+
+        LocalBinding::Param { idx, .. } => {
+            let param = &func.params(db).to_opt()?.data(db)[idx];
+            if param.is_self_param(db) {
+                return None;
+            }
+
+            Some((param.name()?, func.span().params().param(idx).name().into()))
+        }
+    }
+}
+
+fn check_unused_imports<'db>(ctxt: &mut LintCtxt<'db>, usage: &Usage<'db>) {
+    let db = ctxt.db();
+    let top_mod = ctxt.top_mod();
+    let resolved = &resolve_imports(db, top_mod.ingot(db)).1;
+
+    for item in top_mod.all_items(db) {
+        let ItemKind::Use(use_) = *item else {
+            continue;
+        };
+
+        // `use Trait as _` only brings the trait methods into scope.
+        if !use_.vis(db).is_private()
+            || matches!(use_.alias(db), Some(Partial::Present(UseAlias::Underscore)))
+            || usage.imports.contains(&use_)
+        {
+            continue;
+        }
+
+        // Unresolved imports are already reported as errors.
+        if imported_resolutions(db, resolved, use_).is_empty() {
+            continue;
+        }
+
+        let Partial::Present(path) = use_.path(db) else {
+            continue;
+        };
+        ctxt.report(
+            &UNUSED_IMPORTS,
+            use_.scope(),
+            use_.span().path().into(),
+            format!("unused import: `{}`", path.pretty_path(db)),
+            "unused import".to_string(),
+        );
+    }
+}
+
+/// Returns the resolutions that `use_` brings into scope.
+fn imported_resolutions<'db, 'a>(
+    db: &'db dyn HirAnalysisDb,
+    resolved: &'a ResolvedImports<'db>,
+    use_: Use<'db>,
+) -> Vec<&'a NameRes<'db>> {
+    let Some(scope) = use_.scope().lex_parent(db) else {
+        return Vec::new();
+    };
+
+    if use_.is_glob(db) {
+        resolved
+            .glob_resolved
+            .get(&scope)
+            .into_iter()
+            .flat_map(|imports| imports.iter())
+            .filter(|(glob, _)| **glob == use_)
+            .flat_map(|(_, resolutions)| resolutions.values().flatten())
+            .collect()
+    } else {
+        let Some(name) = use_.imported_name(db) else {
+            return Vec::new();
+        };
+
+        resolved
+            .named_resolved
+            .get(&scope)
+            .and_then(|imports| imports.get(&name))
+            .into_iter()
+            .flat_map(|bucket| bucket.iter_ok())
+            .filter(|res| res.derivation == NameDerivation::NamedImported(use_))
+            .collect()
+    }
+}
+
+fn check_dead_code<'db>(ctxt: &mut LintCtxt<'db>, usage: &Usage<'db>) {
+    let db = ctxt.db();
+    for item in ctxt.top_mod().all_items(db) {
+        match *item {
+            ItemKind::Func(func) => {
+                // Trait methods and their implementations are used through the trait.
+                if !func.vis(db).is_private()
+                    || func.is_extern(db)
+                    || matches!(
+                        func.scope().parent_item(db),
+                        Some(ItemKind::Trait(_) | ItemKind::ImplTrait(_))
+                    )
+                    || usage.used.contains(&func.scope())
+                {
+                    continue;
+                }
+
+                let Some(name) = func.name(db).to_opt() else {
+                    continue;
+                };
+                ctxt.report(
+                    &DEAD_CODE,
+                    func.scope(),
+                    func.span().name().into(),
+                    format!("function `{}` is never used", name.data(db)),
+                    "this function is never used".to_string(),
+                );
+            }
+
+            ItemKind::Struct(struct_) => {
+                if !struct_.vis(db).is_private() || usage.constructed.contains(&struct_.scope()) {
+                    continue;
+                }
+
+                let Some(name) = struct_.name(db).to_opt() else {
+                    continue;
+                };
+                ctxt.report(
+                    &DEAD_CODE,
+                    struct_.scope(),
+                    struct_.span().name().into(),
+                    format!("struct `{}` is never constructed", name.data(db)),
+                    "this struct is never constructed".to_string(),
+                );
+            }
+
+            ItemKind::Enum(enum_) => {
+                if !enum_.vis(db).is_private() {
+                    continue;
+                }
+
+                if !usage.used.contains(&enum_.scope()) {
+                    let Some(name) = enum_.name(db).to_opt() else {
+                        continue;
+                    };
+                    ctxt.report(
+                        &DEAD_CODE,
+                        enum_.scope(),
+                        enum_.span().name().into(),
+                        format!("enum `{}` is never used", name.data(db)),
+                        "this enum is never used".to_string(),
+                    );
+                    continue;
+                }
+
+                for idx in 0..enum_.variants(db).data(db).len() {
+                    let variant = EnumVariant::new(enum_, idx);
+                    if usage.constructed.contains(&variant.scope()) {
+                        continue;
+                    }
+
+                    let Some(name) = variant.name(db) else {
+                        continue;
+                    };
+                    ctxt.report(
+                        &DEAD_CODE,
+                        variant.scope(),
+                        enum_.span().variants().variant(idx).name().into(),
+                        format!("variant `{name}` is never constructed"),
+                        "this variant is never constructed".to_string(),
+                    );
+                }
+            }
+
+            _ => {}
+        }
+    }
+}
+
+/// Returns the imports that bring `trait_` into `scope`, which make the methods
+/// of the trait callable there. Only the innermost scope that has the trait in
+/// scope is taken into account.
+fn trait_imports<'db>(
+    db: &'db dyn HirAnalysisDb,
+    resolved: &ResolvedImports<'db>,
+    trait_: Trait<'db>,
+    scope: ScopeId<'db>,
+) -> Vec<Use<'db>> {
+    let mut current = Some(scope);
+    while let Some(scope) = current {
+        let named = resolved
+            .named_resolved
+            .get(&scope)
+            .into_iter()
+            .flat_map(|imports| imports.values())
+            .flat_map(|bucket| bucket.iter_ok());
+        let glob = resolved
+            .glob_resolved
+            .get(&scope)
+            .into_iter()
+            .flat_map(|imports| imports.values())
+            .flat_map(|resolutions| resolutions.values().flatten());
+        let unnamed = resolved
+            .unnamed_resolved
+            .get(&scope)
+            .into_iter()
+            .flatten()
+            .flat_map(|bucket| bucket.iter_ok());
+
+        let imports: Vec<_> = named
+            .chain(glob)
+            .chain(unnamed)
+            .filter(|res| res.trait_() == Some(trait_))
+            .filter_map(|res| res.derivation.use_stmt())
+            .collect();
+        if !imports.is_empty()
+            || scope
+                .child_items(db)
+                .any(|item| item == ItemKind::Trait(trait_))
+        {
+            return imports;
+        }
+        current = scope.parent(db);
+    }
+
+    Vec::new()
+}
+
+/// Collects the items and imports that are referred to anywhere in `ingot`.
+///
+/// A private item or import can only be referred to from its module or from
+/// the descendants of the module, so collecting them for the whole ingot at
+/// once gives the same result as collecting them for each module.
+#[salsa::tracked(return_ref)]
+fn ingot_usage<'db>(db: &'db dyn HirAnalysisDb, ingot: Ingot<'db>) -> Usage<'db> {
+    let mut collector = UsageCollector {
+        db,
+        resolved: &resolve_imports(db, ingot).1,
+        usage: Usage::default(),
+        func: None,
+    };
+
+    for top_mod in ingot.module_tree(db).all_modules() {
+        let mut ctxt = VisitorCtxt::with_top_mod(db, top_mod);
+        collector.visit_top_mod(&mut ctxt, top_mod);
+    }
+
+    collector.usage
+}
+
+/// The items and imports that are referred to in an ingot.
+#[derive(Debug, Default, PartialEq, Eq, salsa::Update)]
+struct Usage<'db> {
+    /// The scopes of the items that are referred to in any way.
+    used: FxHashSet<ScopeId<'db>>,
+    /// The scopes of the structs and enum variants that are constructed.
+    constructed: FxHashSet<ScopeId<'db>>,
+    /// The imports that some name is resolved through.
+    imports: FxHashSet<Use<'db>>,
+}
+
+struct UsageCollector<'db> {
+    db: &'db dyn HirAnalysisDb,
+    resolved: &'db ResolvedImports<'db>,
+    usage: Usage<'db>,
+    /// The function whose body is being visited, and its typed body.
+    func: Option<(Func<'db>, &'db TypedBody<'db>)>,
+}
+
+impl<'db> UsageCollector<'db> {
+    /// Resolves `path` as a value, falling back to a type.
+    fn resolve(&self, path: Partial<PathId<'db>>, scope: ScopeId<'db>) -> Option<PathRes<'db>> {
+        resolve_path(self.db, path.to_opt()?, scope, true).ok()
+    }
+
+    fn mark_used(&mut self, scope: ScopeId<'db>) {
+        // Recursive calls don't make a function used.
+        if self.func.map(|(func, _)| func.scope()) != Some(scope) {
+            self.usage.used.insert(scope);
+        }
+    }
+}
+
+impl<'db> Visitor<'db> for UsageCollector<'db> {
+    fn visit_func(&mut self, ctxt: &mut VisitorCtxt<'db, LazyFuncSpan<'db>>, func: Func<'db>) {
+        let typed_body = &check_func_body(self.db, func).1;
+        let outer = self.func.replace((func, typed_body));
+        walk_func(self, ctxt, func);
+        self.func = outer;
+    }
+
+    fn visit_use(&mut self, ctxt: &mut VisitorCtxt<'db, LazyUseSpan<'db>>, use_: Use<'db>) {
+        // An import can be used by the first segment of another import, e.g.,
+        // `use super::foo` and `use foo::Bar`.
+        let first_segment = use_
+            .path(self.db)
+            .to_opt()
+            .and_then(|path| path.data(self.db).first()?.to_opt()?.ident());
+
+        if let (Some(name), Some(scope)) = (first_segment, use_.scope().lex_parent(self.db)) {
+            let query = EarlyNameQueryId::new(self.db, name, scope, QueryDirective::new());
+            self.usage.imports.extend(
+                resolve_query(self.db, query)
+                    .iter_ok()
+                    .filter_map(|res| res.derivation.use_stmt())
+                    .filter(|import| *import != use_),
+            );
+        }
+
+        walk_use(self, ctxt, use_)
+    }
+
+    fn visit_expr(
+        &mut self,
+        ctxt: &mut VisitorCtxt<'db, LazyExprSpan<'db>>,
+        expr: ExprId,
+        expr_data: &Expr<'db>,
+    ) {
+        match expr_data {
+            Expr::Call(..) | Expr::MethodCall(..) => {
+                // Methods and associated functions are resolved by the type checker.
+                let callee = self.func.and_then(|(_, typed_body)| {
+                    typed_body
+                        .callable_expr(expr)?
+                        .func_def
+                        .hir_func_def(self.db)
+                });
+                if let Some(callee) = callee {
+                    self.mark_used(callee.scope());
+
+                    // Trait methods are callable only if the trait is in scope, which
+                    // uses the imports of the trait even if it isn't referred to by name.
+                    let trait_ = match callee.scope().parent_item(self.db) {
+                        Some(ItemKind::Trait(trait_)) => Some(trait_),
+                        Some(ItemKind::ImplTrait(impl_trait)) => {
+                            lower_impl_trait(self.db, impl_trait).map(|implementor| {
+                                implementor.skip_binder().trait_def(self.db).trait_(self.db)
+                            })
+                        }
+                        _ => None,
+                    };
+                    if let Some(trait_) = trait_ {
+                        self.usage.imports.extend(trait_imports(
+                            self.db,
+                            self.resolved,
+                            trait_,
+                            ctxt.scope(),
+                        ));
+                    }
+                }
+            }
+
+            Expr::RecordInit(path, ..) => match self.resolve(*path, ctxt.scope()) {
+                Some(PathRes::EnumVariant(variant)) => {
+                    self.usage.constructed.insert(variant.variant.scope());
+                }
+                Some(res) => {
+                    self.usage.constructed.extend(res.as_scope(self.db));
+                }
+                None => {}
+            },
+
+            Expr::Path(path) => {
+                if let Some(PathRes::EnumVariant(variant)) = self.resolve(*path, ctxt.scope()) {
+                    self.usage.constructed.insert(variant.variant.scope());
+                }
+            }
+
+            _ => {}
+        }
+
+        walk_expr(self, ctxt, expr)
+    }
+
+    fn visit_path(&mut self, ctxt: &mut VisitorCtxt<'db, LazyPathSpan<'db>>, path: PathId<'db>) {
+        let scope = ctxt.scope();
+        self.usage
+            .imports
+            .extend(resolve_path_imports(self.db, path, scope));
+
+        if let Some(scope) = self
+            .resolve(Partial::Present(path), scope)
+            .and_then(|res| res.as_scope(self.db))
+        {
+            self.mark_used(scope);
+        }
+
+        walk_path(self, ctxt, path)
+    }
+}
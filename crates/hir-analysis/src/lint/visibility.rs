@@ -0,0 +1,137 @@
+//! Lints for the visibility of items.
+
+use common::diagnostics::LintLevel;
+use hir::{
+    hir_def::{scope_graph::ScopeId, Func, ItemKind, TypeId as HirTyId, Visibility},
+    span::DynLazySpan,
+};
+use rustc_hash::FxHashSet;
+
+use super::{Lint, LintCtxt, LintPass};
+use crate::{
+    ty::{
+        adt_def::{AdtDef, AdtRef},
+        ty_def::TyId,
+        ty_lower::lower_hir_ty,
+        visitor::{TyVisitable, TyVisitor},
+    },
+    HirAnalysisDb,
+};
+
+pub const PRIVATE_INTERFACES: Lint = Lint {
+    name: "private_interfaces",
+    code: 6,
+    default_level: LintLevel::Warn,
+    description: "detects types that are less visible than the `pub` functions using them",
+};
+
+/// Reports types that are not `pub` in the signatures of `pub` functions.
+pub struct VisibilityLintPass {}
+
+impl LintPass for VisibilityLintPass {
+    fn lints(&self) -> &'static [&'static Lint] {
+        &[&PRIVATE_INTERFACES]
+    }
+
+    fn check_module(&mut self, ctxt: &mut LintCtxt<'_>) {
+        let db = ctxt.db();
+        for &func in ctxt.top_mod().all_funcs(db) {
+            if !is_public_interface(db, func)
+                || ctxt.level(&PRIVATE_INTERFACES, func.scope()) == LintLevel::Allow
+            {
+                continue;
+            }
+            let Some(name) = func.name(db).to_opt() else {
+                continue;
+            };
+
+            let mut tys: Vec<(HirTyId, DynLazySpan)> = vec![];
+            if let Some(params) = func.params(db).to_opt() {
+                for (idx, param) in params.data(db).iter().enumerate() {
+                    if let (false, Some(ty)) = (param.self_ty_fallback, param.ty.to_opt()) {
+                        tys.push((ty, func.span().params().param(idx).ty().into()));
+                    }
+                }
+            }
+            if let Some(ty) = func.ret_ty(db) {
+                tys.push((ty, func.span().ret_ty().into()));
+            }
+
+            let mut reported = FxHashSet::default();
+            for (ty, span) in tys {
+                for adt in non_pub_adts(db, ty, func.scope()) {
+                    if !reported.insert(adt) {
+                        continue;
+                    }
+                    let Some(adt_name) = adt.name(db) else {
+                        continue;
+                    };
+
+                    ctxt.report_with_secondary(
+                        &PRIVATE_INTERFACES,
+                        func.scope(),
+                        span.clone(),
+                        format!(
+                            "type `{}` is less visible than the public function `{}`",
+                            adt_name.data(db),
+                            name.data(db)
+                        ),
+                        format!("`{}` is used here", adt_name.data(db)),
+                        Some((
+                            adt.name_span(db),
+                            format!("`{}` is not `pub`", adt_name.data(db)),
+                        )),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Returns `true` if the signature of `func` is visible outside of its ingot.
+fn is_public_interface<'db>(db: &'db dyn HirAnalysisDb, func: Func<'db>) -> bool {
+    if !func.vis(db).is_pub() {
+        return false;
+    }
+
+    match func.scope().parent_item(db) {
+        // The methods of an inherent impl are only reachable through the type.
+        Some(ItemKind::Impl(impl_)) => impl_
+            .ty(db)
+            .to_opt()
+            .is_some_and(|ty| non_pub_adts(db, ty, impl_.scope()).is_empty()),
+        // The visibility of trait methods is decided by the trait.
+        Some(ItemKind::Trait(_) | ItemKind::ImplTrait(_)) => false,
+        _ => true,
+    }
+}
+
+/// Returns the ADTs in the type that are not `pub`.
+fn non_pub_adts<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ty: HirTyId<'db>,
+    scope: ScopeId<'db>,
+) -> Vec<AdtRef<'db>> {
+    struct AdtCollector<'db> {
+        db: &'db dyn HirAnalysisDb,
+        adts: Vec<AdtRef<'db>>,
+    }
+
+    impl<'db> TyVisitor<'db> for AdtCollector<'db> {
+        fn db(&self) -> &'db dyn HirAnalysisDb {
+            self.db
+        }
+
+        fn visit_adt(&mut self, adt: AdtDef<'db>) {
+            let adt = adt.adt_ref(self.db);
+            if adt.scope().data(self.db).vis != Visibility::Public && !self.adts.contains(&adt) {
+                self.adts.push(adt);
+            }
+        }
+    }
+
+    let ty: TyId<'db> = lower_hir_ty(db, ty, scope);
+    let mut collector = AdtCollector { db, adts: vec![] };
+    ty.visit_with(&mut collector);
+    collector.adts
+}
@@ -1,5 +1,7 @@
+use std::ops::RangeInclusive;
+
 use hir::{
-    hir_def::{IdentId, TopLevelMod},
+    hir_def::{scope_graph::ScopeId, IdentId, PathId, TopLevelMod},
     span::DynLazySpan,
 };
 use salsa::Update;
@@ -17,7 +19,14 @@ pub enum NameResDiag<'db> {
     NotFound(DynLazySpan<'db>, IdentId<'db>),
 
     /// The resolved name is not visible.
-    Invisible(DynLazySpan<'db>, IdentId<'db>, Option<DynLazySpan<'db>>),
+    /// The last field is the scope whose visibility hides the name, e.g., the
+    /// item itself or the `use` it is imported by.
+    Invisible(
+        DynLazySpan<'db>,
+        IdentId<'db>,
+        Option<DynLazySpan<'db>>,
+        Option<ScopeId<'db>>,
+    ),
 
     /// The resolved name is ambiguous.
     Ambiguous(DynLazySpan<'db>, IdentId<'db>, Vec<DynLazySpan<'db>>),
@@ -42,6 +51,9 @@ pub enum NameResDiag<'db> {
     /// The name is found but belongs to a different name domain other than the
     /// value.
     ExpectedValue(DynLazySpan<'db>, IdentId<'db>, &'static str),
+
+    /// The restriction of `pub(super)` or `pub(in path)` is invalid.
+    InvalidVisRestriction(DynLazySpan<'db>, VisRestrictionError<'db>),
 }
 
 impl<'db> NameResDiag<'db> {
@@ -54,13 +66,14 @@ impl<'db> NameResDiag<'db> {
                 .min()
                 .unwrap(),
             Self::NotFound(span, _) => span.top_mod(db).unwrap(),
-            Self::Invisible(span, _, _) => span.top_mod(db).unwrap(),
+            Self::Invisible(span, ..) => span.top_mod(db).unwrap(),
             Self::Ambiguous(span, _, _) => span.top_mod(db).unwrap(),
             Self::InvalidPathSegment(span, _, _) => span.top_mod(db).unwrap(),
             Self::ExpectedType(span, _, _) => span.top_mod(db).unwrap(),
             Self::ExpectedTrait(span, _, _) => span.top_mod(db).unwrap(),
             Self::ExpectedValue(span, _, _) => span.top_mod(db).unwrap(),
             Self::TooManyGenericArgs { span, .. } => span.top_mod(db).unwrap(),
+            Self::InvalidVisRestriction(span, _) => span.top_mod(db).unwrap(),
         }
     }
 
@@ -77,6 +90,9 @@ impl<'db> NameResDiag<'db> {
         Self::Ambiguous(span, ident, cands)
     }
 
+    /// The codes returned by [`Self::local_code`].
+    pub const LOCAL_CODES: RangeInclusive<u16> = 1..=10;
+
     pub fn local_code(&self) -> u16 {
         match self {
             Self::Conflict(..) => 1,
@@ -88,6 +104,18 @@ impl<'db> NameResDiag<'db> {
             Self::ExpectedTrait(..) => 7,
             Self::ExpectedValue(..) => 8,
             Self::TooManyGenericArgs { .. } => 9,
+            Self::InvalidVisRestriction(..) => 10,
         }
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Update)]
+pub enum VisRestrictionError<'db> {
+    /// `pub(super)` is used in the root module of an ingot.
+    NoParentModule,
+    /// The segment of the path in `pub(in path)` doesn't resolve to a module.
+    NotModule(PathId<'db>),
+    /// The module named by `pub(in path)` is not an ancestor of the module
+    /// containing the item.
+    NotAncestor(ScopeId<'db>),
+}
@@ -352,7 +352,7 @@ impl<'db> ImportResolver<'db> {
         let mut resolver = NameResolver::new(self.db, &self.resolved_imports);
         let mut bucket = resolver.resolve_query(query);
         // Filter out invisible resolutions.
-        let mut invisible_span = None;
+        let mut invisible = None;
         bucket.bucket.retain(|(_, res)| {
             let Ok(res) = res else {
                 return true;
@@ -365,7 +365,7 @@ impl<'db> ImportResolver<'db> {
                 true
             } else {
                 if let Some(span) = res.derived_from(self.db) {
-                    invisible_span.get_or_insert(span);
+                    invisible.get_or_insert((span, res.vis_scope()));
                 }
                 false
             }
@@ -382,8 +382,8 @@ impl<'db> ImportResolver<'db> {
         }
         if bucket.is_empty() {
             if self.is_decidable(i_use) {
-                let err = if let Some(invisible_span) = invisible_span {
-                    NameResolutionError::Invisible(invisible_span.into())
+                let err = if let Some((span, vis_scope)) = invisible {
+                    NameResolutionError::Invisible(span.into(), vis_scope)
                 } else {
                     NameResolutionError::NotFound
                 };
@@ -567,11 +567,12 @@ impl<'db> ImportResolver<'db> {
                     ))
             }
 
-            NameResolutionError::Invisible(invisible_span) => {
+            NameResolutionError::Invisible(invisible_span, vis_scope) => {
                 self.accumulated_errors.push(NameResDiag::Invisible(
                     i_use.current_segment_span(),
                     i_use.current_segment_ident(self.db).unwrap(),
                     invisible_span,
+                    vis_scope,
                 ));
             }
 
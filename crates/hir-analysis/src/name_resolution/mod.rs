@@ -7,21 +7,27 @@ pub(crate) mod traits_in_scope;
 mod visibility_checker;
 
 use common::ingot::Ingot;
-use hir::hir_def::TopLevelMod;
+use hir::{
+    hir_def::{scope_graph::ScopeId, FieldParent, ItemKind, TopLevelMod},
+    span::item::LazyVisRestrictionSpan,
+};
 pub use import_resolver::ResolvedImports;
 pub use name_resolver::{
     EarlyNameQueryId, NameDerivation, NameDomain, NameRes, NameResBucket, NameResKind,
     NameResolutionError, QueryDirective,
 };
 pub use path_resolver::{
-    resolve_ident_to_bucket, resolve_name_res, resolve_path, resolve_path_with_observer, PathRes,
-    PathResError, PathResErrorKind, ResolvedVariant,
+    resolve_ident_to_bucket, resolve_name_res, resolve_path, resolve_path_imports,
+    resolve_path_with_observer, PathRes, PathResError, PathResErrorKind, ResolvedVariant,
 };
 use tracing::debug;
 pub use traits_in_scope::available_traits_in_scope;
-pub(crate) use visibility_checker::is_scope_visible_from;
+pub(crate) use visibility_checker::{is_scope_visible_from, restricted_vis_scope};
 
-use self::{diagnostics::NameResDiag, import_resolver::DefaultImporter};
+use self::{
+    diagnostics::{NameResDiag, VisRestrictionError},
+    import_resolver::DefaultImporter,
+};
 use crate::{analysis_pass::ModuleAnalysisPass, diagnostics::DiagnosticVoucher, HirAnalysisDb};
 
 #[salsa::tracked(return_ref)]
@@ -54,6 +60,72 @@ impl ModuleAnalysisPass for ImportAnalysisPass {
     }
 }
 
+/// Checks that the module paths of `pub(super)` and `pub(in path)` name
+/// ancestors of the modules containing the items.
+pub struct VisibilityAnalysisPass {}
+
+impl ModuleAnalysisPass for VisibilityAnalysisPass {
+    fn run_on_module<'db>(
+        &mut self,
+        db: &'db dyn HirAnalysisDb,
+        top_mod: TopLevelMod<'db>,
+    ) -> Vec<Box<dyn DiagnosticVoucher + 'db>> {
+        let graph = top_mod.scope_graph(db);
+
+        graph
+            .scopes
+            .keys()
+            .filter_map(|&scope| {
+                let Err(err) = restricted_vis_scope(db, scope)? else {
+                    return None;
+                };
+
+                let span = vis_restriction_span(scope)?;
+                let span = match err {
+                    VisRestrictionError::NoParentModule => span.into(),
+                    VisRestrictionError::NotModule(segment) => {
+                        span.path().segment(segment.segment_index(db)).into()
+                    }
+                    VisRestrictionError::NotAncestor(_) => span.path().into(),
+                };
+                Some(Box::new(NameResDiag::InvalidVisRestriction(span, err)) as _)
+            })
+            .collect()
+    }
+}
+
+fn vis_restriction_span(scope: ScopeId) -> Option<LazyVisRestrictionSpan> {
+    let span = match scope {
+        ScopeId::Item(item) => match item {
+            ItemKind::Mod(mod_) => mod_.span().modifier(),
+            ItemKind::Func(func) => func.span().modifier(),
+            ItemKind::Struct(struct_) => struct_.span().modifier(),
+            ItemKind::Contract(contract) => contract.span().modifier(),
+            ItemKind::Enum(enum_) => enum_.span().modifier(),
+            ItemKind::TypeAlias(alias) => alias.span().modifier(),
+            ItemKind::Trait(trait_) => trait_.span().modifier(),
+            ItemKind::Const(const_) => const_.span().modifier(),
+            ItemKind::Use(use_) => use_.span().modifier(),
+            _ => return None,
+        }
+        .vis_restriction(),
+
+        ScopeId::Field(parent, idx) => {
+            let idx = idx as usize;
+            match parent {
+                FieldParent::Struct(struct_) => struct_.span().fields().field(idx),
+                FieldParent::Contract(contract) => contract.span().fields().field(idx),
+                FieldParent::Variant(variant) => variant.span().fields().field(idx),
+            }
+            .vis_restriction()
+        }
+
+        _ => return None,
+    };
+
+    Some(span)
+}
+
 #[salsa::tracked(return_ref, cycle_fn=resolve_imports_cycle_recover, cycle_initial=resolve_imports_cycle_initial)]
 pub fn resolve_imports<'db>(
     db: &'db dyn HirAnalysisDb,
@@ -266,6 +266,22 @@ impl<'db> NameRes<'db> {
         }
     }
 
+    /// Returns the scope whose visibility determines the visibility of the
+    /// resolution, i.e., the `use` if the name is imported.
+    pub(crate) fn vis_scope(&self) -> Option<ScopeId<'db>> {
+        let mut derivation = &self.derivation;
+        while let NameDerivation::Lex(inner) = derivation {
+            derivation = inner;
+        }
+
+        match derivation {
+            NameDerivation::NamedImported(use_) | NameDerivation::GlobImported(use_) => {
+                Some(ScopeId::from_item((*use_).into()))
+            }
+            _ => self.scope(),
+        }
+    }
+
     /// Returns `true` if the resolution is a type.
     pub fn is_type(&self) -> bool {
         match self.kind {
@@ -723,7 +739,8 @@ pub enum NameResolutionError<'db> {
     Invalid,
 
     /// The name is found, but it's not visible from the reference site.
-    Invisible(Option<DynLazySpan<'db>>),
+    /// The second field is the scope whose visibility hides the name.
+    Invisible(Option<DynLazySpan<'db>>, Option<ScopeId<'db>>),
 
     /// The name is found, but it's ambiguous.
     Ambiguous(ThinVec<NameRes<'db>>),
@@ -742,7 +759,7 @@ impl fmt::Display for NameResolutionError<'_> {
         match self {
             NameResolutionError::NotFound => write!(f, "name not found"),
             NameResolutionError::Invalid => write!(f, "invalid name"),
-            NameResolutionError::Invisible(_) => write!(f, "name is not visible"),
+            NameResolutionError::Invisible(..) => write!(f, "name is not visible"),
             NameResolutionError::Ambiguous(_) => write!(f, "name is ambiguous"),
             NameResolutionError::InvalidPathSegment(_) => write!(
                 f,
@@ -1,7 +1,7 @@
 use hir::{
     hir_def::{
-        scope_graph::ScopeId, Enum, EnumVariant, GenericParamOwner, ItemKind, Partial, PathId,
-        TypeId, VariantKind,
+        scope_graph::ScopeId, Const as HirConst, Enum, EnumVariant, GenericParamOwner, ItemKind,
+        Partial, PathId, TypeId, Use, VariantKind,
     },
     span::DynLazySpan,
 };
@@ -19,6 +19,8 @@ use crate::{
     name_resolution::{NameResKind, QueryDirective},
     ty::{
         adt_def::{lower_adt, AdtRef},
+        assoc_const::resolve_assoc_const,
+        assoc_ty::resolve_assoc_ty,
         binder::Binder,
         func_def::{lower_func, FuncDef, HirFuncDefKind},
         trait_def::TraitDef,
@@ -86,7 +88,7 @@ impl<'db> PathResError<'db> {
             NameResolutionError::Invalid => PathResErrorKind::ParseError,
             NameResolutionError::Ambiguous(vec) => PathResErrorKind::Ambiguous(vec),
             NameResolutionError::Conflict(_ident, vec) => PathResErrorKind::Conflict(vec),
-            NameResolutionError::Invisible(_) => unreachable!(),
+            NameResolutionError::Invisible(..) => unreachable!(),
             NameResolutionError::InvalidPathSegment(_) => unreachable!(),
         };
         Self::new(kind, path)
@@ -106,7 +108,7 @@ impl<'db> PathResError<'db> {
                 format!("Incorrect number of generic args; expected {expected}, given {actual}.")
             }
             PathResErrorKind::TraitMethodNotFound(_) => "Trait method not found".to_string(),
-            PathResErrorKind::AssocTy(_) => "Types cannot be nested inside other types".to_string(),
+            PathResErrorKind::AssocTy(_) => "Associated type not found".to_string(),
         }
     }
 
@@ -147,7 +149,7 @@ impl<'db> PathResError<'db> {
 
             PathResErrorKind::Ambiguous(cands) => NameResDiag::ambiguous(db, span, ident, cands),
 
-            PathResErrorKind::AssocTy(_) => todo!(),
+            PathResErrorKind::AssocTy(_) => NameResDiag::NotFound(span, ident),
             PathResErrorKind::TraitMethodNotFound(_) => todo!(),
             PathResErrorKind::TooManyGenericArgs { expected, given } => {
                 NameResDiag::TooManyGenericArgs {
@@ -178,6 +180,33 @@ pub fn resolve_ident_to_bucket<'db>(
     resolve_query(db, query)
 }
 
+/// Returns the imports through which the segments of `path` are resolved.
+pub fn resolve_path_imports<'db>(
+    db: &'db dyn HirAnalysisDb,
+    path: PathId<'db>,
+    scope: ScopeId<'db>,
+) -> Vec<Use<'db>> {
+    let (mut imports, query_scope) = match path.parent(db) {
+        Some(parent) => {
+            let imports = resolve_path_imports(db, parent, scope);
+            match resolve_path(db, parent, scope, false)
+                .ok()
+                .and_then(|res| res.as_scope(db))
+            {
+                Some(parent_scope) => (imports, parent_scope),
+                None => return imports,
+            }
+        }
+        None => (Vec::new(), scope),
+    };
+
+    if path.ident(db).is_present() {
+        let bucket = resolve_query(db, make_query(db, path, query_scope));
+        imports.extend(bucket.iter_ok().filter_map(|res| res.derivation.use_stmt()));
+    }
+    imports
+}
+
 /// Panics if path.ident is `Absent`
 fn make_query<'db>(
     db: &'db dyn HirAnalysisDb,
@@ -203,7 +232,7 @@ pub enum PathRes<'db> {
     FuncParam(ItemKind<'db>, u16),
     Trait(TraitDef<'db>),
     EnumVariant(ResolvedVariant<'db>),
-    Const(TyId<'db>),
+    Const(HirConst<'db>, TyId<'db>),
     Mod(ScopeId<'db>),
     TypeMemberTbd(TyId<'db>),
 }
@@ -217,7 +246,7 @@ impl<'db> PathRes<'db> {
             PathRes::Ty(ty) => PathRes::Ty(f(ty)),
             PathRes::TyAlias(alias, ty) => PathRes::TyAlias(alias, f(ty)),
             PathRes::Func(ty) => PathRes::Func(f(ty)),
-            PathRes::Const(ty) => PathRes::Const(f(ty)),
+            PathRes::Const(const_, ty) => PathRes::Const(const_, f(ty)),
             PathRes::EnumVariant(v) => PathRes::EnumVariant(ResolvedVariant { ty: f(v.ty), ..v }),
             PathRes::TypeMemberTbd(parent_ty) => PathRes::TypeMemberTbd(f(parent_ty)),
             r @ (PathRes::Trait(_) | PathRes::Mod(_) | PathRes::FuncParam(..)) => r,
@@ -228,7 +257,7 @@ impl<'db> PathRes<'db> {
         match self {
             PathRes::Ty(ty)
             | PathRes::Func(ty)
-            | PathRes::Const(ty)
+            | PathRes::Const(_, ty)
             | PathRes::TypeMemberTbd(ty) => ty.as_scope(db),
             PathRes::TyAlias(alias, _) => Some(alias.alias.scope()),
             PathRes::Trait(trait_) => Some(trait_.trait_(db).scope()),
@@ -242,7 +271,7 @@ impl<'db> PathRes<'db> {
         match self {
             PathRes::Ty(ty)
             | PathRes::Func(ty)
-            | PathRes::Const(ty)
+            | PathRes::Const(_, ty)
             | PathRes::TypeMemberTbd(ty) => is_ty_visible_from(db, *ty, from_scope),
             r => is_scope_visible_from(db, r.as_scope(db).unwrap(), from_scope),
         }
@@ -262,7 +291,7 @@ impl<'db> PathRes<'db> {
         };
 
         match self {
-            PathRes::Ty(ty) | PathRes::Func(ty) | PathRes::Const(ty) => ty_path(*ty),
+            PathRes::Ty(ty) | PathRes::Func(ty) | PathRes::Const(_, ty) => ty_path(*ty),
             PathRes::TyAlias(alias, _) => alias.alias.scope().pretty_path(db),
             PathRes::EnumVariant(v) => Some(format!(
                 "{}::{}",
@@ -287,7 +316,7 @@ impl<'db> PathRes<'db> {
             PathRes::FuncParam(..) => "function parameter",
             PathRes::Trait(_) => "trait",
             PathRes::EnumVariant(_) => "enum variant",
-            PathRes::Const(_) => "constant",
+            PathRes::Const(..) => "constant",
             PathRes::Mod(_) => "module",
             PathRes::TypeMemberTbd(_) => "method",
         }
@@ -425,13 +454,27 @@ where
                     }
                 }
             }
-            if is_tail {
-                let r = PathRes::TypeMemberTbd(ty);
-                observer(path, &r);
-                return Ok(r);
+            let name = *path.ident(db).unwrap();
+            let resolve_const = || {
+                resolve_assoc_const(db, ty, name, scope)
+                    .map(|(const_, const_ty)| PathRes::Const(const_, const_ty))
+            };
+
+            // Associated consts share the value namespace with methods, and
+            // are also allowed in type position as const generic arguments.
+            let r = if is_tail && resolve_tail_as_value {
+                resolve_const().unwrap_or(PathRes::TypeMemberTbd(ty))
             } else {
-                todo!() // assoc type error
-            }
+                match resolve_assoc_ty(db, ty, name, scope) {
+                    Some(assoc_ty) => PathRes::Ty(assoc_ty),
+                    None if is_tail => resolve_const().unwrap_or(PathRes::TypeMemberTbd(ty)),
+                    None => {
+                        return Err(PathResError::new(PathResErrorKind::AssocTy(ty), path));
+                    }
+                }
+            };
+            observer(path, &r);
+            return Ok(r);
         }
 
         Some(PathRes::Func(_) | PathRes::EnumVariant(..)) => {
@@ -441,7 +484,7 @@ where
             ));
         }
         Some(PathRes::TypeMemberTbd(_) | PathRes::FuncParam(..)) => unreachable!(),
-        Some(PathRes::Const(_) | PathRes::Mod(_) | PathRes::Trait(_)) | None => {}
+        Some(PathRes::Const(..) | PathRes::Mod(_) | PathRes::Trait(_)) | None => {}
     };
 
     let query = make_query(db, path, parent_scope);
@@ -491,11 +534,11 @@ pub fn resolve_name_res<'db>(
                 ItemKind::Const(const_) => {
                     // TODO err if any args
                     let ty = if let Some(ty) = const_.ty(db).to_opt() {
-                        lower_hir_ty(db, ty, scope)
+                        lower_hir_ty(db, ty, const_.scope())
                     } else {
                         TyId::invalid(db, InvalidCause::Other)
                     };
-                    PathRes::Const(ty)
+                    PathRes::Const(const_, ty)
                 }
 
                 ItemKind::TypeAlias(type_alias) => {
@@ -1,5 +1,9 @@
-use hir::hir_def::{scope_graph::ScopeId, ItemKind, Use};
+use hir::hir_def::{
+    scope_graph::{EdgeKind, ModEdge, ScopeId},
+    ItemKind, Use, Visibility,
+};
 
+use super::diagnostics::VisRestrictionError;
 use crate::{
     ty::{
         const_ty::ConstTyData,
@@ -15,8 +19,11 @@ pub(crate) fn is_scope_visible_from(
     from_scope: ScopeId,
 ) -> bool {
     // If resolved is public, then it is visible.
-    if scope.data(db).vis.is_pub() {
+    let vis = scope.data(db).vis;
+    if vis.is_pub() {
         return true;
+    } else if vis == Visibility::Ingot {
+        return scope.ingot(db) == from_scope.ingot(db);
     }
 
     let Some(def_scope) = (match scope {
@@ -34,6 +41,20 @@ pub(crate) fn is_scope_visible_from(
                 scope.parent(db)
             }
         }
+        ScopeId::Item(ItemKind::Const(const_)) => {
+            let parent_item = scope.parent_item(db);
+            if matches!(parent_item, Some(ItemKind::Trait(..))) {
+                return true;
+            }
+
+            if const_.is_associated_const(db) {
+                scope
+                    .parent_item(db)
+                    .and_then(|item| ScopeId::Item(item).parent(db))
+            } else {
+                scope.parent(db)
+            }
+        }
         ScopeId::Item(_) => scope.parent(db),
         ScopeId::Field(..) | ScopeId::Variant(..) => {
             let parent_item = scope.item();
@@ -45,7 +66,70 @@ pub(crate) fn is_scope_visible_from(
         return false;
     };
 
-    from_scope.is_transitive_child_of(db, def_scope)
+    let visible_scope = match restricted_vis_scope(db, scope) {
+        Some(Ok(module)) => module,
+        // An invalid restriction is reported separately, and the scope is
+        // treated as private.
+        _ => def_scope,
+    };
+    from_scope.is_transitive_child_of(db, visible_scope)
+}
+
+/// Returns the module that `scope` is visible within if the scope has
+/// `pub(super)` or `pub(in path)` visibility.
+///
+/// The module path of the restriction is resolved through the module
+/// structure only, without looking at imports, and must name an ancestor of
+/// the module containing `scope`.
+pub(crate) fn restricted_vis_scope<'db>(
+    db: &'db dyn HirAnalysisDb,
+    scope: ScopeId<'db>,
+) -> Option<Result<ScopeId<'db>, VisRestrictionError<'db>>> {
+    let vis = scope.data(db).vis;
+    if !matches!(vis, Visibility::Super | Visibility::In(_)) {
+        return None;
+    }
+    let module = scope.parent_module(db)?;
+
+    let path = match vis {
+        Visibility::In(path) => path.to_opt()?,
+        _ => {
+            let parent = super_module(db, module).ok_or(VisRestrictionError::NoParentModule);
+            return Some(parent);
+        }
+    };
+
+    let mut current = module;
+    for idx in 0..path.len(db) {
+        let segment = path.segment(db, idx).unwrap();
+        let ident = segment.ident(db).to_opt()?;
+        let dest = current.edges(db).iter().find_map(|edge| match edge.kind {
+            EdgeKind::Ingot(_) if ident.is_ingot(db) => Some(edge.dest),
+            EdgeKind::Super(_) if ident.is_super(db) => Some(edge.dest),
+            EdgeKind::Self_(_) if ident.is_self(db) => Some(edge.dest),
+            EdgeKind::Mod(ModEdge(name)) if name == ident => Some(edge.dest),
+            _ => None,
+        });
+
+        match dest {
+            Some(dest) => current = dest,
+            None => return Some(Err(VisRestrictionError::NotModule(segment))),
+        }
+    }
+
+    if module.is_transitive_child_of(db, current) {
+        Some(Ok(current))
+    } else {
+        Some(Err(VisRestrictionError::NotAncestor(current)))
+    }
+}
+
+fn super_module<'db>(db: &'db dyn HirAnalysisDb, module: ScopeId<'db>) -> Option<ScopeId<'db>> {
+    module
+        .edges(db)
+        .iter()
+        .find(|edge| matches!(edge.kind, EdgeKind::Super(_)))
+        .map(|edge| edge.dest)
 }
 
 pub(crate) fn is_ty_visible_from(db: &dyn HirAnalysisDb, ty: TyId, from_scope: ScopeId) -> bool {
@@ -65,6 +149,10 @@ pub(crate) fn is_ty_visible_from(db: &dyn HirAnalysisDb, ty: TyId, from_scope: S
             ConstTyData::Evaluated(_, _) => true,
             ConstTyData::UnEvaluated(body) => is_scope_visible_from(db, body.scope(), from_scope),
         },
+        TyData::AssocTy(assoc_ty) => {
+            is_scope_visible_from(db, assoc_ty.trait_.def(db).trait_(db).scope(), from_scope)
+                && is_ty_visible_from(db, assoc_ty.trait_.self_ty(db), from_scope)
+        }
         TyData::TyVar(_) | TyData::Never | TyData::Invalid(_) => true,
         TyData::TyApp(_, _) => unreachable!(),
     }
@@ -74,10 +162,17 @@ pub(crate) fn is_ty_visible_from(db: &dyn HirAnalysisDb, ty: TyId, from_scope: S
 pub(super) fn is_use_visible(db: &dyn HirAnalysisDb, ref_scope: ScopeId, use_: Use) -> bool {
     let use_scope = ScopeId::from_item(use_.into());
 
-    if use_scope.data(db).vis.is_pub() {
+    let vis = use_scope.data(db).vis;
+    if vis.is_pub() {
         return true;
+    } else if vis == Visibility::Ingot {
+        return use_scope.ingot(db) == ref_scope.ingot(db);
     }
 
     let use_def_scope = use_scope.parent(db).unwrap();
-    ref_scope.is_transitive_child_of(db, use_def_scope)
+    let visible_scope = match restricted_vis_scope(db, use_scope) {
+        Some(Ok(module)) => module,
+        _ => use_def_scope,
+    };
+    ref_scope.is_transitive_child_of(db, visible_scope)
 }
@@ -0,0 +1,84 @@
+//! This module implements the resolution of associated consts.
+
+use hir::hir_def::{scope_graph::ScopeId, Const, HirIngot, IdentId};
+
+use super::{
+    assoc_ty::{assoc_ty_cands, select_implementor},
+    binder::Binder,
+    fold::TyFoldable,
+    ty_def::{InvalidCause, TyId},
+    ty_lower::lower_hir_ty,
+    unify::UnificationTable,
+};
+use crate::HirAnalysisDb;
+
+/// Resolves the associated const `name` of the given `ty` in the `scope`.
+///
+/// The consts defined in the inherent impls of `ty` take precedence over the
+/// consts declared in the traits that `ty` implements. If the const is
+/// declared in a trait and the implementor of the trait can be determined,
+/// the const defined in the implementor is returned instead of the
+/// declaration in the trait.
+///
+/// Returns the resolved const and its type.
+pub(crate) fn resolve_assoc_const<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ty: TyId<'db>,
+    name: IdentId<'db>,
+    scope: ScopeId<'db>,
+) -> Option<(Const<'db>, TyId<'db>)> {
+    if let Some(res) = resolve_inherent_assoc_const(db, ty, name, scope) {
+        return Some(res);
+    }
+
+    let mut cands = assoc_ty_cands(db, ty, scope)
+        .into_iter()
+        .filter_map(|inst| Some((inst, inst.def(db).assoc_const(db, name)?)));
+    let (inst, assoc_const) = cands.next()?;
+    if cands.next().is_some() {
+        return None;
+    }
+
+    let const_ty = assoc_const.ty.instantiate(db, inst.args(db));
+    let const_ = select_implementor(db, inst)
+        .and_then(|implementor| implementor.assoc_const(db, name))
+        .map(|assoc_const| assoc_const.const_)
+        .unwrap_or(assoc_const.const_);
+
+    Some((const_, const_ty))
+}
+
+fn resolve_inherent_assoc_const<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ty: TyId<'db>,
+    name: IdentId<'db>,
+    scope: ScopeId<'db>,
+) -> Option<(Const<'db>, TyId<'db>)> {
+    let ingot = ty.ingot(db).unwrap_or(scope.ingot(db));
+
+    for impl_ in ingot.all_impls(db) {
+        let Some(const_) = impl_
+            .consts(db)
+            .find(|const_| const_.name(db).to_opt() == Some(name))
+        else {
+            continue;
+        };
+        let Some(impl_ty) = impl_.ty(db).to_opt() else {
+            continue;
+        };
+
+        let impl_ty = lower_hir_ty(db, impl_ty, impl_.scope());
+        let const_ty = match const_.ty(db).to_opt() {
+            Some(const_ty) => lower_hir_ty(db, const_ty, const_.scope()),
+            None => TyId::invalid(db, InvalidCause::Other),
+        };
+
+        let mut table = UnificationTable::new(db);
+        let tys = table.instantiate_with_fresh_vars(Binder::bind(vec![impl_ty, const_ty]));
+        if table.unify(tys[0], ty).is_ok() {
+            return Some((const_, tys[1].fold_with(&mut table)));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,209 @@
+//! This module implements the resolution and normalization of associated
+//! types.
+
+use common::indexmap::IndexSet;
+use hir::hir_def::{
+    scope_graph::ScopeId, GenericParam, GenericParamOwner, IdentId, ItemKind, TypeBound,
+    TypeKind as HirTyKind,
+};
+
+use super::{
+    canonical::Canonical,
+    fold::TyFoldable,
+    trait_def::{impls_for_trait, impls_for_ty, Implementor, TraitInstId},
+    trait_lower::{lower_trait, lower_trait_ref},
+    ty_def::{InvalidCause, TyData, TyId, TyParam},
+    unify::UnificationTable,
+};
+use crate::HirAnalysisDb;
+
+/// Resolves the associated type `name` of the given `ty` in the `scope`.
+///
+/// The candidate traits are the trait bounds of `ty` if `ty` is a type
+/// parameter, or the traits implemented by `ty` otherwise. Super traits of the
+/// candidates are also taken into account.
+///
+/// Returns `None` if no candidate trait declares the associated type.
+pub(crate) fn resolve_assoc_ty<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ty: TyId<'db>,
+    name: IdentId<'db>,
+    scope: ScopeId<'db>,
+) -> Option<TyId<'db>> {
+    let cands: Vec<_> = assoc_ty_cands(db, ty, scope)
+        .into_iter()
+        .filter(|inst| inst.def(db).declares_assoc_ty(db, name))
+        .collect();
+
+    match cands.as_slice() {
+        [] => None,
+        [inst] => Some(TyId::assoc_ty(db, *inst, name)),
+        _ => Some(TyId::invalid(
+            db,
+            InvalidCause::AmbiguousAssocTy { ty, name, cands },
+        )),
+    }
+}
+
+/// Tries to normalize the given associated type into the type that is defined
+/// in the corresponding impl, or into the default type of the trait if the
+/// impl doesn't define it.
+///
+/// Returns `None` if the impl can't be determined, e.g., the self type is a
+/// type parameter or contains type variables.
+#[salsa::tracked]
+pub(crate) fn normalize_assoc_ty<'db>(
+    db: &'db dyn HirAnalysisDb,
+    trait_: TraitInstId<'db>,
+    name: IdentId<'db>,
+) -> Option<TyId<'db>> {
+    let implementor = select_implementor(db, trait_)?;
+
+    match implementor.assoc_ty(db, name) {
+        Some(ty) => Some(ty.instantiate(db, implementor.params(db))),
+        None => Some(
+            trait_
+                .def(db)
+                .assoc_ty_default(db, name)?
+                .instantiate(db, trait_.args(db)),
+        ),
+    }
+}
+
+/// Selects the implementor of the given trait instance. The returned
+/// implementor is instantiated so that it matches the trait instance.
+///
+/// Returns `None` if the implementor can't be determined uniquely, e.g., the
+/// self type is a type parameter or contains type variables.
+pub(super) fn select_implementor<'db>(
+    db: &'db dyn HirAnalysisDb,
+    trait_: TraitInstId<'db>,
+) -> Option<Implementor<'db>> {
+    let self_ty = trait_.self_ty(db);
+    if matches!(
+        self_ty.base_ty(db).data(db),
+        TyData::TyParam(_) | TyData::TyVar(_) | TyData::AssocTy(_) | TyData::Invalid(_)
+    ) || trait_.args(db).iter().any(|arg| arg.has_var(db))
+    {
+        return None;
+    }
+
+    let trait_ingot = trait_.def(db).ingot(db);
+    let ingot = self_ty.ingot(db).unwrap_or(trait_ingot);
+    let mut impls = impls_for_trait(db, ingot, Canonical::new(db, trait_));
+    if impls.is_empty() && ingot != trait_ingot {
+        impls = impls_for_trait(db, trait_ingot, Canonical::new(db, trait_));
+    }
+
+    let [implementor] = impls.as_slice() else {
+        return None;
+    };
+
+    let mut table = UnificationTable::new(db);
+    let implementor = table.instantiate_with_fresh_vars(*implementor);
+    table.unify(implementor.trait_(db), trait_).ok()?;
+    Some(implementor.fold_with(&mut table))
+}
+
+/// Collects the trait instances whose associated items can be referred to
+/// through `ty`.
+pub(super) fn assoc_ty_cands<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ty: TyId<'db>,
+    scope: ScopeId<'db>,
+) -> IndexSet<TraitInstId<'db>> {
+    let mut cands = IndexSet::new();
+
+    match ty.data(db) {
+        TyData::TyParam(param) if param.is_trait_self => {
+            if let Some(ItemKind::Trait(trait_)) = param.owner.to_item() {
+                let def = lower_trait(db, trait_);
+                let mut args = def.params(db).to_vec();
+                args[0] = ty;
+                cands.insert(TraitInstId::new(db, def, args));
+            }
+        }
+
+        TyData::TyParam(param) => {
+            cands.extend(param_bounds(db, ty, param));
+        }
+
+        _ => {
+            for implementor in impls_for_ty(db, scope.ingot(db), Canonical::new(db, ty)) {
+                let mut table = UnificationTable::new(db);
+                let implementor = table.instantiate_with_fresh_vars(*implementor);
+                if table.unify(implementor.self_ty(db), ty).is_ok() {
+                    cands.insert(implementor.trait_(db).fold_with(&mut table));
+                }
+            }
+        }
+    }
+
+    // Add all super traits of the candidates.
+    let mut i = 0;
+    while i < cands.len() {
+        let inst = cands[i];
+        for super_trait in inst.def(db).super_traits(db) {
+            cands.insert(super_trait.instantiate(db, inst.args(db)));
+        }
+        i += 1;
+    }
+
+    cands
+}
+
+/// Collects the trait bounds that are declared on the given type parameter,
+/// either in the generic parameter list or in the where clause of its owner.
+///
+/// NOTE: The bounds are collected syntactically so that resolving associated
+/// types in the signature of the owner doesn't depend on the constraints of the
+/// owner, which would introduce a cycle.
+fn param_bounds<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ty: TyId<'db>,
+    param: &TyParam<'db>,
+) -> Vec<TraitInstId<'db>> {
+    let Some(owner) = param
+        .owner
+        .to_item()
+        .and_then(GenericParamOwner::from_item_opt)
+    else {
+        return vec![];
+    };
+    let scope = owner.scope();
+
+    let mut bounds: Vec<TypeBound> = owner
+        .params(db)
+        .data(db)
+        .iter()
+        .filter_map(|param_def| match param_def {
+            GenericParam::Type(type_param) if type_param.name.to_opt() == Some(param.name) => {
+                Some(type_param.bounds.iter().cloned())
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect();
+
+    if let Some(where_clause_owner) = owner.where_clause_owner() {
+        for pred in where_clause_owner.where_clause(db).data(db) {
+            let Some(HirTyKind::Path(path)) = pred.ty.to_opt().map(|ty| ty.data(db)) else {
+                continue;
+            };
+            let Some(path) = path.to_opt() else {
+                continue;
+            };
+            if path.is_bare_ident(db) && path.ident(db).to_opt() == Some(param.name) {
+                bounds.extend(pred.bounds.iter().cloned());
+            }
+        }
+    }
+
+    bounds
+        .into_iter()
+        .filter_map(|bound| match bound {
+            TypeBound::Trait(trait_ref) => lower_trait_ref(db, ty, trait_ref, scope).ok(),
+            TypeBound::Kind(_) => None,
+        })
+        .collect()
+}
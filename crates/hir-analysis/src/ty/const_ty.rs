@@ -1,10 +1,12 @@
 use hir::hir_def::{Body, Expr, IntegerId, LitKind, Partial};
+use rustc_hash::FxHashSet;
 
 use super::{
     ty_def::{InvalidCause, TyId, TyParam, TyVar},
     unify::UnificationTable,
 };
 use crate::{
+    name_resolution::{resolve_path, PathRes},
     ty::ty_def::{Kind, TyBase, TyData, TyVarSort},
     HirAnalysisDb,
 };
@@ -33,7 +35,10 @@ pub(crate) fn evaluate_const_ty<'db>(
         };
     };
 
-    let Partial::Present(expr) = body.expr(db).data(db, *body) else {
+    let Some(value_body) = follow_const_paths(db, *body) else {
+        return ConstTyId::invalid(db, InvalidCause::Other);
+    };
+    let Partial::Present(expr) = value_body.expr(db).data(db, value_body) else {
         let data = ConstTyData::Evaluated(
             EvaluatedConstTy::Invalid,
             TyId::invalid(db, InvalidCause::Other),
@@ -72,6 +77,28 @@ pub(crate) fn evaluate_const_ty<'db>(
     ConstTyId::new(db, data)
 }
 
+/// Follows the paths to consts starting from `body`, e.g., `{ Self::MAX }`,
+/// and returns the first body that isn't a path to a const.
+///
+/// Returns `None` if the paths form a cycle or reach a const without a value.
+fn follow_const_paths<'db>(db: &'db dyn HirAnalysisDb, mut body: Body<'db>) -> Option<Body<'db>> {
+    let mut visited = FxHashSet::default();
+    loop {
+        let Partial::Present(Expr::Path(Partial::Present(path))) = body.expr(db).data(db, body)
+        else {
+            return Some(body);
+        };
+        let Ok(PathRes::Const(const_, _)) = resolve_path(db, *path, body.scope(), true) else {
+            return Some(body);
+        };
+
+        if !visited.insert(const_) {
+            return None;
+        }
+        body = const_.body(db).to_opt()?;
+    }
+}
+
 // FIXME: When we add type inference, we need to use the inference engine to
 // check the type of the expression instead of this function.
 fn check_const_ty<'db>(
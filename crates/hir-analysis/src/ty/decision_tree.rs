@@ -14,6 +14,9 @@ pub enum DecisionTree<'db> {
     Leaf(LeafNode<'db>),
     /// Switch node - test a value and branch
     Switch(SwitchNode<'db>),
+    /// Guard node - execute this match arm if its guard holds, otherwise
+    /// continue with the remaining arms
+    Guard(GuardNode<'db>),
 }
 
 /// A leaf node in the decision tree
@@ -34,6 +37,15 @@ impl<'db> LeafNode<'db> {
     }
 }
 
+/// A guard node in the decision tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardNode<'db> {
+    /// The guarded arm to execute if the guard holds
+    pub leaf: LeafNode<'db>,
+    /// The tree to continue with if the guard fails. `None` if no arm remains
+    pub otherwise: Option<Box<DecisionTree<'db>>>,
+}
+
 /// A switch node in the decision tree
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SwitchNode<'db> {
@@ -189,15 +201,15 @@ impl ColumnScoringFunction {
     ) -> i32 {
         match self {
             ColumnScoringFunction::Arity => matrix
-                .sigma_set(col)
+                .sigma_set(db, col)
                 .0
                 .iter()
                 .map(|c| -(c.arity(db) as i32))
                 .sum(),
 
             ColumnScoringFunction::SmallBranching => {
-                let sigma_set = matrix.sigma_set(col);
-                let score = -(matrix.sigma_set(col).len() as i32);
+                let sigma_set = matrix.sigma_set(db, col);
+                let score = -(sigma_set.len() as i32);
                 if sigma_set.is_complete(db) {
                     score
                 } else {
@@ -217,19 +229,27 @@ impl ColumnScoringFunction {
 }
 
 /// Build a decision tree from a pattern matrix with a specific column selection policy
+///
+/// `guarded[i]` tells whether the `i`-th row of the matrix belongs to a guarded
+/// match arm.
 pub fn build_decision_tree_with_policy<'db>(
     db: &'db dyn HirAnalysisDb,
     matrix: &PatternMatrix<'db>,
+    guarded: &[bool],
     policy: ColumnSelectionPolicy,
 ) -> DecisionTree<'db> {
-    let simplified_matrix = SimplifiedArmMatrix::new(matrix);
+    let simplified_matrix = SimplifiedArmMatrix::new(matrix, guarded);
     DecisionTreeBuilder::new(policy).build(db, simplified_matrix)
 }
 
 /// Build a decision tree from a pattern matrix with optimized column selection
+///
+/// `guarded[i]` tells whether the `i`-th row of the matrix belongs to a guarded
+/// match arm.
 pub fn build_decision_tree<'db>(
     db: &'db dyn HirAnalysisDb,
     matrix: &PatternMatrix<'db>,
+    guarded: &[bool],
 ) -> DecisionTree<'db> {
     let policy = {
         let mut policy = ColumnSelectionPolicy::default();
@@ -237,7 +257,7 @@ pub fn build_decision_tree<'db>(
         policy.needed_prefix().small_branching().arity();
         policy
     };
-    build_decision_tree_with_policy(db, matrix, policy)
+    build_decision_tree_with_policy(db, matrix, guarded, policy)
 }
 
 /// Decision tree builder with configurable policy
@@ -258,11 +278,16 @@ impl DecisionTreeBuilder {
         debug_assert!(matrix.nrows() > 0, "unexhausted pattern matrix");
 
         if matrix.is_first_arm_satisfied() {
-            matrix.arms.truncate(1);
-            return DecisionTree::Leaf(LeafNode::new(
-                matrix.arms.pop().unwrap(),
-                &matrix.occurrences,
-            ));
+            let first = matrix.arms.remove(0);
+            let guarded = first.guarded;
+            let leaf = LeafNode::new(first, &matrix.occurrences);
+            if !guarded {
+                return DecisionTree::Leaf(leaf);
+            }
+
+            // The guard may fail, so the remaining arms still need to be tested.
+            let otherwise = (matrix.nrows() > 0).then(|| Box::new(self.build(db, matrix)));
+            return DecisionTree::Guard(GuardNode { leaf, otherwise });
         }
 
         let col = self.policy.select_column(db, &matrix);
@@ -270,7 +295,7 @@ impl DecisionTreeBuilder {
 
         let mut switch_arms = vec![];
         let occurrence = &matrix.occurrences[0];
-        let sigma_set = matrix.sigma_set(0);
+        let sigma_set = matrix.sigma_set(db, 0);
         for &ctor in sigma_set.0.iter() {
             let destructured_mat = matrix.phi_specialize(db, ctor, occurrence);
             let subtree = self.build(db, destructured_mat);
@@ -295,16 +320,18 @@ impl DecisionTreeBuilder {
 struct SimplifiedArm<'db> {
     pat_vec: PatternRowVec<'db>,
     body: usize,
+    guarded: bool,
     binds: IndexMap<(IdentId<'db>, usize), Occurrence>,
 }
 
 impl<'db> SimplifiedArm<'db> {
-    fn new(pat_vec: &PatternRowVec<'db>, body: usize) -> Self {
+    fn new(pat_vec: &PatternRowVec<'db>, body: usize, guarded: bool) -> Self {
         let generalized_patterns = pat_vec.inner.iter().map(generalize_pattern).collect();
         let pat_vec = PatternRowVec::new(generalized_patterns);
         Self {
             pat_vec,
             body,
+            guarded,
             binds: IndexMap::new(),
         }
     }
@@ -315,10 +342,10 @@ impl<'db> SimplifiedArm<'db> {
 
     fn new_binds(&self, occurrence: &Occurrence) -> IndexMap<(IdentId<'db>, usize), Occurrence> {
         let mut binds = self.binds.clone();
-        if let Some(SimplifiedPatternKind::WildCard(Some(bind))) =
-            self.pat_vec.head().map(|pat| &pat.kind)
-        {
-            binds.entry(*bind).or_insert(occurrence.clone());
+        if let Some(head) = self.pat_vec.head() {
+            for bind in pattern_binds(head) {
+                binds.entry(bind).or_insert(occurrence.clone());
+            }
         }
         binds
     }
@@ -327,14 +354,12 @@ impl<'db> SimplifiedArm<'db> {
         &self,
         occurrences: &[Occurrence],
     ) -> IndexMap<(IdentId<'db>, usize), Occurrence> {
-        use super::simplified_pattern::SimplifiedPatternKind;
-
         let mut binds = self.binds.clone();
 
         // Extract bindings from current patterns
         for (pat, occurrence) in self.pat_vec.inner.iter().zip(occurrences.iter()) {
-            if let SimplifiedPatternKind::WildCard(Some(bind)) = &pat.kind {
-                binds.entry(*bind).or_insert_with(|| occurrence.clone());
+            for bind in pattern_binds(pat) {
+                binds.entry(bind).or_insert_with(|| occurrence.clone());
             }
         }
 
@@ -350,13 +375,16 @@ struct SimplifiedArmMatrix<'db> {
 }
 
 impl<'db> SimplifiedArmMatrix<'db> {
-    fn new(matrix: &PatternMatrix<'db>) -> Self {
+    fn new(matrix: &PatternMatrix<'db>, guarded: &[bool]) -> Self {
         let cols = matrix.ncols();
         let arms: Vec<_> = matrix
             .rows
             .iter()
             .enumerate()
-            .map(|(body, pat)| SimplifiedArm::new(pat, body))
+            .map(|(body, pat)| {
+                let guarded = guarded.get(body).copied().unwrap_or_default();
+                SimplifiedArm::new(pat, body, guarded)
+            })
             .collect();
         let occurrences = vec![Occurrence::default(); cols];
 
@@ -392,8 +420,8 @@ impl<'db> SimplifiedArmMatrix<'db> {
         PatternMatrix::new(rows)
     }
 
-    fn sigma_set(&self, col: usize) -> SigmaSet<'db> {
-        SigmaSet::from_rows(self.arms.iter().map(|arm| &arm.pat_vec), col)
+    fn sigma_set(&self, db: &'db dyn HirAnalysisDb, col: usize) -> SigmaSet<'db> {
+        SigmaSet::from_rows(db, self.arms.iter().map(|arm| &arm.pat_vec), col)
     }
 
     fn is_first_arm_satisfied(&self) -> bool {
@@ -438,6 +466,7 @@ impl<'db> SimplifiedArmMatrix<'db> {
                     .map(|pat_vec| SimplifiedArm {
                         pat_vec,
                         body: arm.body,
+                        guarded: arm.guarded,
                         binds: binds.clone(),
                     }),
             );
@@ -465,6 +494,7 @@ impl<'db> SimplifiedArmMatrix<'db> {
                     .map(|pat_vec| SimplifiedArm {
                         pat_vec,
                         body: arm.body,
+                        guarded: arm.guarded,
                         binds: binds.clone(),
                     }),
             );
@@ -545,6 +575,17 @@ impl NecessityMatrix {
     }
 }
 
+/// Returns the bindings introduced by `pat` itself, not by its fields.
+fn pattern_binds<'db>(
+    pat: &SimplifiedPattern<'db>,
+) -> impl Iterator<Item = (IdentId<'db>, usize)> + '_ {
+    let wildcard_bind = match &pat.kind {
+        SimplifiedPatternKind::WildCard(bind) => *bind,
+        _ => None,
+    };
+    wildcard_bind.into_iter().chain(pat.binds.iter().copied())
+}
+
 /// Generalize a pattern by removing bindings from constructors
 fn generalize_pattern<'db>(pat: &SimplifiedPattern<'db>) -> SimplifiedPattern<'db> {
     match &pat.kind {
@@ -556,7 +597,10 @@ fn generalize_pattern<'db>(pat: &SimplifiedPattern<'db>) -> SimplifiedPattern<'d
                 kind: *kind,
                 fields,
             };
-            SimplifiedPattern::new(kind, pat.ty)
+            SimplifiedPattern {
+                binds: pat.binds.clone(),
+                ..SimplifiedPattern::new(kind, pat.ty)
+            }
         }
 
         SimplifiedPatternKind::Or(pats) => {
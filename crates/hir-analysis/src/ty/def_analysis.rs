@@ -5,9 +5,9 @@
 use common::indexmap::IndexSet;
 use hir::{
     hir_def::{
-        scope_graph::ScopeId, EnumVariant, FieldDef, FieldParent, Func, GenericParam, IdentId,
-        Impl as HirImpl, ImplTrait, ItemKind, PathId, Trait, TraitRefId, TypeId as HirTyId,
-        VariantKind,
+        scope_graph::ScopeId, Attr, DerivableTrait, EnumVariant, FieldDef, FieldParent, Func,
+        GenericParam, IdentId, Impl as HirImpl, ImplTrait, ItemKind, Partial, PathId, Trait,
+        TraitRefId, TypeId as HirTyId, VariantKind,
     },
     visitor::prelude::*,
 };
@@ -94,12 +94,55 @@ pub fn analyze_adt<'db>(
         ))
     }
 
+    dupes.extend(check_derive_attrs(db, adt_ref));
+
     let analyzer = DefAnalyzer::for_adt(db, adt_ref);
     let mut diags = analyzer.analyze();
     diags.extend(dupes);
     diags
 }
 
+/// Checks the arguments of the `#derive(..)` attributes of the ADT. The
+/// implementations of the valid arguments are generated while lowering the
+/// ADT, and are checked like the other implementations.
+fn check_derive_attrs<'db>(
+    db: &'db dyn HirAnalysisDb,
+    adt_ref: AdtRef<'db>,
+) -> SmallVec<[TyDiagCollection<'db>; 2]> {
+    let (attrs, attrs_span) = match adt_ref {
+        AdtRef::Struct(struct_) => (struct_.attributes(db), struct_.span().attributes()),
+        AdtRef::Enum(enum_) => (enum_.attributes(db), enum_.span().attributes()),
+        AdtRef::Contract(_) => return SmallVec::new(),
+    };
+
+    let mut diags = SmallVec::new();
+    for (i, attr) in attrs.data(db).iter().enumerate() {
+        let Attr::Normal(attr) = attr else {
+            continue;
+        };
+        if !attr.is_derive(db) {
+            continue;
+        }
+
+        let args_span = attrs_span.clone().attr(i).into_normal_attr().args();
+        for (j, arg) in attr.args.iter().enumerate() {
+            let Partial::Present(name) = arg.key else {
+                continue;
+            };
+
+            let span = args_span.clone().arg(j).into();
+            match DerivableTrait::from_name(name.data(db)) {
+                None => diags.push(TyLowerDiag::UnknownDerive { span, name }.into()),
+                Some(DerivableTrait::Default) if matches!(adt_ref, AdtRef::Enum(_)) => {
+                    diags.push(TyLowerDiag::DefaultDeriveOnEnum(span).into())
+                }
+                Some(_) => {}
+            }
+        }
+    }
+    diags
+}
+
 fn check_duplicate_field_names<'db>(
     db: &'db dyn HirAnalysisDb,
     owner: FieldParent<'db>,
@@ -1264,18 +1307,131 @@ impl<'db> ImplTraitMethodAnalyzer<'db> {
             required_methods.remove(name);
         }
 
+        let impl_trait = self.implementor.hir_impl_trait(self.db);
+        for (idx, assoc_ty) in impl_trait
+            .assoc_types(self.db)
+            .data(self.db)
+            .iter()
+            .enumerate()
+        {
+            let Some(name) = assoc_ty.name.to_opt() else {
+                continue;
+            };
+
+            if hir_trait.assoc_types(self.db).get(self.db, name).is_none() {
+                self.diags.push(
+                    ImplDiag::AssocTyNotDefinedInTrait {
+                        primary: impl_trait.span().assoc_ty(idx).name().into(),
+                        trait_: hir_trait,
+                        name,
+                    }
+                    .into(),
+                );
+            }
+        }
+
         if !required_methods.is_empty() {
             self.diags.push(
                 ImplDiag::NotAllTraitItemsImplemented {
-                    primary: self.implementor.hir_impl_trait(self.db).span().ty().into(),
+                    primary: impl_trait.span().ty().into(),
                     not_implemented: required_methods.into_iter().collect(),
                 }
                 .into(),
             );
         }
 
+        // Associated types without a default type must be defined in the impl.
+        let missing_assoc_tys: Vec<_> = hir_trait
+            .assoc_types(self.db)
+            .data(self.db)
+            .iter()
+            .filter_map(|assoc_ty| {
+                let name = assoc_ty.name.to_opt()?;
+                let is_defined = impl_trait
+                    .assoc_types(self.db)
+                    .get(self.db, name)
+                    .is_some_and(|impl_assoc_ty| impl_assoc_ty.ty.is_some());
+                (assoc_ty.ty.is_none() && !is_defined).then_some(name)
+            })
+            .collect();
+
+        if !missing_assoc_tys.is_empty() {
+            self.diags.push(
+                ImplDiag::NotAllTraitAssocTysDefined {
+                    primary: impl_trait.span().ty().into(),
+                    not_defined: missing_assoc_tys,
+                }
+                .into(),
+            );
+        }
+
+        self.analyze_assoc_consts();
+
         self.diags
     }
+
+    fn analyze_assoc_consts(&mut self) {
+        let trait_def = self.implementor.trait_def(self.db);
+        let hir_trait = trait_def.trait_(self.db);
+        let impl_trait = self.implementor.hir_impl_trait(self.db);
+
+        // Associated consts without a default value must be defined in the impl.
+        let mut required_consts: IndexSet<_> = hir_trait
+            .consts(self.db)
+            .filter_map(|const_| {
+                let name = const_.name(self.db).to_opt()?;
+                (!const_.body(self.db).is_present()).then_some(name)
+            })
+            .collect();
+
+        for impl_const in impl_trait.consts(self.db) {
+            let Some(name) = impl_const.name(self.db).to_opt() else {
+                continue;
+            };
+
+            let Some(trait_const) = trait_def.assoc_const(self.db, name) else {
+                self.diags.push(
+                    ImplDiag::AssocConstNotDefinedInTrait {
+                        primary: impl_const.span().name().into(),
+                        trait_: hir_trait,
+                        name,
+                    }
+                    .into(),
+                );
+                continue;
+            };
+            required_consts.remove(&name);
+
+            let Some(impl_const_def) = self.implementor.assoc_const(self.db, name) else {
+                continue;
+            };
+            let expected = trait_const
+                .ty
+                .instantiate(self.db, self.implementor.trait_(self.db).args(self.db));
+            let given = impl_const_def.ty.instantiate_identity();
+            if !expected.has_invalid(self.db) && !given.has_invalid(self.db) && expected != given {
+                self.diags.push(
+                    ImplDiag::AssocConstTyMismatch {
+                        primary: impl_const.span().ty().into(),
+                        name,
+                        expected,
+                        given,
+                    }
+                    .into(),
+                );
+            }
+        }
+
+        if !required_consts.is_empty() {
+            self.diags.push(
+                ImplDiag::NotAllTraitAssocConstsDefined {
+                    primary: impl_trait.span().ty().into(),
+                    not_defined: required_consts.into_iter().collect(),
+                }
+                .into(),
+            );
+        }
+    }
 }
 
 fn find_const_ty_param<'db>(
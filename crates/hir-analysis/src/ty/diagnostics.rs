@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use super::{
     adt_def::AdtRef,
     def_analysis::AdtCycleMember,
@@ -12,8 +14,8 @@ use crate::{
 use either::Either;
 use hir::{
     hir_def::{
-        Enum, FieldIndex, FieldParent, Func, IdentId, ImplTrait, ItemKind, PathId, Trait,
-        TypeAlias as HirTypeAlias,
+        Enum, FieldIndex, FieldParent, Func, IdentId, ImplTrait, IntegerId, ItemKind, PathId,
+        Trait, TypeAlias as HirTypeAlias,
     },
     span::{expr::LazyMethodCallExprSpan, params::LazyGenericParamSpan, DynLazySpan},
 };
@@ -123,12 +125,35 @@ pub enum TyLowerDiag<'db> {
         given: TyId<'db>,
     },
 
-    AssocTy(DynLazySpan<'db>),
+    AssocTyNotFound {
+        span: DynLazySpan<'db>,
+        ty: TyId<'db>,
+        name: IdentId<'db>,
+    },
+
+    AmbiguousAssocTy {
+        span: DynLazySpan<'db>,
+        ty: TyId<'db>,
+        name: IdentId<'db>,
+        cands: Vec<TraitInstId<'db>>,
+    },
 
     InvalidConstTyExpr(DynLazySpan<'db>),
+
+    /// The trait given to `#derive(..)` can't be derived.
+    UnknownDerive {
+        span: DynLazySpan<'db>,
+        name: IdentId<'db>,
+    },
+
+    /// `#derive(Default)` is given to an enum.
+    DefaultDeriveOnEnum(DynLazySpan<'db>),
 }
 
 impl TyLowerDiag<'_> {
+    /// The codes returned by [`Self::local_code`].
+    pub const LOCAL_CODES: RangeInclusive<u16> = 0..=23;
+
     pub(crate) fn local_code(&self) -> u16 {
         match self {
             Self::ExpectedStarKind(_) => 0,
@@ -145,13 +170,16 @@ impl TyLowerDiag<'_> {
             Self::ConstTyMismatch { .. } => 11,
             Self::ConstTyExpected { .. } => 12,
             Self::NormalTypeExpected { .. } => 13,
-            Self::AssocTy(_) => 14,
+            Self::AssocTyNotFound { .. } => 14,
             Self::InvalidConstTyExpr(_) => 15,
             Self::TooManyGenericArgs { .. } => 16,
             Self::DuplicateFieldName(..) => 17,
             Self::DuplicateVariantName(..) => 18,
             Self::DuplicateGenericParamName(..) => 19,
             Self::DuplicateArgLabel(..) => 20,
+            Self::AmbiguousAssocTy { .. } => 21,
+            Self::UnknownDerive { .. } => 22,
+            Self::DefaultDeriveOnEnum(_) => 23,
         }
     }
 }
@@ -260,6 +288,52 @@ pub enum BodyDiag<'db> {
         is_break: bool,
     },
 
+    UndeclaredLabel {
+        primary: DynLazySpan<'db>,
+        label: IdentId<'db>,
+    },
+
+    BreakWithValueInNonLoop(DynLazySpan<'db>),
+
+    /// A branch of a function body that ends without producing the returned
+    /// value. `label` explains why the branch doesn't produce a value.
+    MissingReturnValue {
+        primary: DynLazySpan<'db>,
+        label: String,
+        expected: TyId<'db>,
+        func: Func<'db>,
+    },
+
+    /// A binding declared without an initializer is used before it's assigned
+    /// on every path. `possibly` is `true` if it's assigned on some path.
+    UninitializedBinding {
+        primary: DynLazySpan<'db>,
+        binding: (IdentId<'db>, DynLazySpan<'db>),
+        possibly: bool,
+    },
+
+    /// An immutable binding declared without an initializer is assigned more
+    /// than once.
+    ImmutableReassignment {
+        primary: DynLazySpan<'db>,
+        binding: (IdentId<'db>, DynLazySpan<'db>),
+    },
+
+    /// An integer literal doesn't fit into its inferred type. `negated` is
+    /// `true` if the literal is the operand of a unary `-`.
+    LiteralOutOfRange {
+        primary: DynLazySpan<'db>,
+        lit: IntegerId<'db>,
+        negated: bool,
+        ty: TyId<'db>,
+    },
+
+    /// A function that requires an `unsafe` block is called outside of one.
+    UnsafeCallOutsideUnsafe {
+        primary: DynLazySpan<'db>,
+        func: Func<'db>,
+    },
+
     TraitNotImplemented {
         primary: DynLazySpan<'db>,
         ty: String,
@@ -342,6 +416,18 @@ pub enum BodyDiag<'db> {
     UnreachablePattern {
         primary: DynLazySpan<'db>,
     },
+
+    RefutablePatInLet {
+        primary: DynLazySpan<'db>,
+        missing_patterns: Vec<String>,
+    },
+
+    LetElseMustDiverge {
+        primary: DynLazySpan<'db>,
+        given: TyId<'db>,
+    },
+
+    InvalidRangePat(DynLazySpan<'db>),
 }
 
 impl<'db> BodyDiag<'db> {
@@ -422,6 +508,9 @@ impl<'db> BodyDiag<'db> {
         }
     }
 
+    /// The codes returned by [`Self::local_code`].
+    pub const LOCAL_CODES: RangeInclusive<u16> = 0..=45;
+
     pub(crate) fn local_code(&self) -> u16 {
         match self {
             Self::TypeMismatch { .. } => 0,
@@ -460,6 +549,16 @@ impl<'db> BodyDiag<'db> {
             Self::NotAMethod { .. } => 33,
             Self::NonExhaustiveMatch { .. } => 34,
             Self::UnreachablePattern { .. } => 35,
+            Self::RefutablePatInLet { .. } => 36,
+            Self::LetElseMustDiverge { .. } => 37,
+            Self::InvalidRangePat(..) => 38,
+            Self::UndeclaredLabel { .. } => 39,
+            Self::BreakWithValueInNonLoop(..) => 40,
+            Self::MissingReturnValue { .. } => 41,
+            Self::UninitializedBinding { .. } => 42,
+            Self::ImmutableReassignment { .. } => 43,
+            Self::LiteralOutOfRange { .. } => 44,
+            Self::UnsafeCallOutsideUnsafe { .. } => 45,
         }
     }
 }
@@ -477,6 +576,9 @@ pub enum TraitLowerDiag<'db> {
 }
 
 impl TraitLowerDiag<'_> {
+    /// The codes returned by [`Self::local_code`].
+    pub const LOCAL_CODES: RangeInclusive<u16> = 0..=2;
+
     pub fn local_code(&self) -> u16 {
         match self {
             Self::ExternalTraitForExternalType(_) => 0,
@@ -519,6 +621,9 @@ pub enum TraitConstraintDiag<'db> {
 }
 
 impl TraitConstraintDiag<'_> {
+    /// The codes returned by [`Self::local_code`].
+    pub const LOCAL_CODES: RangeInclusive<u16> = 0..=6;
+
     pub fn local_code(&self) -> u16 {
         match self {
             Self::KindMismatch { .. } => 0,
@@ -603,23 +708,64 @@ pub enum ImplDiag<'db> {
         ty: String,
         is_nominal: bool,
     },
+
+    AssocTyNotDefinedInTrait {
+        primary: DynLazySpan<'db>,
+        trait_: Trait<'db>,
+        name: IdentId<'db>,
+    },
+
+    NotAllTraitAssocTysDefined {
+        primary: DynLazySpan<'db>,
+        not_defined: Vec<IdentId<'db>>,
+    },
+
+    AssocConstNotDefinedInTrait {
+        primary: DynLazySpan<'db>,
+        trait_: Trait<'db>,
+        name: IdentId<'db>,
+    },
+
+    NotAllTraitAssocConstsDefined {
+        primary: DynLazySpan<'db>,
+        not_defined: Vec<IdentId<'db>>,
+    },
+
+    AssocConstTyMismatch {
+        primary: DynLazySpan<'db>,
+        name: IdentId<'db>,
+        expected: TyId<'db>,
+        given: TyId<'db>,
+    },
 }
 
 impl ImplDiag<'_> {
+    /// The codes returned by [`Self::local_code`].
+    ///
+    /// Impl diagnostics share the trait satisfaction pass with
+    /// [`TraitConstraintDiag`], whose codes are `0..=6`, so the variants that
+    /// used to collide with them are numbered after the others.
+    pub const LOCAL_CODES: RangeInclusive<u16> = 7..=23;
+
     pub fn local_code(&self) -> u16 {
         match self {
-            Self::ConflictMethodImpl { .. } => 0,
-            Self::MethodNotDefinedInTrait { .. } => 1,
-            Self::NotAllTraitItemsImplemented { .. } => 2,
-            Self::MethodTypeParamNumMismatch { .. } => 3,
-            Self::MethodTypeParamKindMismatch { .. } => 4,
-            Self::MethodArgNumMismatch { .. } => 5,
-            Self::MethodArgLabelMismatch { .. } => 6,
+            Self::ConflictMethodImpl { .. } => 17,
+            Self::MethodNotDefinedInTrait { .. } => 18,
+            Self::NotAllTraitItemsImplemented { .. } => 19,
+            Self::MethodTypeParamNumMismatch { .. } => 20,
+            Self::MethodTypeParamKindMismatch { .. } => 21,
+            Self::MethodArgNumMismatch { .. } => 22,
+            Self::MethodArgLabelMismatch { .. } => 23,
             Self::MethodArgTyMismatch { .. } => 7,
             Self::MethodRetTyMismatch { .. } => 8,
             Self::MethodStricterBound { .. } => 9,
             Self::InvalidSelfType { .. } => 10,
             Self::InherentImplIsNotAllowed { .. } => 11,
+            Self::AssocTyNotDefinedInTrait { .. } => 12,
+            Self::NotAllTraitAssocTysDefined { .. } => 13,
+            Self::AssocConstNotDefinedInTrait { .. } => 14,
+            Self::NotAllTraitAssocConstsDefined { .. } => 15,
+            Self::AssocConstTyMismatch { .. } => 16,
         }
     }
 }
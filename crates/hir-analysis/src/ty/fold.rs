@@ -73,6 +73,17 @@ impl<'db> TyFoldable<'db> for TyId<'db> {
                 TyId::const_ty(db, const_ty)
             }
 
+            AssocTy(assoc_ty) => {
+                // The projection is normalized again only if folding changed
+                // its trait instance.
+                let trait_ = assoc_ty.trait_.fold_with(folder);
+                if trait_ == assoc_ty.trait_ {
+                    self
+                } else {
+                    TyId::assoc_ty(db, trait_, assoc_ty.name)
+                }
+            }
+
             TyVar(_) | TyParam(_) | TyBase(_) | Never | Invalid(_) => self,
         }
     }
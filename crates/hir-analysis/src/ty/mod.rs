@@ -19,6 +19,8 @@ use self::def_analysis::{
 use crate::{analysis_pass::ModuleAnalysisPass, diagnostics::DiagnosticVoucher, HirAnalysisDb};
 
 pub mod adt_def;
+pub mod assoc_const;
+pub mod assoc_ty;
 pub mod binder;
 pub mod const_ty;
 
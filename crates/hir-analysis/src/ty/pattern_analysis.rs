@@ -2,13 +2,15 @@
 //! Based on "Warnings for pattern matching" by Luc Maranget
 
 use super::simplified_pattern::{
-    ctor_variant_num, ConstructorKind, SimplifiedPattern, SimplifiedPatternKind,
+    ctor_variant_num, split_int_ctors, unsigned_max, ConstructorKind, SimplifiedPattern,
+    SimplifiedPatternKind,
 };
 use crate::ty::ty_def::TyId;
 use crate::ty::AdtRef;
 use crate::HirAnalysisDb;
 use common::indexmap::IndexSet;
 use hir::hir_def::{scope_graph::ScopeId, Body as HirBody, LitKind, Pat as HirPat};
+use num_bigint::BigUint;
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PatternMatrix<'db> {
@@ -56,7 +58,7 @@ impl<'db> PatternMatrix<'db> {
         }
 
         let ty = self.first_column_ty();
-        let sigma_set = self.sigma_set();
+        let sigma_set = self.sigma_set(db);
 
         if sigma_set.is_complete(db) {
             for ctor in sigma_set.into_iter() {
@@ -115,17 +117,17 @@ impl<'db> PatternMatrix<'db> {
             None
         } else {
             self.d_specialize().find_missing_patterns(db).map(|vec| {
-                let sigma_set = self.sigma_set();
+                let sigma_set = self.sigma_set(db);
                 let kind = if sigma_set.is_empty() {
                     SimplifiedPatternKind::WildCard(None)
                 } else {
-                    let complete_sigma = SigmaSet::complete_sigma(db, ty);
                     SimplifiedPatternKind::Or(
-                        complete_sigma
-                            .difference(&sigma_set)
+                        sigma_set
+                            .missing_ctors(db, ty)
+                            .into_iter()
                             .map(|ctor| {
                                 let kind =
-                                    SimplifiedPatternKind::ctor_with_wild_card_fields(db, *ctor);
+                                    SimplifiedPatternKind::ctor_with_wild_card_fields(db, ctor);
                                 SimplifiedPattern::new(kind, ty)
                             })
                             .collect(),
@@ -178,13 +180,22 @@ impl<'db> PatternMatrix<'db> {
             }
 
             SimplifiedPatternKind::Constructor { kind, .. } => {
-                let phi_specialized = pat_vec.phi_specialize(db, *kind);
-                if phi_specialized.is_empty() {
-                    false
-                } else {
-                    self.phi_specialize(db, *kind)
-                        .is_pattern_useful(db, &phi_specialized[0])
-                }
+                // An integer range can be partially covered by the previous rows,
+                // so it's useful if any part of it is useful.
+                let mut ctors = self.sigma_set(db).0;
+                ctors.insert(*kind);
+                split_int_ctors(db, ctors)
+                    .into_iter()
+                    .filter(|ctor| kind.covers(db, ctor))
+                    .any(|ctor| {
+                        let phi_specialized = pat_vec.phi_specialize(db, ctor);
+                        if phi_specialized.is_empty() {
+                            false
+                        } else {
+                            self.phi_specialize(db, ctor)
+                                .is_pattern_useful(db, &phi_specialized[0])
+                        }
+                    })
             }
 
             SimplifiedPatternKind::Or(pats) => pats
@@ -213,8 +224,8 @@ impl<'db> PatternMatrix<'db> {
         PatternMatrix::new(rows)
     }
 
-    pub fn sigma_set(&self) -> SigmaSet<'db> {
-        SigmaSet::from_rows(self.rows.iter(), 0)
+    pub fn sigma_set(&self, db: &'db dyn HirAnalysisDb) -> SigmaSet<'db> {
+        SigmaSet::from_rows(db, self.rows.iter(), 0)
     }
 
     pub fn first_column_ty(&self) -> TyId<'db> {
@@ -279,7 +290,7 @@ impl<'db> PatternRowVec<'db> {
             }
 
             SimplifiedPatternKind::Constructor { kind, fields } => {
-                if *kind == ctor {
+                if kind.covers(db, &ctor) {
                     let mut inner = Vec::with_capacity(self.inner.len() + ctor_fields.len() - 1);
                     inner.extend_from_slice(fields);
                     inner.extend_from_slice(&self.inner[1..]);
@@ -344,7 +355,13 @@ impl<'db> PatternRowVec<'db> {
 pub struct SigmaSet<'db>(pub IndexSet<ConstructorKind<'db>>);
 
 impl<'db> SigmaSet<'db> {
-    pub fn from_rows<'a>(rows: impl Iterator<Item = &'a PatternRowVec<'db>>, column: usize) -> Self
+    /// Collects the constructors in the `column` of `rows`. Integer ranges are
+    /// split so that the resulting constructors don't overlap each other.
+    pub fn from_rows<'a>(
+        db: &'db dyn HirAnalysisDb,
+        rows: impl Iterator<Item = &'a PatternRowVec<'db>>,
+        column: usize,
+    ) -> Self
     where
         'db: 'a,
     {
@@ -354,7 +371,7 @@ impl<'db> SigmaSet<'db> {
                 ctor_set.insert(ctor);
             }
         }
-        Self(ctor_set)
+        Self(split_int_ctors(db, ctor_set))
     }
 
     pub fn complete_sigma(db: &'db dyn HirAnalysisDb, ty: TyId<'db>) -> Self {
@@ -363,7 +380,7 @@ impl<'db> SigmaSet<'db> {
         if ty.is_bool(db) {
             ctors.insert(ConstructorKind::Literal(LitKind::Bool(true), ty));
             ctors.insert(ConstructorKind::Literal(LitKind::Bool(false), ty));
-        } else if ty.is_tuple(db) {
+        } else if ty.is_tuple(db) || ty.is_array(db) {
             ctors.insert(ConstructorKind::Type(ty));
         } else if let Some(adt_def) = ty.adt_def(db) {
             if let AdtRef::Enum(enum_def) = adt_def.adt_ref(db) {
@@ -383,6 +400,23 @@ impl<'db> SigmaSet<'db> {
 
     pub fn is_complete(&self, db: &'db dyn HirAnalysisDb) -> bool {
         match self.0.first() {
+            Some(ctor) if ctor.int_bounds(db).is_some() => {
+                let Some(max) = unsigned_max(db, ctor.ty()) else {
+                    return false;
+                };
+
+                // The constructors are disjoint, so it's enough to count the
+                // covered values.
+                let covered: BigUint = self
+                    .0
+                    .iter()
+                    .filter_map(|ctor| ctor.int_bounds(db))
+                    .filter(|(start, _)| *start <= max)
+                    .map(|(start, end)| end.min(max.clone()) - start + 1u8)
+                    .sum();
+                covered == max + 1u8
+            }
+
             Some(ctor) => {
                 let expected = ctor_variant_num(db, ctor);
                 debug_assert!(self.0.len() <= expected);
@@ -392,6 +426,47 @@ impl<'db> SigmaSet<'db> {
         }
     }
 
+    /// Returns the constructors of `ty` that are not in the set.
+    pub fn missing_ctors(
+        &self,
+        db: &'db dyn HirAnalysisDb,
+        ty: TyId<'db>,
+    ) -> Vec<ConstructorKind<'db>> {
+        if !self.0.iter().any(|ctor| ctor.int_bounds(db).is_some()) {
+            let complete_sigma = Self::complete_sigma(db, ty);
+            return complete_sigma.difference(self).copied().collect();
+        }
+
+        let Some(max) = unsigned_max(db, ty) else {
+            return vec![];
+        };
+
+        let mut bounds: Vec<_> = self.0.iter().filter_map(|c| c.int_bounds(db)).collect();
+        bounds.sort();
+
+        let mut missing = vec![];
+        let mut next = BigUint::default();
+        for (start, end) in bounds {
+            if next > max {
+                break;
+            }
+            if start > next {
+                missing.push(ConstructorKind::int_range(
+                    db,
+                    next.clone(),
+                    (&start - 1u8).min(max.clone()),
+                    ty,
+                ));
+            }
+            next = next.max(end + 1u8);
+        }
+        if next <= max {
+            missing.push(ConstructorKind::int_range(db, next, max, ty));
+        }
+
+        missing
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -418,6 +493,11 @@ impl<'db> IntoIterator for SigmaSet<'db> {
 }
 
 // Public API for pattern analysis
+
+/// Checks whether `patterns` cover all the values of `ty`.
+///
+/// Patterns of guarded match arms must not be passed here, since the guard
+/// may fail even if the pattern matches.
 pub fn check_exhaustiveness<'db>(
     db: &'db dyn HirAnalysisDb,
     patterns: &[HirPat<'db>],
@@ -435,16 +515,34 @@ pub fn check_exhaustiveness<'db>(
     }
 }
 
+/// Returns whether each of `patterns` is reachable.
+///
+/// `guarded[i]` tells whether the `i`-th pattern belongs to a guarded match
+/// arm. A guarded row never makes the following rows unreachable because
+/// its guard may fail.
 pub fn check_reachability<'db>(
     db: &'db dyn HirAnalysisDb,
     patterns: &[HirPat<'db>],
+    guarded: &[bool],
     body: HirBody<'db>,
     scope: ScopeId<'db>,
     ty: TyId<'db>,
 ) -> Vec<bool> {
+    debug_assert_eq!(patterns.len(), guarded.len());
+
     let matrix = PatternMatrix::from_hir_patterns(db, patterns, body, scope, ty);
     (0..patterns.len())
-        .map(|i| matrix.is_row_useful(db, i))
+        .map(|i| {
+            let previous = PatternMatrix::new(
+                matrix.rows[..i]
+                    .iter()
+                    .zip(guarded)
+                    .filter(|(_, is_guarded)| !**is_guarded)
+                    .map(|(row, _)| row.clone())
+                    .collect(),
+            );
+            previous.is_pattern_useful(db, &matrix.rows[i])
+        })
         .collect()
 }
 
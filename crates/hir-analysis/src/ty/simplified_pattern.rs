@@ -4,12 +4,14 @@
 //! representation that's easier to work with during pattern analysis.
 
 use crate::name_resolution::{resolve_path, PathRes, ResolvedVariant};
-use crate::ty::ty_def::TyId;
+use crate::ty::ty_def::{TyBase, TyData, TyId};
 use crate::HirAnalysisDb;
+use common::indexmap::IndexSet;
 use hir::hir_def::{
     scope_graph::ScopeId, Body as HirBody, LitKind, Partial, Pat as HirPat, PathId, VariantKind,
 };
-use hir::hir_def::{EnumVariant, FieldParent, IdentId, PatId};
+use hir::hir_def::{EnumVariant, FieldParent, IdentId, IntegerId, PatId};
+use num_bigint::BigUint;
 use rustc_hash::FxHashMap;
 use smallvec1::SmallVec;
 
@@ -20,11 +22,17 @@ use super::adt_def::AdtRef;
 pub struct SimplifiedPattern<'db> {
     pub kind: SimplifiedPatternKind<'db>,
     pub ty: TyId<'db>,
+    /// Bindings introduced by `name @ pat` on top of this pattern.
+    pub binds: Vec<(IdentId<'db>, usize)>,
 }
 
 impl<'db> SimplifiedPattern<'db> {
     pub fn new(kind: SimplifiedPatternKind<'db>, ty: TyId<'db>) -> Self {
-        Self { kind, ty }
+        Self {
+            kind,
+            ty,
+            binds: vec![],
+        }
     }
 
     pub fn wildcard(bind: Option<(IdentId<'db>, usize)>, ty: TyId<'db>) -> Self {
@@ -46,6 +54,24 @@ impl<'db> SimplifiedPattern<'db> {
         matches!(self.kind, SimplifiedPatternKind::WildCard(_))
     }
 
+    /// Attaches the binding of `name @ pat` to the simplified `pat`.
+    fn with_bind(mut self, bind: (IdentId<'db>, usize)) -> Self {
+        match &mut self.kind {
+            SimplifiedPatternKind::WildCard(wild_bind) if wild_bind.is_none() => {
+                *wild_bind = Some(bind)
+            }
+            // Or-patterns are expanded during the analysis, so each alternative
+            // needs to carry the binding.
+            SimplifiedPatternKind::Or(pats) => {
+                for pat in pats.iter_mut() {
+                    *pat = pat.clone().with_bind(bind);
+                }
+            }
+            _ => self.binds.push(bind),
+        }
+        self
+    }
+
     pub fn from_hir_pat(
         db: &'db dyn HirAnalysisDb,
         pat: &HirPat<'db>,
@@ -56,7 +82,7 @@ impl<'db> SimplifiedPattern<'db> {
     ) -> Self {
         match pat {
             HirPat::Rest => {
-                unreachable!("Rest pattern is only allowed within tuple and record patterns")
+                unreachable!("Rest pattern is only allowed within tuple, record and array patterns")
             }
             HirPat::WildCard => SimplifiedPattern::wildcard(None, expected_ty),
 
@@ -69,6 +95,58 @@ impl<'db> SimplifiedPattern<'db> {
                 }
             }
 
+            HirPat::Range(start, end) => match (start, end) {
+                (Partial::Present(LitKind::Int(start)), Partial::Present(LitKind::Int(end)))
+                    if start.data(db) <= end.data(db) =>
+                {
+                    let ctor = ConstructorKind::int_range(
+                        db,
+                        start.data(db).clone(),
+                        end.data(db).clone(),
+                        expected_ty,
+                    );
+                    SimplifiedPattern::constructor(ctor, vec![], expected_ty)
+                }
+                // An empty range matches nothing.
+                (Partial::Present(_), Partial::Present(_)) => {
+                    SimplifiedPattern::new(SimplifiedPatternKind::Or(vec![]), expected_ty)
+                }
+                _ => SimplifiedPattern::wildcard(None, expected_ty),
+            },
+
+            HirPat::Binding(name, _, pat) => {
+                let simplified =
+                    Self::from_partial_pat_id(*pat, db, body, scope, arm_idx, expected_ty);
+                match name {
+                    Partial::Present(name) => simplified.with_bind((*name, arm_idx)),
+                    Partial::Absent => simplified,
+                }
+            }
+
+            HirPat::Array(elements) => {
+                let elem_tys = expected_ty.field_types(db);
+                let has_rest = elements.iter().any(|pat| pat.is_rest(db, body));
+                let len_matches = if has_rest {
+                    elements.len() - 1 <= elem_tys.len()
+                } else {
+                    elements.len() == elem_tys.len()
+                };
+
+                // The length of the array is not known statically, or the
+                // pattern is ill-typed.
+                if expected_ty.array_len(db).is_none() || !len_matches {
+                    return SimplifiedPattern::wildcard(None, expected_ty);
+                }
+
+                let simplified =
+                    simplify_tuple_pattern_elements(db, body, scope, arm_idx, elements, &elem_tys);
+                SimplifiedPattern::constructor(
+                    ConstructorKind::Type(expected_ty),
+                    simplified,
+                    expected_ty,
+                )
+            }
+
             HirPat::Path(path_partial, _) => {
                 if let Some((ctor, ctor_ty)) =
                     Self::resolve_constructor(path_partial, db, scope, Some(expected_ty))
@@ -328,9 +406,60 @@ pub enum ConstructorKind<'db> {
     Variant(EnumVariant<'db>, TyId<'db>),
     Type(TyId<'db>),
     Literal(LitKind<'db>, TyId<'db>),
+    /// Inclusive range of integers, e.g., `0..=9`. A range of a single value
+    /// is always represented as an integer literal.
+    Range(IntegerId<'db>, IntegerId<'db>, TyId<'db>),
 }
 
 impl<'db> ConstructorKind<'db> {
+    /// Makes a constructor matching the integers in `start..=end`.
+    pub fn int_range(
+        db: &'db dyn HirAnalysisDb,
+        start: BigUint,
+        end: BigUint,
+        ty: TyId<'db>,
+    ) -> Self {
+        if start == end {
+            Self::Literal(LitKind::Int(IntegerId::new(db, start)), ty)
+        } else {
+            Self::Range(IntegerId::new(db, start), IntegerId::new(db, end), ty)
+        }
+    }
+
+    /// Returns the inclusive bounds of the integers matched by the
+    /// constructor, or `None` if the constructor doesn't match integers.
+    pub fn int_bounds(&self, db: &'db dyn HirAnalysisDb) -> Option<(BigUint, BigUint)> {
+        match self {
+            Self::Literal(LitKind::Int(n), _) => Some((n.data(db).clone(), n.data(db).clone())),
+            Self::Range(start, end, _) => Some((start.data(db).clone(), end.data(db).clone())),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if every value matched by `other` is also matched by
+    /// `self`.
+    pub fn covers(&self, db: &'db dyn HirAnalysisDb, other: &Self) -> bool {
+        if self == other {
+            return true;
+        }
+
+        match (self.int_bounds(db), other.int_bounds(db)) {
+            (Some((start, end)), Some((other_start, other_end))) => {
+                start <= other_start && other_end <= end
+            }
+            _ => false,
+        }
+    }
+
+    pub(crate) fn ty(&self) -> TyId<'db> {
+        match self {
+            Self::Variant(_, ty)
+            | Self::Type(ty)
+            | Self::Literal(_, ty)
+            | Self::Range(_, _, ty) => *ty,
+        }
+    }
+
     pub fn field_types(&self, db: &'db dyn HirAnalysisDb) -> Vec<TyId<'db>> {
         match self {
             Self::Variant(variant, ty) => {
@@ -352,7 +481,7 @@ impl<'db> ConstructorKind<'db> {
                 }
             }
             Self::Type(ty) => ty.field_types(db),
-            Self::Literal(_, _) => vec![],
+            Self::Literal(_, _) | Self::Range(..) => vec![],
         }
     }
 
@@ -388,7 +517,7 @@ impl<'db> ConstructorKind<'db> {
                 }
             }
             Self::Type(ty) => ty.field_count(db),
-            Self::Literal(_, _) => 0,
+            Self::Literal(_, _) | Self::Range(..) => 0,
         }
     }
 }
@@ -400,6 +529,60 @@ pub fn ctor_variant_num<'db>(db: &'db dyn HirAnalysisDb, ctor: &ConstructorKind<
         ConstructorKind::Literal(LitKind::Bool(_), _) => 2,
         ConstructorKind::Literal(LitKind::Int(_), _) => usize::MAX, // Infinite possibilities
         ConstructorKind::Literal(LitKind::String(_), _) => usize::MAX, // Infinite possibilities
+        ConstructorKind::Range(..) => usize::MAX, // Integers are covered by ranges instead
+    }
+}
+
+/// Splits the integer constructors in `ctors` into disjoint ranges, so that
+/// each of the resulting constructors is either covered by or disjoint from
+/// every constructor in `ctors`. Other constructors are kept as they are.
+pub(crate) fn split_int_ctors<'db>(
+    db: &'db dyn HirAnalysisDb,
+    ctors: IndexSet<ConstructorKind<'db>>,
+) -> IndexSet<ConstructorKind<'db>> {
+    // Integer literals never overlap with each other.
+    let Some(ty) = ctors.iter().find_map(|ctor| match ctor {
+        ConstructorKind::Range(..) => Some(ctor.ty()),
+        _ => None,
+    }) else {
+        return ctors;
+    };
+
+    let mut bounds = vec![];
+    let mut split = IndexSet::new();
+    for ctor in ctors {
+        match ctor.int_bounds(db) {
+            Some(bound) => bounds.push(bound),
+            None => {
+                split.insert(ctor);
+            }
+        }
+    }
+
+    let mut points: Vec<BigUint> = bounds
+        .iter()
+        .flat_map(|(start, end)| [start.clone(), end + 1u8])
+        .collect();
+    points.sort();
+    points.dedup();
+
+    for window in points.windows(2) {
+        let start = &window[0];
+        if bounds.iter().any(|(lo, hi)| lo <= start && start <= hi) {
+            let end = &window[1] - 1u8;
+            split.insert(ConstructorKind::int_range(db, start.clone(), end, ty));
+        }
+    }
+
+    split
+}
+
+/// Returns the maximum value of `ty` if it's an unsigned integral type with a
+/// fixed width.
+pub(crate) fn unsigned_max<'db>(db: &'db dyn HirAnalysisDb, ty: TyId<'db>) -> Option<BigUint> {
+    match ty.data(db) {
+        TyData::TyBase(TyBase::Prim(prim)) => prim.unsigned_max(),
+        _ => None,
     }
 }
 
@@ -452,7 +635,13 @@ pub fn display_missing_pattern<'db>(
                     }
                 }
                 ConstructorKind::Type(ty) => {
-                    if ty.is_tuple(db) {
+                    if ty.is_array(db) {
+                        let parts: Vec<String> = fields
+                            .iter()
+                            .map(|f| display_missing_pattern(db, f))
+                            .collect();
+                        format!("[{}]", parts.join(", "))
+                    } else if ty.is_tuple(db) {
                         if fields.is_empty() {
                             "()".to_string()
                         } else {
@@ -473,6 +662,9 @@ pub fn display_missing_pattern<'db>(
                     LitKind::Int(i) => i.data(db).to_string(),
                     LitKind::String(s) => format!("\"{}\"", s.data(db)),
                 },
+                ConstructorKind::Range(start, end, _) => {
+                    format!("{}..={}", start.data(db), end.data(db))
+                }
             }
         }
 
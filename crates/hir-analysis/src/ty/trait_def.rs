@@ -5,7 +5,7 @@ use common::{
     ingot::Ingot,
 };
 use hir::{
-    hir_def::{HirIngot, IdentId, ImplTrait, Trait},
+    hir_def::{Const, HirIngot, IdentId, ImplTrait, Trait},
     span::DynLazySpan,
 };
 use rustc_hash::FxHashMap;
@@ -16,7 +16,11 @@ use super::{
     canonical::Canonical,
     diagnostics::{TraitConstraintDiag, TyDiagCollection},
     func_def::FuncDef,
-    trait_lower::collect_implementor_methods,
+    trait_lower::{
+        collect_implementor_assoc_consts, collect_implementor_assoc_tys,
+        collect_implementor_methods, collect_trait_assoc_consts, collect_trait_assoc_ty_defaults,
+        collect_trait_methods,
+    },
     trait_resolution::{
         check_trait_inst_wf,
         constraint::{collect_implementor_constraints, collect_super_traits},
@@ -217,6 +221,35 @@ impl<'db> Implementor<'db> {
     ) -> &'db IndexMap<IdentId<'db>, FuncDef<'db>> {
         collect_implementor_methods(db, self)
     }
+
+    /// Returns the associated type `name` defined in the impl trait.
+    pub(super) fn assoc_ty(
+        self,
+        db: &'db dyn HirAnalysisDb,
+        name: IdentId<'db>,
+    ) -> Option<Binder<TyId<'db>>> {
+        collect_implementor_assoc_tys(db, self).get(&name).copied()
+    }
+
+    /// Returns the associated const `name` defined in the impl trait.
+    pub(super) fn assoc_const(
+        self,
+        db: &'db dyn HirAnalysisDb,
+        name: IdentId<'db>,
+    ) -> Option<AssocConst<'db>> {
+        collect_implementor_assoc_consts(db, self)
+            .get(&name)
+            .copied()
+    }
+}
+
+/// An associated const declared in a trait or defined in an impl trait.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Update)]
+pub struct AssocConst<'db> {
+    pub const_: Const<'db>,
+    /// The type of the const, which is bound by the generic parameters of the
+    /// trait or the impl trait.
+    pub ty: Binder<TyId<'db>>,
 }
 
 /// Returns `true` if the given two implementor conflicts.
@@ -308,11 +341,45 @@ pub struct TraitDef<'db> {
     pub trait_: Trait<'db>,
     #[return_ref]
     pub(crate) param_set: GenericParamTypeSet<'db>,
-    #[return_ref]
-    pub methods: IndexMap<IdentId<'db>, TraitMethod<'db>>,
 }
 
 impl<'db> TraitDef<'db> {
+    /// Returns the methods declared in the trait.
+    /// The methods are lowered lazily so that the method signatures can refer
+    /// to the associated types of the trait.
+    pub fn methods(
+        self,
+        db: &'db dyn HirAnalysisDb,
+    ) -> &'db IndexMap<IdentId<'db>, TraitMethod<'db>> {
+        collect_trait_methods(db, self)
+    }
+
+    /// Returns `true` if the trait declares the associated type `name`.
+    pub(crate) fn declares_assoc_ty(self, db: &'db dyn HirAnalysisDb, name: IdentId<'db>) -> bool {
+        self.trait_(db).assoc_types(db).get(db, name).is_some()
+    }
+
+    /// Returns the default type of the associated type `name`, which is bound
+    /// by the trait's generic parameters.
+    pub(crate) fn assoc_ty_default(
+        self,
+        db: &'db dyn HirAnalysisDb,
+        name: IdentId<'db>,
+    ) -> Option<Binder<TyId<'db>>> {
+        collect_trait_assoc_ty_defaults(db, self)
+            .get(&name)
+            .copied()
+    }
+
+    /// Returns the associated const `name` declared in the trait.
+    pub(crate) fn assoc_const(
+        self,
+        db: &'db dyn HirAnalysisDb,
+        name: IdentId<'db>,
+    ) -> Option<AssocConst<'db>> {
+        collect_trait_assoc_consts(db, self).get(&name).copied()
+    }
+
     pub fn params(self, db: &'db dyn HirAnalysisDb) -> &'db [TyId<'db>] {
         self.param_set(db).params(db)
     }
@@ -2,7 +2,8 @@
 
 use common::{indexmap::IndexMap, ingot::Ingot};
 use hir::hir_def::{
-    scope_graph::ScopeId, HirIngot, IdentId, ImplTrait, Partial, Trait, TraitRefId,
+    scope_graph::ScopeId, AssocTypeListId, Const, HirIngot, IdentId, ImplTrait, Partial, Trait,
+    TraitRefId,
 };
 use rustc_hash::FxHashMap;
 use salsa::Update;
@@ -10,7 +11,9 @@ use salsa::Update;
 use super::{
     binder::Binder,
     func_def::FuncDef,
-    trait_def::{does_impl_trait_conflict, Implementor, TraitDef, TraitInstId, TraitMethod},
+    trait_def::{
+        does_impl_trait_conflict, AssocConst, Implementor, TraitDef, TraitInstId, TraitMethod,
+    },
     ty_def::{InvalidCause, Kind, TyId},
     ty_lower::{collect_generic_params, lower_generic_arg_list, GenericParamTypeSet},
 };
@@ -182,6 +185,108 @@ pub(crate) fn collect_implementor_methods<'db>(
     methods
 }
 
+#[salsa::tracked(return_ref)]
+pub(crate) fn collect_implementor_assoc_tys<'db>(
+    db: &'db dyn HirAnalysisDb,
+    implementor: Implementor<'db>,
+) -> IndexMap<IdentId<'db>, Binder<TyId<'db>>> {
+    let impl_trait = implementor.hir_impl_trait(db);
+    lower_assoc_ty_defs(db, impl_trait.assoc_types(db), impl_trait.scope())
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn collect_trait_methods<'db>(
+    db: &'db dyn HirAnalysisDb,
+    trait_: TraitDef<'db>,
+) -> IndexMap<IdentId<'db>, TraitMethod<'db>> {
+    let mut methods = IndexMap::default();
+
+    for method in trait_.trait_(db).methods(db) {
+        let Some(func) = lower_func(db, method) else {
+            continue;
+        };
+
+        // We can simply ignore the conflict here because it's already handled by the
+        // name resolution.
+        methods.entry(func.name(db)).or_insert(TraitMethod(func));
+    }
+
+    methods
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn collect_trait_assoc_ty_defaults<'db>(
+    db: &'db dyn HirAnalysisDb,
+    trait_: TraitDef<'db>,
+) -> IndexMap<IdentId<'db>, Binder<TyId<'db>>> {
+    let hir_trait = trait_.trait_(db);
+    lower_assoc_ty_defs(db, hir_trait.assoc_types(db), hir_trait.scope())
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn collect_implementor_assoc_consts<'db>(
+    db: &'db dyn HirAnalysisDb,
+    implementor: Implementor<'db>,
+) -> IndexMap<IdentId<'db>, AssocConst<'db>> {
+    lower_assoc_consts(db, implementor.hir_impl_trait(db).consts(db))
+}
+
+#[salsa::tracked(return_ref)]
+pub(crate) fn collect_trait_assoc_consts<'db>(
+    db: &'db dyn HirAnalysisDb,
+    trait_: TraitDef<'db>,
+) -> IndexMap<IdentId<'db>, AssocConst<'db>> {
+    lower_assoc_consts(db, trait_.trait_(db).consts(db))
+}
+
+fn lower_assoc_consts<'db>(
+    db: &'db dyn HirAnalysisDb,
+    consts: impl Iterator<Item = Const<'db>>,
+) -> IndexMap<IdentId<'db>, AssocConst<'db>> {
+    let mut assoc_consts = IndexMap::default();
+
+    for const_ in consts {
+        let Some(name) = const_.name(db).to_opt() else {
+            continue;
+        };
+
+        let ty = match const_.ty(db).to_opt() {
+            Some(ty) => lower_hir_ty(db, ty, const_.scope()),
+            None => TyId::invalid(db, InvalidCause::Other),
+        };
+
+        // We can simply ignore the conflict here because it's already handled by the
+        // name resolution.
+        assoc_consts.entry(name).or_insert(AssocConst {
+            const_,
+            ty: Binder::bind(ty),
+        });
+    }
+
+    assoc_consts
+}
+
+/// Lowers the associated types that have a type, i.e., the definitions in an
+/// impl trait or the defaults in a trait.
+fn lower_assoc_ty_defs<'db>(
+    db: &'db dyn HirAnalysisDb,
+    assoc_types: AssocTypeListId<'db>,
+    scope: ScopeId<'db>,
+) -> IndexMap<IdentId<'db>, Binder<TyId<'db>>> {
+    let mut tys = IndexMap::default();
+
+    for assoc_ty in assoc_types.data(db) {
+        let (Some(name), Some(ty)) = (assoc_ty.name.to_opt(), assoc_ty.ty) else {
+            continue;
+        };
+
+        tys.entry(name)
+            .or_insert_with(|| Binder::bind(lower_hir_ty(db, ty, scope)));
+    }
+
+    tys
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Update)]
 pub(crate) enum TraitRefLowerError<'db> {
     /// The number of arguments doesn't match the number of parameters.
@@ -216,7 +321,6 @@ struct TraitBuilder<'db> {
     db: &'db dyn HirAnalysisDb,
     trait_: Trait<'db>,
     param_set: GenericParamTypeSet<'db>,
-    methods: IndexMap<IdentId<'db>, TraitMethod<'db>>,
 }
 
 impl<'db> TraitBuilder<'db> {
@@ -227,35 +331,18 @@ impl<'db> TraitBuilder<'db> {
             db,
             trait_,
             param_set,
-            methods: IndexMap::default(),
         }
     }
 
     fn build(mut self) -> TraitDef<'db> {
         self.collect_params();
-        self.collect_methods();
 
-        TraitDef::new(self.db, self.trait_, self.param_set, self.methods)
+        TraitDef::new(self.db, self.trait_, self.param_set)
     }
 
     fn collect_params(&mut self) {
         self.param_set = collect_generic_params(self.db, self.trait_.into());
     }
-
-    fn collect_methods(&mut self) {
-        let hir_db = self.db;
-        for method in self.trait_.methods(hir_db) {
-            let Some(func) = lower_func(self.db, method) else {
-                continue;
-            };
-
-            let name = func.name(self.db);
-            let trait_method = TraitMethod(func);
-            // We can simply ignore the conflict here because it's already handled by the
-            // name resolution.
-            self.methods.entry(name).or_insert(trait_method);
-        }
-    }
 }
 
 /// Collect all implementors in an ingot.
@@ -524,6 +524,16 @@ impl ConsumerNode {
 pub(crate) fn ty_depth_impl<'db>(db: &'db dyn HirAnalysisDb, ty: TyId<'db>) -> usize {
     match ty.data(db) {
         TyData::ConstTy(cty) => ty_depth_impl(db, cty.ty(db)),
+        TyData::AssocTy(assoc_ty) => {
+            assoc_ty
+                .trait_
+                .args(db)
+                .iter()
+                .map(|arg| ty_depth_impl(db, *arg))
+                .max()
+                .unwrap_or_default()
+                + 1
+        }
         TyData::Invalid(_)
         | TyData::Never
         | TyData::TyBase(_)
@@ -0,0 +1,404 @@
+//! Definite initialization analysis of the local bindings declared by `let`
+//! without an initializer.
+
+use hir::hir_def::{BinOp, Body, Expr, ExprId, IdentId, Partial, Stmt, StmtId};
+use rustc_hash::FxHashSet;
+
+use super::{env::LocalBinding, TyChecker};
+use crate::ty::{diagnostics::BodyDiag, fold::TyFoldable};
+
+impl<'db> TyChecker<'db> {
+    /// Reports the uses of bindings declared without an initializer that
+    /// aren't assigned on every path leading to the use, and the repeated
+    /// assignments to such bindings that are immutable.
+    pub(super) fn check_definite_init(&mut self, root_expr: ExprId) {
+        if !self.env.has_deferred_bindings() {
+            return;
+        }
+
+        let mut state = InitState::default();
+        InitChecker {
+            checker: self,
+            loops: Vec::new(),
+            reported: FxHashSet::default(),
+            silent: false,
+        }
+        .check_expr(root_expr, &mut state);
+    }
+}
+
+/// The bindings that are assigned at a point of the body.
+#[derive(Clone, Default, PartialEq)]
+struct InitState<'db> {
+    /// The bindings that are assigned on every path to the point.
+    assigned: FxHashSet<LocalBinding<'db>>,
+    /// The bindings that are assigned on some path to the point.
+    maybe_assigned: FxHashSet<LocalBinding<'db>>,
+    /// `true` if no path reaches the point.
+    diverged: bool,
+}
+
+impl<'db> InitState<'db> {
+    fn diverged() -> Self {
+        Self {
+            diverged: true,
+            ..Self::default()
+        }
+    }
+
+    fn assign(&mut self, binding: LocalBinding<'db>) {
+        self.assigned.insert(binding);
+        self.maybe_assigned.insert(binding);
+    }
+
+    /// Merges the states of two paths reaching the same point.
+    fn join(self, other: Self) -> Self {
+        if self.diverged {
+            return other;
+        } else if other.diverged {
+            return self;
+        }
+
+        Self {
+            assigned: self
+                .assigned
+                .intersection(&other.assigned)
+                .copied()
+                .collect(),
+            maybe_assigned: self
+                .maybe_assigned
+                .union(&other.maybe_assigned)
+                .copied()
+                .collect(),
+            diverged: false,
+        }
+    }
+}
+
+struct LoopCtxt<'db> {
+    label: Option<IdentId<'db>>,
+    /// The merged state of the `break`s of the loop.
+    exit: InitState<'db>,
+    /// The merged state of the `continue`s of the loop.
+    next: InitState<'db>,
+}
+
+struct InitChecker<'a, 'db> {
+    checker: &'a mut TyChecker<'db>,
+    loops: Vec<LoopCtxt<'db>>,
+    /// The bindings already reported as used before being assigned. Each
+    /// binding is reported only once.
+    reported: FxHashSet<LocalBinding<'db>>,
+    /// `true` while computing the state at the start of a loop iteration, in
+    /// which case no diagnostics are reported.
+    silent: bool,
+}
+
+impl<'db> InitChecker<'_, 'db> {
+    fn body(&self) -> Body<'db> {
+        self.checker.body()
+    }
+
+    fn check_expr(&mut self, expr: ExprId, state: &mut InitState<'db>) {
+        if state.diverged {
+            return;
+        }
+        let db = self.checker.db;
+        let Partial::Present(expr_data) = expr.data(db, self.body()) else {
+            return;
+        };
+
+        match expr_data {
+            Expr::Lit(..) => {}
+
+            Expr::Block(stmts) => {
+                for &stmt in stmts {
+                    self.check_stmt(stmt, state);
+                    if state.diverged {
+                        break;
+                    }
+                }
+            }
+
+            Expr::Bin(lhs, rhs, op) => {
+                self.check_expr(*lhs, state);
+                // The rhs of `&&` and `||` is evaluated conditionally.
+                if let Partial::Present(BinOp::Logical(_)) = op {
+                    let mut rhs_state = state.clone();
+                    self.check_expr(*rhs, &mut rhs_state);
+                    *state = std::mem::take(state).join(rhs_state);
+                } else {
+                    self.check_expr(*rhs, state);
+                }
+            }
+
+            Expr::Un(expr, _) | Expr::Field(expr, _) | Expr::ArrayRep(expr, _) => {
+                self.check_expr(*expr, state)
+            }
+
+            Expr::Call(callee, args) => {
+                self.check_expr(*callee, state);
+                for arg in args {
+                    self.check_expr(arg.expr, state);
+                }
+            }
+
+            Expr::MethodCall(receiver, _, _, args) => {
+                self.check_expr(*receiver, state);
+                for arg in args {
+                    self.check_expr(arg.expr, state);
+                }
+            }
+
+            Expr::Path(..) => self.check_use(expr, state),
+
+            Expr::RecordInit(_, fields) => {
+                for field in fields {
+                    self.check_expr(field.expr, state);
+                }
+            }
+
+            Expr::Tuple(elems) | Expr::Array(elems) => {
+                for &elem in elems {
+                    self.check_expr(elem, state);
+                }
+            }
+
+            Expr::Index(lhs, index) => {
+                self.check_expr(*lhs, state);
+                self.check_expr(*index, state);
+            }
+
+            Expr::If(cond, then, else_) => {
+                self.check_expr(*cond, state);
+                self.check_branches(*then, *else_, state);
+            }
+
+            Expr::IfLet(_, scrutinee, then, else_) => {
+                self.check_expr(*scrutinee, state);
+                self.check_branches(*then, *else_, state);
+            }
+
+            Expr::Match(scrutinee, arms) => {
+                self.check_expr(*scrutinee, state);
+                let Partial::Present(arms) = arms else {
+                    return;
+                };
+
+                let mut merged = InitState::diverged();
+                for arm in arms {
+                    let mut arm_state = state.clone();
+                    if let Some(guard) = arm.guard {
+                        self.check_expr(guard, &mut arm_state);
+                    }
+                    self.check_expr(arm.body, &mut arm_state);
+                    merged = merged.join(arm_state);
+                }
+                *state = merged;
+            }
+
+            Expr::Loop(label, body) => {
+                // A `loop` is only exited by `break`.
+                *state = self.check_loop(*label, *body, state).1;
+            }
+
+            Expr::Unsafe(block) => self.check_expr(*block, state),
+
+            Expr::Assign(lhs, rhs) => {
+                self.check_expr(*rhs, state);
+                self.check_assign(*lhs, state);
+            }
+
+            Expr::AugAssign(lhs, rhs, _) => {
+                self.check_expr(*lhs, state);
+                self.check_expr(*rhs, state);
+            }
+        }
+
+        let ty = self.checker.env.typed_expr(expr).map(|prop| prop.ty);
+        if ty.is_some_and(|ty| ty.fold_with(&mut self.checker.table).is_never(db)) {
+            state.diverged = true;
+        }
+    }
+
+    fn check_stmt(&mut self, stmt: StmtId, state: &mut InitState<'db>) {
+        let Partial::Present(stmt_data) = stmt.data(self.checker.db, self.body()) else {
+            return;
+        };
+
+        match stmt_data {
+            Stmt::Let(_, _, init, else_) => {
+                if let Some(init) = init {
+                    self.check_expr(*init, state);
+                }
+                // The `else` block always diverges, so it doesn't affect the
+                // following statements.
+                if let Some(else_) = else_ {
+                    self.check_expr(*else_, &mut state.clone());
+                }
+            }
+
+            // A `for` or `while` loop exits either by `break` or at the start
+            // of an iteration.
+            Stmt::For(label, _, iterable, body) => {
+                self.check_expr(*iterable, state);
+                let (head, exit) = self.check_loop(*label, *body, state);
+                *state = head.join(exit);
+            }
+
+            Stmt::While(label, cond, body) => {
+                self.check_expr(*cond, state);
+                let (head, exit) = self.check_loop(*label, *body, state);
+                *state = head.join(exit);
+            }
+
+            Stmt::WhileLet(label, _, scrutinee, body) => {
+                self.check_expr(*scrutinee, state);
+                let (head, exit) = self.check_loop(*label, *body, state);
+                *state = head.join(exit);
+            }
+
+            Stmt::Continue(label) => {
+                if let Some(loop_ctxt) = self.find_loop(*label) {
+                    loop_ctxt.next = std::mem::take(&mut loop_ctxt.next).join(state.clone());
+                }
+                state.diverged = true;
+            }
+
+            Stmt::Break(label, value) => {
+                if let Some(value) = value {
+                    self.check_expr(*value, state);
+                }
+                if state.diverged {
+                    return;
+                }
+
+                if let Some(loop_ctxt) = self.find_loop(*label) {
+                    loop_ctxt.exit = std::mem::take(&mut loop_ctxt.exit).join(state.clone());
+                }
+                state.diverged = true;
+            }
+
+            Stmt::Return(value) => {
+                if let Some(value) = value {
+                    self.check_expr(*value, state);
+                }
+                state.diverged = true;
+            }
+
+            Stmt::Expr(expr) => self.check_expr(*expr, state),
+        }
+    }
+
+    /// Checks the `then` and `else` branches of an `if` expression, and merges
+    /// the states at the end of them into `state`.
+    fn check_branches(&mut self, then: ExprId, else_: Option<ExprId>, state: &mut InitState<'db>) {
+        let mut then_state = state.clone();
+        self.check_expr(then, &mut then_state);
+        if let Some(else_) = else_ {
+            self.check_expr(else_, state);
+        }
+        *state = then_state.join(std::mem::take(state));
+    }
+
+    /// Checks the body of a loop entered with `state`, and returns the state
+    /// at the start of an iteration and the merged state of the `break`s of
+    /// the loop.
+    ///
+    /// The state at the start of an iteration is computed by checking the
+    /// body silently until the state reaches a fixed point. The body is then
+    /// checked once more with the diagnostics enabled.
+    fn check_loop(
+        &mut self,
+        label: Option<IdentId<'db>>,
+        body: ExprId,
+        state: &InitState<'db>,
+    ) -> (InitState<'db>, InitState<'db>) {
+        let silent = std::mem::replace(&mut self.silent, true);
+        let mut head = state.clone();
+        loop {
+            let (next, _) = self.check_iteration(label, body, &head);
+            let next = head.clone().join(next);
+            if next == head {
+                break;
+            }
+            head = next;
+        }
+
+        self.silent = silent;
+        let (_, exit) = self.check_iteration(label, body, &head);
+        (head, exit)
+    }
+
+    /// Checks a single iteration of a loop, and returns the state at the
+    /// start of the next iteration and the merged state of the `break`s.
+    fn check_iteration(
+        &mut self,
+        label: Option<IdentId<'db>>,
+        body: ExprId,
+        head: &InitState<'db>,
+    ) -> (InitState<'db>, InitState<'db>) {
+        self.loops.push(LoopCtxt {
+            label,
+            exit: InitState::diverged(),
+            next: InitState::diverged(),
+        });
+        let mut body_state = head.clone();
+        self.check_expr(body, &mut body_state);
+
+        let loop_ctxt = self.loops.pop().unwrap();
+        (loop_ctxt.next.join(body_state), loop_ctxt.exit)
+    }
+
+    fn find_loop(&mut self, label: Option<IdentId<'db>>) -> Option<&mut LoopCtxt<'db>> {
+        match label {
+            Some(label) => self.loops.iter_mut().rev().find(|l| l.label == Some(label)),
+            None => self.loops.last_mut(),
+        }
+    }
+
+    fn check_use(&mut self, expr: ExprId, state: &InitState<'db>) {
+        let Some(binding) = self.deferred_binding(expr) else {
+            return;
+        };
+        if self.silent || state.assigned.contains(&binding) || !self.reported.insert(binding) {
+            return;
+        }
+
+        let env = &self.checker.env;
+        let diag = BodyDiag::UninitializedBinding {
+            primary: expr.span(self.body()).into(),
+            binding: (env.binding_name(binding), env.binding_def_span(binding)),
+            possibly: state.maybe_assigned.contains(&binding),
+        };
+        self.checker.push_diag(diag);
+    }
+
+    fn check_assign(&mut self, lhs: ExprId, state: &mut InitState<'db>) {
+        let Some(binding) = self.deferred_binding(lhs) else {
+            // Assigning to a field or an element reads the base binding.
+            self.check_expr(lhs, state);
+            return;
+        };
+
+        if !self.silent && !binding.is_mut() && state.maybe_assigned.contains(&binding) {
+            let env = &self.checker.env;
+            let diag = BodyDiag::ImmutableReassignment {
+                primary: lhs.span(self.body()).into(),
+                binding: (env.binding_name(binding), env.binding_def_span(binding)),
+            };
+            self.checker.push_diag(diag);
+        }
+        state.assign(binding);
+    }
+
+    /// Returns the binding `expr` refers to if the binding is declared without
+    /// an initializer.
+    fn deferred_binding(&self, expr: ExprId) -> Option<LocalBinding<'db>> {
+        let binding = self.checker.env.typed_expr(expr)?.binding()?;
+        self.checker
+            .env
+            .is_deferred_binding(binding)
+            .then_some(binding)
+    }
+}
@@ -6,7 +6,7 @@ use hir::{
     span::DynLazySpan,
 };
 use num_bigint::BigUint;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::Update;
 
 use super::{Callable, TypedBody};
@@ -41,7 +41,22 @@ pub(super) struct TyCheckEnv<'db> {
 
     var_env: Vec<BlockEnv<'db>>,
     pending_vars: FxHashMap<IdentId<'db>, LocalBinding<'db>>,
-    loop_stack: Vec<StmtId>,
+    loop_stack: Vec<LoopScope<'db>>,
+
+    /// All the local bindings and parameters introduced in the body.
+    bindings: Vec<LocalBinding<'db>>,
+    /// The local bindings declared by `let` without an initializer.
+    deferred_bindings: FxHashSet<LocalBinding<'db>>,
+
+    /// All the `unsafe` blocks in the body. The flag is `true` if the block
+    /// contains an operation that requires it.
+    unsafe_blocks: Vec<(ExprId, bool)>,
+    /// The indices of the `unsafe` blocks enclosing the expression being
+    /// checked.
+    unsafe_stack: Vec<usize>,
+    /// `true` if the body is the body of an `unsafe fn`, which is an unsafe
+    /// context as a whole.
+    is_unsafe_fn: bool,
 }
 
 impl<'db> TyCheckEnv<'db> {
@@ -60,6 +75,11 @@ impl<'db> TyCheckEnv<'db> {
             var_env: vec![BlockEnv::new(func.scope(), 0)],
             pending_vars: FxHashMap::default(),
             loop_stack: Vec::new(),
+            bindings: Vec::new(),
+            deferred_bindings: FxHashSet::default(),
+            unsafe_blocks: Vec::new(),
+            unsafe_stack: Vec::new(),
+            is_unsafe_fn: func.is_unsafe(db),
         };
 
         env.enter_scope(body.expr(db));
@@ -88,6 +108,7 @@ impl<'db> TyCheckEnv<'db> {
             };
 
             env.var_env.last_mut().unwrap().register_var(name, var);
+            env.bindings.push(var);
         }
 
         Ok(env)
@@ -158,16 +179,53 @@ impl<'db> TyCheckEnv<'db> {
         self.var_env.pop().unwrap();
     }
 
-    pub(super) fn enter_loop(&mut self, stmt: StmtId) {
-        self.loop_stack.push(stmt);
+    pub(super) fn enter_loop(&mut self, label: Option<IdentId<'db>>, break_ty: Option<TyId<'db>>) {
+        self.loop_stack.push(LoopScope::new(label, break_ty));
     }
 
-    pub(super) fn leave_loop(&mut self) {
-        self.loop_stack.pop();
+    pub(super) fn leave_loop(&mut self) -> LoopScope<'db> {
+        self.loop_stack.pop().unwrap()
     }
 
-    pub(super) fn current_loop(&self) -> Option<StmtId> {
-        self.loop_stack.last().copied()
+    /// Returns the loop that `break` or `continue` with the given `label`
+    /// refers to, i.e., the innermost loop if `label` is `None`.
+    pub(super) fn find_loop(&mut self, label: Option<IdentId<'db>>) -> Option<&mut LoopScope<'db>> {
+        match label {
+            Some(label) => self
+                .loop_stack
+                .iter_mut()
+                .rev()
+                .find(|scope| scope.label == Some(label)),
+            None => self.loop_stack.last_mut(),
+        }
+    }
+
+    pub(super) fn enter_unsafe(&mut self, expr: ExprId) {
+        self.unsafe_stack.push(self.unsafe_blocks.len());
+        self.unsafe_blocks.push((expr, false));
+    }
+
+    pub(super) fn leave_unsafe(&mut self) {
+        self.unsafe_stack.pop().unwrap();
+    }
+
+    /// Marks the outermost enclosing `unsafe` block as used. Returns `false`
+    /// if the expression being checked isn't in an unsafe context.
+    ///
+    /// Nested `unsafe` blocks and `unsafe` blocks in an `unsafe fn` are never
+    /// marked, so that they are reported as unnecessary.
+    pub(super) fn use_unsafe(&mut self) -> bool {
+        if self.is_unsafe_fn {
+            return true;
+        }
+
+        match self.unsafe_stack.first() {
+            Some(&idx) => {
+                self.unsafe_blocks[idx].1 = true;
+                true
+            }
+            None => false,
+        }
     }
 
     pub(super) fn type_expr(&mut self, expr: ExprId, typed: ExprProp<'db>) {
@@ -201,9 +259,27 @@ impl<'db> TyCheckEnv<'db> {
         name: IdentId<'db>,
         binding: LocalBinding<'db>,
     ) -> Option<LocalBinding<'db>> {
+        self.bindings.push(binding);
         self.pending_vars.insert(name, binding)
     }
 
+    /// Marks all pending bindings as declared without an initializer. Such
+    /// bindings must be assigned before they're used, see
+    /// [`TyChecker::check_definite_init`](super::TyChecker::check_definite_init).
+    pub(super) fn defer_pending_bindings(&mut self) {
+        self.deferred_bindings
+            .extend(self.pending_vars.values().copied());
+    }
+
+    /// Returns `true` if `binding` is declared without an initializer.
+    pub(super) fn is_deferred_binding(&self, binding: LocalBinding<'db>) -> bool {
+        self.deferred_bindings.contains(&binding)
+    }
+
+    pub(super) fn has_deferred_bindings(&self) -> bool {
+        !self.deferred_bindings.is_empty()
+    }
+
     /// Flushes all pending variable bindings into the current variable
     /// environment.
     ///
@@ -267,6 +343,8 @@ impl<'db> TyCheckEnv<'db> {
             pat_ty: self.pat_ty,
             expr_ty: self.expr_ty,
             callables,
+            bindings: self.bindings,
+            unsafe_blocks: self.unsafe_blocks,
         }
     }
 
@@ -312,31 +390,9 @@ impl<'db> TyCheckEnv<'db> {
         sink: &mut Vec<FuncBodyDiag<'db>>,
     ) {
         let assumptions = self.assumptions();
-        let mut changed = true;
         let hir_db = self.db;
         let ingot = self.body().top_mod(hir_db).ingot(hir_db);
-        // Try to perform confirmation until all pending confirmations reaches to
-        // the fixed point.
-        while changed {
-            changed = false;
-            for (inst, _) in &self.pending_confirmations {
-                let inst = inst.fold_with(prober);
-                let canonical_inst = Canonicalized::new(self.db, inst);
-                if let GoalSatisfiability::Satisfied(solution) =
-                    is_goal_satisfiable(self.db, ingot, canonical_inst.value, assumptions)
-                {
-                    let solution = canonical_inst.extract_solution(prober.table, *solution);
-                    prober.table.unify(inst, solution).unwrap();
-
-                    // We need compare old and new inst in a canonical form since a new inst might
-                    // introduce new type variable in some cases.
-                    // In other word, we need to check ⍺-equivalence to know whether the
-                    // confirmation step move forward.
-                    let new_canonical_inst = Canonical::new(self.db, inst.fold_with(prober.table));
-                    changed |= new_canonical_inst != canonical_inst.value;
-                }
-            }
-        }
+        self.confirm(prober);
 
         // Finds ambiguous trait inst and emits diags.
         for (inst, span) in &self.pending_confirmations {
@@ -364,6 +420,59 @@ impl<'db> TyCheckEnv<'db> {
             }
         }
     }
+
+    /// Performs the pending trait confirmations without reporting the ones
+    /// that remain ambiguous. See [`Self::perform_pending_confirmation`].
+    pub(super) fn confirm_pending(&self, table: &mut UnificationTable<'db>) {
+        self.confirm(&mut Prober { table });
+    }
+
+    fn confirm(&self, prober: &mut Prober<'db, '_>) {
+        let assumptions = self.assumptions();
+        let mut changed = true;
+        let hir_db = self.db;
+        let ingot = self.body().top_mod(hir_db).ingot(hir_db);
+        // Try to perform confirmation until all pending confirmations reaches to
+        // the fixed point.
+        while changed {
+            changed = false;
+            for (inst, _) in &self.pending_confirmations {
+                let inst = inst.fold_with(prober);
+                let canonical_inst = Canonicalized::new(self.db, inst);
+                if let GoalSatisfiability::Satisfied(solution) =
+                    is_goal_satisfiable(self.db, ingot, canonical_inst.value, assumptions)
+                {
+                    let solution = canonical_inst.extract_solution(prober.table, *solution);
+                    prober.table.unify(inst, solution).unwrap();
+
+                    // We need compare old and new inst in a canonical form since a new inst might
+                    // introduce new type variable in some cases.
+                    // In other word, we need to check ⍺-equivalence to know whether the
+                    // confirmation step move forward.
+                    let new_canonical_inst = Canonical::new(self.db, inst.fold_with(prober.table));
+                    changed |= new_canonical_inst != canonical_inst.value;
+                }
+            }
+        }
+    }
+}
+
+pub(super) struct LoopScope<'db> {
+    pub(super) label: Option<IdentId<'db>>,
+    /// The type of the values given to `break`. This is `None` for `for` and
+    /// `while` loops, which can't be broken out of with a value.
+    pub(super) break_ty: Option<TyId<'db>>,
+    pub(super) has_break: bool,
+}
+
+impl<'db> LoopScope<'db> {
+    fn new(label: Option<IdentId<'db>>, break_ty: Option<TyId<'db>>) -> Self {
+        Self {
+            label,
+            break_ty,
+            has_break: false,
+        }
+    }
 }
 
 pub(super) struct BlockEnv<'db> {
@@ -458,14 +567,13 @@ impl<'db> LocalBinding<'db> {
     pub(super) fn binding_name(&self, env: &TyCheckEnv<'db>) -> IdentId<'db> {
         let hir_db = env.db;
         match self {
-            Self::Local { pat, .. } => {
-                let Partial::Present(Pat::Path(Partial::Present(path), ..)) =
-                    pat.data(hir_db, env.body())
-                else {
-                    unreachable!();
-                };
-                path.ident(hir_db).to_opt().unwrap()
-            }
+            Self::Local { pat, .. } => match pat.data(hir_db, env.body()) {
+                Partial::Present(Pat::Path(Partial::Present(path), ..)) => {
+                    path.ident(hir_db).to_opt().unwrap()
+                }
+                Partial::Present(Pat::Binding(Partial::Present(name), ..)) => *name,
+                _ => unreachable!(),
+            },
 
             Self::Param { idx, .. } => {
                 let func = env.func().unwrap();
@@ -2,7 +2,7 @@ use either::Either;
 use hir::{
     hir_def::{
         ArithBinOp, BinOp, Expr, ExprId, FieldIndex, GenericArgListId, IdentId, Partial, Pat,
-        PatId, PathId, UnOp, VariantKind,
+        PatId, PathId, Stmt, UnOp, VariantKind,
     },
     span::path::LazyPathSpan,
 };
@@ -58,11 +58,19 @@ impl<'db> TyChecker<'db> {
             Expr::Array(..) => self.check_array(expr, expr_data, expected),
             Expr::ArrayRep(..) => self.check_array_rep(expr, expr_data, expected),
             Expr::If(..) => self.check_if(expr, expr_data),
+            Expr::IfLet(..) => self.check_if_let(expr, expr_data),
             Expr::Match(..) => self.check_match(expr, expr_data),
+            Expr::Loop(..) => self.check_loop(expr, expr_data),
+            Expr::Unsafe(..) => self.check_unsafe(expr, expr_data, expected),
             Expr::Assign(..) => self.check_assign(expr, expr_data),
             Expr::AugAssign(..) => self.check_aug_assign(expr, expr_data),
         };
 
+        if self.check_valueless_branch(expr, expr_data, actual.ty) {
+            self.env.type_expr(expr, actual);
+            return ExprProp::invalid(self.db);
+        }
+
         let typeable = Typeable::Expr(expr, actual);
         let ty = self.unify_ty(typeable, actual.ty, expected);
         actual.swap_ty(ty);
@@ -83,18 +91,50 @@ impl<'db> TyChecker<'db> {
             ExprProp::new(TyId::unit(self.db), true)
         } else {
             self.env.enter_scope(expr);
+            let mut diverges = false;
             for &stmt in stmts[..stmts.len() - 1].iter() {
                 let ty = self.fresh_ty();
-                self.check_stmt(stmt, ty);
+                let ty = self.check_stmt(stmt, ty);
+                diverges |= self.stmt_diverges(stmt, ty);
             }
 
             let last_stmt = stmts[stmts.len() - 1];
-            let res = self.check_stmt(last_stmt, expected);
+            let tail = match self.env.stmt_data(last_stmt) {
+                Partial::Present(Stmt::Expr(tail)) => Some(*tail),
+                _ => None,
+            };
+            if let Some(tail) = tail.filter(|_| !diverges) {
+                self.inherit_tail(expr, tail);
+            }
+
+            let mut res = self.check_stmt(last_stmt, expected);
+            // A block without a tail expression never produces a value if one
+            // of its statements diverges.
+            if tail.is_none() && (diverges || self.stmt_diverges(last_stmt, res)) {
+                res = TyId::never(self.db);
+            }
             self.env.leave_scope();
             ExprProp::new(res, true)
         }
     }
 
+    fn check_unsafe(
+        &mut self,
+        expr: ExprId,
+        expr_data: &Expr<'db>,
+        expected: TyId<'db>,
+    ) -> ExprProp<'db> {
+        let Expr::Unsafe(block) = expr_data else {
+            unreachable!()
+        };
+
+        self.inherit_tail(expr, *block);
+        self.env.enter_unsafe(expr);
+        let ty = self.check_expr(*block, expected).ty;
+        self.env.leave_unsafe();
+        ExprProp::new(ty, true)
+    }
+
     fn check_unary(&mut self, expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
         let Expr::Un(lhs, op) = expr_data else {
             unreachable!()
@@ -298,6 +338,7 @@ impl<'db> TyChecker<'db> {
         };
 
         callable.check_args(self, args, call_span.args(), None);
+        self.check_unsafe_call(expr, &callable);
 
         let ret_ty = callable.ret_ty(self.db);
         self.env.register_callable(expr, callable);
@@ -387,11 +428,28 @@ impl<'db> TyChecker<'db> {
             call_span.args(),
             Some((*receiver, receiver_prop)),
         );
+        self.check_unsafe_call(expr, &callable);
         let ret_ty = callable.ret_ty(self.db);
         self.env.register_callable(expr, callable);
         ExprProp::new(ret_ty, true)
     }
 
+    /// Reports the call `expr` if the callee requires an `unsafe` block and
+    /// the call isn't in an unsafe context.
+    fn check_unsafe_call(&mut self, expr: ExprId, callable: &Callable<'db>) {
+        let Some(func) = callable.func_def.hir_func_def(self.db) else {
+            return;
+        };
+
+        if func.is_unsafe(self.db) && !self.env.use_unsafe() {
+            let diag = BodyDiag::UnsafeCallOutsideUnsafe {
+                primary: expr.span(self.body()).into(),
+                func,
+            };
+            self.push_diag(diag);
+        }
+    }
+
     fn check_path(&mut self, expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
         let Expr::Path(path) = expr_data else {
             unreachable!()
@@ -478,7 +536,7 @@ impl<'db> TyChecker<'db> {
 
                     ExprProp::new(self.table.instantiate_to_term(ty), true)
                 }
-                PathRes::Const(ty) => ExprProp::new(ty, true),
+                PathRes::Const(_, ty) => ExprProp::new(ty, true),
                 PathRes::TypeMemberTbd(parent_ty) => {
                     let ty = if parent_ty.has_invalid(self.db) {
                         let span = span.path().segment(path.segment_index(self.db) - 1);
@@ -525,7 +583,7 @@ impl<'db> TyChecker<'db> {
                 }
             }
 
-            PathRes::Func(ty) | PathRes::Const(ty) => {
+            PathRes::Func(ty) | PathRes::Const(_, ty) => {
                 let record_like = RecordLike::from_ty(ty);
                 let diag =
                     BodyDiag::record_expected(self.db, span.path().into(), Some(record_like));
@@ -635,6 +693,7 @@ impl<'db> TyChecker<'db> {
                                 expr.span(self.body()).into_field_expr().accessor().into(),
                                 *label,
                                 scope.name_span(self.db),
+                                Some(scope),
                             );
 
                             self.push_diag(diag);
@@ -787,10 +846,14 @@ impl<'db> TyChecker<'db> {
         ExprProp::new(ty, true)
     }
 
-    fn check_if(&mut self, _expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
+    fn check_if(&mut self, expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
         let Expr::If(cond, then, else_) = expr_data else {
             unreachable!()
         };
+        self.inherit_tail(expr, *then);
+        if let Some(else_) = else_ {
+            self.inherit_tail(expr, *else_);
+        }
 
         self.check_expr(*cond, TyId::bool(self.db));
 
@@ -811,6 +874,56 @@ impl<'db> TyChecker<'db> {
         ExprProp::new(ty, true)
     }
 
+    fn check_if_let(&mut self, expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
+        let Expr::IfLet(pat, scrutinee, then, else_) = expr_data else {
+            unreachable!()
+        };
+        self.inherit_tail(expr, *then);
+        if let Some(else_) = else_ {
+            self.inherit_tail(expr, *else_);
+        }
+
+        let scrutinee_ty = self.fresh_ty();
+        let scrutinee_ty = self.check_expr(*scrutinee, scrutinee_ty).ty;
+        self.check_pat(*pat, scrutinee_ty);
+
+        // The bindings introduced by the pattern are only visible in the then
+        // branch.
+        let if_ty = self.fresh_ty();
+        self.env.enter_scope(*then);
+        self.env.flush_pending_bindings();
+        self.check_expr(*then, if_ty);
+        self.env.leave_scope();
+
+        let ty = match else_ {
+            Some(else_) => self.check_expr_in_new_scope(*else_, if_ty).ty,
+            // If there is no else branch, the if expression itself typed as `()`
+            None => TyId::unit(self.db),
+        };
+
+        ExprProp::new(ty, true)
+    }
+
+    fn check_loop(&mut self, _expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
+        let Expr::Loop(label, body) = expr_data else {
+            unreachable!()
+        };
+
+        let loop_ty = self.fresh_ty();
+        self.env.enter_loop(*label, Some(loop_ty));
+        self.check_expr_in_new_scope(*body, TyId::unit(self.db));
+        let loop_scope = self.env.leave_loop();
+
+        // A `loop` that is never broken out of diverges.
+        let ty = if loop_scope.has_break {
+            loop_ty
+        } else {
+            TyId::never(self.db)
+        };
+
+        ExprProp::new(ty, true)
+    }
+
     fn check_match(&mut self, expr: ExprId, expr_data: &Expr<'db>) -> ExprProp<'db> {
         let Expr::Match(scrutinee, arms) = expr_data else {
             unreachable!()
@@ -826,6 +939,8 @@ impl<'db> TyChecker<'db> {
         let mut match_ty = self.fresh_ty();
         // Store cloned HirPat data and the original PatId for diagnostics.
         let mut hir_pats_with_ids: Vec<(&Pat<'db>, PatId)> = Vec::with_capacity(arms.len());
+        // Whether each of the collected patterns belongs to a guarded arm.
+        let mut guarded = Vec::with_capacity(arms.len());
 
         // First loop: Type check patterns, collect HIR patterns for analysis, and type check arm bodies.
         for arm in arms.iter() {
@@ -835,12 +950,18 @@ impl<'db> TyChecker<'db> {
             if let Partial::Present(actual_pat_data) = pat_data_partial {
                 // Clone the Pat data for ownership in the vector.
                 hir_pats_with_ids.push((actual_pat_data, arm.pat));
+                guarded.push(arm.guard.is_some());
             }
             // If pat_data is Partial::Absent, check_pat should have already emitted an error.
             // We only include valid patterns in the exhaustiveness/reachability analysis.
 
+            self.inherit_tail(expr, arm.body);
             self.env.enter_scope(arm.body);
             self.env.flush_pending_bindings();
+            // The bindings of the pattern are visible in the guard.
+            if let Some(guard) = arm.guard {
+                self.check_expr(guard, TyId::bool(self.db));
+            }
             match_ty = self.check_expr(arm.body, match_ty).ty;
             self.env.leave_scope();
         }
@@ -855,6 +976,7 @@ impl<'db> TyChecker<'db> {
         let reachability = crate::ty::pattern_analysis::check_reachability(
             self.db,
             &collected_hir_pats,
+            &guarded,
             self.body(),
             self.env.scope(),
             scrutinee_ty,
@@ -870,10 +992,17 @@ impl<'db> TyChecker<'db> {
             }
         }
 
-        // Perform exhaustiveness analysis.
+        // Perform exhaustiveness analysis. Guarded arms don't count toward
+        // exhaustiveness since their guards may fail.
+        let unguarded_hir_pats: Vec<Pat<'db>> = collected_hir_pats
+            .into_iter()
+            .zip(&guarded)
+            .filter(|(_, is_guarded)| !**is_guarded)
+            .map(|(pat, _)| pat)
+            .collect();
         if let Err(missing_patterns) = crate::ty::pattern_analysis::check_exhaustiveness(
             self.db,
-            &collected_hir_pats,
+            &unguarded_hir_pats,
             self.body(),
             self.env.scope(),
             scrutinee_ty,
@@ -900,7 +1029,15 @@ impl<'db> TyChecker<'db> {
 
         let result_ty = TyId::unit(self.db);
 
-        self.check_assign_lhs(*lhs, &typed_lhs);
+        // Assigning to a binding declared without an initializer initializes
+        // it, which is allowed even if the binding is immutable. Assigning to
+        // it twice is reported by `check_definite_init`.
+        let initializes = typed_lhs
+            .binding()
+            .is_some_and(|binding| self.env.is_deferred_binding(binding));
+        if !initializes {
+            self.check_assign_lhs(*lhs, &typed_lhs);
+        }
 
         ExprProp::new(result_ty, true)
     }
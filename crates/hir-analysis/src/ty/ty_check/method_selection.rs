@@ -103,6 +103,7 @@ pub(super) fn select_method_candidate<'db>(
                 method_name.span,
                 method_name.data,
                 func.name_span(db).into(),
+                Some(func.scope(db)),
             );
             Err(diag.into())
         }
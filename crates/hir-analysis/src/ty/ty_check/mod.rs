@@ -1,24 +1,31 @@
 mod callable;
+mod definite_init;
 mod env;
 mod expr;
 mod method_selection;
 mod pat;
 mod path;
+mod reachability;
 mod stmt;
 
 pub use self::path::RecordLike;
 pub use callable::Callable;
 pub use env::ExprProp;
+pub(crate) use env::LocalBinding;
 use env::TyCheckEnv;
 pub(super) use expr::TraitOps;
 use hir::{
-    hir_def::{Body, Expr, ExprId, Func, LitKind, Pat, PatId, PathId, TypeId as HirTyId},
+    hir_def::{
+        Body, Expr, ExprId, Func, IntegerId, LitKind, Partial, Pat, PatId, PathId,
+        TypeId as HirTyId, UnOp,
+    },
     span::{
         expr::LazyExprSpan, pat::LazyPatSpan, path::LazyPathSpan, types::LazyTySpan, DynLazySpan,
     },
     visitor::{walk_expr, walk_pat, Visitor, VisitorCtxt},
 };
 
+use num_bigint::{BigInt, Sign};
 use rustc_hash::{FxHashMap, FxHashSet};
 use salsa::Update;
 
@@ -27,7 +34,7 @@ use super::{
     fold::{TyFoldable, TyFolder},
     trait_def::{TraitInstId, TraitMethod},
     trait_resolution::PredicateListId,
-    ty_def::{InvalidCause, Kind, TyId, TyVarSort},
+    ty_def::{InvalidCause, Kind, TyBase, TyData, TyId, TyVarSort},
     ty_lower::lower_hir_ty,
     unify::{InferenceKey, UnificationError, UnificationTable},
 };
@@ -58,6 +65,13 @@ pub struct TyChecker<'db> {
     table: UnificationTable<'db>,
     expected: TyId<'db>,
     diags: Vec<FuncBodyDiag<'db>>,
+    /// The reachable expressions whose value is returned from the function,
+    /// if the function returns a value. See [`Self::check_valueless_branch`].
+    tail_exprs: FxHashSet<ExprId>,
+    /// The pairs of types whose unification is deferred until their
+    /// associated types can be normalized, with the span to report a mismatch
+    /// at. See [`Self::unify_pending_projections`].
+    pending_projections: Vec<(TyId<'db>, TyId<'db>, DynLazySpan<'db>)>,
 }
 
 impl<'db> TyChecker<'db> {
@@ -80,7 +94,15 @@ impl<'db> TyChecker<'db> {
 
     fn run(&mut self) {
         let root_expr = self.env.body().expr(self.db);
+        let expected = self.expected;
+        if expected != TyId::unit(self.db)
+            && !expected.is_never(self.db)
+            && !expected.has_invalid(self.db)
+        {
+            self.tail_exprs.insert(root_expr);
+        }
         self.check_expr(root_expr, self.expected);
+        self.check_definite_init(root_expr);
     }
 
     fn finish(self) -> (Vec<FuncBodyDiag<'db>>, TypedBody<'db>) {
@@ -95,6 +117,8 @@ impl<'db> TyChecker<'db> {
             table,
             expected,
             diags: Vec::new(),
+            tail_exprs: FxHashSet::default(),
+            pending_projections: Vec::new(),
         }
     }
 
@@ -186,6 +210,9 @@ impl<'db> TyChecker<'db> {
 
         match self.table.unify(actual, expected) {
             Ok(()) => {
+                self.defer_projections(&span);
+                self.unify_pending_projections();
+
                 // FIXME: This is a temporary workaround, this should be removed when we
                 // implement subtyping.
                 let actual = actual.fold_with(&mut self.table);
@@ -196,7 +223,22 @@ impl<'db> TyChecker<'db> {
                 }
             }
 
-            Err(UnificationError::TypeMismatch) => {
+            Err(err) => {
+                self.push_unification_error(err, actual, expected, span);
+                TyId::invalid(self.db, InvalidCause::Other)
+            }
+        }
+    }
+
+    fn push_unification_error(
+        &mut self,
+        err: UnificationError,
+        actual: TyId<'db>,
+        expected: TyId<'db>,
+        span: DynLazySpan<'db>,
+    ) {
+        match err {
+            UnificationError::TypeMismatch => {
                 let actual = actual.fold_with(&mut self.table);
                 let expected = expected.fold_with(&mut self.table);
                 self.push_diag(BodyDiag::TypeMismatch {
@@ -204,13 +246,38 @@ impl<'db> TyChecker<'db> {
                     expected,
                     given: actual,
                 });
-                TyId::invalid(self.db, InvalidCause::Other)
             }
 
-            Err(UnificationError::OccursCheckFailed) => {
+            UnificationError::OccursCheckFailed => {
                 self.push_diag(BodyDiag::InfiniteOccurrence(span));
+            }
+        }
+    }
 
-                TyId::invalid(self.db, InvalidCause::Other)
+    /// Takes the unifications that the last unification deferred until their
+    /// associated types can be normalized, so that a mismatch found later is
+    /// reported at `span` rather than where the projections got resolved.
+    fn defer_projections(&mut self, span: &DynLazySpan<'db>) {
+        for (actual, expected) in self.table.take_pending_projections() {
+            self.pending_projections
+                .push((actual, expected, span.clone()));
+        }
+    }
+
+    /// Unifies the deferred pairs of types again, until no more of their
+    /// associated types can be normalized.
+    fn unify_pending_projections(&mut self) {
+        loop {
+            let pending = std::mem::take(&mut self.pending_projections);
+            let len = pending.len();
+            for (actual, expected, span) in pending {
+                match self.table.unify(actual, expected) {
+                    Ok(()) => self.defer_projections(&span),
+                    Err(err) => self.push_unification_error(err, actual, expected, span),
+                }
+            }
+            if self.pending_projections.len() >= len {
+                return;
             }
         }
     }
@@ -228,7 +295,7 @@ impl<'db> TyChecker<'db> {
                 return;
             }
             if !reso.is_visible_from(self.db, scope) {
-                invisible = Some((path, reso.name_span(self.db)));
+                invisible = Some((path, reso.name_span(self.db), reso.as_scope(self.db)));
             }
         };
 
@@ -243,10 +310,10 @@ impl<'db> TyChecker<'db> {
             Err(err) => Err(err),
         };
 
-        if let Some((path, deriv_span)) = invisible {
+        if let Some((path, deriv_span, vis_scope)) = invisible {
             let span = span.clone().segment(path.segment_index(self.db)).ident();
             let ident = path.ident(self.db);
-            let diag = NameResDiag::Invisible(span.into(), *ident.unwrap(), deriv_span);
+            let diag = NameResDiag::Invisible(span.into(), *ident.unwrap(), deriv_span, vis_scope);
             self.diags.push(diag.into());
         }
 
@@ -260,6 +327,10 @@ pub struct TypedBody<'db> {
     pat_ty: FxHashMap<PatId, TyId<'db>>,
     expr_ty: FxHashMap<ExprId, ExprProp<'db>>,
     callables: FxHashMap<ExprId, Callable<'db>>,
+    bindings: Vec<LocalBinding<'db>>,
+    /// The `unsafe` blocks in the body, with `true` if the block contains an
+    /// operation that requires it.
+    unsafe_blocks: Vec<(ExprId, bool)>,
 }
 
 impl<'db> TypedBody<'db> {
@@ -285,12 +356,36 @@ impl<'db> TypedBody<'db> {
         self.callables.get(&expr)
     }
 
+    /// Returns the local bindings and parameters that are never referred to
+    /// in the body.
+    pub(crate) fn unused_bindings(&self) -> impl Iterator<Item = LocalBinding<'db>> + '_ {
+        let used: FxHashSet<_> = self
+            .expr_ty
+            .values()
+            .filter_map(|prop| prop.binding)
+            .collect();
+
+        self.bindings
+            .iter()
+            .copied()
+            .filter(move |binding| !used.contains(binding))
+    }
+
+    /// Returns the `unsafe` blocks that contain no operation requiring them.
+    pub(crate) fn unused_unsafe_blocks(&self) -> impl Iterator<Item = ExprId> + '_ {
+        self.unsafe_blocks
+            .iter()
+            .filter_map(|&(expr, is_used)| (!is_used).then_some(expr))
+    }
+
     fn empty() -> Self {
         Self {
             body: None,
             pat_ty: FxHashMap::default(),
             expr_ty: FxHashMap::default(),
             callables: FxHashMap::default(),
+            bindings: Vec::new(),
+            unsafe_blocks: Vec::new(),
         }
     }
 }
@@ -331,7 +426,13 @@ struct TyCheckerFinalizer<'db> {
     db: &'db dyn HirAnalysisDb,
     body: TypedBody<'db>,
     assumptions: PredicateListId<'db>,
+    /// The associated types that still can't be normalized at the end of type
+    /// checking, with the span where their unification was deferred.
+    unresolved_projections: Vec<(TyId<'db>, DynLazySpan<'db>)>,
     ty_vars: FxHashSet<InferenceKey<'db>>,
+    /// The integer literals that are the operand of a unary `-`. These are
+    /// checked together with the `-`.
+    negated_lits: FxHashSet<ExprId>,
     diags: Vec<FuncBodyDiag<'db>>,
 }
 
@@ -340,12 +441,28 @@ impl<'db> Visitor<'db> for TyCheckerFinalizer<'db> {
         &mut self,
         ctxt: &mut VisitorCtxt<'db, LazyPatSpan<'db>>,
         pat: PatId,
-        _: &Pat<'db>,
+        pat_data: &Pat<'db>,
     ) {
         let ty = self.body.pat_ty(self.db, pat);
         let span = ctxt.span().unwrap();
         self.check_unknown(ty, span.clone().into());
 
+        match pat_data {
+            Pat::Lit(Partial::Present(LitKind::Int(lit))) => {
+                self.check_int_lit(*lit, false, ty, span.into())
+            }
+            Pat::Range(start, end) => {
+                let range_span = span.into_range_pat();
+                if let Partial::Present(LitKind::Int(lit)) = start {
+                    self.check_int_lit(*lit, false, ty, range_span.clone().start().into());
+                }
+                if let Partial::Present(LitKind::Int(lit)) = end {
+                    self.check_int_lit(*lit, false, ty, range_span.end().into());
+                }
+            }
+            _ => {}
+        }
+
         walk_pat(self, ctxt, pat)
     }
 
@@ -365,6 +482,25 @@ impl<'db> Visitor<'db> for TyCheckerFinalizer<'db> {
             }
         }
 
+        match expr_data {
+            Expr::Un(operand, Partial::Present(UnOp::Minus)) => {
+                if let Some(Partial::Present(Expr::Lit(LitKind::Int(lit)))) =
+                    self.body.body.map(|body| operand.data(self.db, body))
+                {
+                    self.negated_lits.insert(*operand);
+                    let ty = self.body.expr_ty(self.db, *operand);
+                    self.check_int_lit(*lit, true, ty, ctxt.span().unwrap().into());
+                }
+            }
+
+            Expr::Lit(LitKind::Int(lit)) if !self.negated_lits.contains(&expr) => {
+                let ty = self.body.expr_ty(self.db, expr);
+                self.check_int_lit(*lit, false, ty, ctxt.span().unwrap().into());
+            }
+
+            _ => {}
+        }
+
         // We need this additional check for method call because the callable type is
         // not tied to the expression type.
         if let Expr::MethodCall(..) = expr_data {
@@ -389,23 +525,52 @@ impl<'db> Visitor<'db> for TyCheckerFinalizer<'db> {
 
 impl<'db> TyCheckerFinalizer<'db> {
     fn new(mut checker: TyChecker<'db>) -> Self {
+        let db = checker.db;
         let assumptions = checker.env.assumptions();
+
+        // Trait confirmation can resolve the type variables of the deferred
+        // projections, so they are unified once more before giving up on them.
+        checker.env.confirm_pending(&mut checker.table);
+        checker.unify_pending_projections();
+        let mut unresolved_projections = Vec::new();
+        for (actual, expected, span) in std::mem::take(&mut checker.pending_projections) {
+            let actual = actual.fold_with(&mut checker.table);
+            let projection = if matches!(actual.data(db), TyData::AssocTy(_)) && actual.has_var(db)
+            {
+                actual
+            } else {
+                expected.fold_with(&mut checker.table)
+            };
+            unresolved_projections.push((projection, span));
+        }
+
         let body = checker.env.finish(&mut checker.table, &mut checker.diags);
 
         Self {
-            db: checker.db,
+            db,
             body,
             assumptions,
+            unresolved_projections,
             ty_vars: FxHashSet::default(),
+            negated_lits: FxHashSet::default(),
             diags: checker.diags,
         }
     }
 
     fn finish(mut self) -> (Vec<FuncBodyDiag<'db>>, TypedBody<'db>) {
+        self.check_unresolved_projections();
         self.check_unknown_types();
         (self.diags, self.body)
     }
 
+    /// Reports the associated types that can't be normalized because their
+    /// type variables are never resolved.
+    fn check_unresolved_projections(&mut self) {
+        for (projection, span) in std::mem::take(&mut self.unresolved_projections) {
+            self.check_unknown(projection, span);
+        }
+    }
+
     fn check_unknown_types(&mut self) {
         if let Some(body) = self.body.body {
             let mut ctxt = VisitorCtxt::with_body(self.db, body);
@@ -432,14 +597,51 @@ impl<'db> TyCheckerFinalizer<'db> {
         }
     }
 
+    /// Reports an integer literal whose value doesn't fit into its inferred
+    /// type.
+    fn check_int_lit(
+        &mut self,
+        lit: IntegerId<'db>,
+        negated: bool,
+        ty: TyId<'db>,
+        span: DynLazySpan<'db>,
+    ) {
+        let TyData::TyBase(TyBase::Prim(prim)) = ty.data(self.db) else {
+            return;
+        };
+        if !prim.is_integral() {
+            return;
+        }
+
+        let value = BigInt::from(lit.data(self.db).clone());
+        let value = if negated { -value } else { value };
+        let fits = match prim.int_range() {
+            Some((min, max)) => min <= value && value <= max,
+            None => prim.is_signed() || value.sign() != Sign::Minus,
+        };
+
+        if !fits {
+            let diag = BodyDiag::LiteralOutOfRange {
+                primary: span,
+                lit,
+                negated,
+                ty,
+            };
+            self.diags.push(diag.into());
+        }
+    }
+
     fn check_wf(&mut self, ty: TyId<'db>, span: DynLazySpan<'db>) {
         let flags = ty.flags(self.db);
         if flags.contains(TyFlags::HAS_INVALID) || flags.contains(TyFlags::HAS_VAR) {
             return;
         }
 
+        let Some(body) = self.body.body else {
+            return;
+        };
         let hir_db = self.db;
-        let ingot = self.body.body.unwrap().top_mod(hir_db).ingot(hir_db);
+        let ingot = body.top_mod(hir_db).ingot(hir_db);
         if let Some(diag) = ty.emit_wf_diag(self.db, ingot, self.assumptions, span) {
             self.diags.push(diag.into());
         }
@@ -1,7 +1,7 @@
 use std::ops::Range;
 
 use either::Either;
-use hir::hir_def::{Partial, Pat, PatId, VariantKind};
+use hir::hir_def::{LitKind, Partial, Pat, PatId, VariantKind};
 
 use super::{env::LocalBinding, path::RecordInitChecker, RecordLike, TyChecker};
 use crate::{
@@ -38,6 +38,10 @@ impl<'db> TyChecker<'db> {
                 self.check_pat(*lhs, expected);
                 self.check_pat(*rhs, expected)
             }
+
+            Pat::Range(..) => self.check_range_pat(pat, pat_data),
+            Pat::Binding(..) => self.check_binding_pat(pat, pat_data, expected),
+            Pat::Array(..) => self.check_array_pat(pat, pat_data, expected),
         };
 
         self.unify_ty(pat, ty, expected)
@@ -54,6 +58,100 @@ impl<'db> TyChecker<'db> {
         }
     }
 
+    fn check_range_pat(&mut self, pat: PatId, pat_data: &Pat<'db>) -> TyId<'db> {
+        let Pat::Range(start, end) = pat_data else {
+            unreachable!()
+        };
+
+        let (Partial::Present(start), Partial::Present(end)) = (start, end) else {
+            return TyId::invalid(self.db, InvalidCause::Other);
+        };
+
+        match (start, end) {
+            (LitKind::Int(start_int), LitKind::Int(end_int))
+                if start_int.data(self.db) <= end_int.data(self.db) =>
+            {
+                self.lit_ty(start)
+            }
+
+            _ => {
+                let diag = BodyDiag::InvalidRangePat(pat.span(self.body()).into());
+                self.push_diag(diag);
+                TyId::invalid(self.db, InvalidCause::Other)
+            }
+        }
+    }
+
+    fn check_binding_pat(
+        &mut self,
+        pat: PatId,
+        pat_data: &Pat<'db>,
+        expected: TyId<'db>,
+    ) -> TyId<'db> {
+        let Pat::Binding(name, is_mut, sub_pat) = pat_data else {
+            unreachable!()
+        };
+
+        if let Partial::Present(name) = name {
+            let binding = LocalBinding::local(pat, *is_mut);
+            if let Some(LocalBinding::Local {
+                pat: conflict_with, ..
+            }) = self.env.register_pending_binding(*name, binding)
+            {
+                let diag = BodyDiag::DuplicatedBinding {
+                    primary: pat.span(self.body()).into_binding_pat().name().into(),
+                    conflicat_with: conflict_with.span(self.body()).into(),
+                    name: *name,
+                };
+                self.push_diag(diag);
+            }
+        }
+
+        self.check_pat(*sub_pat, expected)
+    }
+
+    fn check_array_pat(
+        &mut self,
+        pat: PatId,
+        pat_data: &Pat<'db>,
+        expected: TyId<'db>,
+    ) -> TyId<'db> {
+        let Pat::Array(elems) = pat_data else {
+            unreachable!()
+        };
+
+        let expected_len = expected.array_len(self.db);
+        let (actual, _) = self.unpack_rest_pat(elems, expected_len);
+        let elem_ty = match expected.decompose_ty_app(self.db) {
+            (base, args) if base.is_array(self.db) && !args.is_empty() => args[0],
+            _ => self.fresh_ty(),
+        };
+
+        let has_rest = elems.iter().any(|elem| elem.is_rest(self.db, self.body()));
+        let actual = if has_rest && expected_len.is_none() && expected.is_array(self.db) {
+            // The length covered by `..` is unknown, so the array length can't
+            // be determined from the pattern.
+            expected
+        } else {
+            TyId::array_with_len(self.db, elem_ty, actual.len())
+        };
+
+        let unified = self.unify_ty(pat, actual, expected);
+        let elem_ty = if unified.has_invalid(self.db) {
+            TyId::invalid(self.db, InvalidCause::Other)
+        } else {
+            elem_ty
+        };
+
+        for &elem in elems {
+            if !elem.is_rest(self.db, self.body()) {
+                self.check_pat(elem, elem_ty);
+            }
+        }
+
+        unified
+    }
+
     fn check_tuple_pat(
         &mut self,
         pat: PatId,
@@ -169,7 +267,7 @@ impl<'db> TyChecker<'db> {
                     PathRes::Ty(ty)
                     | PathRes::TyAlias(_, ty)
                     | PathRes::Func(ty)
-                    | PathRes::Const(ty),
+                    | PathRes::Const(_, ty),
                 ) => {
                     let record_like = RecordLike::from_ty(ty);
                     if record_like.is_record(self.db) {
@@ -237,7 +335,7 @@ impl<'db> TyChecker<'db> {
                 PathRes::Ty(ty)
                 | PathRes::TyAlias(_, ty)
                 | PathRes::Func(ty)
-                | PathRes::Const(ty) => {
+                | PathRes::Const(_, ty) => {
                     let diag = BodyDiag::tuple_variant_expected(
                         self.db,
                         pat.span(self.body()).into(),
@@ -361,7 +459,7 @@ impl<'db> TyChecker<'db> {
                 PathRes::Ty(ty)
                 | PathRes::TyAlias(_, ty)
                 | PathRes::Func(ty)
-                | PathRes::Const(ty) => {
+                | PathRes::Const(_, ty) => {
                     let diag = BodyDiag::record_expected(
                         self.db,
                         pat.span(self.body()).into(),
@@ -120,7 +120,12 @@ impl<'tc, 'db, 'a> RecordInitChecker<'tc, 'db, 'a> {
         if is_scope_visible_from(self.tc.db, field_scope, self.tc.env.scope()) {
             Ok(ty)
         } else {
-            let diag = NameResDiag::Invisible(field_span, label, field_scope.name_span(self.tc.db));
+            let diag = NameResDiag::Invisible(
+                field_span,
+                label,
+                field_scope.name_span(self.tc.db),
+                Some(field_scope),
+            );
 
             self.invalid_field_given = true;
             Err(diag.into())
@@ -0,0 +1,144 @@
+//! Control-flow reachability analysis of function bodies.
+
+use hir::{
+    hir_def::{Body, Expr, ExprId, Partial, Stmt, StmtId},
+    span::DynLazySpan,
+};
+
+use super::{TyChecker, TypedBody};
+use crate::{
+    ty::{diagnostics::BodyDiag, fold::TyFoldable, ty_def::TyId},
+    HirAnalysisDb,
+};
+
+/// A statement that can never be executed because an earlier statement in
+/// the same block diverges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct UnreachableStmt {
+    /// The first unreachable statement of the block.
+    pub stmt: StmtId,
+    /// The statement that diverges.
+    pub cause: StmtId,
+}
+
+impl<'db> TypedBody<'db> {
+    /// Returns the first unreachable statement of every block in the body.
+    pub(crate) fn unreachable_stmts(&self, db: &'db dyn HirAnalysisDb) -> Vec<UnreachableStmt> {
+        let Some(body) = self.body else {
+            return Vec::new();
+        };
+
+        body.exprs(db)
+            .values()
+            .filter_map(|expr| match expr {
+                Partial::Present(Expr::Block(stmts)) => Some(stmts),
+                _ => None,
+            })
+            .filter_map(|stmts| {
+                let idx = stmts
+                    .iter()
+                    .position(|&stmt| self.stmt_diverges(db, body, stmt))?;
+                Some(UnreachableStmt {
+                    stmt: *stmts.get(idx + 1)?,
+                    cause: stmts[idx],
+                })
+            })
+            .collect()
+    }
+
+    /// Returns `true` if control never reaches the end of `stmt`, i.e. `stmt`
+    /// is `return`, `break` or `continue`, or evaluates an expression of type
+    /// `!`.
+    fn stmt_diverges(&self, db: &'db dyn HirAnalysisDb, body: Body<'db>, stmt: StmtId) -> bool {
+        match stmt.data(db, body) {
+            Partial::Present(Stmt::Return(..) | Stmt::Break(..) | Stmt::Continue(..)) => true,
+            Partial::Present(Stmt::Expr(expr) | Stmt::Let(_, _, Some(expr), _)) => {
+                self.expr_ty(db, *expr).is_never(db)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<'db> TyChecker<'db> {
+    /// Reports `expr` if it's a branch through which control reaches the end
+    /// of the function body without producing the returned value, e.g. a block
+    /// ending with a `while` loop or an `if` without an `else` branch.
+    ///
+    /// Returns `true` if `expr` was reported, in which case it shouldn't be
+    /// unified with the returned type, since that would only report the same
+    /// problem as a type mismatch.
+    pub(super) fn check_valueless_branch(
+        &mut self,
+        expr: ExprId,
+        expr_data: &Expr<'db>,
+        actual: TyId<'db>,
+    ) -> bool {
+        if !self.tail_exprs.contains(&expr) {
+            return false;
+        }
+        let actual = actual.fold_with(&mut self.table);
+        if actual.is_never(self.db) || actual.has_invalid(self.db) {
+            return false;
+        }
+        let Some(func) = self.env.func().and_then(|func| func.hir_func_def(self.db)) else {
+            return false;
+        };
+
+        let body = self.body();
+        let (span, label): (DynLazySpan<'db>, _) = match expr_data {
+            Expr::Block(stmts) => match stmts.last() {
+                None => (expr.span(body).into(), "this block is empty"),
+                Some(&last) => {
+                    let label = match self.env.stmt_data(last) {
+                        Partial::Present(Stmt::Let(..)) => {
+                            "this `let` statement doesn't produce a value"
+                        }
+                        Partial::Present(Stmt::For(..)) => {
+                            "this `for` loop doesn't produce a value"
+                        }
+                        Partial::Present(Stmt::While(..) | Stmt::WhileLet(..)) => {
+                            "this `while` loop doesn't produce a value"
+                        }
+                        _ => return false,
+                    };
+                    (last.span(body).into(), label)
+                }
+            },
+            Expr::If(_, _, None) | Expr::IfLet(_, _, _, None) => {
+                (expr.span(body).into(), "this `if` has no `else` branch")
+            }
+            _ => return false,
+        };
+
+        let diag = BodyDiag::MissingReturnValue {
+            primary: span,
+            label: label.to_string(),
+            expected: self.expected,
+            func,
+        };
+        self.push_diag(diag);
+        true
+    }
+
+    /// Marks `expr` as a tail expression if `parent` is one, i.e. if the value
+    /// of `expr` is the value of `parent`.
+    pub(super) fn inherit_tail(&mut self, parent: ExprId, expr: ExprId) {
+        if self.tail_exprs.contains(&parent) {
+            self.tail_exprs.insert(expr);
+        }
+    }
+
+    /// Returns `true` if control never reaches the end of the checked `stmt`,
+    /// whose type is `ty`.
+    pub(super) fn stmt_diverges(&mut self, stmt: StmtId, ty: TyId<'db>) -> bool {
+        let ty = match self.env.stmt_data(stmt) {
+            Partial::Present(Stmt::Let(_, _, Some(init), _)) => match self.env.typed_expr(*init) {
+                Some(prop) => prop.ty,
+                None => return false,
+            },
+            _ => ty,
+        };
+        ty.fold_with(&mut self.table).is_never(self.db)
+    }
+}
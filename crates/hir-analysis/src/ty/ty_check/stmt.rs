@@ -1,9 +1,13 @@
-use hir::hir_def::{IdentId, Partial, Stmt, StmtId};
+use hir::{
+    hir_def::{ExprId, IdentId, Partial, PatId, Stmt, StmtId},
+    span::DynLazySpan,
+};
 
 use super::TyChecker;
 use crate::ty::{
     diagnostics::BodyDiag,
     fold::TyFoldable,
+    pattern_analysis::check_exhaustiveness,
     ty_def::{InvalidCause, TyId},
 };
 
@@ -17,15 +21,16 @@ impl<'db> TyChecker<'db> {
             Stmt::Let(..) => self.check_let(stmt, stmt_data),
             Stmt::For(..) => self.check_for(stmt, stmt_data),
             Stmt::While(..) => self.check_while(stmt, stmt_data),
-            Stmt::Continue => self.check_continue(stmt, stmt_data),
-            Stmt::Break => self.check_break(stmt, stmt_data),
+            Stmt::WhileLet(..) => self.check_while_let(stmt, stmt_data),
+            Stmt::Continue(..) => self.check_continue(stmt, stmt_data),
+            Stmt::Break(..) => self.check_break(stmt, stmt_data),
             Stmt::Return(..) => self.check_return(stmt, stmt_data),
             Stmt::Expr(expr) => self.check_expr(*expr, expected).ty,
         }
     }
 
     fn check_let(&mut self, stmt: StmtId, stmt_data: &Stmt<'db>) -> TyId<'db> {
-        let Stmt::Let(pat, ascription, expr) = stmt_data else {
+        let Stmt::Let(pat, ascription, expr, else_) = stmt_data else {
             unreachable!()
         };
 
@@ -40,13 +45,72 @@ impl<'db> TyChecker<'db> {
             self.check_expr(*expr, ascription);
         }
 
-        self.check_pat(*pat, ascription);
+        // The bindings introduced by the pattern are not visible in the `else`
+        // block, so it needs to be checked before the pattern.
+        if let Some(else_) = else_ {
+            self.check_let_else(*else_);
+        }
+
+        let pat_ty = self.check_pat(*pat, ascription);
+        if else_.is_none() {
+            self.check_irrefutable(*pat, pat_ty);
+        }
+
+        if expr.is_none() {
+            self.env.defer_pending_bindings();
+        }
         self.env.flush_pending_bindings();
         TyId::unit(self.db)
     }
 
+    /// Checks the `else` block of `let pat = expr else { .. }`, which must
+    /// diverge.
+    fn check_let_else(&mut self, else_: ExprId) {
+        let else_ty = self.fresh_ty();
+        self.env.enter_scope(else_);
+        let else_ty = self
+            .check_expr(else_, else_ty)
+            .ty
+            .fold_with(&mut self.table);
+        self.env.leave_scope();
+
+        if !else_ty.is_never(self.db) && !else_ty.has_invalid(self.db) {
+            let diag = BodyDiag::LetElseMustDiverge {
+                primary: else_.span(self.body()).into(),
+                given: else_ty,
+            };
+            self.push_diag(diag);
+        }
+    }
+
+    /// Reports an error if `pat` doesn't match all the values of `ty`.
+    fn check_irrefutable(&mut self, pat: PatId, ty: TyId<'db>) {
+        let ty = ty.fold_with(&mut self.table);
+        if ty.has_invalid(self.db) || ty.is_ty_var(self.db) {
+            return;
+        }
+
+        let Partial::Present(pat_data) = pat.data(self.db, self.body()) else {
+            return;
+        };
+
+        if let Err(missing_patterns) = check_exhaustiveness(
+            self.db,
+            &[pat_data.clone()],
+            self.body(),
+            self.env.scope(),
+            ty,
+        ) {
+            let diag = BodyDiag::RefutablePatInLet {
+                primary: pat.span(self.body()).into(),
+                missing_patterns,
+            };
+            self.push_diag(diag);
+        }
+    }
+
     fn check_for(&mut self, stmt: StmtId, stmt_data: &Stmt<'db>) -> TyId<'db> {
-        let Stmt::For(pat, expr, body) = stmt_data else {
+        let Stmt::For(label, pat, expr, body) = stmt_data else {
             unreachable!()
         };
 
@@ -78,7 +142,7 @@ impl<'db> TyChecker<'db> {
 
         self.check_pat(*pat, elem_ty);
 
-        self.env.enter_loop(stmt);
+        self.env.enter_loop(*label, None);
         self.env.enter_scope(*body);
         self.env.flush_pending_bindings();
 
@@ -92,27 +156,56 @@ impl<'db> TyChecker<'db> {
     }
 
     fn check_while(&mut self, stmt: StmtId, stmt_data: &Stmt<'db>) -> TyId<'db> {
-        let Stmt::While(cond, body) = stmt_data else {
+        let Stmt::While(label, cond, body) = stmt_data else {
             unreachable!()
         };
 
         self.check_expr(*cond, TyId::bool(self.db));
 
-        self.env.enter_loop(stmt);
+        self.env.enter_loop(*label, None);
         self.check_expr(*body, TyId::unit(self.db));
         self.env.leave_loop();
 
         TyId::unit(self.db)
     }
 
+    fn check_while_let(&mut self, stmt: StmtId, stmt_data: &Stmt<'db>) -> TyId<'db> {
+        let Stmt::WhileLet(label, pat, scrutinee, body) = stmt_data else {
+            unreachable!()
+        };
+
+        let scrutinee_ty = self.fresh_ty();
+        let scrutinee_ty = self.check_expr(*scrutinee, scrutinee_ty).ty;
+        self.check_pat(*pat, scrutinee_ty);
+
+        self.env.enter_loop(*label, None);
+        self.env.enter_scope(*body);
+        self.env.flush_pending_bindings();
+
+        self.check_expr(*body, TyId::unit(self.db));
+
+        self.env.leave_scope();
+        self.env.leave_loop();
+
+        TyId::unit(self.db)
+    }
+
     fn check_continue(&mut self, stmt: StmtId, stmt_data: &Stmt<'db>) -> TyId<'db> {
-        assert!(matches!(stmt_data, Stmt::Continue));
+        let Stmt::Continue(label) = stmt_data else {
+            unreachable!()
+        };
 
-        if self.env.current_loop().is_none() {
+        if self.env.find_loop(*label).is_none() {
             let span = stmt.span(self.env.body());
-            let diag = BodyDiag::LoopControlOutsideOfLoop {
-                primary: span.into(),
-                is_break: false,
+            let diag = match label {
+                Some(label) => BodyDiag::UndeclaredLabel {
+                    primary: span.into_continue_stmt().label().into(),
+                    label: *label,
+                },
+                None => BodyDiag::LoopControlOutsideOfLoop {
+                    primary: span.into(),
+                    is_break: false,
+                },
             };
             self.push_diag(diag);
         }
@@ -121,15 +214,62 @@ impl<'db> TyChecker<'db> {
     }
 
     fn check_break(&mut self, stmt: StmtId, stmt_data: &Stmt<'db>) -> TyId<'db> {
-        assert!(matches!(stmt_data, Stmt::Break));
+        let Stmt::Break(label, value) = stmt_data else {
+            unreachable!()
+        };
 
-        if self.env.current_loop().is_none() {
-            let span = stmt.span(self.env.body());
-            let diag = BodyDiag::LoopControlOutsideOfLoop {
-                primary: span.into(),
-                is_break: true,
+        let span = stmt.span(self.env.body()).into_break_stmt();
+        let Some(loop_scope) = self.env.find_loop(*label) else {
+            let diag = match label {
+                Some(label) => BodyDiag::UndeclaredLabel {
+                    primary: span.label().into(),
+                    label: *label,
+                },
+                None => BodyDiag::LoopControlOutsideOfLoop {
+                    primary: span.into(),
+                    is_break: true,
+                },
             };
             self.push_diag(diag);
+
+            if let Some(value) = value {
+                let value_ty = self.fresh_ty();
+                self.check_expr(*value, value_ty);
+            }
+            return TyId::never(self.db);
+        };
+
+        loop_scope.has_break = true;
+        match (loop_scope.break_ty, value) {
+            (Some(break_ty), Some(value)) => {
+                self.check_expr(*value, break_ty);
+            }
+
+            // `break` without a value breaks out of `loop` with `()`.
+            (Some(break_ty), None) => {
+                let unit = TyId::unit(self.db);
+                let span: DynLazySpan = span.into();
+                if self.table.unify(break_ty, unit).is_err() {
+                    let diag = BodyDiag::TypeMismatch {
+                        span,
+                        expected: break_ty.fold_with(&mut self.table),
+                        given: unit,
+                    };
+                    self.push_diag(diag);
+                } else {
+                    self.defer_projections(&span);
+                }
+            }
+
+            (None, Some(value)) => {
+                let diag = BodyDiag::BreakWithValueInNonLoop(span.expr().into());
+                self.push_diag(diag);
+
+                let value_ty = self.fresh_ty();
+                self.check_expr(*value, value_ty);
+            }
+
+            (None, None) => {}
         }
 
         TyId::never(self.db)
@@ -148,17 +288,19 @@ impl<'db> TyChecker<'db> {
             TyId::unit(self.db)
         };
 
+        let span: DynLazySpan = stmt.span(self.env.body()).into();
         if self.table.unify(returned_ty, self.expected).is_err() {
             let func = self.env.func();
-            let span = stmt.span(self.env.body());
             let diag = BodyDiag::ReturnedTypeMismatch {
-                primary: span.into(),
+                primary: span,
                 actual: returned_ty,
                 expected: self.expected,
                 func: func.map(|f| f.hir_func_def(self.db).unwrap()),
             };
 
             self.push_diag(diag);
+        } else {
+            self.defer_projections(&span);
         }
 
         TyId::never(self.db)
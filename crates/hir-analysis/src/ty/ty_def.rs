@@ -16,7 +16,7 @@ use hir::{
     span::DynLazySpan,
 };
 use if_chain::if_chain;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use rustc_hash::FxHashSet;
 use salsa::Update;
 use smallvec::SmallVec;
@@ -26,13 +26,17 @@ use super::{
     const_ty::{ConstTyData, ConstTyId, EvaluatedConstTy},
     diagnostics::{TraitConstraintDiag, TyDiagCollection},
     func_def::FuncDef,
+    trait_def::TraitInstId,
     trait_resolution::{PredicateListId, WellFormedness},
     ty_lower::collect_generic_params,
     unify::InferenceKey,
     visitor::{TyVisitable, TyVisitor},
 };
 use crate::{
-    ty::{adt_def::AdtRef, trait_resolution::check_ty_wf, ty_error::emit_invalid_ty_error},
+    ty::{
+        adt_def::AdtRef, assoc_ty::normalize_assoc_ty, trait_resolution::check_ty_wf,
+        ty_error::emit_invalid_ty_error,
+    },
     HirAnalysisDb,
 };
 
@@ -150,6 +154,7 @@ impl<'db> TyId<'db> {
             TyData::TyApp(_, _) => pretty_print_ty_app(db, self),
             TyData::TyBase(ty_con) => ty_con.pretty_print(db),
             TyData::ConstTy(const_ty) => const_ty.pretty_print(db),
+            TyData::AssocTy(assoc_ty) => assoc_ty.pretty_print(db),
             TyData::Never => "!".to_string(),
             TyData::Invalid(cause) => format!("invalid({})", cause.pretty_print(db)),
         }
@@ -264,13 +269,29 @@ impl<'db> TyId<'db> {
         )
     }
 
-    pub(crate) fn is_array(self, db: &dyn HirAnalysisDb) -> bool {
+    pub fn is_array(self, db: &dyn HirAnalysisDb) -> bool {
         matches!(
             self.base_ty(db).data(db),
             TyData::TyBase(TyBase::Prim(PrimTy::Array))
         )
     }
 
+    /// Returns the length of the array type if it's statically known.
+    pub(crate) fn array_len(self, db: &dyn HirAnalysisDb) -> Option<usize> {
+        if !self.is_array(db) {
+            return None;
+        }
+
+        let (_, args) = self.decompose_ty_app(db);
+        let TyData::ConstTy(const_ty) = args.get(1)?.data(db) else {
+            return None;
+        };
+        let ConstTyData::Evaluated(EvaluatedConstTy::LitInt(len), _) = const_ty.data(db) else {
+            return None;
+        };
+        usize::try_from(len.data(db)).ok()
+    }
+
     pub(crate) fn is_string(self, db: &dyn HirAnalysisDb) -> bool {
         matches!(
             self.base_ty(db).data(db),
@@ -322,7 +343,7 @@ impl<'db> TyId<'db> {
                 ConstTyData::UnEvaluated(body) => Some(body.scope()),
             },
 
-            TyData::Never | TyData::Invalid(_) | TyData::TyVar(_) => None,
+            TyData::Never | TyData::Invalid(_) | TyData::TyVar(_) | TyData::AssocTy(_) => None,
             TyData::TyApp(..) => unreachable!(),
         }
     }
@@ -342,7 +363,7 @@ impl<'db> TyId<'db> {
                 _ => None,
             },
 
-            TyData::Never | TyData::Invalid(_) => None,
+            TyData::Never | TyData::Invalid(_) | TyData::AssocTy(_) => None,
             TyData::TyApp(..) => unreachable!(),
         }
     }
@@ -475,6 +496,24 @@ impl<'db> TyId<'db> {
         Self::new(db, TyData::Invalid(cause))
     }
 
+    /// Returns the associated type `name` of the given trait instance. The
+    /// projection is normalized to the type defined in the corresponding impl
+    /// if the impl can be determined.
+    ///
+    /// The impl is never determined while the trait instance contains type
+    /// variables, so the projection is kept as is without searching for it.
+    pub(crate) fn assoc_ty(
+        db: &'db dyn HirAnalysisDb,
+        trait_: TraitInstId<'db>,
+        name: IdentId<'db>,
+    ) -> Self {
+        let projection = || Self::new(db, TyData::AssocTy(AssocTy { trait_, name }));
+        if trait_.args(db).iter().any(|arg| arg.has_var(db)) {
+            return projection();
+        }
+        normalize_assoc_ty(db, trait_, name).unwrap_or_else(projection)
+    }
+
     pub(crate) fn from_hir_prim_ty(db: &'db dyn HirAnalysisDb, hir_prim: HirPrimTy) -> Self {
         Self::new(db, TyData::TyBase(hir_prim.into()))
     }
@@ -582,11 +621,14 @@ impl<'db> TyId<'db> {
         })
     }
 
-    /// Returns the number of fields for tuple types and structs
+    /// Returns the number of fields for tuple types, structs and arrays with a
+    /// known length
     pub fn field_count(self, db: &'db dyn HirAnalysisDb) -> usize {
         if self.is_tuple(db) {
             let (_, elems) = self.decompose_ty_app(db);
             elems.len()
+        } else if let Some(len) = self.array_len(db) {
+            len
         } else if let Some(adt_def) = self.adt_def(db) {
             match adt_def.adt_ref(db) {
                 AdtRef::Struct(_) => adt_def.fields(db)[0].num_types(),
@@ -597,11 +639,15 @@ impl<'db> TyId<'db> {
         }
     }
 
-    /// Returns the field types for tuple types and structs
+    /// Returns the field types for tuple types, structs and arrays with a known
+    /// length
     pub fn field_types(self, db: &'db dyn HirAnalysisDb) -> Vec<TyId<'db>> {
         if self.is_tuple(db) {
             let (_, elems) = self.decompose_ty_app(db);
             elems.to_vec()
+        } else if let Some(len) = self.array_len(db) {
+            let (_, args) = self.decompose_ty_app(db);
+            vec![args[0]; len]
         } else if let Some(adt_def) = self.adt_def(db) {
             match adt_def.adt_ref(db) {
                 AdtRef::Struct(_) => {
@@ -643,6 +689,10 @@ pub enum TyData<'db> {
 
     ConstTy(ConstTyId<'db>),
 
+    /// An associated type projection that couldn't be normalized yet, e.g.,
+    /// `<T as Iterator>::Item`.
+    AssocTy(AssocTy<'db>),
+
     /// A never(bottom) type.
     Never,
 
@@ -698,8 +748,18 @@ pub enum InvalidCause<'db> {
 
     AliasCycle(SmallVec<HirTypeAlias<'db>, 4>),
 
-    /// Associated Type is not allowed at the moment.
-    AssocTy,
+    /// No trait in the scope declares the associated type.
+    AssocTyNotFound {
+        ty: TyId<'db>,
+        name: IdentId<'db>,
+    },
+
+    /// More than one trait declares the associated type.
+    AmbiguousAssocTy {
+        ty: TyId<'db>,
+        name: IdentId<'db>,
+        cands: Vec<TraitInstId<'db>>,
+    },
 
     // The given expression is not supported yet in the const type context.
     // TODO: Remove this error kind and introduce a new error kind for more specific cause when
@@ -743,12 +803,21 @@ impl InvalidCause<'_> {
                 )
             }
             InvalidCause::AliasCycle(v) => format!("AliasCycle(len={})", v.len()),
+            InvalidCause::AssocTyNotFound { ty, name } => format!(
+                "AssocTyNotFound {{ ty: {}, name: {} }}",
+                ty.pretty_print(db),
+                name.data(db)
+            ),
+            InvalidCause::AmbiguousAssocTy { ty, name, .. } => format!(
+                "AmbiguousAssocTy {{ ty: {}, name: {} }}",
+                ty.pretty_print(db),
+                name.data(db)
+            ),
 
             InvalidCause::NotFullyApplied
             | InvalidCause::TooManyGenericArgs { .. }
             | InvalidCause::InvalidConstParamTy
             | InvalidCause::RecursiveConstParamTy
-            | InvalidCause::AssocTy
             | InvalidCause::Other => format!("{self:?}"),
 
             InvalidCause::InvalidConstTyExpr { body: _ } => "InvalidConstTyExpr".into(),
@@ -857,6 +926,24 @@ impl TyVar<'_> {
     }
 }
 
+/// An associated type of a trait instance, e.g., `<T as Iterator>::Item`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssocTy<'db> {
+    pub trait_: TraitInstId<'db>,
+    pub name: IdentId<'db>,
+}
+
+impl<'db> AssocTy<'db> {
+    pub(super) fn pretty_print(&self, db: &dyn HirAnalysisDb) -> String {
+        format!(
+            "<{} as {}>::{}",
+            self.trait_.self_ty(db).pretty_print(db),
+            self.trait_.pretty_print(db, false),
+            self.name.data(db)
+        )
+    }
+}
+
 /// Type generics parameter. We also treat `Self` type in a trait definition as
 /// a special type parameter.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -1073,6 +1160,50 @@ impl PrimTy {
     pub fn is_bool(self) -> bool {
         matches!(self, Self::Bool)
     }
+
+    pub fn is_signed(self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::I256 | Self::Isize
+        )
+    }
+
+    /// Returns the minimum and maximum values of the type if it's an integral
+    /// type with a fixed width.
+    pub fn int_range(self) -> Option<(BigInt, BigInt)> {
+        let bits = match self {
+            Self::U8 | Self::I8 => 8,
+            Self::U16 | Self::I16 => 16,
+            Self::U32 | Self::I32 => 32,
+            Self::U64 | Self::I64 => 64,
+            Self::U128 | Self::I128 => 128,
+            Self::U256 | Self::I256 => 256,
+            _ => return None,
+        };
+
+        let one = BigInt::from(1u8);
+        if self.is_signed() {
+            let bound = one << (bits - 1);
+            Some((-bound.clone(), bound - 1u8))
+        } else {
+            Some((BigInt::from(0u8), (one << bits) - 1u8))
+        }
+    }
+
+    /// Returns the maximum value of the type if it's an unsigned integral type
+    /// with a fixed width.
+    pub fn unsigned_max(self) -> Option<BigUint> {
+        let bits = match self {
+            Self::U8 => 8,
+            Self::U16 => 16,
+            Self::U32 => 32,
+            Self::U64 => 64,
+            Self::U128 => 128,
+            Self::U256 => 256,
+            _ => return None,
+        };
+        Some((BigUint::from(1u8) << bits) - 1u8)
+    }
 }
 
 pub(super) trait HasKind {
@@ -1094,6 +1225,8 @@ impl HasKind for TyData<'_> {
 
             TyData::ConstTy(const_ty) => const_ty.ty(db).kind(db).clone(),
 
+            TyData::AssocTy(_) => Kind::Star,
+
             TyData::Never => Kind::Any,
 
             TyData::Invalid(_) => Kind::Any,
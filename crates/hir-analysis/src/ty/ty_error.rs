@@ -1,7 +1,7 @@
 use hir::{
-    hir_def::{scope_graph::ScopeId, PathId, TypeId},
-    span::{path::LazyPathSpan, types::LazyTySpan},
-    visitor::{prelude::DynLazySpan, walk_path, walk_type, Visitor, VisitorCtxt},
+    hir_def::{scope_graph::ScopeId, PathId, TraitRefId, TypeId},
+    span::{params::LazyTraitRefSpan, path::LazyPathSpan, types::LazyTySpan},
+    visitor::{prelude::DynLazySpan, walk_path, walk_trait_ref, walk_type, Visitor, VisitorCtxt},
 };
 
 use crate::{
@@ -27,6 +27,7 @@ pub fn collect_ty_lower_errors<'db>(
     let mut vis = HirTyErrVisitor {
         db,
         diags: Vec::new(),
+        expected: ExpectedPathKind::Type,
     };
     let mut ctxt = VisitorCtxt::new(db, scope, span);
     vis.visit_ty(&mut ctxt, hir_ty);
@@ -36,6 +37,9 @@ pub fn collect_ty_lower_errors<'db>(
 struct HirTyErrVisitor<'db> {
     db: &'db dyn HirAnalysisDb,
     diags: Vec<TyDiagCollection<'db>>,
+    /// The kind of the path that is currently visited. This is `Trait` only
+    /// for the trait ref of a qualified path type, e.g., `<T as Trait>::Item`.
+    expected: ExpectedPathKind,
 }
 
 impl<'db> HirTyErrVisitor<'db> {
@@ -84,7 +88,18 @@ impl<'db> Visitor<'db> for HirTyErrVisitor<'db> {
         }
     }
 
+    fn visit_trait_ref(
+        &mut self,
+        ctxt: &mut VisitorCtxt<'db, LazyTraitRefSpan<'db>>,
+        trait_ref: TraitRefId<'db>,
+    ) {
+        self.expected = ExpectedPathKind::Trait;
+        walk_trait_ref(self, ctxt, trait_ref);
+        self.expected = ExpectedPathKind::Type;
+    }
+
     fn visit_path(&mut self, ctxt: &mut VisitorCtxt<'db, LazyPathSpan<'db>>, path: PathId<'db>) {
+        let expected = std::mem::replace(&mut self.expected, ExpectedPathKind::Type);
         let scope = ctxt.scope();
         let path_span = ctxt.span().unwrap();
 
@@ -94,7 +109,7 @@ impl<'db> Visitor<'db> for HirTyErrVisitor<'db> {
                 return;
             }
             if !reso.is_visible_from(self.db, scope) {
-                invisible = Some((path, reso.name_span(self.db)));
+                invisible = Some((path, reso.name_span(self.db), reso.as_scope(self.db)));
             }
         };
 
@@ -107,8 +122,7 @@ impl<'db> Visitor<'db> for HirTyErrVisitor<'db> {
                         .segment(err.failed_at.segment_index(self.db))
                         .ident();
 
-                    if let Some(diag) =
-                        err.into_diag(self.db, path, segment_span.into(), ExpectedPathKind::Type)
+                    if let Some(diag) = err.into_diag(self.db, path, segment_span.into(), expected)
                     {
                         self.diags.push(diag.into());
                     }
@@ -116,16 +130,26 @@ impl<'db> Visitor<'db> for HirTyErrVisitor<'db> {
                 }
             };
 
-        if !matches!(res, PathRes::Ty(_) | PathRes::TyAlias(..)) {
-            let ident = path.ident(self.db).to_opt().unwrap();
-            let span = path_span.clone().segment(path.segment_index(self.db));
+        let ident = path.ident(self.db).to_opt().unwrap();
+        let span = path_span.clone().segment(path.segment_index(self.db));
+        if expected == ExpectedPathKind::Trait {
+            if !matches!(res, PathRes::Trait(_)) {
+                self.diags
+                    .push(NameResDiag::ExpectedTrait(span.into(), ident, res.kind_name()).into());
+            }
+        } else if !matches!(
+            // An unresolved associated type is reported when the type is lowered, and a
+            // const is allowed as a const generic argument.
+            res,
+            PathRes::Ty(_) | PathRes::TyAlias(..) | PathRes::Const(..) | PathRes::TypeMemberTbd(_)
+        ) {
             self.diags
                 .push(NameResDiag::ExpectedType(span.into(), ident, res.kind_name()).into());
         }
-        if let Some((path, deriv_span)) = invisible {
+        if let Some((path, deriv_span, vis_scope)) = invisible {
             let span = path_span.segment(path.segment_index(self.db)).ident();
             let ident = path.ident(self.db);
-            let diag = NameResDiag::Invisible(span.into(), *ident.unwrap(), deriv_span);
+            let diag = NameResDiag::Invisible(span.into(), *ident.unwrap(), deriv_span, vis_scope);
             self.diags.push(diag.into());
         }
 
@@ -218,7 +242,17 @@ fn diag_from_invalid_cause<'db>(
         }
         .into(),
 
-        InvalidCause::AssocTy => TyLowerDiag::AssocTy(span).into(),
+        InvalidCause::AssocTyNotFound { ty, name } => {
+            TyLowerDiag::AssocTyNotFound { span, ty, name }.into()
+        }
+
+        InvalidCause::AmbiguousAssocTy { ty, name, cands } => TyLowerDiag::AmbiguousAssocTy {
+            span,
+            ty,
+            name,
+            cands,
+        }
+        .into(),
 
         InvalidCause::AliasCycle(cycle) => TyLowerDiag::TypeAliasCycle {
             cycle: cycle.to_vec(),
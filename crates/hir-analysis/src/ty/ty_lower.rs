@@ -8,6 +8,7 @@ use smallvec::smallvec;
 
 use super::{
     const_ty::{ConstTyData, ConstTyId},
+    trait_lower::lower_trait_ref,
     ty_def::{InvalidCause, Kind, TyData, TyId, TyParam},
 };
 use crate::{
@@ -63,6 +64,22 @@ pub fn lower_hir_ty<'db>(
             TyId::app(db, array, len_ty)
         }
 
+        HirTyKind::QualifiedPath(self_ty, trait_ref, name) => {
+            let self_ty = lower_opt_hir_ty(db, scope, *self_ty);
+            let (Some(trait_ref), Some(name)) = (trait_ref.to_opt(), name.to_opt()) else {
+                return TyId::invalid(db, InvalidCause::Other);
+            };
+            let Ok(trait_inst) = lower_trait_ref(db, self_ty, trait_ref, scope) else {
+                return TyId::invalid(db, InvalidCause::Other);
+            };
+
+            if trait_inst.def(db).declares_assoc_ty(db, name) {
+                TyId::assoc_ty(db, trait_inst, name)
+            } else {
+                TyId::invalid(db, InvalidCause::AssocTyNotFound { ty: self_ty, name })
+            }
+        }
+
         HirTyKind::Never => TyId::never(db),
     }
 }
@@ -88,6 +105,18 @@ fn lower_path<'db>(
     };
     match resolve_path(db, path, scope, false) {
         Ok(PathRes::Ty(ty) | PathRes::TyAlias(_, ty) | PathRes::Func(ty)) => ty,
+        // A const in type position is a const generic argument.
+        Ok(PathRes::Const(const_, ty)) => {
+            let const_ty = ConstTyId::from_opt_body(db, const_.body(db)).evaluate(db, Some(ty));
+            TyId::const_ty(db, const_ty)
+        }
+        Ok(PathRes::TypeMemberTbd(ty)) => TyId::invalid(
+            db,
+            InvalidCause::AssocTyNotFound {
+                ty,
+                name: *path.ident(db).unwrap(),
+            },
+        ),
         _ => TyId::invalid(db, InvalidCause::Other),
     }
 }
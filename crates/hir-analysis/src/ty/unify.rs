@@ -29,7 +29,11 @@ pub(crate) type UnificationTable<'db> = UnificationTableBase<'db, InPlace<Infere
 pub(crate) type PersistentUnificationTable<'db> =
     UnificationTableBase<'db, ena::unify::Persistent<InferenceKey<'db>>>;
 
-pub type Snapshot<U> = ena::unify::Snapshot<U>;
+pub struct Snapshot<'db, U: ena::unify::UnificationStore> {
+    table: ena::unify::Snapshot<U>,
+    pending_projections: Vec<(TyId<'db>, TyId<'db>)>,
+}
+
 pub type UnificationResult = Result<(), UnificationError>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -62,6 +66,10 @@ where
 {
     pub db: &'db dyn HirAnalysisDb,
     table: ena::unify::UnificationTable<U>,
+    /// The pairs of types that contain an associated type that was unified
+    /// before it could be normalized. They are unified again by each
+    /// [`Self::unify`] until they are taken by [`Self::take_pending_projections`].
+    pending_projections: Vec<(TyId<'db>, TyId<'db>)>,
 }
 
 impl<'db, U> UnificationTableBase<'db, U>
@@ -72,6 +80,7 @@ where
         Self {
             db,
             table: ena::unify::UnificationTable::new(),
+            pending_projections: Vec::new(),
         }
     }
 
@@ -84,12 +93,16 @@ where
         self.len() == 0
     }
 
-    pub fn rollback_to(&mut self, snapshot: Snapshot<U>) {
-        self.table.rollback_to(snapshot);
+    pub fn rollback_to(&mut self, snapshot: Snapshot<'db, U>) {
+        self.table.rollback_to(snapshot.table);
+        self.pending_projections = snapshot.pending_projections;
     }
 
-    pub fn snapshot(&mut self) -> Snapshot<U> {
-        self.table.snapshot()
+    pub fn snapshot(&mut self) -> Snapshot<'db, U> {
+        Snapshot {
+            table: self.table.snapshot(),
+            pending_projections: self.pending_projections.clone(),
+        }
     }
 
     pub fn unify<T>(&mut self, lhs: T, rhs: T) -> UnificationResult
@@ -97,9 +110,12 @@ where
         T: Unifiable<'db>,
     {
         let snapshot = self.snapshot();
-        match lhs.unify(self, rhs) {
+        match lhs
+            .unify(self, rhs)
+            .and_then(|_| self.unify_pending_projections())
+        {
             Ok(()) => {
-                self.table.commit(snapshot);
+                self.table.commit(snapshot.table);
                 Ok(())
             }
             Err(err) => {
@@ -109,6 +125,13 @@ where
         }
     }
 
+    /// Takes the pairs of types whose unification is deferred until their
+    /// associated types can be normalized, so that the caller can unify them
+    /// again by itself and report a mismatch where they were deferred.
+    pub fn take_pending_projections(&mut self) -> Vec<(TyId<'db>, TyId<'db>)> {
+        std::mem::take(&mut self.pending_projections)
+    }
+
     /// Returns `Ok()` if the two types were unified, otherwise returns an
     /// error. This method doesn't roll back the unification table. Please
     /// refer to `unify`[Self::unify] if you need to roll back the table
@@ -122,6 +145,18 @@ where
         let ty2 = ty2.fold_with(self);
 
         match (ty1.data(self.db), ty2.data(self.db)) {
+            // An associated type whose trait instance still contains type variables
+            // might be normalized once they are resolved, so the unification is
+            // deferred until then.
+            (TyData::AssocTy(_), _) if ty1.has_var(self.db) => {
+                self.pending_projections.push((ty1, ty2));
+                Ok(())
+            }
+            (_, TyData::AssocTy(_)) if ty2.has_var(self.db) => {
+                self.pending_projections.push((ty1, ty2));
+                Ok(())
+            }
+
             (TyData::TyVar(_), TyData::TyVar(_)) => self.unify_var_var(ty1, ty2),
 
             (TyData::TyVar(var), _) => self.unify_var_value(var, ty2),
@@ -141,6 +176,16 @@ where
                 }
             }
 
+            // Associated types that are still not normalized at this point are equal
+            // only if they project the same item of the same trait instance.
+            (TyData::AssocTy(assoc1), TyData::AssocTy(assoc2)) => {
+                if assoc1.name == assoc2.name {
+                    assoc1.trait_.unify(self, assoc2.trait_)
+                } else {
+                    Err(UnificationError::TypeMismatch)
+                }
+            }
+
             (TyData::Invalid(_), _)
             | (_, TyData::Invalid(_))
             | (TyData::Never, _)
@@ -175,6 +220,21 @@ where
         }
     }
 
+    /// Unifies the pending associated types again, until no more of them can
+    /// be normalized.
+    fn unify_pending_projections(&mut self) -> UnificationResult {
+        loop {
+            let pending = std::mem::take(&mut self.pending_projections);
+            let len = pending.len();
+            for (ty1, ty2) in pending {
+                self.unify_ty(ty1, ty2)?;
+            }
+            if self.pending_projections.len() >= len {
+                return Ok(());
+            }
+        }
+    }
+
     pub fn new_var(&mut self, sort: TyVarSort, kind: &Kind) -> TyId<'db> {
         let key = self.new_key(kind, sort);
         TyId::ty_var(self.db, sort, kind.clone(), key)
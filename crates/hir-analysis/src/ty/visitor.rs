@@ -76,6 +76,12 @@ where
 
         TyData::ConstTy(const_ty) => visitor.visit_const_ty(const_ty),
 
+        TyData::AssocTy(assoc_ty) => {
+            for &arg in assoc_ty.trait_.args(visitor.db()) {
+                visitor.visit_ty(arg);
+            }
+        }
+
         TyData::Never => {}
 
         TyData::Invalid(cause) => visitor.visit_invalid(cause),
@@ -4,7 +4,7 @@ use std::path::Path;
 use ascii_tree::{write_tree, Tree};
 use dir_test::{dir_test, Fixture};
 use fe_hir_analysis::ty::{
-    decision_tree::{build_decision_tree, DecisionTree, Occurrence},
+    decision_tree::{build_decision_tree, DecisionTree, LeafNode, Occurrence},
     pattern_analysis::PatternMatrix,
     simplified_pattern::ConstructorKind,
     ty_check::{check_func_body, TypedBody},
@@ -33,23 +33,7 @@ fn convert_to_ascii_tree<'db>(
     tree: &DecisionTree<'db>,
 ) -> Tree {
     match tree {
-        DecisionTree::Leaf(leaf_node) => {
-            let mut lines = vec![];
-
-            // Add arm content with simple, robust format
-            lines.push(format!("Execute arm #{}", leaf_node.arm_index));
-
-            // Add bindings if present
-            for ((name, _idx), occurrence) in &leaf_node.bindings {
-                lines.push(format!(
-                    "  {} ← {}",
-                    name.data(db),
-                    render_occurrence(occurrence)
-                ));
-            }
-
-            Tree::Leaf(lines)
-        }
+        DecisionTree::Leaf(leaf_node) => convert_leaf_to_ascii_tree(db, leaf_node),
 
         DecisionTree::Switch(switch_node) => {
             let mut children = Vec::new();
@@ -69,9 +53,48 @@ fn convert_to_ascii_tree<'db>(
                 children,
             )
         }
+
+        DecisionTree::Guard(guard_node) => {
+            let mut children = vec![Tree::Node(
+                "guard holds =>".to_string(),
+                vec![convert_leaf_to_ascii_tree(db, &guard_node.leaf)],
+            )];
+            if let Some(otherwise) = &guard_node.otherwise {
+                children.push(Tree::Node(
+                    "guard fails =>".to_string(),
+                    vec![convert_to_ascii_tree(db, otherwise)],
+                ));
+            }
+
+            Tree::Node(
+                format!("Guard of arm #{}", guard_node.leaf.arm_index),
+                children,
+            )
+        }
     }
 }
 
+fn convert_leaf_to_ascii_tree<'db>(
+    db: &'db dyn fe_hir_analysis::HirAnalysisDb,
+    leaf_node: &LeafNode<'db>,
+) -> Tree {
+    let mut lines = vec![];
+
+    // Add arm content with simple, robust format
+    lines.push(format!("Execute arm #{}", leaf_node.arm_index));
+
+    // Add bindings if present
+    for ((name, _idx), occurrence) in &leaf_node.bindings {
+        lines.push(format!(
+            "  {} ← {}",
+            name.data(db),
+            render_occurrence(occurrence)
+        ));
+    }
+
+    Tree::Leaf(lines)
+}
+
 fn render_occurrence(occurrence: &Occurrence) -> String {
     if occurrence.0.is_empty() {
         "expr".to_string()
@@ -95,7 +118,9 @@ fn render_constructor<'db>(
             variant_name.to_string()
         }
         ConstructorKind::Type(ty) => {
-            if ty.is_tuple(db) {
+            if ty.is_array(db) {
+                "array[]".to_string()
+            } else if ty.is_tuple(db) {
                 "tuple()".to_string()
             } else {
                 "record{}".to_string()
@@ -106,6 +131,9 @@ fn render_constructor<'db>(
             LitKind::Int(int_id) => int_id.data(db).to_string(),
             LitKind::String(string_id) => format!("\"{}\"", string_id.data(db)),
         },
+        ConstructorKind::Range(start, end, _) => {
+            format!("{}..={}", start.data(db), end.data(db))
+        }
     }
 }
 
@@ -174,10 +202,13 @@ impl<'db> Visitor<'db> for DecisionTreeVisitor<'db, '_> {
         if let Expr::Match(_scrutinee, arms) = expr {
             if let Some(arms) = arms.clone().to_opt() {
                 let body = ctxt.body();
-                let patterns: Vec<_> = arms
+                let (patterns, guarded): (Vec<_>, Vec<_>) = arms
                     .iter()
-                    .filter_map(|arm| arm.pat.data(self.db, body).clone().to_opt())
-                    .collect();
+                    .filter_map(|arm| {
+                        let pat = arm.pat.data(self.db, body).clone().to_opt()?;
+                        Some((pat, arm.guard.is_some()))
+                    })
+                    .unzip();
 
                 if !patterns.is_empty() {
                     // Get the actual scrutinee type from the typed body
@@ -195,7 +226,7 @@ impl<'db> Visitor<'db> for DecisionTreeVisitor<'db, '_> {
                         scrutinee_ty,
                     );
 
-                    let tree = build_decision_tree(self.db, &matrix);
+                    let tree = build_decision_tree(self.db, &matrix, &guarded);
                     let visualization = render_decision_tree(self.db, &tree);
 
                     let func_name = self.current_func.as_deref().unwrap_or("unknown");
@@ -16,7 +16,7 @@ use common::{
 use driver::diagnostics::{CsDbWrapper, ToCsDiag};
 use fe_hir_analysis::{
     analysis_pass::{AnalysisPassManager, ParsingPass},
-    name_resolution::ImportAnalysisPass,
+    name_resolution::{ImportAnalysisPass, VisibilityAnalysisPass},
     ty::{
         AdtDefAnalysisPass, BodyAnalysisPass, DefConflictAnalysisPass, FuncAnalysisPass,
         ImplAnalysisPass, ImplTraitAnalysisPass, TraitAnalysisPass, TypeAliasAnalysisPass,
@@ -233,6 +233,7 @@ pub(crate) fn initialize_analysis_pass() -> AnalysisPassManager {
     pass_manager.add_module_pass(Box::new(ParsingPass {}));
     pass_manager.add_module_pass(Box::new(DefConflictAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(ImportAnalysisPass {}));
+    pass_manager.add_module_pass(Box::new(VisibilityAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(AdtDefAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(TypeAliasAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(TraitAnalysisPass {}));
@@ -1,4 +1,5 @@
 use super::{IdentId, Partial, StringId};
+use crate::HirDb;
 
 #[salsa::interned]
 #[derive(Debug)]
@@ -30,3 +31,66 @@ pub struct AttrArg<'db> {
     pub key: Partial<IdentId<'db>>,
     pub value: Partial<IdentId<'db>>,
 }
+
+impl<'db> AttrListId<'db> {
+    /// Returns `true` if the list contains a normal attribute with the given
+    /// name, e.g., `#safe`.
+    pub fn has_normal_attr(self, db: &dyn HirDb, name: &str) -> bool {
+        self.data(db).iter().any(|attr| match attr {
+            Attr::Normal(attr) => {
+                matches!(attr.name, Partial::Present(attr_name) if attr_name.data(db) == name)
+            }
+            Attr::DocComment(_) => false,
+        })
+    }
+}
+
+impl<'db> NormalAttr<'db> {
+    /// Returns `true` if the attribute is `#derive(..)`.
+    pub fn is_derive(&self, db: &dyn HirDb) -> bool {
+        matches!(self.name, Partial::Present(name) if name.data(db) == "derive")
+    }
+}
+
+/// A core trait whose implementation can be generated by `#derive(..)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DerivableTrait {
+    Clone,
+    Default,
+    Eq,
+}
+
+impl DerivableTrait {
+    pub const ALL: [Self; 3] = [Self::Clone, Self::Default, Self::Eq];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|trait_| trait_.name() == name)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Clone => "Clone",
+            Self::Default => "Default",
+            Self::Eq => "Eq",
+        }
+    }
+
+    /// Returns the module of `core` in which the trait is defined.
+    pub fn module_name(self) -> &'static str {
+        match self {
+            Self::Clone => "clone",
+            Self::Default => "default",
+            Self::Eq => "cmp",
+        }
+    }
+
+    /// Returns the name of the trait method. `core` also defines a generic
+    /// function with the same name next to the trait.
+    pub fn method_name(self) -> &'static str {
+        match self {
+            Self::Clone => "clone",
+            Self::Default => "default",
+            Self::Eq => "eq",
+        }
+    }
+}
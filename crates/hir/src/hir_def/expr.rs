@@ -40,9 +40,21 @@ pub enum Expr<'db> {
     /// In case `else if`, the third is the lowered into `If` expression.
     If(ExprId, ExprId, Option<ExprId>),
 
+    /// The `if let` expression. The `PatId` is the pattern to match, the first
+    /// `ExprId` is the scrutinee, the second is the then branch, and the third
+    /// is the else branch.
+    IfLet(PatId, ExprId, ExprId, Option<ExprId>),
+
     /// The first `ExprId` is the scrutinee, the second is the arms.
     Match(ExprId, Partial<Vec<MatchArm>>),
 
+    /// The `loop` expression. The `Option<IdentId>` is the label, and the
+    /// `ExprId` is the body of the loop.
+    Loop(Option<IdentId<'db>>, ExprId),
+
+    /// The `unsafe` block. The `ExprId` is the block.
+    Unsafe(ExprId),
+
     /// The `Assign` Expression. The first `ExprId` is the destination of the
     /// assignment, and the second `ExprId` is the rhs value of the binding.
     Assign(ExprId, ExprId),
@@ -77,6 +89,8 @@ pub enum FieldIndex<'db> {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, salsa::Update)]
 pub struct MatchArm {
     pub pat: PatId,
+    /// The guard condition of `pat if cond => body`.
+    pub guard: Option<ExprId>,
     pub body: ExprId,
 }
 
@@ -11,7 +11,7 @@ use parser::ast;
 use super::{
     scope_graph::{ScopeGraph, ScopeId},
     AttrListId, Body, FuncParamListId, FuncParamName, GenericParam, GenericParamListId, HirIngot,
-    IdentId, Partial, TupleTypeId, TypeId, UseAlias, WhereClauseId,
+    IdentId, Partial, TupleTypeId, TypeBound, TypeId, UseAlias, WhereClauseId,
 };
 use crate::{
     hir_def::TraitRefId,
@@ -136,7 +136,7 @@ impl<'db> ItemKind<'db> {
         }
     }
 
-    pub fn vis(self, db: &dyn HirDb) -> Visibility {
+    pub fn vis(self, db: &'db dyn HirDb) -> Visibility<'db> {
         use ItemKind::*;
         match self {
             TopMod(top_mod) => top_mod.vis(db),
@@ -366,7 +366,7 @@ pub struct TopLevelMod<'db> {
     // No #[id] here, because `TopLevelMod` is always unique to a `InputFile` that is an argument
     // of `module_scope_graph`.
     pub name: IdentId<'db>,
-    pub(crate) file: File,
+    pub file: File,
 }
 
 #[salsa::tracked]
@@ -425,7 +425,7 @@ impl<'db> TopLevelMod<'db> {
         module_tree.parent(self)
     }
 
-    pub fn vis(self, _db: &dyn HirDb) -> Visibility {
+    pub fn vis(self, _db: &dyn HirDb) -> Visibility<'db> {
         // We don't have a way to specify visibility of a top level module.
         // Please change here if we introduce it.
         Visibility::Public
@@ -549,7 +549,7 @@ pub struct Mod<'db> {
 
     pub name: Partial<IdentId<'db>>,
     pub attributes: AttrListId<'db>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
 
     pub top_mod: TopLevelMod<'db>,
 
@@ -588,6 +588,7 @@ pub struct Func<'db> {
     pub params: Partial<FuncParamListId<'db>>,
     pub ret_ty: Option<TypeId<'db>>,
     pub modifier: ItemModifier,
+    pub vis: Visibility<'db>,
     pub body: Option<Body<'db>>,
     pub is_extern: bool,
     pub top_mod: TopLevelMod<'db>,
@@ -604,10 +605,6 @@ impl<'db> Func<'db> {
         ScopeId::from_item(self.into())
     }
 
-    pub fn vis(self, db: &dyn HirDb) -> Visibility {
-        self.modifier(db).to_visibility()
-    }
-
     pub fn is_method(self, db: &dyn HirDb) -> bool {
         let Some(params) = self.params(db).to_opt() else {
             return false;
@@ -641,6 +638,24 @@ impl<'db> Func<'db> {
         let param = self.params(db).to_opt()?.data(db).get(idx)?;
         param.label.or(param.name.to_opt())
     }
+
+    /// Returns `true` if calling the function requires an `unsafe` block.
+    /// This is the case for `unsafe fn`s and for extern functions, unless the
+    /// extern function is marked with `#safe`, e.g., the intrinsics of `core`.
+    pub fn is_unsafe(self, db: &dyn HirDb) -> bool {
+        match self.modifier(db) {
+            ItemModifier::Unsafe | ItemModifier::PubAndUnsafe => true,
+            ItemModifier::Pub | ItemModifier::None => {
+                self.is_extern(db) && !self.attributes(db).has_normal_attr(db, "safe")
+            }
+        }
+    }
+
+    /// Returns `true` if the function is generated by the compiler, e.g., a
+    /// method of a `#derive(..)` implementation.
+    pub fn is_expanded(self, db: &dyn HirDb) -> bool {
+        matches!(self.origin(db), HirOrigin::Expanded(_))
+    }
 }
 
 #[salsa::tracked]
@@ -651,7 +666,7 @@ pub struct Struct<'db> {
 
     pub name: Partial<IdentId<'db>>,
     pub attributes: AttrListId<'db>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub generic_params: GenericParamListId<'db>,
     pub where_clause: WhereClauseId<'db>,
     pub fields: FieldDefListId<'db>,
@@ -692,7 +707,7 @@ pub struct Contract<'db> {
 
     pub name: Partial<IdentId<'db>>,
     pub attributes: AttrListId<'db>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub fields: FieldDefListId<'db>,
     pub top_mod: TopLevelMod<'db>,
 
@@ -717,7 +732,7 @@ pub struct Enum<'db> {
 
     pub name: Partial<IdentId<'db>>,
     pub attributes: AttrListId<'db>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub generic_params: GenericParamListId<'db>,
     pub where_clause: WhereClauseId<'db>,
     pub variants: VariantDefListId<'db>,
@@ -782,7 +797,7 @@ pub struct TypeAlias<'db> {
 
     pub name: Partial<IdentId<'db>>,
     pub attributes: AttrListId<'db>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub generic_params: GenericParamListId<'db>,
     pub ty: Partial<TypeId<'db>>,
     pub top_mod: TopLevelMod<'db>,
@@ -838,6 +853,13 @@ impl<'db> Impl<'db> {
         })
     }
 
+    pub fn consts(self, db: &'db dyn HirDb) -> impl Iterator<Item = Const<'db>> + 'db {
+        self.children_non_nested(db).filter_map(|item| match item {
+            ItemKind::Const(const_) => Some(const_),
+            _ => None,
+        })
+    }
+
     pub fn scope(self) -> ScopeId<'db> {
         ScopeId::from_item(self.into())
     }
@@ -852,11 +874,12 @@ pub struct Trait<'db> {
     pub name: Partial<IdentId<'db>>,
 
     pub attributes: AttrListId<'db>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub generic_params: GenericParamListId<'db>,
     #[return_ref]
     pub super_traits: Vec<TraitRefId<'db>>,
     pub where_clause: WhereClauseId<'db>,
+    pub assoc_types: AssocTypeListId<'db>,
     pub top_mod: TopLevelMod<'db>,
 
     #[return_ref]
@@ -888,6 +911,13 @@ impl<'db> Trait<'db> {
             _ => None,
         })
     }
+
+    pub fn consts(self, db: &'db dyn HirDb) -> impl Iterator<Item = Const<'db>> + 'db {
+        self.children_non_nested(db).filter_map(|item| match item {
+            ItemKind::Const(const_) => Some(const_),
+            _ => None,
+        })
+    }
 }
 
 #[salsa::tracked]
@@ -901,6 +931,7 @@ pub struct ImplTrait<'db> {
     pub attributes: AttrListId<'db>,
     pub generic_params: GenericParamListId<'db>,
     pub where_clause: WhereClauseId<'db>,
+    pub assoc_types: AssocTypeListId<'db>,
     pub top_mod: TopLevelMod<'db>,
 
     #[return_ref]
@@ -930,6 +961,13 @@ impl<'db> ImplTrait<'db> {
             _ => None,
         })
     }
+
+    pub fn consts(self, db: &'db dyn HirDb) -> impl Iterator<Item = Const<'db>> + 'db {
+        self.children_non_nested(db).filter_map(|item| match item {
+            ItemKind::Const(const_) => Some(const_),
+            _ => None,
+        })
+    }
 }
 
 #[salsa::tracked]
@@ -942,7 +980,7 @@ pub struct Const<'db> {
     pub attributes: AttrListId<'db>,
     pub ty: Partial<TypeId<'db>>,
     pub body: Partial<Body<'db>>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub top_mod: TopLevelMod<'db>,
 
     #[return_ref]
@@ -956,6 +994,20 @@ impl<'db> Const<'db> {
     pub fn scope(self) -> ScopeId<'db> {
         ScopeId::from_item(self.into())
     }
+
+    /// Returns `true` if the const is an associated const of a trait, an impl
+    /// or a trait impl.
+    pub fn is_associated_const(self, db: &dyn HirDb) -> bool {
+        let item = match self.scope().parent(db) {
+            Some(ScopeId::Item(item)) => item,
+            _ => return false,
+        };
+
+        matches!(
+            item,
+            ItemKind::Trait(_) | ItemKind::Impl(_) | ItemKind::ImplTrait(_)
+        )
+    }
 }
 
 #[salsa::tracked]
@@ -966,7 +1018,7 @@ pub struct Use<'db> {
 
     pub path: Partial<super::UsePathId<'db>>,
     pub alias: Option<Partial<UseAlias<'db>>>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
     pub top_mod: TopLevelMod<'db>,
 
     #[return_ref]
@@ -1046,15 +1098,6 @@ pub enum ItemModifier {
     None,
 }
 
-impl ItemModifier {
-    pub fn to_visibility(self) -> Visibility {
-        match self {
-            ItemModifier::Pub | ItemModifier::PubAndUnsafe => Visibility::Public,
-            ItemModifier::Unsafe | ItemModifier::None => Visibility::Private,
-        }
-    }
-}
-
 #[salsa::interned]
 #[derive(Debug)]
 pub struct FieldDefListId<'db> {
@@ -1160,7 +1203,39 @@ pub struct FieldDef<'db> {
     pub attributes: AttrListId<'db>,
     pub name: Partial<IdentId<'db>>,
     pub ty: Partial<TypeId<'db>>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
+}
+
+/// An associated type declared in a trait, e.g., `type Item: Clone`, or
+/// defined in an impl trait block, e.g., `type Item = i32`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AssocType<'db> {
+    pub attributes: AttrListId<'db>,
+    pub name: Partial<IdentId<'db>>,
+    pub bounds: Vec<TypeBound<'db>>,
+    /// The default type in a trait, or the definition in an impl trait.
+    pub ty: Option<TypeId<'db>>,
+}
+
+#[salsa::interned]
+#[derive(Debug)]
+pub struct AssocTypeListId<'db> {
+    #[return_ref]
+    pub data: Vec<AssocType<'db>>,
+}
+
+impl<'db> AssocTypeListId<'db> {
+    pub fn get(self, db: &'db dyn HirDb, name: IdentId<'db>) -> Option<&'db AssocType<'db>> {
+        self.data(db)
+            .iter()
+            .find(|assoc_ty| assoc_ty.name.to_opt() == Some(name))
+    }
+
+    pub fn idx(self, db: &dyn HirDb, name: IdentId<'db>) -> Option<usize> {
+        self.data(db)
+            .iter()
+            .position(|assoc_ty| assoc_ty.name.to_opt() == Some(name))
+    }
 }
 
 #[salsa::interned]
@@ -1222,16 +1297,33 @@ pub type ImplTraitItemListId<'db> = ImplItemListId<'db>;
 pub type ExternItemListId<'db> = ImplItemListId<'db>;
 // } xxx dead code
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Visibility {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, salsa::Update)]
+pub enum Visibility<'db> {
+    /// `pub`
     Public,
+    /// `pub(ingot)`: visible from anywhere in the ingot.
+    Ingot,
+    /// `pub(super)`: visible from the parent module of the defining module.
+    Super,
+    /// `pub(in path)`: visible from the module `path` which must be an
+    /// ancestor of the defining module.
+    In(Partial<super::PathId<'db>>),
     Private,
 }
 
-impl Visibility {
+impl Visibility<'_> {
     pub fn is_pub(self) -> bool {
         self == Self::Public
     }
+
+    pub fn is_private(self) -> bool {
+        self == Self::Private
+    }
+
+    /// Returns `true` if the visibility is one of the restricted `pub` forms.
+    pub fn is_restricted(self) -> bool {
+        matches!(self, Self::Ingot | Self::Super | Self::In(_))
+    }
 }
 
 #[salsa::interned]
@@ -218,8 +218,19 @@ impl<'db> ModuleTreeBuilder<'db> {
         }
     }
 
+    /// Returns the files of the ingot, excluding the files of ingots nested
+    /// in its directory, e.g., vendored dependencies.
+    fn ingot_files(&self) -> Vec<File> {
+        self.ingot
+            .files(self.db)
+            .iter()
+            .map(|(_url, file)| file)
+            .filter(|file| file.containing_ingot(self.db) == Some(self.ingot))
+            .collect()
+    }
+
     fn set_modules(&mut self) {
-        for (_url, file) in self.ingot.files(self.db).iter() {
+        for file in self.ingot_files() {
             // Only process source files, skip config files like fe.toml
             if let Some(IngotFileKind::Source) = file.kind(self.db) {
                 let top_mod = map_file_to_mod_impl(self.db, file);
@@ -244,7 +255,7 @@ impl<'db> ModuleTreeBuilder<'db> {
             std::process::exit(2)
         });
 
-        for (_url, child) in self.ingot.files(self.db).iter() {
+        for child in self.ingot_files() {
             // Ignore the root file because it has no parent.
             if child == root {
                 continue;
@@ -14,6 +14,12 @@ pub enum Pat<'db> {
     PathTuple(Partial<PathId<'db>>, Vec<PatId>),
     Record(Partial<PathId<'db>>, Vec<RecordPatField<'db>>),
     Or(PatId, PatId),
+    /// An inclusive range of literals, e.g., `0..=9`.
+    Range(Partial<LitKind<'db>>, Partial<LitKind<'db>>),
+    /// `name @ pat`. The bool is `true` if the binding has `mut` in front of
+    /// it.
+    Binding(Partial<IdentId<'db>>, bool, PatId),
+    Array(Vec<PatId>),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, salsa::Update)]
@@ -408,11 +408,11 @@ impl<'db> std::iter::Iterator for ScopeGraphItemIterDfs<'db, '_> {
 pub struct Scope<'db> {
     pub id: ScopeId<'db>,
     pub edges: IndexSet<ScopeEdge<'db>>,
-    pub vis: Visibility,
+    pub vis: Visibility<'db>,
 }
 
 impl<'db> Scope<'db> {
-    pub fn new(kind: ScopeId<'db>, vis: Visibility) -> Self {
+    pub fn new(kind: ScopeId<'db>, vis: Visibility<'db>) -> Self {
         Self {
             id: kind,
             edges: Default::default(),
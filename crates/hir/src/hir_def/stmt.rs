@@ -1,27 +1,41 @@
 use cranelift_entity::entity_impl;
 
-use super::{Body, ExprId, Partial, PatId, TypeId};
+use super::{Body, ExprId, IdentId, Partial, PatId, TypeId};
 use crate::{span::stmt::LazyStmtSpan, HirDb};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, salsa::Update)]
 pub enum Stmt<'db> {
     /// The `let` statement. The first `PatId` is the pattern for binding, the
-    /// second `Option<TypeId>` is the type annotation, and the third
-    /// `Option<ExprId>` is the expression for initialization.
-    Let(PatId, Option<TypeId<'db>>, Option<ExprId>),
-    /// The first `PatId` is the pattern for binding which can be used in the
+    /// second `Option<TypeId>` is the type annotation, the third
+    /// `Option<ExprId>` is the expression for initialization, and the fourth
+    /// `Option<ExprId>` is the `else` block of `let pat = expr else { .. }`.
+    Let(PatId, Option<TypeId<'db>>, Option<ExprId>, Option<ExprId>),
+    /// The first `Option<IdentId>` is the label of the for-loop.
+    ///
+    /// The `PatId` is the pattern for binding which can be used in the
     /// for-loop body.
     ///
-    /// The second `ExprId` is the iterable expression.
+    /// The first `ExprId` is the iterable expression.
     ///
-    /// The third `ExprId` is the for-loop body.
-    For(PatId, ExprId, ExprId),
+    /// The second `ExprId` is the for-loop body.
+    For(Option<IdentId<'db>>, PatId, ExprId, ExprId),
 
+    /// The `Option<IdentId>` is the label of the while-loop.
     /// The first `ExprId` is the condition of the while-loop.
     /// The second `ExprId` is the body of the while-loop.
-    While(ExprId, ExprId),
-    Continue,
-    Break,
+    While(Option<IdentId<'db>>, ExprId, ExprId),
+
+    /// The `while let` statement. The `Option<IdentId>` is the label, the
+    /// `PatId` is the pattern to match, the first `ExprId` is the scrutinee,
+    /// and the second is the body of the while-loop.
+    WhileLet(Option<IdentId<'db>>, PatId, ExprId, ExprId),
+
+    /// The `Option<IdentId>` is the label of the loop to continue.
+    Continue(Option<IdentId<'db>>),
+
+    /// The `Option<IdentId>` is the label of the loop to break out of, and the
+    /// `Option<ExprId>` is the value of the `loop` expression.
+    Break(Option<IdentId<'db>>, Option<ExprId>),
     Return(Option<ExprId>),
     Expr(ExprId),
 }
@@ -1,4 +1,4 @@
-use super::{Body, GenericArgListId, Partial, PathId};
+use super::{Body, GenericArgListId, IdentId, Partial, PathId, TraitRefId};
 use crate::HirDb;
 
 #[salsa::interned]
@@ -40,6 +40,16 @@ impl<'db> TypeId<'db> {
                 .collect::<Vec<_>>()
                 .join(", "),
             TypeKind::Array(t, _) => format!("[{}; {{..}}]", print_ty(t)),
+            TypeKind::QualifiedPath(t, trait_ref, name) => {
+                let trait_ = trait_ref
+                    .to_opt()
+                    .and_then(|trait_ref| trait_ref.path(db).to_opt())
+                    .map_or_else(|| "<missing>".into(), |p| p.pretty_print(db));
+                let name = name
+                    .to_opt()
+                    .map_or_else(|| "<missing>".into(), |n| n.data(db).to_string());
+                format!("<{} as {trait_}>::{name}", print_ty(t))
+            }
             TypeKind::Never => "!".into(),
         }
     }
@@ -53,6 +63,12 @@ pub enum TypeKind<'db> {
     Tuple(TupleTypeId<'db>),
     /// The first `TypeId` is the element type, the second `Body` is the length.
     Array(Partial<TypeId<'db>>, Partial<Body<'db>>),
+    /// A fully qualified associated type, e.g., `<T as Trait>::Item`.
+    QualifiedPath(
+        Partial<TypeId<'db>>,
+        Partial<TraitRefId<'db>>,
+        Partial<IdentId<'db>>,
+    ),
     Never,
 }
 
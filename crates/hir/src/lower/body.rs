@@ -1,4 +1,4 @@
-use parser::ast;
+use parser::ast::{self, SyntaxNodePtr};
 
 use super::FileLowerCtxt;
 use crate::{
@@ -12,16 +12,37 @@ use crate::{
 impl<'db> Body<'db> {
     pub(super) fn lower_ast(f_ctxt: &mut FileLowerCtxt<'db>, ast: ast::Expr) -> Self {
         let id = f_ctxt.joined_id(TrackedItemVariant::FuncBody);
+        let origin = f_ctxt.origin(&ast);
         let mut ctxt = BodyCtxt::new(f_ctxt, id);
-        let body_expr = Expr::lower_ast(&mut ctxt, ast.clone());
-        ctxt.build(&ast, body_expr, BodyKind::FuncBody)
+        let body_expr = Expr::lower_ast(&mut ctxt, ast);
+        ctxt.build(origin, body_expr, BodyKind::FuncBody)
     }
 
     pub(super) fn lower_ast_nameless(f_ctxt: &mut FileLowerCtxt<'db>, ast: ast::Expr) -> Self {
         let id = f_ctxt.joined_id(TrackedItemVariant::NamelessBody);
+        let origin = f_ctxt.origin(&ast);
         let mut ctxt = BodyCtxt::new(f_ctxt, id);
-        let body_expr = Expr::lower_ast(&mut ctxt, ast.clone());
-        ctxt.build(&ast, body_expr, BodyKind::Anonymous)
+        let body_expr = Expr::lower_ast(&mut ctxt, ast);
+        ctxt.build(origin, body_expr, BodyKind::Anonymous)
+    }
+
+    /// Creates a body whose nodes are generated by the compiler rather than
+    /// lowered from the source, e.g., the method bodies of a `#derive(..)`.
+    /// `build_expr` returns the root expression of the body.
+    pub(super) fn lower_expanded(
+        f_ctxt: &mut FileLowerCtxt<'db>,
+        origin: SyntaxNodePtr,
+        body_kind: BodyKind,
+        build_expr: impl FnOnce(&mut BodyCtxt<'_, 'db>) -> ExprId,
+    ) -> Self {
+        let variant = match body_kind {
+            BodyKind::FuncBody => TrackedItemVariant::FuncBody,
+            BodyKind::Anonymous => TrackedItemVariant::NamelessBody,
+        };
+        let id = f_ctxt.joined_id(variant);
+        let mut ctxt = BodyCtxt::new(f_ctxt, id);
+        let body_expr = build_expr(&mut ctxt);
+        ctxt.build(HirOrigin::Expanded(origin), body_expr, body_kind)
     }
 }
 
@@ -87,8 +108,12 @@ impl<'ctxt, 'db> BodyCtxt<'ctxt, 'db> {
         }
     }
 
-    fn build(self, ast: &ast::Expr, body_expr: ExprId, body_kind: BodyKind) -> Body<'db> {
-        let origin = HirOrigin::raw(ast);
+    fn build(
+        self,
+        origin: HirOrigin<ast::Expr>,
+        body_expr: ExprId,
+        body_kind: BodyKind,
+    ) -> Body<'db> {
         let body = Body::new(
             self.f_ctxt.db(),
             self.id,
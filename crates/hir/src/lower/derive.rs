@@ -0,0 +1,548 @@
+//! Expansion of `#derive(..)` attributes on structs and enums.
+//!
+//! Each derivable trait listed in the attribute is expanded into an
+//! `ImplTrait` of the `core` trait, e.g. `#derive(Clone)` on `Foo<T>`
+//! generates
+//!
+//! ```fe
+//! impl<T: core::Clone> core::Clone for Foo<T> {
+//!     fn clone(self) -> Self {
+//!         Self { x: core::clone::clone(value: self.x) }
+//!     }
+//! }
+//! ```
+//!
+//! All the generated nodes have [`HirOrigin::Expanded`] pointing to the
+//! attribute argument, so the errors in the generated code, e.g., a field type
+//! that doesn't implement the trait, are reported at the argument.
+
+use parser::ast::{self, prelude::*, SyntaxNodePtr};
+
+use super::{body::BodyCtxt, FileLowerCtxt};
+use crate::{
+    hir_def::{
+        item::*, AttrListId, Body, BodyKind, CallArg, DerivableTrait, Expr, ExprId, Field,
+        FieldIndex, FuncParam, FuncParamListId, FuncParamName, GenericArg, GenericArgListId,
+        GenericParam, GenericParamListId, HirIngot, IdentId, LitKind, LogicalBinOp, MatchArm,
+        Partial, Pat, PatId, PathId, RecordPatField, Stmt, TraitRefId, TypeBound, TypeGenericArg,
+        TypeGenericParam, TypeId, TypeKind, WhereClauseId,
+    },
+    span::HirOrigin,
+    HirDb,
+};
+
+/// The ADT a `#derive(..)` attribute is attached to.
+#[derive(Clone, Copy)]
+pub(super) enum DeriveTarget<'db> {
+    Struct(Struct<'db>),
+    Enum(Enum<'db>),
+}
+
+impl<'db> DeriveTarget<'db> {
+    fn name(self, db: &'db dyn HirDb) -> Partial<IdentId<'db>> {
+        match self {
+            Self::Struct(struct_) => struct_.name(db),
+            Self::Enum(enum_) => enum_.name(db),
+        }
+    }
+
+    fn generic_params(self, db: &'db dyn HirDb) -> GenericParamListId<'db> {
+        match self {
+            Self::Struct(struct_) => struct_.generic_params(db),
+            Self::Enum(enum_) => enum_.generic_params(db),
+        }
+    }
+
+    fn where_clause(self, db: &'db dyn HirDb) -> WhereClauseId<'db> {
+        match self {
+            Self::Struct(struct_) => struct_.where_clause(db),
+            Self::Enum(enum_) => enum_.where_clause(db),
+        }
+    }
+}
+
+/// Generates the trait implementations requested by the `#derive(..)`
+/// attributes in `attrs`.
+///
+/// Unknown traits and `Default` on enums are skipped here, they are reported
+/// by the ADT analysis.
+pub(super) fn lower_derives<'db>(
+    ctxt: &mut FileLowerCtxt<'db>,
+    attrs: Option<ast::AttrList>,
+    target: DeriveTarget<'db>,
+) {
+    let Some(attrs) = attrs else {
+        return;
+    };
+    let Partial::Present(adt_name) = target.name(ctxt.db()) else {
+        return;
+    };
+
+    for attr in attrs.normal_attrs() {
+        if !attr.name().is_some_and(|name| name.text() == "derive") {
+            continue;
+        }
+
+        for arg in attr.args().into_iter().flatten() {
+            let Some(trait_) = arg
+                .key()
+                .and_then(|key| DerivableTrait::from_name(key.text()))
+            else {
+                continue;
+            };
+            if trait_ == DerivableTrait::Default && matches!(target, DeriveTarget::Enum(_)) {
+                continue;
+            }
+
+            let origin = ctxt.expansion_site(arg.syntax());
+            lower_derive(ctxt, target, adt_name, trait_, origin);
+        }
+    }
+}
+
+fn lower_derive<'db>(
+    ctxt: &mut FileLowerCtxt<'db>,
+    target: DeriveTarget<'db>,
+    adt_name: IdentId<'db>,
+    trait_: DerivableTrait,
+    origin: SyntaxNodePtr,
+) -> ImplTrait<'db> {
+    let db = ctxt.db();
+    let trait_path = core_path(ctxt).push_ident(db, IdentId::new(db, trait_.name().to_string()));
+    let trait_ref = TraitRefId::new(db, Partial::Present(trait_path));
+
+    let params = target.generic_params(db);
+    let args = params
+        .data(db)
+        .iter()
+        .map(|param| {
+            let ty = param
+                .name()
+                .to_opt()
+                .map(|name| {
+                    let path = PathId::from_ident(db, name);
+                    TypeId::new(db, TypeKind::Path(Partial::Present(path)))
+                })
+                .into();
+            GenericArg::Type(TypeGenericArg { ty })
+        })
+        .collect::<Vec<_>>();
+    let is_given = !args.is_empty();
+    let self_path = PathId::new(
+        db,
+        Partial::Present(adt_name),
+        GenericArgListId::new(db, args, is_given),
+        None,
+    );
+    let ty = Partial::Present(TypeId::new(db, TypeKind::Path(Partial::Present(self_path))));
+
+    let id = ctxt.joined_id(TrackedItemVariant::ImplTrait(
+        Partial::Present(trait_ref),
+        ty,
+    ));
+    ctxt.enter_item_scope(id, false);
+
+    // Every type parameter of the ADT is required to implement the trait.
+    let generic_params = params
+        .data(db)
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(param) => {
+                let mut bounds = param.bounds.clone();
+                bounds.push(TypeBound::Trait(trait_ref));
+                GenericParam::Type(TypeGenericParam {
+                    name: param.name,
+                    bounds,
+                })
+            }
+            GenericParam::Const(_) => param.clone(),
+        })
+        .collect();
+    let generic_params = GenericParamListId::new(db, generic_params);
+
+    lower_derived_method(ctxt, target, trait_, &origin);
+
+    let impl_trait = ImplTrait::new(
+        db,
+        id,
+        Partial::Present(trait_ref),
+        ty,
+        AttrListId::new(db, vec![]),
+        generic_params,
+        target.where_clause(db),
+        AssocTypeListId::new(db, vec![]),
+        ctxt.top_mod(),
+        HirOrigin::Expanded(origin),
+    );
+    ctxt.leave_item_scope(impl_trait)
+}
+
+fn lower_derived_method<'db>(
+    ctxt: &mut FileLowerCtxt<'db>,
+    target: DeriveTarget<'db>,
+    trait_: DerivableTrait,
+    origin: &SyntaxNodePtr,
+) -> Func<'db> {
+    let db = ctxt.db();
+    let name = Partial::Present(IdentId::new(db, trait_.method_name().to_string()));
+    let id = ctxt.joined_id(TrackedItemVariant::Func(name));
+    ctxt.enter_item_scope(id, false);
+
+    let self_ty = TypeId::fallback_self_ty(db);
+    let self_param = FuncParam {
+        is_mut: false,
+        label: None,
+        name: Partial::Present(FuncParamName::Ident(IdentId::make_self(db))),
+        ty: Partial::Present(self_ty),
+        self_ty_fallback: true,
+    };
+    let (params, ret_ty) = match trait_ {
+        DerivableTrait::Clone => (vec![self_param], self_ty),
+        DerivableTrait::Default => (vec![], self_ty),
+        DerivableTrait::Eq => {
+            let other_param = FuncParam {
+                is_mut: false,
+                label: None,
+                name: Partial::Present(FuncParamName::Ident(IdentId::new(db, "other".to_string()))),
+                ty: Partial::Present(self_ty),
+                self_ty_fallback: false,
+            };
+            let bool_path = PathId::from_ident(db, IdentId::make_bool(db));
+            let bool_ty = TypeId::new(db, TypeKind::Path(Partial::Present(bool_path)));
+            (vec![self_param, other_param], bool_ty)
+        }
+    };
+
+    let core = core_path(ctxt);
+    let body = Body::lower_expanded(ctxt, origin.clone(), BodyKind::FuncBody, |ctxt| {
+        BodyBuilder {
+            ctxt,
+            origin: origin.clone(),
+            core,
+            trait_,
+        }
+        .build(target)
+    });
+
+    let func = Func::new(
+        db,
+        id,
+        name,
+        AttrListId::new(db, vec![]),
+        GenericParamListId::new(db, vec![]),
+        WhereClauseId::new(db, vec![]),
+        Partial::Present(FuncParamListId::new(db, params)),
+        Some(ret_ty),
+        ItemModifier::None,
+        Visibility::Private,
+        Some(body),
+        false,
+        ctxt.top_mod(),
+        HirOrigin::Expanded(origin.clone()),
+    );
+    ctxt.leave_item_scope(func)
+}
+
+/// Returns the path to the root of the `core` ingot from the current ingot.
+fn core_path<'db>(ctxt: &FileLowerCtxt<'db>) -> PathId<'db> {
+    let db = ctxt.db();
+    let root = if ctxt.top_mod().ingot(db).is_core(db) {
+        IdentId::make_ingot(db)
+    } else {
+        IdentId::new(db, "core".to_string())
+    };
+    PathId::from_ident(db, root)
+}
+
+/// Builds the body of a derived trait method.
+struct BodyBuilder<'a, 'ctxt, 'db> {
+    ctxt: &'a mut BodyCtxt<'ctxt, 'db>,
+    origin: SyntaxNodePtr,
+    core: PathId<'db>,
+    trait_: DerivableTrait,
+}
+
+impl<'db> BodyBuilder<'_, '_, 'db> {
+    fn build(mut self, target: DeriveTarget<'db>) -> ExprId {
+        self.ctxt.f_ctxt.enter_block_scope();
+
+        let expr = match target {
+            DeriveTarget::Struct(struct_) => self.build_struct(struct_),
+            DeriveTarget::Enum(enum_) => self.build_enum(enum_),
+        };
+        let stmt = self
+            .ctxt
+            .push_stmt(Stmt::Expr(expr), HirOrigin::Expanded(self.origin.clone()));
+        let block = self.expr(Expr::Block(vec![stmt]));
+
+        self.ctxt.f_ctxt.leave_block_scope(block);
+        block
+    }
+
+    /// Builds
+    /// - `Self { x: clone(value: self.x), .. }` for `Clone`,
+    /// - `Self { x: default(), .. }` for `Default`,
+    /// - `eq(lhs: self.x, rhs: other.x) && ..` for `Eq`.
+    fn build_struct(&mut self, struct_: Struct<'db>) -> ExprId {
+        let db = self.db();
+        let fields = struct_.fields(db).data(db);
+
+        if self.trait_ == DerivableTrait::Eq {
+            let eqs = fields
+                .iter()
+                .map(|field| {
+                    let lhs = self.field_access("self", field.name);
+                    let rhs = self.field_access("other", field.name);
+                    self.call_helper(vec![("lhs", lhs), ("rhs", rhs)])
+                })
+                .collect();
+            return self.all(eqs);
+        }
+
+        let fields = fields
+            .iter()
+            .map(|field| {
+                let expr = match self.trait_ {
+                    DerivableTrait::Default => self.call_helper(vec![]),
+                    _ => {
+                        let value = self.field_access("self", field.name);
+                        self.call_helper(vec![("value", value)])
+                    }
+                };
+                Field {
+                    label: field.name.to_opt(),
+                    expr,
+                }
+            })
+            .collect();
+        let self_path = PathId::self_ty(db, GenericArgListId::none(db));
+        self.expr(Expr::RecordInit(Partial::Present(self_path), fields))
+    }
+
+    /// Builds
+    /// - `match self { Self::A(_0) => Self::A(clone(value: _0)), .. }` for
+    ///   `Clone`,
+    /// - `match (self, other) { (Self::A(_l0), Self::A(_r0)) => eq(lhs: _l0,
+    ///   rhs: _r0), .., _ => false }` for `Eq`.
+    fn build_enum(&mut self, enum_: Enum<'db>) -> ExprId {
+        let db = self.db();
+        let variants = enum_.variants(db).data(db);
+
+        // A value of an empty enum can't exist.
+        if variants.is_empty() {
+            return match self.trait_ {
+                DerivableTrait::Eq => self.bool_lit(true),
+                _ => self.path_expr("self"),
+            };
+        }
+
+        let (scrutinee, mut arms) = if self.trait_ == DerivableTrait::Eq {
+            let lhs = self.path_expr("self");
+            let rhs = self.path_expr("other");
+            let scrutinee = self.expr(Expr::Tuple(vec![lhs, rhs]));
+
+            let arms = variants
+                .iter()
+                .map(|variant| {
+                    let (lhs_pat, lhs_bindings) = self.variant_pat(variant, "_l");
+                    let (rhs_pat, rhs_bindings) = self.variant_pat(variant, "_r");
+                    let eqs = lhs_bindings
+                        .into_iter()
+                        .zip(rhs_bindings)
+                        .map(|(lhs, rhs)| {
+                            let lhs = self.path_expr(&lhs);
+                            let rhs = self.path_expr(&rhs);
+                            self.call_helper(vec![("lhs", lhs), ("rhs", rhs)])
+                        })
+                        .collect();
+                    MatchArm {
+                        pat: self.pat(Pat::Tuple(vec![lhs_pat, rhs_pat])),
+                        guard: None,
+                        body: self.all(eqs),
+                    }
+                })
+                .collect::<Vec<_>>();
+            (scrutinee, arms)
+        } else {
+            let scrutinee = self.path_expr("self");
+            let arms = variants
+                .iter()
+                .map(|variant| {
+                    let (pat, bindings) = self.variant_pat(variant, "_");
+                    let args = bindings
+                        .into_iter()
+                        .map(|binding| {
+                            let value = self.path_expr(&binding);
+                            self.call_helper(vec![("value", value)])
+                        })
+                        .collect();
+                    MatchArm {
+                        pat,
+                        guard: None,
+                        body: self.variant_init(variant, args),
+                    }
+                })
+                .collect();
+            (scrutinee, arms)
+        };
+
+        if self.trait_ == DerivableTrait::Eq && variants.len() > 1 {
+            arms.push(MatchArm {
+                pat: self.pat(Pat::WildCard),
+                guard: None,
+                body: self.bool_lit(false),
+            });
+        }
+
+        self.expr(Expr::Match(scrutinee, Partial::Present(arms)))
+    }
+
+    /// Builds a pattern matching `variant` that binds its fields to
+    /// `{prefix}0`, `{prefix}1`, ..., and returns the pattern with the names
+    /// of the bindings.
+    fn variant_pat(&mut self, variant: &VariantDef<'db>, prefix: &str) -> (PatId, Vec<String>) {
+        let db = self.db();
+        let path = Partial::Present(self.variant_path(variant));
+
+        match variant.kind {
+            VariantKind::Unit => (self.pat(Pat::Path(path, false)), vec![]),
+
+            VariantKind::Tuple(elems) => {
+                let names = (0..elems.data(db).len())
+                    .map(|i| format!("{prefix}{i}"))
+                    .collect::<Vec<_>>();
+                let pats = names.iter().map(|name| self.binding_pat(name)).collect();
+                (self.pat(Pat::PathTuple(path, pats)), names)
+            }
+
+            VariantKind::Record(fields) => {
+                let fields = fields.data(db);
+                let names = (0..fields.len())
+                    .map(|i| format!("{prefix}{i}"))
+                    .collect::<Vec<_>>();
+                let pat_fields = fields
+                    .iter()
+                    .zip(&names)
+                    .map(|(field, name)| RecordPatField {
+                        label: field.name,
+                        pat: self.binding_pat(name),
+                    })
+                    .collect();
+                (self.pat(Pat::Record(path, pat_fields)), names)
+            }
+        }
+    }
+
+    /// Builds an expression constructing `variant` from `args`, which are
+    /// given in the order of the fields.
+    fn variant_init(&mut self, variant: &VariantDef<'db>, args: Vec<ExprId>) -> ExprId {
+        let db = self.db();
+        let path = self.variant_path(variant);
+
+        match variant.kind {
+            VariantKind::Unit => self.expr(Expr::Path(Partial::Present(path))),
+
+            VariantKind::Tuple(_) => {
+                let callee = self.expr(Expr::Path(Partial::Present(path)));
+                let args = args
+                    .into_iter()
+                    .map(|expr| CallArg { label: None, expr })
+                    .collect();
+                self.expr(Expr::Call(callee, args))
+            }
+
+            VariantKind::Record(fields) => {
+                let fields = fields
+                    .data(db)
+                    .iter()
+                    .zip(args)
+                    .map(|(field, expr)| Field {
+                        label: field.name.to_opt(),
+                        expr,
+                    })
+                    .collect();
+                self.expr(Expr::RecordInit(Partial::Present(path), fields))
+            }
+        }
+    }
+
+    /// Builds a call to the function of `core` that has the same name as the
+    /// trait method, e.g., `core::clone::clone(value: x)`.
+    fn call_helper(&mut self, args: Vec<(&str, ExprId)>) -> ExprId {
+        let db = self.db();
+        let path = self
+            .core
+            .push_ident(db, self.ident(self.trait_.module_name()))
+            .push_ident(db, self.ident(self.trait_.method_name()));
+        let callee = self.expr(Expr::Path(Partial::Present(path)));
+
+        let args = args
+            .into_iter()
+            .map(|(label, expr)| CallArg {
+                label: Some(self.ident(label)),
+                expr,
+            })
+            .collect();
+        self.expr(Expr::Call(callee, args))
+    }
+
+    /// Joins `exprs` with `&&`, or builds `true` if `exprs` is empty.
+    fn all(&mut self, exprs: Vec<ExprId>) -> ExprId {
+        let mut exprs = exprs.into_iter();
+        let Some(first) = exprs.next() else {
+            return self.bool_lit(true);
+        };
+
+        exprs.fold(first, |lhs, rhs| {
+            let op = Partial::Present(LogicalBinOp::And.into());
+            self.expr(Expr::Bin(lhs, rhs, op))
+        })
+    }
+
+    fn field_access(&mut self, base: &str, field: Partial<IdentId<'db>>) -> ExprId {
+        let base = self.path_expr(base);
+        let field = field.to_opt().map(FieldIndex::Ident).into();
+        self.expr(Expr::Field(base, field))
+    }
+
+    fn variant_path(&self, variant: &VariantDef<'db>) -> PathId<'db> {
+        let db = self.db();
+        PathId::self_ty(db, GenericArgListId::none(db)).push(
+            db,
+            variant.name,
+            GenericArgListId::none(db),
+        )
+    }
+
+    fn path_expr(&mut self, name: &str) -> ExprId {
+        let path = PathId::from_ident(self.db(), self.ident(name));
+        self.expr(Expr::Path(Partial::Present(path)))
+    }
+
+    fn binding_pat(&mut self, name: &str) -> PatId {
+        let path = PathId::from_ident(self.db(), self.ident(name));
+        self.pat(Pat::Path(Partial::Present(path), false))
+    }
+
+    fn bool_lit(&mut self, value: bool) -> ExprId {
+        self.expr(Expr::Lit(LitKind::Bool(value)))
+    }
+
+    fn expr(&mut self, expr: Expr<'db>) -> ExprId {
+        let origin = HirOrigin::Expanded(self.origin.clone());
+        self.ctxt.push_expr(expr, origin)
+    }
+
+    fn pat(&mut self, pat: Pat<'db>) -> PatId {
+        let origin = HirOrigin::Expanded(self.origin.clone());
+        self.ctxt.push_pat(pat, origin)
+    }
+
+    fn ident(&self, name: &str) -> IdentId<'db> {
+        IdentId::new(self.db(), name.to_string())
+    }
+
+    fn db(&self) -> &'db dyn HirDb {
+        self.ctxt.f_ctxt.db()
+    }
+}
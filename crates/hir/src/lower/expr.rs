@@ -1,11 +1,8 @@
 use parser::ast::{self, prelude::*};
 
 use super::body::BodyCtxt;
-use crate::{
-    hir_def::{
-        expr::*, Body, GenericArgListId, IdentId, IntegerId, ItemKind, LitKind, Pat, PathId, Stmt,
-    },
-    span::HirOrigin,
+use crate::hir_def::{
+    expr::*, Body, GenericArgListId, IdentId, IntegerId, ItemKind, LitKind, Pat, PathId, Stmt,
 };
 
 impl<'db> Expr<'db> {
@@ -16,7 +13,7 @@ impl<'db> Expr<'db> {
                     let lit = LitKind::lower_ast(ctxt.f_ctxt, lit);
                     Self::Lit(lit)
                 } else {
-                    return ctxt.push_invalid_expr(HirOrigin::raw(&ast));
+                    return ctxt.push_invalid_expr(ctxt.f_ctxt.origin(&ast));
                 }
             }
 
@@ -28,7 +25,7 @@ impl<'db> Expr<'db> {
                     let stmt = Stmt::push_to_body(ctxt, stmt);
                     stmts.push(stmt);
                 }
-                let expr_id = ctxt.push_expr(Self::Block(stmts), HirOrigin::raw(&ast));
+                let expr_id = ctxt.push_expr(Self::Block(stmts), ctxt.f_ctxt.origin(&ast));
 
                 for item in block.items() {
                     ItemKind::lower_ast(ctxt.f_ctxt, item);
@@ -145,6 +142,7 @@ impl<'db> Expr<'db> {
             }
 
             ast::ExprKind::If(if_) => {
+                let pat = if_.pat().map(|pat| Pat::lower_ast(ctxt, pat));
                 let cond = Self::push_to_body_opt(ctxt, if_.cond());
                 let then = Expr::push_to_body_opt(
                     ctxt,
@@ -152,7 +150,10 @@ impl<'db> Expr<'db> {
                         .and_then(|body| ast::Expr::cast(body.syntax().clone())),
                 );
                 let else_ = if_.else_().map(|ast| Self::lower_ast(ctxt, ast));
-                Self::If(cond, then, else_)
+                match pat {
+                    Some(pat) => Self::IfLet(pat, cond, then, else_),
+                    None => Self::If(cond, then, else_),
+                }
             }
 
             ast::ExprKind::Match(match_) => {
@@ -169,6 +170,29 @@ impl<'db> Expr<'db> {
                 Self::Match(scrutinee, arm)
             }
 
+            ast::ExprKind::Loop(loop_) => {
+                let label = loop_
+                    .label()
+                    .map(|label| IdentId::lower_token(ctxt.f_ctxt, label));
+                let body = Self::push_to_body_opt(
+                    ctxt,
+                    loop_
+                        .body()
+                        .and_then(|body| ast::Expr::cast(body.syntax().clone())),
+                );
+                Self::Loop(label, body)
+            }
+
+            ast::ExprKind::Unsafe(unsafe_) => {
+                let body = Self::push_to_body_opt(
+                    ctxt,
+                    unsafe_
+                        .body()
+                        .and_then(|body| ast::Expr::cast(body.syntax().clone())),
+                );
+                Self::Unsafe(body)
+            }
+
             ast::ExprKind::Paren(paren) => {
                 return Self::push_to_body_opt(ctxt, paren.expr());
             }
@@ -186,9 +210,22 @@ impl<'db> Expr<'db> {
 
                 Self::AugAssign(lhs, rhs, binop)
             }
+
+            ast::ExprKind::MacroCall(call) => {
+                let origin = ctxt.f_ctxt.origin(&ast);
+                let Some(root) = ctxt.f_ctxt.enter_expansion(&call) else {
+                    return ctxt.push_invalid_expr(origin);
+                };
+                let expr_id = match root.expr() {
+                    Some(expr) => Self::lower_ast(ctxt, expr),
+                    None => ctxt.push_invalid_expr(origin),
+                };
+                ctxt.f_ctxt.leave_expansion();
+                return expr_id;
+            }
         };
 
-        ctxt.push_expr(expr, HirOrigin::raw(&ast))
+        ctxt.push_expr(expr, ctxt.f_ctxt.origin(&ast))
     }
 
     pub(super) fn push_to_body_opt(ctxt: &mut BodyCtxt<'_, '_>, ast: Option<ast::Expr>) -> ExprId {
@@ -264,8 +301,11 @@ impl UnOp {
 impl MatchArm {
     fn lower_ast(ctxt: &mut BodyCtxt<'_, '_>, ast: ast::MatchArm) -> Self {
         let pat = Pat::lower_ast_opt(ctxt, ast.pat());
+        let guard = ast
+            .guard()
+            .map(|guard| Expr::push_to_body_opt(ctxt, guard.cond()));
         let body = Expr::push_to_body_opt(ctxt, ast.body());
-        Self { pat, body }
+        Self { pat, guard, body }
     }
 }
 
@@ -1,12 +1,12 @@
 use parser::ast::{self, prelude::*};
 
-use super::FileLowerCtxt;
-use crate::{
-    hir_def::{
-        item::*, AttrListId, Body, FuncParamListId, GenericParamListId, IdentId, TraitRefId,
-        TupleTypeId, TypeId, WhereClauseId,
-    },
-    span::HirOrigin,
+use super::{
+    derive::{lower_derives, DeriveTarget},
+    FileLowerCtxt,
+};
+use crate::hir_def::{
+    item::*, AttrListId, Body, FuncParamListId, GenericParamListId, IdentId, Partial, PathId,
+    TraitRefId, TupleTypeId, TypeBound, TypeId, WhereClauseId,
 };
 
 pub(crate) fn lower_module_items(ctxt: &mut FileLowerCtxt<'_>, items: ast::ItemList) {
@@ -62,6 +62,17 @@ impl<'db> ItemKind<'db> {
                     }
                 }
             }
+            ast::ItemKind::MacroDef(_) => {
+                // Macro definitions are consumed by the macro expansion.
+            }
+            ast::ItemKind::MacroCall(call) => {
+                if let Some(root) = ctxt.enter_expansion(&call) {
+                    if let Some(items) = root.items() {
+                        lower_module_items(ctxt, items);
+                    }
+                    ctxt.leave_expansion();
+                }
+            }
         }
     }
 }
@@ -73,12 +84,12 @@ impl<'db> Mod<'db> {
         ctxt.enter_item_scope(id, true);
 
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         if let Some(items) = ast.items() {
             lower_module_items(ctxt, items);
         }
 
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
         let mod_ = Self::new(ctxt.db(), id, name, attributes, vis, ctxt.top_mod(), origin);
         ctxt.leave_item_scope(mod_)
     }
@@ -103,10 +114,11 @@ impl<'db> Func<'db> {
             .into();
         let ret_ty = ast.ret_ty().map(|ty| TypeId::lower_ast(ctxt, ty));
         let modifier = ItemModifier::lower_ast(ast.modifier());
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         let body = ast
             .body()
             .map(|body| Body::lower_ast(ctxt, ast::Expr::cast(body.syntax().clone()).unwrap()));
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
         let fn_ = Self::new(
             ctxt.db(),
@@ -118,6 +130,7 @@ impl<'db> Func<'db> {
             params,
             ret_ty,
             modifier,
+            vis,
             body,
             is_extern,
             ctxt.top_mod(),
@@ -134,11 +147,11 @@ impl<'db> Struct<'db> {
         ctxt.enter_item_scope(id, false);
 
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         let generic_params = GenericParamListId::lower_ast_opt(ctxt, ast.generic_params());
         let where_clause = WhereClauseId::lower_ast_opt(ctxt, ast.where_clause());
         let fields = FieldDefListId::lower_ast_opt(ctxt, ast.fields());
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
         let struct_ = Self::new(
             ctxt.db(),
@@ -152,7 +165,10 @@ impl<'db> Struct<'db> {
             ctxt.top_mod(),
             origin,
         );
-        ctxt.leave_item_scope(struct_)
+        ctxt.leave_item_scope(struct_);
+
+        lower_derives(ctxt, ast.attr_list(), DeriveTarget::Struct(struct_));
+        struct_
     }
 }
 
@@ -163,9 +179,9 @@ impl<'db> Contract<'db> {
         ctxt.enter_item_scope(id, false);
 
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         let fields = FieldDefListId::lower_ast_opt(ctxt, ast.fields());
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
         let contract = Self::new(
             ctxt.db(),
@@ -188,11 +204,11 @@ impl<'db> Enum<'db> {
         ctxt.enter_item_scope(id, false);
 
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         let generic_params = GenericParamListId::lower_ast_opt(ctxt, ast.generic_params());
         let where_clause = WhereClauseId::lower_ast_opt(ctxt, ast.where_clause());
         let variants = VariantDefListId::lower_ast_opt(ctxt, ast.variants());
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
         let enum_ = Self::new(
             ctxt.db(),
@@ -206,7 +222,10 @@ impl<'db> Enum<'db> {
             ctxt.top_mod(),
             origin,
         );
-        ctxt.leave_item_scope(enum_)
+        ctxt.leave_item_scope(enum_);
+
+        lower_derives(ctxt, ast.attr_list(), DeriveTarget::Enum(enum_));
+        enum_
     }
 }
 
@@ -217,10 +236,10 @@ impl<'db> TypeAlias<'db> {
         ctxt.enter_item_scope(id, false);
 
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         let generic_params = GenericParamListId::lower_ast_opt(ctxt, ast.generic_params());
         let ty = TypeId::lower_ast_partial(ctxt, ast.ty());
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
         let alias = Self::new(
             ctxt.db(),
@@ -246,9 +265,12 @@ impl<'db> Impl<'db> {
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
         let generic_params = GenericParamListId::lower_ast_opt(ctxt, ast.generic_params());
         let where_clause = WhereClauseId::lower_ast_opt(ctxt, ast.where_clause());
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
         if let Some(item_list) = ast.item_list() {
+            for assoc_const in item_list.assoc_consts() {
+                Const::lower_ast(ctxt, assoc_const);
+            }
             for impl_item in item_list {
                 Func::lower_ast(ctxt, impl_item, false);
             }
@@ -275,7 +297,7 @@ impl<'db> Trait<'db> {
         ctxt.enter_item_scope(id, false);
 
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
         let generic_params = GenericParamListId::lower_ast_opt(ctxt, ast.generic_params());
         let where_clause = WhereClauseId::lower_ast_opt(ctxt, ast.where_clause());
         let super_traits = if let Some(super_traits) = ast.super_trait_list() {
@@ -286,9 +308,16 @@ impl<'db> Trait<'db> {
         } else {
             vec![]
         };
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
+        let assoc_types = AssocTypeListId::lower_ast_opt(
+            ctxt,
+            ast.item_list().map(|item_list| item_list.assoc_types()),
+        );
         if let Some(item_list) = ast.item_list() {
+            for assoc_const in item_list.assoc_consts() {
+                Const::lower_ast(ctxt, assoc_const);
+            }
             for impl_item in item_list {
                 Func::lower_ast(ctxt, impl_item, false);
             }
@@ -303,6 +332,7 @@ impl<'db> Trait<'db> {
             generic_params,
             super_traits,
             where_clause,
+            assoc_types,
             ctxt.top_mod(),
             origin,
         );
@@ -321,9 +351,16 @@ impl<'db> ImplTrait<'db> {
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
         let generic_params = GenericParamListId::lower_ast_opt(ctxt, ast.generic_params());
         let where_clause = WhereClauseId::lower_ast_opt(ctxt, ast.where_clause());
-        let origin = HirOrigin::raw(&ast);
+        let origin = ctxt.origin(&ast);
 
+        let assoc_types = AssocTypeListId::lower_ast_opt(
+            ctxt,
+            ast.item_list().map(|item_list| item_list.assoc_types()),
+        );
         if let Some(item_list) = ast.item_list() {
+            for assoc_const in item_list.assoc_consts() {
+                Const::lower_ast(ctxt, assoc_const);
+            }
             for impl_item in item_list {
                 Func::lower_ast(ctxt, impl_item, false);
             }
@@ -337,6 +374,7 @@ impl<'db> ImplTrait<'db> {
             attributes,
             generic_params,
             where_clause,
+            assoc_types,
             ctxt.top_mod(),
             origin,
         );
@@ -353,8 +391,8 @@ impl<'db> Const<'db> {
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
         let ty = TypeId::lower_ast_partial(ctxt, ast.ty());
         let body = ast.value().map(|ast| Body::lower_ast(ctxt, ast)).into();
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
-        let origin = HirOrigin::raw(&ast);
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
+        let origin = ctxt.origin(&ast);
 
         let const_ = Self::new(
             ctxt.db(),
@@ -386,6 +424,31 @@ impl ItemModifier {
     }
 }
 
+impl<'db> Visibility<'db> {
+    pub(super) fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: Option<ast::ItemModifier>) -> Self {
+        match ast {
+            Some(ast) if ast.pub_kw().is_some() => Self::lower_pub(ctxt, ast.vis_restriction()),
+            _ => Self::Private,
+        }
+    }
+
+    /// Lowers `pub` followed by an optional restriction.
+    fn lower_pub(ctxt: &mut FileLowerCtxt<'db>, ast: Option<ast::VisRestriction>) -> Self {
+        let Some(ast) = ast else {
+            return Self::Public;
+        };
+
+        match ast.kind() {
+            Some(ast::VisRestrictionKind::Ingot) => Self::Ingot,
+            Some(ast::VisRestrictionKind::Super) => Self::Super,
+            Some(ast::VisRestrictionKind::In(path)) => {
+                Self::In(PathId::lower_ast_partial(ctxt, path))
+            }
+            None => Self::In(Partial::Absent),
+        }
+    }
+}
+
 impl<'db> FieldDefListId<'db> {
     fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: ast::RecordFieldDefList) -> Self {
         let fields = ast
@@ -401,13 +464,51 @@ impl<'db> FieldDefListId<'db> {
     }
 }
 
+impl<'db> AssocTypeListId<'db> {
+    fn lower_ast_opt(
+        ctxt: &mut FileLowerCtxt<'db>,
+        ast: Option<ast::AstChildren<ast::AssocType>>,
+    ) -> Self {
+        let assoc_types = ast
+            .into_iter()
+            .flatten()
+            .map(|assoc_ty| AssocType::lower_ast(ctxt, assoc_ty))
+            .collect::<Vec<_>>();
+        Self::new(ctxt.db(), assoc_types)
+    }
+}
+
+impl<'db> AssocType<'db> {
+    fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: ast::AssocType) -> Self {
+        let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
+        let name = IdentId::lower_token_partial(ctxt, ast.name());
+        let bounds = ast
+            .bounds()
+            .map(|bounds| {
+                bounds
+                    .into_iter()
+                    .map(|bound| TypeBound::lower_ast(ctxt, bound))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let ty = ast.ty().map(|ty| TypeId::lower_ast(ctxt, ty));
+
+        Self {
+            attributes,
+            name,
+            bounds,
+            ty,
+        }
+    }
+}
+
 impl<'db> FieldDef<'db> {
     fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: ast::RecordFieldDef) -> Self {
         let attributes = AttrListId::lower_ast_opt(ctxt, ast.attr_list());
         let name = IdentId::lower_token_partial(ctxt, ast.name());
         let ty = TypeId::lower_ast_partial(ctxt, ast.ty());
         let vis = if ast.pub_kw().is_some() {
-            Visibility::Public
+            Visibility::lower_pub(ctxt, ast.vis_restriction())
         } else {
             Visibility::Private
         };
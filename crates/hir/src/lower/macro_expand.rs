@@ -0,0 +1,1160 @@
+//! This module implements the expansion of declarative macros.
+//!
+//! A macro is defined by a `macro` item, and is visible in the whole file it's
+//! defined in regardless of the position of the definition.
+//!
+//! ```fe
+//! macro getter {
+//!     ($name:ident: $ty:ty) => {
+//!         pub fn $name(self) -> $ty { self.$name }
+//!     }
+//! }
+//! ```
+//!
+//! Invocations are expanded on tokens before lowering: the arguments of an
+//! invocation are matched against the matcher of each rule in order, and the
+//! transcriber of the first matching rule is instantiated with the captured
+//! fragments. Invocations in the result are expanded recursively, then the
+//! resulting tokens are parsed into a syntax tree separate from the tree of
+//! the file. Every token of the expansion remembers the range of the file text
+//! it's copied from, which is used to map the spans of the expanded nodes back
+//! to the file.
+//!
+//! The supported fragment specifiers are `ident`, `lit`, `tt`, `block`,
+//! `expr`, `ty`, `path` and `pat`. The latter four capture tokens up to the
+//! token following the fragment in the matcher.
+//!
+//! Expansion is hygienic for local bindings: bindings introduced by `let` and
+//! `for` written literally in a transcriber are renamed so that they neither
+//! capture nor shadow the bindings of the invocation site.
+//!
+//! Other identifiers aren't hygienic, since the expansion is lowered and
+//! resolved in the scope of the invocation like code written there. A path
+//! written literally in a transcriber, e.g. a call of a helper function,
+//! resolves to whatever the name refers to at the invocation site, including
+//! local bindings that shadow the item the macro author meant. Macros are only
+//! visible in the file they're defined in, which limits the damage; a
+//! transcriber can refer to items by fully qualified paths, e.g.
+//! `ingot::helper(x)`, to avoid being captured.
+
+use parser::{
+    ast::{self, prelude::*},
+    parser::token_stream::{LexicalToken, TokenStream},
+    GreenNode, ParseError, SyntaxKind, SyntaxNode, SyntaxToken, TextRange, TextSize,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use common::diagnostics::SpanKind;
+
+/// The maximum depth of nested macro invocations.
+const RECURSION_LIMIT: usize = 64;
+
+/// The expansions of the macro invocations in a file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MacroExpansions {
+    /// The expansions keyed by the range of their invocation.
+    expansions: FxHashMap<TextRange, MacroExpansion>,
+}
+
+impl MacroExpansions {
+    /// Returns the expansion of `call`, or `None` if the invocation couldn't
+    /// be expanded.
+    pub fn get(&self, call: &ast::MacroCall) -> Option<&MacroExpansion> {
+        self.get_by_range(call.syntax().text_range())
+    }
+
+    /// Returns the expansion of the invocation that contains `offset`.
+    pub fn find(&self, offset: TextSize) -> Option<&MacroExpansion> {
+        self.expansions
+            .values()
+            .find(|expansion| expansion.call.contains_inclusive(offset))
+    }
+
+    pub(crate) fn get_by_range(&self, call: TextRange) -> Option<&MacroExpansion> {
+        self.expansions.get(&call)
+    }
+}
+
+/// The expansion of a macro invocation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacroExpansion {
+    name: String,
+    green: GreenNode,
+    /// The range of the invocation in the file.
+    call: TextRange,
+    /// The ranges of the non-trivia tokens in the expansion, paired with the
+    /// ranges of the file text they are copied from.
+    token_map: Vec<(TextRange, TextRange)>,
+}
+
+impl MacroExpansion {
+    /// Returns the name of the invoked macro.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the range of the invocation in the file.
+    pub fn call_range(&self) -> TextRange {
+        self.call
+    }
+
+    /// Returns the root of the syntax tree of the expansion.
+    pub fn syntax(&self) -> SyntaxNode {
+        SyntaxNode::new_root(self.green.clone())
+    }
+
+    /// Maps `range` of the expansion to a range of the file.
+    ///
+    /// If all the tokens in `range` are copied from the arguments of the
+    /// invocation, the range covering them is returned as is. Otherwise, the
+    /// returned range points to the invocation, and the span kind points to
+    /// the part of the macro definition the tokens are copied from.
+    pub(crate) fn map_range(&self, range: TextRange) -> (TextRange, SpanKind) {
+        let start = self
+            .token_map
+            .partition_point(|(expanded, _)| expanded.end() <= range.start());
+
+        let mut from_call: Option<TextRange> = None;
+        let mut from_def: Option<TextRange> = None;
+        for &(_, origin) in self.token_map[start..]
+            .iter()
+            .take_while(|(expanded, _)| expanded.start() < range.end())
+        {
+            let cover = if self.call.contains_range(origin) {
+                &mut from_call
+            } else {
+                &mut from_def
+            };
+            *cover = Some(cover.map_or(origin, |cover| cover.cover(origin)));
+        }
+
+        match (from_call, from_def) {
+            (Some(range), None) => (range, SpanKind::Original),
+            (_, def) => (self.call, SpanKind::Expanded { def }),
+        }
+    }
+}
+
+/// Expands all the macro invocations in the file of `root`.
+pub(crate) fn expand_file(root: &SyntaxNode) -> (MacroExpansions, Vec<ParseError>) {
+    let mut expander = Expander::default();
+    for def in root.descendants().filter_map(ast::MacroDef::cast) {
+        expander.add_def(def);
+    }
+
+    let mut expansions = MacroExpansions::default();
+    for call in root.descendants().filter_map(ast::MacroCall::cast) {
+        if let Some(expansion) = expander.expand_top_call(&call) {
+            expansions.expansions.insert(expansion.call, expansion);
+        }
+    }
+
+    (expansions, expander.errors)
+}
+
+#[derive(Default)]
+struct Expander {
+    defs: FxHashMap<String, Vec<MacroRule>>,
+    errors: Vec<ParseError>,
+    /// The number of the rule applications so far, which is used to make the
+    /// hygienic bindings of each application distinct.
+    applications: usize,
+}
+
+impl Expander {
+    fn add_def(&mut self, def: ast::MacroDef) {
+        let Some(name) = def.name() else {
+            return;
+        };
+        if self.defs.contains_key(name.text()) {
+            self.error(
+                format!("the macro `{}` is defined multiple times", name.text()),
+                name.text_range(),
+            );
+            return;
+        }
+
+        let rules = def
+            .rules()
+            .into_iter()
+            .flatten()
+            .filter_map(|rule| self.lower_rule(rule))
+            .collect();
+        self.defs.insert(name.text().to_string(), rules);
+    }
+
+    fn lower_rule(&mut self, rule: ast::MacroRule) -> Option<MacroRule> {
+        let matcher = token_tree_contents(&rule.matcher()?);
+        let matcher: Vec<_> = matcher.into_iter().filter(|t| !t.is_trivia()).collect();
+        let matcher = self.parse_matcher(&matcher)?;
+
+        let transcriber = token_tree_contents(&rule.transcriber()?);
+        let hygienic = hygienic_tokens(&transcriber);
+        let transcriber = self.parse_transcriber(&transcriber, &hygienic)?;
+
+        let mut vars = FxHashMap::default();
+        collect_matcher_vars(&matcher, 0, &mut vars);
+        if !self.check_transcriber_vars(&transcriber, &vars) {
+            return None;
+        }
+
+        Some(MacroRule {
+            matcher,
+            transcriber,
+        })
+    }
+
+    /// Parses the non-trivia `tokens` of a matcher.
+    fn parse_matcher(&mut self, tokens: &[Token]) -> Option<Vec<MatcherElem>> {
+        let mut elems = vec![];
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = &tokens[i];
+            if token.kind != SyntaxKind::Dollar {
+                elems.push(MatcherElem::Token(token.clone()));
+                i += 1;
+                continue;
+            }
+
+            match tokens.get(i + 1) {
+                Some(name) if name.kind == SyntaxKind::Ident => {
+                    let spec = match (tokens.get(i + 2), tokens.get(i + 3)) {
+                        (Some(colon), Some(spec)) if colon.kind == SyntaxKind::Colon => spec,
+                        _ => {
+                            self.error(
+                                format!("missing fragment specifier for `${}`", name.text),
+                                name.origin,
+                            );
+                            return None;
+                        }
+                    };
+                    let Some(kind) = FragmentKind::from_name(&spec.text) else {
+                        self.error(
+                            format!("unknown fragment specifier `{}`", spec.text),
+                            spec.origin,
+                        );
+                        return None;
+                    };
+                    elems.push(MatcherElem::Fragment {
+                        name: name.text.clone(),
+                        kind,
+                    });
+                    i += 4;
+                }
+
+                Some(open) if open.kind == SyntaxKind::LParen => {
+                    let close = matching_close(tokens, i + 1)?;
+                    let inner = self.parse_matcher(&tokens[i + 2..close])?;
+                    let (sep, op, next) = self.parse_repetition_op(tokens, close + 1, token)?;
+                    elems.push(MatcherElem::Repeat(Repetition {
+                        elems: inner,
+                        sep,
+                        op,
+                    }));
+                    i = next;
+                }
+
+                _ => {
+                    self.error(
+                        "expected a macro variable or `$(` after `$`".to_string(),
+                        token.origin,
+                    );
+                    return None;
+                }
+            }
+        }
+
+        Some(elems)
+    }
+
+    /// Parses `tokens` of a transcriber, including trivia tokens.
+    /// `hygienic[i]` is `true` if `tokens[i]` is a binding to be renamed.
+    fn parse_transcriber(
+        &mut self,
+        tokens: &[Token],
+        hygienic: &[bool],
+    ) -> Option<Vec<TranscriberElem>> {
+        let mut elems = vec![];
+        let mut i = 0;
+        while i < tokens.len() {
+            let token = &tokens[i];
+            if token.kind != SyntaxKind::Dollar {
+                elems.push(TranscriberElem::Token {
+                    token: token.clone(),
+                    hygienic: hygienic[i],
+                });
+                i += 1;
+                continue;
+            }
+
+            let next = next_non_trivia(tokens, i + 1);
+            match next.map(|next| (next, &tokens[next])) {
+                Some((next, name)) if name.kind == SyntaxKind::Ident => {
+                    elems.push(TranscriberElem::Var {
+                        name: name.text.clone(),
+                        origin: token.origin.cover(name.origin),
+                    });
+                    i = next + 1;
+                }
+
+                Some((next, open)) if open.kind == SyntaxKind::LParen => {
+                    let close = matching_close(tokens, next)?;
+                    let inner =
+                        self.parse_transcriber(&tokens[next + 1..close], &hygienic[next + 1..])?;
+                    let (sep, op, next) = self.parse_repetition_op(tokens, close + 1, token)?;
+                    elems.push(TranscriberElem::Repeat(Repetition {
+                        elems: inner,
+                        sep,
+                        op,
+                    }));
+                    i = next;
+                }
+
+                _ => {
+                    self.error(
+                        "expected a macro variable or `$(` after `$`".to_string(),
+                        token.origin,
+                    );
+                    return None;
+                }
+            }
+        }
+
+        Some(elems)
+    }
+
+    /// Parses the optional separator and the operator of a repetition
+    /// starting at `tokens[i]`. Returns the separator, the operator and the
+    /// index of the token following the operator.
+    fn parse_repetition_op(
+        &mut self,
+        tokens: &[Token],
+        i: usize,
+        dollar: &Token,
+    ) -> Option<(Option<Token>, RepeatOp, usize)> {
+        let first = next_non_trivia(tokens, i);
+        if let Some(op) = first.and_then(|first| RepeatOp::from_token(&tokens[first])) {
+            return Some((None, op, first.unwrap() + 1));
+        }
+
+        let second = first.and_then(|first| next_non_trivia(tokens, first + 1));
+        match (first, second) {
+            (Some(first), Some(second)) if tokens[first].kind != SyntaxKind::Dollar => {
+                if let Some(op) = RepeatOp::from_token(&tokens[second]) {
+                    return Some((Some(tokens[first].clone()), op, second + 1));
+                }
+            }
+            _ => {}
+        }
+
+        self.error(
+            "expected one of `*`, `+` or `?` after the repetition".to_string(),
+            dollar.origin,
+        );
+        None
+    }
+
+    /// Checks that all the variables in the transcriber are bound in the
+    /// matcher.
+    fn check_transcriber_vars(
+        &mut self,
+        elems: &[TranscriberElem],
+        vars: &FxHashMap<String, usize>,
+    ) -> bool {
+        let mut ok = true;
+        for elem in elems {
+            match elem {
+                TranscriberElem::Token { .. } => {}
+                TranscriberElem::Var { name, origin } => {
+                    if !vars.contains_key(name) {
+                        self.error(format!("unknown macro variable `${name}`"), *origin);
+                        ok = false;
+                    }
+                }
+                TranscriberElem::Repeat(rep) => {
+                    ok &= self.check_transcriber_vars(&rep.elems, vars);
+                }
+            }
+        }
+        ok
+    }
+
+    /// Expands an invocation in the file, and parses the expansion.
+    fn expand_top_call(&mut self, call: &ast::MacroCall) -> Option<MacroExpansion> {
+        let name = call.name()?;
+        let args = token_tree_contents(&call.token_tree()?);
+        let call_range = call.syntax().text_range();
+        let kind = if call
+            .syntax()
+            .parent()
+            .is_some_and(|parent| parent.kind() == SyntaxKind::Item)
+        {
+            ExpansionKind::Items
+        } else {
+            ExpansionKind::Expr
+        };
+
+        let tokens = match self.expand_call(name.text(), &args, kind, 0) {
+            Ok(tokens) => tokens,
+            Err(msg) => {
+                self.error(msg, call_range);
+                return None;
+            }
+        };
+
+        let mut token_map = vec![];
+        let mut offset = TextSize::from(0);
+        for token in &tokens {
+            let range = TextRange::at(offset, TextSize::of(token.text.as_str()));
+            if !token.is_trivia() {
+                token_map.push((range, token.origin));
+            }
+            offset = range.end();
+        }
+
+        let stream = ExpandedTokenStream(tokens.into_iter().peekable());
+        let (green, parse_errors) = match kind {
+            ExpansionKind::Items => parser::parse_expanded_items(stream),
+            ExpansionKind::Expr => parser::parse_expanded_expr(stream),
+        };
+
+        let expansion = MacroExpansion {
+            name: name.text().to_string(),
+            green,
+            call: call_range,
+            token_map,
+        };
+
+        for error in parse_errors {
+            let (range, kind) = expansion.map_range(error.range());
+            let range = match kind {
+                SpanKind::Original => range,
+                _ => call_range,
+            };
+            let msg = format!(
+                "{} in the expansion of `{}!`",
+                error.msg(),
+                expansion.name()
+            );
+            self.error(msg, range);
+        }
+
+        Some(expansion)
+    }
+
+    /// Expands an invocation of the macro `name` with `args`, including the
+    /// invocations in the result.
+    fn expand_call(
+        &mut self,
+        name: &str,
+        args: &[Token],
+        kind: ExpansionKind,
+        depth: usize,
+    ) -> Result<Vec<Token>, String> {
+        if depth > RECURSION_LIMIT {
+            return Err(format!("recursion limit reached while expanding `{name}!`"));
+        }
+        let Some(rules) = self.defs.get(name) else {
+            return Err(format!("cannot find macro `{name}` in this file"));
+        };
+
+        let Some((rule, bindings)) = rules.iter().find_map(|rule| {
+            let mut bindings = Bindings::default();
+            let mut pos = 0;
+            (match_elems(&rule.matcher, args, &mut pos, None, &mut bindings)
+                && next_non_trivia(args, pos).is_none())
+            .then_some((rule, bindings))
+        }) else {
+            return Err(format!("no rules of macro `{name}` match this invocation"));
+        };
+
+        let transcriber = rule.transcriber.clone();
+        self.applications += 1;
+        let mut tokens = vec![];
+        transcribe(
+            &transcriber,
+            &bindings,
+            self.applications,
+            &mut vec![],
+            &mut tokens,
+        )?;
+
+        self.expand_nested_calls(tokens, kind, depth)
+    }
+
+    /// Expands the invocations in `tokens` produced by an expansion.
+    fn expand_nested_calls(
+        &mut self,
+        tokens: Vec<Token>,
+        kind: ExpansionKind,
+        depth: usize,
+    ) -> Result<Vec<Token>, String> {
+        let mut expanded = Vec::with_capacity(tokens.len());
+        let mut nesting = 0_usize;
+        let mut i = 0;
+        while i < tokens.len() {
+            let Some((bang, open)) = macro_call_head(&tokens, i) else {
+                let token = &tokens[i];
+                if token.is_open_delim() {
+                    nesting += 1;
+                } else if token.is_close_delim() {
+                    nesting = nesting.saturating_sub(1);
+                }
+                expanded.push(token.clone());
+                i += 1;
+                continue;
+            };
+
+            let Some(close) = matching_close(&tokens, open) else {
+                return Err("unclosed delimiter in a macro expansion".to_string());
+            };
+            let name = &tokens[i];
+            // An invocation directly in the item list of an item expansion
+            // expands to items. Otherwise it expands to an expression, which
+            // is parenthesized to keep the precedence.
+            let nested_kind = if kind == ExpansionKind::Items && nesting == 0 {
+                ExpansionKind::Items
+            } else {
+                ExpansionKind::Expr
+            };
+            let result =
+                self.expand_call(&name.text, &tokens[open + 1..close], nested_kind, depth + 1)?;
+
+            let origin = name.origin.cover(tokens[bang].origin);
+            if nested_kind == ExpansionKind::Expr {
+                expanded.push(Token::new(SyntaxKind::LParen, "(", origin));
+                expanded.extend(result);
+                expanded.push(Token::new(SyntaxKind::RParen, ")", origin));
+            } else {
+                expanded.extend(result);
+            }
+            i = close + 1;
+        }
+
+        Ok(expanded)
+    }
+
+    fn error(&mut self, msg: String, range: TextRange) {
+        self.errors.push(ParseError::Msg(msg, range));
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ExpansionKind {
+    Items,
+    Expr,
+}
+
+struct MacroRule {
+    matcher: Vec<MatcherElem>,
+    transcriber: Vec<TranscriberElem>,
+}
+
+#[derive(Debug, Clone)]
+enum MatcherElem {
+    Token(Token),
+    Fragment { name: String, kind: FragmentKind },
+    Repeat(Repetition<MatcherElem>),
+}
+
+impl MatcherElem {
+    /// Returns the token the element starts with, if it's fixed.
+    fn first_token(&self) -> Option<&Token> {
+        match self {
+            Self::Token(token) => Some(token),
+            Self::Repeat(rep) => rep.elems.first().and_then(Self::first_token),
+            Self::Fragment { .. } => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TranscriberElem {
+    Token { token: Token, hygienic: bool },
+    Var { name: String, origin: TextRange },
+    Repeat(Repetition<TranscriberElem>),
+}
+
+/// `$( elems ) sep op`
+#[derive(Debug, Clone)]
+struct Repetition<T> {
+    elems: Vec<T>,
+    sep: Option<Token>,
+    op: RepeatOp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RepeatOp {
+    /// `*`
+    ZeroOrMore,
+    /// `+`
+    OneOrMore,
+    /// `?`
+    ZeroOrOne,
+}
+
+impl RepeatOp {
+    fn from_token(token: &Token) -> Option<Self> {
+        match (token.kind, token.text.as_str()) {
+            (SyntaxKind::Star, _) => Some(Self::ZeroOrMore),
+            (SyntaxKind::Plus, _) => Some(Self::OneOrMore),
+            (SyntaxKind::InvalidToken, "?") => Some(Self::ZeroOrOne),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FragmentKind {
+    Ident,
+    Lit,
+    Tt,
+    Block,
+    Expr,
+    Ty,
+    Path,
+    Pat,
+}
+
+impl FragmentKind {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ident" => Some(Self::Ident),
+            "lit" => Some(Self::Lit),
+            "tt" => Some(Self::Tt),
+            "block" => Some(Self::Block),
+            "expr" => Some(Self::Expr),
+            "ty" => Some(Self::Ty),
+            "path" => Some(Self::Path),
+            "pat" => Some(Self::Pat),
+            _ => None,
+        }
+    }
+}
+
+/// The fragments captured by a matcher.
+type Bindings = FxHashMap<String, Binding>;
+
+#[derive(Debug, Clone)]
+enum Binding {
+    Fragment(FragmentKind, Vec<Token>),
+    /// The bindings of each iteration of a repetition.
+    Seq(Vec<Binding>),
+}
+
+/// Matches `input[pos..]` against `elems`, and advances `pos` past the matched
+/// tokens. `follow` is the token following `elems` in the enclosing matcher.
+fn match_elems(
+    elems: &[MatcherElem],
+    input: &[Token],
+    pos: &mut usize,
+    follow: Option<&Token>,
+    bindings: &mut Bindings,
+) -> bool {
+    for (i, elem) in elems.iter().enumerate() {
+        let next_follow = match elems.get(i + 1) {
+            Some(elem) => elem.first_token(),
+            None => follow,
+        };
+
+        match elem {
+            MatcherElem::Token(expected) => match next_non_trivia(input, *pos) {
+                Some(next) if input[next].is_same(expected) => *pos = next + 1,
+                _ => return false,
+            },
+
+            MatcherElem::Fragment { name, kind } => {
+                let Some(tokens) = capture_fragment(*kind, input, pos, next_follow) else {
+                    return false;
+                };
+                bindings.insert(name.clone(), Binding::Fragment(*kind, tokens));
+            }
+
+            MatcherElem::Repeat(rep) => {
+                let inner_follow = rep
+                    .sep
+                    .as_ref()
+                    .or_else(|| rep.elems.first().and_then(MatcherElem::first_token))
+                    .or(next_follow);
+                let mut iterations: Vec<Bindings> = vec![];
+                loop {
+                    let start = *pos;
+                    if let (Some(sep), false) = (&rep.sep, iterations.is_empty()) {
+                        match next_non_trivia(input, *pos) {
+                            Some(next) if input[next].is_same(sep) => *pos = next + 1,
+                            _ => break,
+                        }
+                    }
+
+                    let mut iteration = Bindings::default();
+                    if !match_elems(&rep.elems, input, pos, inner_follow, &mut iteration)
+                        || *pos == start
+                    {
+                        *pos = start;
+                        break;
+                    }
+                    iterations.push(iteration);
+                    if rep.op == RepeatOp::ZeroOrOne {
+                        break;
+                    }
+                }
+
+                if rep.op == RepeatOp::OneOrMore && iterations.is_empty() {
+                    return false;
+                }
+
+                let mut vars = FxHashMap::default();
+                collect_matcher_vars(&rep.elems, 0, &mut vars);
+                for var in vars.into_keys() {
+                    let seq = iterations
+                        .iter_mut()
+                        .filter_map(|iteration| iteration.remove(&var))
+                        .collect();
+                    bindings.insert(var, Binding::Seq(seq));
+                }
+            }
+        }
+    }
+
+    true
+}
+
+/// Captures a fragment of `kind` starting at `input[pos]`, and advances `pos`
+/// past the fragment.
+fn capture_fragment(
+    kind: FragmentKind,
+    input: &[Token],
+    pos: &mut usize,
+    follow: Option<&Token>,
+) -> Option<Vec<Token>> {
+    let start = next_non_trivia(input, *pos)?;
+    let first = &input[start];
+
+    let end = match kind {
+        FragmentKind::Ident => (first.kind == SyntaxKind::Ident).then_some(start)?,
+        FragmentKind::Lit => first.kind.is_literal_leaf().then_some(start)?,
+        FragmentKind::Tt if first.is_open_delim() => matching_close(input, start)?,
+        FragmentKind::Tt => (!first.is_close_delim()).then_some(start)?,
+        FragmentKind::Block if first.kind == SyntaxKind::LBrace => matching_close(input, start)?,
+        FragmentKind::Block => return None,
+
+        FragmentKind::Expr | FragmentKind::Ty | FragmentKind::Path | FragmentKind::Pat => {
+            // Generic arguments can contain the separator, e.g., `Map<K, V>`.
+            let angle_brackets = matches!(kind, FragmentKind::Ty | FragmentKind::Path);
+            let mut depth = 0_usize;
+            let mut last = None;
+            let mut i = start;
+            while i < input.len() {
+                let token = &input[i];
+                if depth == 0 && follow.is_some_and(|follow| token.is_same(follow)) {
+                    break;
+                }
+
+                if token.is_open_delim() || (angle_brackets && token.kind == SyntaxKind::Lt) {
+                    depth += 1;
+                } else if token.is_close_delim() || (angle_brackets && token.kind == SyntaxKind::Gt)
+                {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+
+                if !token.is_trivia() {
+                    last = Some(i);
+                }
+                i += 1;
+            }
+            last?
+        }
+    };
+
+    *pos = end + 1;
+    Some(input[start..=end].to_vec())
+}
+
+/// Instantiates the transcriber `elems` with `bindings`. `indices` are the
+/// indices of the iterations of the enclosing repetitions.
+fn transcribe(
+    elems: &[TranscriberElem],
+    bindings: &Bindings,
+    application: usize,
+    indices: &mut Vec<usize>,
+    out: &mut Vec<Token>,
+) -> Result<(), String> {
+    for elem in elems {
+        match elem {
+            TranscriberElem::Token { token, hygienic } => {
+                let mut token = token.clone();
+                if *hygienic {
+                    token.text = format!("{}#{application}", token.text);
+                }
+                out.push(token);
+            }
+
+            TranscriberElem::Var { name, .. } => {
+                let binding = lookup_binding(bindings, name, indices);
+                let Some(Binding::Fragment(kind, tokens)) = binding else {
+                    return Err(format!(
+                        "variable `${name}` is still repeating at this depth"
+                    ));
+                };
+
+                let is_compound = tokens.iter().filter(|t| !t.is_trivia()).nth(1).is_some();
+                if *kind == FragmentKind::Expr && is_compound {
+                    let origin = tokens[0].origin.cover(tokens[tokens.len() - 1].origin);
+                    out.push(Token::new(SyntaxKind::LParen, "(", origin));
+                    out.extend(tokens.iter().cloned());
+                    out.push(Token::new(SyntaxKind::RParen, ")", origin));
+                } else {
+                    out.extend(tokens.iter().cloned());
+                }
+            }
+
+            TranscriberElem::Repeat(rep) => {
+                let mut vars = FxHashSet::default();
+                collect_transcriber_vars(&rep.elems, &mut vars);
+                let mut count = None;
+                for var in vars {
+                    if let Some(Binding::Seq(seq)) = lookup_binding(bindings, var, indices) {
+                        if count.is_some_and(|count| count != seq.len()) {
+                            return Err("macro variables repeat inconsistently".to_string());
+                        }
+                        count = Some(seq.len());
+                    }
+                }
+                let Some(count) = count else {
+                    return Err(
+                        "a repetition in the macro transcriber has no repeating variables"
+                            .to_string(),
+                    );
+                };
+
+                for i in 0..count {
+                    if let (Some(sep), true) = (&rep.sep, i > 0) {
+                        out.push(sep.clone());
+                    }
+                    indices.push(i);
+                    transcribe(&rep.elems, bindings, application, indices, out)?;
+                    indices.pop();
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the binding of `name` in the iterations specified by `indices`.
+fn lookup_binding<'a>(
+    bindings: &'a Bindings,
+    name: &str,
+    indices: &[usize],
+) -> Option<&'a Binding> {
+    let mut binding = bindings.get(name)?;
+    for &i in indices {
+        match binding {
+            Binding::Seq(seq) => binding = seq.get(i)?,
+            Binding::Fragment(..) => break,
+        }
+    }
+    Some(binding)
+}
+
+/// Collects the variables in a matcher with their repetition depths.
+fn collect_matcher_vars(elems: &[MatcherElem], depth: usize, vars: &mut FxHashMap<String, usize>) {
+    for elem in elems {
+        match elem {
+            MatcherElem::Token(_) => {}
+            MatcherElem::Fragment { name, .. } => {
+                vars.insert(name.clone(), depth);
+            }
+            MatcherElem::Repeat(rep) => collect_matcher_vars(&rep.elems, depth + 1, vars),
+        }
+    }
+}
+
+fn collect_transcriber_vars<'a>(elems: &'a [TranscriberElem], vars: &mut FxHashSet<&'a str>) {
+    for elem in elems {
+        match elem {
+            TranscriberElem::Token { .. } => {}
+            TranscriberElem::Var { name, .. } => {
+                vars.insert(name);
+            }
+            TranscriberElem::Repeat(rep) => collect_transcriber_vars(&rep.elems, vars),
+        }
+    }
+}
+
+/// Returns a flag for each token of a transcriber which is `true` if the
+/// token is a binding introduced by `let` or `for`, or a use of such a
+/// binding, written literally in the transcriber.
+fn hygienic_tokens(tokens: &[Token]) -> Vec<bool> {
+    let significant: Vec<usize> = (0..tokens.len())
+        .filter(|&i| !tokens[i].is_trivia())
+        .collect();
+    let kind_at = |j: usize| significant.get(j).map(|&i| tokens[i].kind);
+    let is_var = |j: usize| j > 0 && kind_at(j - 1) == Some(SyntaxKind::Dollar);
+
+    // Collects the names bound by the patterns of `let` and `for`.
+    let mut bound = FxHashSet::default();
+    for (j, &i) in significant.iter().enumerate() {
+        if !matches!(tokens[i].kind, SyntaxKind::LetKw | SyntaxKind::ForKw) {
+            continue;
+        }
+        for k in j + 1.. {
+            match kind_at(k) {
+                Some(SyntaxKind::Ident) if !is_var(k) => {
+                    let is_path = matches!(
+                        kind_at(k + 1),
+                        Some(SyntaxKind::Colon2 | SyntaxKind::LParen | SyntaxKind::LBrace)
+                    ) || kind_at(k - 1) == Some(SyntaxKind::Colon2);
+                    let text = &tokens[significant[k]].text;
+                    if !is_path && !text.starts_with(|c: char| c.is_ascii_uppercase()) {
+                        bound.insert(text.clone());
+                    }
+                }
+                Some(
+                    SyntaxKind::MutKw
+                    | SyntaxKind::LParen
+                    | SyntaxKind::RParen
+                    | SyntaxKind::LBracket
+                    | SyntaxKind::RBracket
+                    | SyntaxKind::Comma
+                    | SyntaxKind::Underscore
+                    | SyntaxKind::Dot2
+                    | SyntaxKind::Colon2,
+                ) => {}
+                _ => break,
+            }
+        }
+    }
+
+    let mut hygienic = vec![false; tokens.len()];
+    for (j, &i) in significant.iter().enumerate() {
+        let token = &tokens[i];
+        if token.kind != SyntaxKind::Ident || is_var(j) || !bound.contains(&token.text) {
+            continue;
+        }
+        let prev = j.checked_sub(1).and_then(kind_at);
+        let next = kind_at(j + 1);
+        // Excludes field accesses, argument labels and field labels.
+        let is_label = next == Some(SyntaxKind::Colon)
+            && matches!(
+                prev,
+                Some(SyntaxKind::LParen | SyntaxKind::LBrace | SyntaxKind::Comma)
+            );
+        hygienic[i] = prev != Some(SyntaxKind::Dot) && !is_label;
+    }
+    hygienic
+}
+
+/// Returns the tokens in `tree` excluding its delimiters.
+fn token_tree_contents(tree: &ast::TokenTree) -> Vec<Token> {
+    let mut tokens: Vec<_> = tree
+        .syntax()
+        .descendants_with_tokens()
+        .filter_map(|element| element.into_token())
+        .map(|token| Token::from_syntax(&token))
+        .collect();
+    if tree.r_delim().is_some() {
+        tokens.pop();
+    }
+    if !tokens.is_empty() {
+        tokens.remove(0);
+    }
+    tokens
+}
+
+/// Returns the indices of `!` and the opening delimiter if `tokens[i]` is the
+/// head of a macro invocation, i.e., `foo!(`.
+fn macro_call_head(tokens: &[Token], i: usize) -> Option<(usize, usize)> {
+    if tokens[i].kind != SyntaxKind::Ident {
+        return None;
+    }
+    let bang = i + 1;
+    let open = bang + 1;
+    (tokens.get(bang)?.kind == SyntaxKind::Not && tokens.get(open)?.is_open_delim())
+        .then_some((bang, open))
+}
+
+/// Returns the index of the delimiter closing `tokens[open]`.
+fn matching_close(tokens: &[Token], open: usize) -> Option<usize> {
+    let mut depth = 0_usize;
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        if token.is_open_delim() {
+            depth += 1;
+        } else if token.is_close_delim() {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+fn next_non_trivia(tokens: &[Token], start: usize) -> Option<usize> {
+    (start..tokens.len()).find(|&i| !tokens[i].is_trivia())
+}
+
+/// A token of a macro definition, an invocation or an expansion.
+#[derive(Debug, Clone)]
+struct Token {
+    kind: SyntaxKind,
+    text: String,
+    /// The range of the file text the token is copied from.
+    origin: TextRange,
+}
+
+impl Token {
+    fn new(kind: SyntaxKind, text: &str, origin: TextRange) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            origin,
+        }
+    }
+
+    fn from_syntax(token: &SyntaxToken) -> Self {
+        Self::new(token.kind(), token.text(), token.text_range())
+    }
+
+    /// Returns `true` if the token is ignored in matching.
+    fn is_trivia(&self) -> bool {
+        self.kind.is_trivia() || self.kind == SyntaxKind::Newline
+    }
+
+    fn is_same(&self, other: &Token) -> bool {
+        self.kind == other.kind && self.text == other.text
+    }
+
+    fn is_open_delim(&self) -> bool {
+        matches!(
+            self.kind,
+            SyntaxKind::LParen | SyntaxKind::LBracket | SyntaxKind::LBrace
+        )
+    }
+
+    fn is_close_delim(&self) -> bool {
+        matches!(
+            self.kind,
+            SyntaxKind::RParen | SyntaxKind::RBracket | SyntaxKind::RBrace
+        )
+    }
+}
+
+impl LexicalToken for Token {
+    fn syntax_kind(&self) -> SyntaxKind {
+        self.kind
+    }
+
+    fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+struct ExpandedTokenStream(std::iter::Peekable<std::vec::IntoIter<Token>>);
+
+impl TokenStream for ExpandedTokenStream {
+    type Token = Token;
+
+    fn next(&mut self) -> Option<Self::Token> {
+        self.0.next()
+    }
+
+    fn peek(&mut self) -> Option<&Self::Token> {
+        self.0.peek()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the expansions with whitespaces removed, and the errors.
+    fn expand(source: &str) -> (Vec<String>, Vec<String>) {
+        let (green, errors) = parser::parse_source_file(source);
+        assert!(errors.is_empty(), "{errors:?}");
+        let root = SyntaxNode::new_root(green);
+        let (expansions, errors) = expand_file(&root);
+
+        let mut expansions: Vec<_> = expansions.expansions.into_values().collect();
+        expansions.sort_by_key(|expansion| expansion.call.start());
+        (
+            expansions
+                .iter()
+                .map(|expansion| {
+                    let mut text = expansion.syntax().to_string();
+                    text.retain(|c| !c.is_whitespace());
+                    text
+                })
+                .collect(),
+            errors.iter().map(|error| error.msg()).collect(),
+        )
+    }
+
+    #[test]
+    fn repetition() {
+        let (expansions, errors) = expand(
+            r#"
+macro sum {
+    () => { 0 }
+    ($x:expr $(, $rest:expr)*) => { $x + sum!($($rest),*) }
+}
+fn foo() -> u8 {
+    sum!(1, 2 * 3, x)
+}
+"#,
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(expansions, ["1+(((2*3))+(x+(0)))"]);
+    }
+
+    #[test]
+    fn hygiene() {
+        let (expansions, errors) = expand(
+            r#"
+macro square {
+    ($x:expr) => {{
+        let y = $x
+        y * y
+    }}
+}
+fn foo(y: u8) -> u8 {
+    square!(y + 1)
+}
+"#,
+        );
+        assert!(errors.is_empty(), "{errors:?}");
+        assert_eq!(expansions, ["{lety#1=(y+1)y#1*y#1}"]);
+    }
+
+    #[test]
+    fn errors() {
+        let (expansions, errors) = expand(
+            r#"
+macro one {
+    ($x:ident) => { $x }
+}
+macro rec {
+    () => { rec!() }
+}
+fn foo() {
+    one!(1)
+    two!()
+    rec!()
+}
+"#,
+        );
+        assert!(expansions.is_empty());
+        assert_eq!(
+            errors,
+            [
+                "no rules of macro `one` match this invocation",
+                "cannot find macro `two` in this file",
+                "recursion limit reached while expanding `rec!`",
+            ]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use common::{file::File, ingot::Ingot};
 use num_bigint::BigUint;
 use num_traits::Num;
 use parser::{
-    ast::{self, prelude::*},
+    ast::{self, prelude::*, AstPtr, SyntaxNodePtr},
     SyntaxNode, SyntaxToken,
 };
 
@@ -12,16 +12,20 @@ use crate::{
         module_tree_impl, scope_graph::ScopeGraph, ExprId, IdentId, IntegerId, ItemKind, LitKind,
         ModuleTree, Partial, StringId, TopLevelMod, TrackedItemId, TrackedItemVariant,
     },
+    span::{HirOrigin, MacroOrigin},
     HirDb, LowerHirDb,
 };
-pub use parse::parse_file_impl;
+pub use macro_expand::{MacroExpansion, MacroExpansions};
+pub use parse::{expand_macros_impl, parse_file_impl};
 
 pub(crate) mod parse;
 
 mod attr;
 mod body;
+mod derive;
 mod expr;
 mod item;
+mod macro_expand;
 mod params;
 mod pat;
 mod path;
@@ -92,15 +96,63 @@ pub(crate) fn top_mod_ast(db: &dyn HirDb, top_mod: TopLevelMod) -> ast::Root {
 
 pub(super) struct FileLowerCtxt<'db> {
     builder: ScopeGraphBuilder<'db>,
+    /// The macro invocation whose expansion is being lowered.
+    expansion: Option<AstPtr<ast::MacroCall>>,
 }
 
 impl<'db> FileLowerCtxt<'db> {
     pub(super) fn enter_top_mod(db: &'db dyn HirDb, top_mod: TopLevelMod<'db>) -> Self {
         Self {
             builder: ScopeGraphBuilder::enter_top_mod(db, top_mod),
+            expansion: None,
         }
     }
 
+    /// Returns the origin of the HIR node lowered from `ast`, taking the
+    /// macro expansion being lowered into account.
+    pub(super) fn origin<T>(&self, ast: &T) -> HirOrigin<T>
+    where
+        T: AstNode<Language = parser::FeLang>,
+    {
+        match &self.expansion {
+            Some(call) => HirOrigin::Macro(MacroOrigin {
+                call: call.clone(),
+                node: SyntaxNodePtr::new(ast.syntax()),
+            }),
+            None => HirOrigin::raw(ast),
+        }
+    }
+
+    /// Returns the pointer to the node a generated HIR node is expanded from.
+    /// In a macro expansion, this is the macro invocation since the nodes of
+    /// the expansion are not part of the file.
+    pub(super) fn expansion_site(&self, node: &SyntaxNode) -> SyntaxNodePtr {
+        match &self.expansion {
+            Some(call) => call.syntax_node_ptr(),
+            None => SyntaxNodePtr::new(node),
+        }
+    }
+
+    /// Starts lowering the expansion of `call`, and returns the root of the
+    /// expansion. Returns `None` if the invocation couldn't be expanded.
+    ///
+    /// Invocations in an expansion are already expanded in place, so this
+    /// also returns `None` if an expansion is being lowered.
+    pub(super) fn enter_expansion(&mut self, call: &ast::MacroCall) -> Option<ast::Root> {
+        if self.expansion.is_some() {
+            return None;
+        }
+
+        let expansions = expand_macros_impl(self.db(), self.top_mod());
+        let root = ast::Root::cast(expansions.get(call)?.syntax())?;
+        self.expansion = Some(AstPtr::new(call));
+        Some(root)
+    }
+
+    pub(super) fn leave_expansion(&mut self) {
+        self.expansion = None;
+    }
+
     pub(super) fn build(self) -> ScopeGraph<'db> {
         self.builder.build()
     }
@@ -178,7 +178,7 @@ impl<'db> WherePredicate<'db> {
 }
 
 impl<'db> TypeBound<'db> {
-    fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: ast::TypeBound) -> Self {
+    pub(super) fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: ast::TypeBound) -> Self {
         if let Some(trait_bound) = ast.trait_bound() {
             Self::Trait(TraitRefId::lower_ast(ctxt, trait_bound))
         } else {
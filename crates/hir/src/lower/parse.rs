@@ -1,7 +1,11 @@
 use common::file::File;
-use parser::GreenNode;
+use parser::{ast::prelude::*, GreenNode};
 use salsa::Accumulator;
 
+use super::{
+    macro_expand::{expand_file, MacroExpansions},
+    top_mod_ast,
+};
 use crate::{hir_def::TopLevelMod, HirDb};
 
 #[salsa::tracked]
@@ -16,9 +20,31 @@ pub fn parse_file_impl<'db>(db: &'db dyn HirDb, top_mod: TopLevelMod<'db>) -> Gr
     node
 }
 
+/// Expands the macro invocations in the given top-level module.
+#[salsa::tracked(return_ref)]
+pub fn expand_macros_impl<'db>(db: &'db dyn HirDb, top_mod: TopLevelMod<'db>) -> MacroExpansions {
+    let file = top_mod.file(db);
+    let root = top_mod_ast(db, top_mod);
+    let (expansions, expand_errors) = expand_file(root.syntax());
+
+    for error in expand_errors {
+        MacroExpansionError { file, error }.accumulate(db);
+    }
+    expansions
+}
+
 #[salsa::accumulator]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ParserError {
     pub file: File,
     pub error: parser::ParseError,
 }
+
+/// An error in the expansion of a macro invocation, e.g. an invocation that
+/// matches none of the rules of the macro.
+#[salsa::accumulator]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroExpansionError {
+    pub file: File,
+    pub error: parser::ParseError,
+}
@@ -1,10 +1,7 @@
 use parser::ast;
 
 use super::body::BodyCtxt;
-use crate::{
-    hir_def::{pat::*, IdentId, LitKind, PathId},
-    span::HirOrigin,
-};
+use crate::hir_def::{pat::*, IdentId, LitKind, PathId};
 
 impl<'db> Pat<'db> {
     pub(super) fn lower_ast(ctxt: &mut BodyCtxt<'_, 'db>, ast: ast::Pat) -> PatId {
@@ -60,9 +57,34 @@ impl<'db> Pat<'db> {
                 let rhs = Self::lower_ast_opt(ctxt, or.rhs());
                 Pat::Or(lhs, rhs)
             }
+
+            ast::PatKind::Range(range) => {
+                let start = range
+                    .start()
+                    .and_then(|lit_pat| lit_pat.lit())
+                    .map(|lit| LitKind::lower_ast(ctxt.f_ctxt, lit))
+                    .into();
+                let end = range
+                    .end()
+                    .and_then(|lit_pat| lit_pat.lit())
+                    .map(|lit| LitKind::lower_ast(ctxt.f_ctxt, lit))
+                    .into();
+                Pat::Range(start, end)
+            }
+
+            ast::PatKind::Binding(binding) => {
+                let name = IdentId::lower_token_partial(ctxt.f_ctxt, binding.name());
+                let pat = Self::lower_ast_opt(ctxt, binding.pat());
+                Pat::Binding(name, binding.mut_token().is_some(), pat)
+            }
+
+            ast::PatKind::Array(array) => {
+                let elems = array.iter().map(|pat| Pat::lower_ast(ctxt, pat)).collect();
+                Pat::Array(elems)
+            }
         };
 
-        ctxt.push_pat(pat, HirOrigin::raw(&ast))
+        ctxt.push_pat(pat, ctxt.f_ctxt.origin(&ast))
     }
 
     pub(super) fn lower_ast_opt(ctxt: &mut BodyCtxt<'_, 'db>, ast: Option<ast::Pat>) -> PatId {
@@ -460,7 +460,7 @@ impl<'db> IntermediateScopeGraph<'db> {
         self.nodes.push((scope_id, scope_data))
     }
 
-    fn initialize_item_scope(&mut self, db: &dyn HirDb, node: NodeId, item: ItemKind<'db>) {
+    fn initialize_item_scope(&mut self, db: &'db dyn HirDb, node: NodeId, item: ItemKind<'db>) {
         let scope_id = ScopeId::Item(item);
 
         let scope_data = &mut self.nodes[node];
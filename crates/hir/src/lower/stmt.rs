@@ -1,10 +1,7 @@
 use parser::ast::{self, prelude::*};
 
 use super::body::BodyCtxt;
-use crate::{
-    hir_def::{stmt::*, Expr, Pat, TypeId},
-    span::HirOrigin,
-};
+use crate::hir_def::{stmt::*, Expr, IdentId, Pat, TypeId};
 
 impl<'db> Stmt<'db> {
     pub(super) fn push_to_body(ctxt: &mut BodyCtxt<'_, 'db>, ast: ast::Stmt) -> StmtId {
@@ -15,9 +12,13 @@ impl<'db> Stmt<'db> {
                     .type_annotation()
                     .map(|ty| TypeId::lower_ast(ctxt.f_ctxt, ty));
                 let init = let_.initializer().map(|init| Expr::lower_ast(ctxt, init));
-                (Stmt::Let(pat, ty, init), HirOrigin::raw(&ast))
+                let else_ = let_.else_block().map(|else_| Expr::lower_ast(ctxt, else_));
+                (Stmt::Let(pat, ty, init, else_), ctxt.f_ctxt.origin(&ast))
             }
             ast::StmtKind::For(for_) => {
+                let label = for_
+                    .label()
+                    .map(|label| IdentId::lower_token(ctxt.f_ctxt, label));
                 let bind = Pat::lower_ast_opt(ctxt, for_.pat());
                 let iter = Expr::push_to_body_opt(ctxt, for_.iterable());
                 let body = Expr::push_to_body_opt(
@@ -26,10 +27,14 @@ impl<'db> Stmt<'db> {
                         .and_then(|body| ast::Expr::cast(body.syntax().clone())),
                 );
 
-                (Stmt::For(bind, iter, body), HirOrigin::raw(&ast))
+                (Stmt::For(label, bind, iter, body), ctxt.f_ctxt.origin(&ast))
             }
 
             ast::StmtKind::While(while_) => {
+                let label = while_
+                    .label()
+                    .map(|label| IdentId::lower_token(ctxt.f_ctxt, label));
+                let pat = while_.pat().map(|pat| Pat::lower_ast(ctxt, pat));
                 let cond = Expr::push_to_body_opt(ctxt, while_.cond());
                 let body = Expr::push_to_body_opt(
                     ctxt,
@@ -38,23 +43,38 @@ impl<'db> Stmt<'db> {
                         .and_then(|body| ast::Expr::cast(body.syntax().clone())),
                 );
 
-                (Stmt::While(cond, body), HirOrigin::raw(&ast))
+                let stmt = match pat {
+                    Some(pat) => Stmt::WhileLet(label, pat, cond, body),
+                    None => Stmt::While(label, cond, body),
+                };
+                (stmt, ctxt.f_ctxt.origin(&ast))
             }
 
-            ast::StmtKind::Continue(_) => (Stmt::Continue, HirOrigin::raw(&ast)),
+            ast::StmtKind::Continue(continue_) => {
+                let label = continue_
+                    .label()
+                    .map(|label| IdentId::lower_token(ctxt.f_ctxt, label));
+                (Stmt::Continue(label), ctxt.f_ctxt.origin(&ast))
+            }
 
-            ast::StmtKind::Break(_) => (Stmt::Break, HirOrigin::raw(&ast)),
+            ast::StmtKind::Break(break_) => {
+                let label = break_
+                    .label()
+                    .map(|label| IdentId::lower_token(ctxt.f_ctxt, label));
+                let expr = break_.expr().map(|expr| Expr::lower_ast(ctxt, expr));
+                (Stmt::Break(label, expr), ctxt.f_ctxt.origin(&ast))
+            }
 
             ast::StmtKind::Return(ret) => {
                 let expr = ret
                     .has_value()
                     .then(|| Expr::push_to_body_opt(ctxt, ret.expr()));
-                (Stmt::Return(expr), HirOrigin::raw(&ast))
+                (Stmt::Return(expr), ctxt.f_ctxt.origin(&ast))
             }
 
             ast::StmtKind::Expr(expr) => {
                 let expr = Expr::push_to_body_opt(ctxt, expr.expr());
-                (Stmt::Expr(expr), HirOrigin::raw(&ast))
+                (Stmt::Expr(expr), ctxt.f_ctxt.origin(&ast))
             }
         };
 
@@ -2,7 +2,7 @@ use parser::ast::{self, prelude::*};
 
 use super::FileLowerCtxt;
 use crate::hir_def::{
-    Body, GenericArgListId, Partial, PathId, TraitRefId, TupleTypeId, TypeId, TypeKind,
+    Body, GenericArgListId, IdentId, Partial, PathId, TraitRefId, TupleTypeId, TypeId, TypeKind,
 };
 
 impl<'db> TypeId<'db> {
@@ -34,6 +34,13 @@ impl<'db> TypeId<'db> {
                 TypeKind::Array(elem_ty, body)
             }
 
+            ast::TypeKind::QualifiedPath(ty) => {
+                let self_ty = Self::lower_ast_partial(ctxt, ty.ty());
+                let trait_ref = TraitRefId::lower_ast_partial(ctxt, ty.trait_ref());
+                let name = IdentId::lower_token_partial(ctxt, ty.name());
+                TypeKind::QualifiedPath(self_ty, trait_ref, name)
+            }
+
             ast::TypeKind::Never(_) => TypeKind::Never,
         };
 
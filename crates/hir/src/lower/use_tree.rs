@@ -2,13 +2,13 @@ use parser::ast::{self, prelude::*};
 
 use super::FileLowerCtxt;
 use crate::{
-    hir_def::{use_tree::*, IdentId, ItemModifier, Partial, TrackedItemVariant, Use},
+    hir_def::{use_tree::*, IdentId, Partial, TrackedItemVariant, Use, Visibility},
     span::{HirOrigin, UseDesugared},
 };
 
 impl<'db> Use<'db> {
     pub(super) fn lower_ast(ctxt: &mut FileLowerCtxt<'db>, ast: ast::Use) -> Vec<Self> {
-        let vis = ItemModifier::lower_ast(ast.modifier()).to_visibility();
+        let vis = Visibility::lower_ast(ctxt, ast.modifier());
 
         let Some(use_tree) = ast.use_tree() else {
             let id = ctxt.joined_id(TrackedItemVariant::Use(Partial::Absent));
@@ -16,7 +16,7 @@ impl<'db> Use<'db> {
             let path = Partial::Absent;
             let alias = None;
             let top_mod = ctxt.top_mod();
-            let origin = HirOrigin::raw(&ast);
+            let origin = ctxt.origin(&ast);
             let use_ = Self::new(ctxt.db(), id, path, alias, vis, top_mod, origin);
             ctxt.leave_item_scope(use_);
             return vec![use_];
@@ -31,12 +31,15 @@ impl<'db> Use<'db> {
                 .alias()
                 .map(|alias| UseAlias::lower_ast_partial(ctxt, alias));
             let top_mod = ctxt.top_mod();
-            let origin = HirOrigin::raw(&ast);
+            let origin = ctxt.origin(&ast);
             let use_ = Self::new(ctxt.db(), id, path, alias, vis, top_mod, origin);
             ctxt.leave_item_scope(use_);
             return vec![use_];
         }
 
+        // The desugared origins point to the nodes of the file, so the uses in a
+        // macro expansion point to the whole `use` instead.
+        let macro_origin = ctxt.expansion.is_some().then(|| ctxt.origin(&ast));
         let decomposed_paths = decompose_tree(ctxt, ast, use_tree);
         decomposed_paths
             .into_iter()
@@ -44,7 +47,9 @@ impl<'db> Use<'db> {
                 let id = ctxt.joined_id(TrackedItemVariant::Use(path));
                 ctxt.enter_item_scope(id, false);
                 let top_mod = ctxt.top_mod();
-                let origin = HirOrigin::desugared(origin);
+                let origin = macro_origin
+                    .clone()
+                    .unwrap_or_else(|| HirOrigin::desugared(origin));
                 let use_ = Self::new(ctxt.db(), id, path, alias, vis, top_mod, origin);
                 ctxt.leave_item_scope(use_)
             })
@@ -61,6 +61,10 @@ impl<'db> LazyExprSpan<'db> {
     pub fn into_assign_expr(self) -> LazyAssignExprSpan<'db> {
         LazyAssignExprSpan(self.0)
     }
+
+    pub fn into_unsafe_expr(self) -> LazyUnsafeExprSpan<'db> {
+        LazyUnsafeExprSpan(self.0)
+    }
 }
 
 define_lazy_span_node! {
@@ -95,6 +99,14 @@ define_lazy_span_node!(
     }
 );
 
+define_lazy_span_node!(
+    LazyUnsafeExprSpan,
+    ast::UnsafeExpr,
+    @token {
+        (unsafe_kw, unsafe_kw),
+    }
+);
+
 define_lazy_span_node!(
     LazyUnExprSpan,
     ast::UnExpr,
@@ -1,9 +1,13 @@
-use parser::ast::{self, prelude::AstNode};
+use parser::ast::{
+    self,
+    prelude::{AstNode, ItemModifierOwner},
+};
 
 use super::{
     attr::LazyAttrListSpan,
     define_lazy_span_node,
     params::{LazyFuncParamListSpan, LazyGenericParamListSpan, LazyWhereClauseSpan},
+    path::LazyPathSpan,
     transition::SpanTransitionChain,
     types::{LazyTupleTypeSpan, LazyTySpan},
     use_tree::LazyUseAliasSpan,
@@ -14,7 +18,7 @@ use crate::{
         Trait, TypeAlias, Use,
     },
     span::{
-        params::LazyTraitRefSpan,
+        params::{LazyTraitRefSpan, LazyTypeBoundListSpan},
         transition::{LazyArg, LazyTransitionFn, ResolvedOrigin, ResolvedOriginKind},
         use_tree::LazyUsePathSpan,
         DesugaredOrigin, DesugaredUseFocus,
@@ -186,6 +190,29 @@ impl<'db> LazyTraitSpan<'db> {
     pub fn new(t: Trait<'db>) -> Self {
         Self(crate::span::transition::SpanTransitionChain::new(t))
     }
+
+    pub fn assoc_ty(mut self, idx: usize) -> LazyAssocTypeSpan<'db> {
+        fn f(origin: ResolvedOrigin, arg: LazyArg) -> ResolvedOrigin {
+            let idx = match arg {
+                LazyArg::Idx(idx) => idx,
+                _ => unreachable!(),
+            };
+            origin.map(|node| {
+                ast::Trait::cast(node)
+                    .and_then(|trait_| trait_.item_list())
+                    .and_then(|list| list.assoc_types().nth(idx))
+                    .map(|n| n.syntax().clone().into())
+            })
+        }
+
+        let lazy_transition = LazyTransitionFn {
+            f,
+            arg: LazyArg::Idx(idx),
+        };
+
+        self.0.push(lazy_transition);
+        LazyAssocTypeSpan(self.0)
+    }
 }
 
 define_lazy_span_node!(
@@ -211,8 +238,44 @@ impl<'db> LazyImplTraitSpan<'db> {
     pub fn new(i: ImplTrait<'db>) -> Self {
         Self(crate::span::transition::SpanTransitionChain::new(i))
     }
+
+    pub fn assoc_ty(mut self, idx: usize) -> LazyAssocTypeSpan<'db> {
+        fn f(origin: ResolvedOrigin, arg: LazyArg) -> ResolvedOrigin {
+            let idx = match arg {
+                LazyArg::Idx(idx) => idx,
+                _ => unreachable!(),
+            };
+            origin.map(|node| {
+                ast::ImplTrait::cast(node)
+                    .and_then(|impl_trait| impl_trait.item_list())
+                    .and_then(|list| list.assoc_types().nth(idx))
+                    .map(|n| n.syntax().clone().into())
+            })
+        }
+
+        let lazy_transition = LazyTransitionFn {
+            f,
+            arg: LazyArg::Idx(idx),
+        };
+
+        self.0.push(lazy_transition);
+        LazyAssocTypeSpan(self.0)
+    }
 }
 
+define_lazy_span_node!(
+    LazyAssocTypeSpan,
+    ast::AssocType,
+    @token {
+        (name, name),
+    }
+    @node {
+        (attributes, attr_list, LazyAttrListSpan),
+        (bounds, bounds, LazyTypeBoundListSpan),
+        (ty, ty, LazyTySpan),
+    }
+);
+
 define_lazy_span_node!(
     LazyConstSpan,
     ast::Const,
@@ -221,6 +284,7 @@ define_lazy_span_node!(
     }
     @node {
         (attributes, attr_list, LazyAttrListSpan),
+        (modifier, modifier, LazyItemModifierSpan),
         (ty, ty, LazyTySpan),
     }
 );
@@ -236,6 +300,35 @@ impl<'db> LazyUseSpan<'db> {
         Self(crate::span::transition::SpanTransitionChain::new(u))
     }
 
+    pub fn modifier(mut self) -> LazyItemModifierSpan<'db> {
+        fn f(origin: ResolvedOrigin, _: LazyArg) -> ResolvedOrigin {
+            origin
+                .map(|node| {
+                    ast::Use::cast(node)
+                        .and_then(|use_| use_.modifier())
+                        .map(|n| n.syntax().clone().into())
+                })
+                .map_desugared(|root, desugared| match desugared {
+                    // All uses desugared from a use tree share the modifier of the root.
+                    DesugaredOrigin::Use(use_) => use_
+                        .root
+                        .to_node(&root)
+                        .modifier()
+                        .map_or(ResolvedOriginKind::None, |n| {
+                            ResolvedOriginKind::Node(n.syntax().clone())
+                        }),
+                })
+        }
+
+        let lazy_transition = LazyTransitionFn {
+            f,
+            arg: LazyArg::None,
+        };
+
+        self.0.push(lazy_transition);
+        LazyItemModifierSpan(self.0)
+    }
+
     pub fn path(mut self) -> LazyUsePathSpan<'db> {
         fn f(origin: ResolvedOrigin, _: LazyArg) -> ResolvedOrigin {
             origin
@@ -313,6 +406,7 @@ define_lazy_span_node!(
     }
     @node {
         (attributes, attr_list, LazyAttrListSpan),
+        (vis_restriction, vis_restriction, LazyVisRestrictionSpan),
         (ty, ty, LazyTySpan),
     }
 );
@@ -345,6 +439,17 @@ define_lazy_span_node!(
         (pub_kw, pub_kw),
         (unsafe_kw, unsafe_kw),
     }
+    @node {
+        (vis_restriction, vis_restriction, LazyVisRestrictionSpan),
+    }
+);
+
+define_lazy_span_node!(
+    LazyVisRestrictionSpan,
+    ast::VisRestriction,
+    @node {
+        (path, path, LazyPathSpan),
+    }
 );
 
 #[cfg(test)]
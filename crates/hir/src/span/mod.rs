@@ -34,13 +34,14 @@ pub mod lazy_spans {
             LazyCallArgSpan, LazyCallExprSpan, LazyExprSpan, LazyFieldExprSpan, LazyFieldListSpan,
             LazyFieldSpan, LazyLitExprSpan, LazyMatchArmListSpan, LazyMatchArmSpan,
             LazyMatchExprSpan, LazyMethodCallExprSpan, LazyPathExprSpan, LazyRecordInitExprSpan,
-            LazyUnExprSpan,
+            LazyUnExprSpan, LazyUnsafeExprSpan,
         },
         item::{
-            LazyBodySpan, LazyConstSpan, LazyContractSpan, LazyEnumSpan, LazyFieldDefListSpan,
-            LazyFieldDefSpan, LazyFuncSpan, LazyImplSpan, LazyImplTraitSpan, LazyItemModifierSpan,
-            LazyItemSpan, LazyModSpan, LazyStructSpan, LazyTopModSpan, LazyTraitSpan,
-            LazyTypeAliasSpan, LazyUseSpan, LazyVariantDefListSpan, LazyVariantDefSpan,
+            LazyAssocTypeSpan, LazyBodySpan, LazyConstSpan, LazyContractSpan, LazyEnumSpan,
+            LazyFieldDefListSpan, LazyFieldDefSpan, LazyFuncSpan, LazyImplSpan, LazyImplTraitSpan,
+            LazyItemModifierSpan, LazyItemSpan, LazyModSpan, LazyStructSpan, LazyTopModSpan,
+            LazyTraitSpan, LazyTypeAliasSpan, LazyUseSpan, LazyVariantDefListSpan,
+            LazyVariantDefSpan,
         },
         params::{
             LazyConstGenericParamSpan, LazyFuncParamListSpan, LazyFuncParamSpan,
@@ -49,13 +50,15 @@ pub mod lazy_spans {
             LazyTypeBoundSpan, LazyTypeGenericArgSpan, LazyWhereClauseSpan, LazyWherePredicateSpan,
         },
         pat::{
-            LazyLitPatSpan, LazyPatSpan, LazyPathPatSpan, LazyPathTuplePatSpan,
-            LazyRecordPatFieldListSpan, LazyRecordPatFieldSpan, LazyRecordPatSpan,
+            LazyBindingPatSpan, LazyLitPatSpan, LazyPatSpan, LazyPathPatSpan, LazyPathTuplePatSpan,
+            LazyRangePatSpan, LazyRecordPatFieldListSpan, LazyRecordPatFieldSpan,
+            LazyRecordPatSpan,
         },
         path::{LazyPathSegmentSpan, LazyPathSpan},
-        stmt::{LazyLetStmtSpan, LazyStmtSpan},
+        stmt::{LazyBreakStmtSpan, LazyContinueStmtSpan, LazyLetStmtSpan, LazyStmtSpan},
         types::{
-            LazyArrayTypeSpan, LazyPathTypeSpan, LazyPtrTypeSpan, LazyTupleTypeSpan, LazyTySpan,
+            LazyArrayTypeSpan, LazyPathTypeSpan, LazyPtrTypeSpan, LazyQualifiedPathTypeSpan,
+            LazyTupleTypeSpan, LazyTySpan,
         },
         use_tree::{LazyUseAliasSpan, LazyUsePathSegmentSpan, LazyUsePathSpan},
         DynLazySpan, LazyLitSpan, LazySpan, LazySpanAtom,
@@ -189,6 +192,8 @@ where
     /// The HIR node is the result of desugaring in the lower phase from AST to
     /// HIR. e.g., `a += b` is desugared into `a = a + b`.
     Desugared(DesugaredOrigin),
+    /// The HIR node is lowered from the expansion of a macro invocation.
+    Macro(MacroOrigin),
 
     /// The HIR node is created by the compiler and not directly from the AST.
     /// This is only used with `Invalid` nodes that don't have a corresponding
@@ -220,6 +225,15 @@ where
     }
 }
 
+/// The origin of the HIR node lowered from a macro expansion.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroOrigin {
+    /// The macro invocation in the file.
+    pub call: AstPtr<ast::MacroCall>,
+    /// The node in the syntax tree of the expansion.
+    pub node: SyntaxNodePtr,
+}
+
 /// This enum represents the origin of the HIR node which is desugared into
 /// other HIR node kinds.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::From)]
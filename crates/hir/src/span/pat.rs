@@ -33,6 +33,14 @@ impl<'db> LazyPatSpan<'db> {
     pub fn into_record_pat(self) -> LazyRecordPatSpan<'db> {
         LazyRecordPatSpan(self.0)
     }
+
+    pub fn into_range_pat(self) -> LazyRangePatSpan<'db> {
+        LazyRangePatSpan(self.0)
+    }
+
+    pub fn into_binding_pat(self) -> LazyBindingPatSpan<'db> {
+        LazyBindingPatSpan(self.0)
+    }
 }
 
 define_lazy_span_node!(
@@ -72,6 +80,24 @@ define_lazy_span_node!(
     }
 );
 
+define_lazy_span_node!(
+    LazyRangePatSpan,
+    ast::RangePat,
+    @node {
+        (start, start, LazyLitPatSpan),
+        (end, end, LazyLitPatSpan),
+    }
+);
+
+define_lazy_span_node!(
+    LazyBindingPatSpan,
+    ast::BindingPat,
+    @token {
+        (name, name),
+        (mut_token, mut_token),
+    }
+);
+
 define_lazy_span_node!(
     LazyRecordPatFieldListSpan,
     ast::RecordPatFieldList,
@@ -7,7 +7,7 @@ use super::{
 };
 use crate::{
     hir_def::{Body, StmtId},
-    span::types::LazyTySpan,
+    span::{expr::LazyExprSpan, types::LazyTySpan},
     SpannedHirDb,
 };
 
@@ -21,6 +21,14 @@ impl<'db> LazyStmtSpan<'db> {
     pub fn into_let_stmt(self) -> LazyLetStmtSpan<'db> {
         LazyLetStmtSpan(self.0)
     }
+
+    pub fn into_continue_stmt(self) -> LazyContinueStmtSpan<'db> {
+        LazyContinueStmtSpan(self.0)
+    }
+
+    pub fn into_break_stmt(self) -> LazyBreakStmtSpan<'db> {
+        LazyBreakStmtSpan(self.0)
+    }
 }
 
 define_lazy_span_node!(
@@ -31,6 +39,25 @@ define_lazy_span_node!(
     }
 );
 
+define_lazy_span_node!(
+    LazyContinueStmtSpan,
+    ast::ContinueStmt,
+    @token {
+        (label, label),
+    }
+);
+
+define_lazy_span_node!(
+    LazyBreakStmtSpan,
+    ast::BreakStmt,
+    @token {
+        (label, label),
+    }
+    @node {
+        (expr, expr, LazyExprSpan),
+    }
+);
+
 #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Update)]
 pub(crate) struct StmtRoot<'db> {
     stmt: StmtId,
@@ -11,14 +11,14 @@ use thin_vec::ThinVec;
 use super::{
     body_ast, const_ast, contract_ast, enum_ast, expr::ExprRoot, func_ast, impl_ast,
     impl_trait_ast, mod_ast, pat::PatRoot, stmt::StmtRoot, struct_ast, trait_ast, type_alias_ast,
-    use_ast, DesugaredOrigin, DesugaredUseFocus, HirOrigin, LazySpan, UseDesugared,
+    use_ast, DesugaredOrigin, DesugaredUseFocus, HirOrigin, LazySpan, MacroOrigin, UseDesugared,
 };
 use crate::{
     hir_def::{
         Body, Const, Contract, Enum, Func, Impl, ImplTrait, ItemKind, Mod, Struct, TopLevelMod,
         Trait, TypeAlias, Use,
     },
-    lower::top_mod_ast,
+    lower::{expand_macros_impl, map_file_to_mod_impl, top_mod_ast},
     HirDb, SpannedHirDb,
 };
 
@@ -137,6 +137,16 @@ impl ResolvedOrigin {
             HirOrigin::Desugared(desugared) => {
                 ResolvedOriginKind::Desugared(root, desugared.clone())
             }
+            HirOrigin::Macro(MacroOrigin { call, node }) => {
+                let call = call.syntax_node_ptr().text_range();
+                match expand_macros_impl(db, top_mod).get_by_range(call) {
+                    Some(expansion) => ResolvedOriginKind::Macro(
+                        call,
+                        NodeOrToken::Node(node.to_node(&expansion.syntax())),
+                    ),
+                    None => ResolvedOriginKind::None,
+                }
+            }
             HirOrigin::None => ResolvedOriginKind::None,
         };
 
@@ -153,6 +163,10 @@ impl ResolvedOrigin {
                 Some(NodeOrToken::Token(token)) => ResolvedOriginKind::Token(token),
                 None => ResolvedOriginKind::None,
             },
+            ResolvedOriginKind::Macro(call, NodeOrToken::Node(node)) => match f(node) {
+                Some(node_or_token) => ResolvedOriginKind::Macro(call, node_or_token),
+                None => ResolvedOriginKind::None,
+            },
             kind => kind,
         };
 
@@ -184,6 +198,8 @@ pub(crate) enum ResolvedOriginKind {
     Token(SyntaxToken),
     Expanded(SyntaxNode),
     Desugared(SyntaxNode, DesugaredOrigin),
+    /// A node or token in the expansion of the macro invocation at the range.
+    Macro(TextRange, NodeOrToken),
     None,
 }
 
@@ -240,8 +256,18 @@ impl LazySpan for SpanTransitionChain<'_> {
             ResolvedOriginKind::Token(token) => {
                 Span::new(resolved.file, token.text_range(), SpanKind::Original)
             }
-            ResolvedOriginKind::Expanded(node) => {
-                Span::new(resolved.file, node.text_range(), SpanKind::Expanded)
+            ResolvedOriginKind::Expanded(node) => Span::new(
+                resolved.file,
+                node.text_range(),
+                SpanKind::Expanded { def: None },
+            ),
+            ResolvedOriginKind::Macro(call, node_or_token) => {
+                let top_mod = map_file_to_mod_impl(db, resolved.file);
+                let expansions = expand_macros_impl(db, top_mod);
+                let (range, kind) = expansions
+                    .get_by_range(call)?
+                    .map_range(node_or_token.text_range());
+                Span::new(resolved.file, range, kind)
             }
             ResolvedOriginKind::Desugared(root, desugared) => {
                 desugared.resolve(db, root, resolved.file)
@@ -1,7 +1,11 @@
 use parser::ast;
 
 use super::define_lazy_span_node;
-use crate::span::{item::LazyBodySpan, params::LazyGenericArgListSpan, path::LazyPathSpan};
+use crate::span::{
+    item::LazyBodySpan,
+    params::{LazyGenericArgListSpan, LazyTraitRefSpan},
+    path::LazyPathSpan,
+};
 
 define_lazy_span_node!(LazyTySpan);
 impl<'db> LazyTySpan<'db> {
@@ -40,6 +44,14 @@ impl<'db> LazyTySpan<'db> {
     pub fn into_self_type(self) -> LazySelfTypeSpan<'db> {
         LazySelfTypeSpan(self.0)
     }
+
+    /// Convert this [`LazyTySpan`] into a [`LazyQualifiedPathTypeSpan`].
+    ///
+    /// If the type that is pointed to by this is not a qualified path type, the
+    /// result span will point to the same span of the original type.
+    pub fn into_qualified_path_type(self) -> LazyQualifiedPathTypeSpan<'db> {
+        LazyQualifiedPathTypeSpan(self.0)
+    }
 }
 
 define_lazy_span_node!(
@@ -97,3 +109,15 @@ define_lazy_span_node!(
         (generic_args, generic_args, LazyGenericArgListSpan),
     }
 );
+
+define_lazy_span_node!(
+    LazyQualifiedPathTypeSpan,
+    ast::QualifiedPathType,
+    @token {
+        (name, name),
+    }
+    @node {
+        (ty, ty, LazyTySpan),
+        (trait_ref, trait_ref, LazyTraitRefSpan),
+    }
+);
@@ -2,8 +2,8 @@ use std::{marker::PhantomData, mem};
 
 use crate::{
     hir_def::{
-        attr, scope_graph::ScopeId, Body, CallArg, Const, Contract, Enum, EnumVariant, Expr,
-        ExprId, Field, FieldDef, FieldDefListId, FieldIndex, FieldParent, Func, FuncParam,
+        attr, scope_graph::ScopeId, AssocType, Body, CallArg, Const, Contract, Enum, EnumVariant,
+        Expr, ExprId, Field, FieldDef, FieldDefListId, FieldIndex, FieldParent, Func, FuncParam,
         FuncParamListId, FuncParamName, GenericArg, GenericArgListId, GenericParam,
         GenericParamListId, IdentId, Impl, ImplTrait, ItemKind, KindBound, LitKind, MatchArm, Mod,
         Partial, Pat, PatId, PathId, Stmt, StmtId, Struct, TopLevelMod, Trait, TraitRefId,
@@ -19,7 +19,7 @@ use crate::{
 
 pub mod prelude {
     pub use super::{
-        walk_arm, walk_attribute, walk_attribute_list, walk_body, walk_call_arg,
+        walk_arm, walk_assoc_type, walk_attribute, walk_attribute_list, walk_body, walk_call_arg,
         walk_call_arg_list, walk_const, walk_contract, walk_enum, walk_expr, walk_field,
         walk_field_def, walk_field_def_list, walk_field_list, walk_func, walk_func_param,
         walk_func_param_list, walk_generic_arg, walk_generic_arg_list, walk_generic_param,
@@ -278,6 +278,14 @@ pub trait Visitor<'db> {
         walk_field_def(self, ctxt, field);
     }
 
+    fn visit_assoc_type(
+        &mut self,
+        ctxt: &mut VisitorCtxt<'db, LazyAssocTypeSpan<'db>>,
+        assoc_ty: &AssocType<'db>,
+    ) {
+        walk_assoc_type(self, ctxt, assoc_ty);
+    }
+
     fn visit_variant_def_list(
         &mut self,
         ctxt: &mut VisitorCtxt<'db, LazyVariantDefListSpan<'db>>,
@@ -787,6 +795,15 @@ pub fn walk_trait<'db, V>(
         },
     );
 
+    for (idx, assoc_ty) in trait_.assoc_types(ctxt.db).data(ctxt.db).iter().enumerate() {
+        ctxt.with_new_ctxt(
+            |span| span.assoc_ty(idx),
+            |ctxt| {
+                visitor.visit_assoc_type(ctxt, assoc_ty);
+            },
+        )
+    }
+
     for item in trait_.children_non_nested(ctxt.db) {
         visitor.visit_item(&mut VisitorCtxt::with_item(ctxt.db, item), item);
     }
@@ -841,6 +858,20 @@ pub fn walk_impl_trait<'db, V>(
         },
     );
 
+    for (idx, assoc_ty) in impl_trait
+        .assoc_types(ctxt.db)
+        .data(ctxt.db)
+        .iter()
+        .enumerate()
+    {
+        ctxt.with_new_ctxt(
+            |span| span.assoc_ty(idx),
+            |ctxt| {
+                visitor.visit_assoc_type(ctxt, assoc_ty);
+            },
+        )
+    }
+
     for item in impl_trait.children_non_nested(ctxt.db) {
         visitor.visit_item(&mut VisitorCtxt::with_item(ctxt.db, item), item);
     }
@@ -925,7 +956,7 @@ pub fn walk_stmt<'db, V>(
     };
 
     match stmt {
-        Stmt::Let(pat_id, ty, expr_id) => {
+        Stmt::Let(pat_id, ty, expr_id, else_id) => {
             visit_node_in_body!(visitor, ctxt, pat_id, pat);
 
             if let Some(ty) = ty {
@@ -940,24 +971,34 @@ pub fn walk_stmt<'db, V>(
             if let Some(expr_id) = expr_id {
                 visit_node_in_body!(visitor, ctxt, expr_id, expr);
             }
+
+            if let Some(else_id) = else_id {
+                visit_node_in_body!(visitor, ctxt, else_id, expr);
+            }
         }
 
-        Stmt::For(pat_id, cond_id, for_body_id) => {
+        Stmt::For(_, pat_id, cond_id, for_body_id) => {
             visit_node_in_body!(visitor, ctxt, pat_id, pat);
             visit_node_in_body!(visitor, ctxt, cond_id, expr);
             visit_node_in_body!(visitor, ctxt, for_body_id, expr);
         }
 
-        Stmt::While(cond_id, while_body_id) => {
+        Stmt::While(_, cond_id, while_body_id) => {
             visit_node_in_body!(visitor, ctxt, cond_id, expr);
             visit_node_in_body!(visitor, ctxt, while_body_id, expr);
         }
 
-        Stmt::Return(Some(expr_id)) | Stmt::Expr(expr_id) => {
+        Stmt::WhileLet(_, pat_id, scrutinee_id, while_body_id) => {
+            visit_node_in_body!(visitor, ctxt, pat_id, pat);
+            visit_node_in_body!(visitor, ctxt, scrutinee_id, expr);
+            visit_node_in_body!(visitor, ctxt, while_body_id, expr);
+        }
+
+        Stmt::Return(Some(expr_id)) | Stmt::Break(_, Some(expr_id)) | Stmt::Expr(expr_id) => {
             visit_node_in_body!(visitor, ctxt, expr_id, expr);
         }
 
-        Stmt::Return(None) | Stmt::Continue | Stmt::Break => {}
+        Stmt::Return(None) | Stmt::Continue(_) | Stmt::Break(_, None) => {}
     }
 }
 
@@ -1133,6 +1174,15 @@ pub fn walk_expr<'db, V>(
             }
         }
 
+        Expr::IfLet(pat, scrutinee, then, else_) => {
+            visit_node_in_body!(visitor, ctxt, pat, pat);
+            visit_node_in_body!(visitor, ctxt, scrutinee, expr);
+            visit_node_in_body!(visitor, ctxt, then, expr);
+            if let Some(else_) = else_ {
+                visit_node_in_body!(visitor, ctxt, else_, expr);
+            }
+        }
+
         Expr::Match(scrutinee, arms) => {
             visit_node_in_body!(visitor, ctxt, scrutinee, expr);
 
@@ -1153,6 +1203,14 @@ pub fn walk_expr<'db, V>(
             }
         }
 
+        Expr::Loop(_, body_id) => {
+            visit_node_in_body!(visitor, ctxt, body_id, expr);
+        }
+
+        Expr::Unsafe(body_id) => {
+            visit_node_in_body!(visitor, ctxt, body_id, expr);
+        }
+
         Expr::Assign(left_expr_id, right_expr_id) => {
             visit_node_in_body!(visitor, ctxt, left_expr_id, expr);
             visit_node_in_body!(visitor, ctxt, right_expr_id, expr);
@@ -1173,6 +1231,9 @@ pub fn walk_arm<'db, V>(
     V: Visitor<'db> + ?Sized,
 {
     visit_node_in_body!(visitor, ctxt, &arm.pat, pat);
+    if let Some(guard) = &arm.guard {
+        visit_node_in_body!(visitor, ctxt, guard, expr);
+    }
     visit_node_in_body!(visitor, ctxt, &arm.body, expr);
 }
 
@@ -1270,6 +1331,45 @@ where
             visit_node_in_body!(visitor, ctxt, rhs, pat);
         }
 
+        Pat::Range(start, end) => {
+            if let Some(start) = start.to_opt() {
+                ctxt.with_new_ctxt(
+                    |span| span.into_range_pat().start().lit(),
+                    |ctxt| {
+                        visitor.visit_lit(ctxt, start);
+                    },
+                )
+            };
+
+            if let Some(end) = end.to_opt() {
+                ctxt.with_new_ctxt(
+                    |span| span.into_range_pat().end().lit(),
+                    |ctxt| {
+                        visitor.visit_lit(ctxt, end);
+                    },
+                )
+            };
+        }
+
+        Pat::Binding(name, _, sub_pat) => {
+            if let Some(name) = name.to_opt() {
+                ctxt.with_new_ctxt(
+                    |span| span.into_binding_pat().name(),
+                    |ctxt| {
+                        visitor.visit_ident(ctxt, name);
+                    },
+                )
+            };
+
+            visit_node_in_body!(visitor, ctxt, sub_pat, pat);
+        }
+
+        Pat::Array(elems) => {
+            for elem in elems {
+                visit_node_in_body!(visitor, ctxt, elem, pat);
+            }
+        }
+
         Pat::WildCard | Pat::Rest => {}
     }
 }
@@ -1630,6 +1730,46 @@ pub fn walk_field_def<'db, V>(
     }
 }
 
+pub fn walk_assoc_type<'db, V>(
+    visitor: &mut V,
+    ctxt: &mut VisitorCtxt<'db, LazyAssocTypeSpan<'db>>,
+    assoc_ty: &AssocType<'db>,
+) where
+    V: Visitor<'db> + ?Sized,
+{
+    ctxt.with_new_ctxt(
+        |span| span.attributes(),
+        |ctxt| {
+            visitor.visit_attribute_list(ctxt, assoc_ty.attributes);
+        },
+    );
+
+    if let Some(name) = assoc_ty.name.to_opt() {
+        ctxt.with_new_ctxt(
+            |span| span.name(),
+            |ctxt| {
+                visitor.visit_ident(ctxt, name);
+            },
+        )
+    }
+
+    ctxt.with_new_ctxt(
+        |span| span.bounds(),
+        |ctxt| {
+            visitor.visit_type_bound_list(ctxt, &assoc_ty.bounds);
+        },
+    );
+
+    if let Some(ty) = assoc_ty.ty {
+        ctxt.with_new_ctxt(
+            |span| span.ty(),
+            |ctxt| {
+                visitor.visit_ty(ctxt, ty);
+            },
+        )
+    }
+}
+
 pub fn walk_variant_def_list<'db, V>(
     visitor: &mut V,
     ctxt: &mut VisitorCtxt<'db, LazyVariantDefListSpan<'db>>,
@@ -1803,6 +1943,38 @@ pub fn walk_type<'db, V>(
             },
         ),
 
+        TypeKind::QualifiedPath(ty, trait_ref, name) => ctxt.with_new_ctxt(
+            |span| span.into_qualified_path_type(),
+            |ctxt| {
+                if let Some(ty) = ty.to_opt() {
+                    ctxt.with_new_ctxt(
+                        |span| span.ty(),
+                        |ctxt| {
+                            visitor.visit_ty(ctxt, ty);
+                        },
+                    );
+                }
+
+                if let Some(trait_ref) = trait_ref.to_opt() {
+                    ctxt.with_new_ctxt(
+                        |span| span.trait_ref(),
+                        |ctxt| {
+                            visitor.visit_trait_ref(ctxt, trait_ref);
+                        },
+                    );
+                }
+
+                if let Some(name) = name.to_opt() {
+                    ctxt.with_new_ctxt(
+                        |span| span.name(),
+                        |ctxt| {
+                            visitor.visit_ident(ctxt, name);
+                        },
+                    );
+                }
+            },
+        ),
+
         TypeKind::Never => {}
     }
 }
@@ -8,7 +8,8 @@ pub struct Backend {
     pub(super) client: ClientSocket,
     pub(super) db: LanguageServerDatabase,
     pub(super) workspace: Workspace,
-    #[allow(dead_code)] // TODO: salsa3-compatible parallelism
+    /// Computes diagnostics off the actor thread, so that they don't block
+    /// other requests.
     pub(super) workers: tokio::runtime::Runtime,
 }
 
@@ -18,7 +19,6 @@ impl Backend {
         let workspace = Workspace::default();
 
         let workers = tokio::runtime::Builder::new_multi_thread()
-            .worker_threads(1)
             .enable_all()
             .build()
             .unwrap();
@@ -1,8 +1,14 @@
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use async_lsp::lsp_types::{Diagnostic, DiagnosticSeverity};
 use common::InputDb;
-use tracing::info;
+use resolver::{
+    ingot::dependency::{DependencyResolver, DependencySource},
+    Resolver,
+};
+use rustc_hash::FxHashMap;
+use tracing::{info, warn};
 use url::Url;
 
 use super::db::LanguageServerDatabase;
@@ -12,11 +18,17 @@ const FE_CONFIG_SUFFIX: &str = "fe.toml";
 /// Manages the workspace files and ingots for the language server
 pub struct Workspace {
     pub(crate) root_path: Option<PathBuf>,
+    /// Issues with the dependencies of each ingot, keyed by the URL of the
+    /// ingot's `fe.toml`.
+    pub(crate) dependency_diagnostics: FxHashMap<Url, Vec<Diagnostic>>,
 }
 
 impl Workspace {
     pub fn default() -> Self {
-        Self { root_path: None }
+        Self {
+            root_path: None,
+            dependency_diagnostics: FxHashMap::default(),
+        }
     }
 
     /// Sets the workspace root path and syncs workspace files
@@ -58,6 +70,7 @@ impl Workspace {
 
         for ingot_path in &ingot_paths {
             self.sync_ingot_files(db, ingot_path)?;
+            self.sync_dependencies(db, ingot_path)?;
         }
 
         Ok(())
@@ -79,7 +92,7 @@ impl Workspace {
 
     /// Syncs files for a specific ingot
     fn sync_ingot_files(&mut self, db: &mut dyn InputDb, config_path: &str) -> Result<()> {
-        let ingot_root = config_path.strip_suffix(FE_CONFIG_SUFFIX).unwrap();
+        let ingot_root = ingot_root(config_path)?;
         info!("Syncing ingot at {}", config_path);
 
         // Find all Fe source files
@@ -119,6 +132,73 @@ impl Workspace {
 
         Ok(())
     }
+
+    /// Loads the path dependencies of an ingot that live outside of the workspace
+    ///
+    /// Issues with the dependencies are recorded in `dependency_diagnostics`.
+    fn sync_dependencies(&mut self, db: &mut dyn InputDb, config_path: &str) -> Result<()> {
+        let ingot_root = ingot_root(config_path)?;
+        let ingot_url =
+            Url::from_directory_path(ingot_root).map_err(|_| anyhow::anyhow!("Invalid URL"))?;
+        let config_url =
+            Url::from_file_path(config_path).map_err(|_| anyhow::anyhow!("Invalid URL"))?;
+
+        let mut dependency_resolver = DependencyResolver::default();
+        let resolved = dependency_resolver.resolve(&ingot_url);
+        let mut messages: Vec<_> = dependency_resolver
+            .take_diagnostics()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        let dependencies = match resolved {
+            Ok(dependencies) => dependencies,
+            Err(error) => {
+                warn!("Failed to resolve dependencies of {}: {}", ingot_url, error);
+                messages.push(error.to_string());
+                vec![]
+            }
+        };
+        self.dependency_diagnostics.insert(
+            config_url,
+            messages
+                .into_iter()
+                .map(|message| Diagnostic {
+                    severity: Some(DiagnosticSeverity::ERROR),
+                    source: Some("fe".to_string()),
+                    message,
+                    ..Default::default()
+                })
+                .collect(),
+        );
+
+        for dependency in dependencies {
+            if let (DependencySource::Vendor | DependencySource::Registry, Some(name)) =
+                (dependency.source, &dependency.metadata.name)
+            {
+                db.workspace()
+                    .register_ingot(db, name.clone(), dependency.url.clone());
+            }
+            if db.workspace().get(db, &dependency.config.url).is_none() {
+                db.workspace()
+                    .touch_ingot(db, &dependency.url, Some(dependency.config.content));
+            }
+            for (url, contents) in dependency.files {
+                if db.workspace().get(db, &url).is_none() {
+                    db.workspace().touch(db, url, Some(contents));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the directory of the ingot that the `fe.toml` at `config_path`
+/// belongs to.
+fn ingot_root(config_path: &str) -> Result<&str> {
+    config_path
+        .strip_suffix(FE_CONFIG_SUFFIX)
+        .ok_or_else(|| anyhow::anyhow!("`{config_path}` is not an ingot config"))
 }
 
 #[cfg(test)]
@@ -303,4 +383,29 @@ mod tests {
         let main_file = db.workspace().get(&db, &main_url);
         assert!(main_file.is_none(), "File should be removed");
     }
+
+    #[test]
+    fn test_dependency_diagnostics() {
+        let (_temp_dir, root_path) = setup_test_fs();
+        let config_path = root_path.join("ingot1").join("fe.toml");
+        std::fs::write(
+            &config_path,
+            "[ingot]\nname = \"ingot1\"\nversion = \"0.1.0\"\n[dependencies]\nmissing = \"../missing\"\n",
+        )
+        .unwrap();
+
+        let mut db = LanguageServerDatabase::default();
+        let mut workspace = Workspace::default();
+        workspace.set_workspace_root(&mut db, &root_path).unwrap();
+
+        let config_url = Url::from_file_path(&config_path).unwrap();
+        let diagnostics = &workspace.dependency_diagnostics[&config_url];
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("`missing` does not exist"));
+
+        // Ingots without dependency issues clear their diagnostics.
+        let config2_url =
+            Url::from_file_path(root_path.join("nested").join("ingot2").join("fe.toml")).unwrap();
+        assert!(workspace.dependency_diagnostics[&config2_url].is_empty());
+    }
 }
@@ -6,8 +6,9 @@ use common::{diagnostics::CompleteDiagnostic, file::File, ingot::Ingot};
 use cs::files as cs_files;
 use hir::lower::map_file_to_mod;
 use hir_analysis::{
-    analysis_pass::{AnalysisPassManager, ParsingPass},
-    name_resolution::ImportAnalysisPass,
+    analysis_pass::{run_on_modules_in_parallel, AnalysisPassManager, ParsingPass},
+    lint::LintAnalysisPass,
+    name_resolution::{ImportAnalysisPass, VisibilityAnalysisPass},
     ty::{
         AdtDefAnalysisPass, BodyAnalysisPass, DefConflictAnalysisPass, FuncAnalysisPass,
         ImplAnalysisPass, ImplTraitAnalysisPass, TraitAnalysisPass, TypeAliasAnalysisPass,
@@ -82,6 +83,8 @@ impl<'a> cs_files::Files<'a> for LanguageServerDatabase {
 }
 
 impl LanguageServerDatabase {
+    /// Computes the diagnostics of the files of the ingot, analyzing the files
+    /// in parallel.
     pub fn diagnostics_for_ingot(
         &self,
         ingot: Ingot,
@@ -89,30 +92,31 @@ impl LanguageServerDatabase {
         let mut result =
             FxHashMap::<async_lsp::lsp_types::Url, Vec<async_lsp::lsp_types::Diagnostic>>::default(
             );
-        let mut pass_manager = initialize_analysis_pass();
         let ingot_files = ingot.files(self);
 
+        let mut top_mods = vec![];
         for (url, file) in ingot_files.iter() {
             // initialize an empty diagnostic list for this file
             // (to clear any previous diagnostics)
             result.entry(url.clone()).or_default();
+            top_mods.push(map_file_to_mod(self, file));
+        }
 
-            let top_mod = map_file_to_mod(self, file);
-            let diagnostics = pass_manager.run_on_module(self, top_mod);
-            let mut finalized_diags: Vec<CompleteDiagnostic> = diagnostics
-                .iter()
-                .map(|d| d.to_complete(self).clone())
-                .collect();
-            finalized_diags.sort_by(|lhs, rhs| match lhs.error_code.cmp(&rhs.error_code) {
-                std::cmp::Ordering::Equal => lhs.primary_span().cmp(&rhs.primary_span()),
-                ord => ord,
+        let (finalized_diags, _) =
+            run_on_modules_in_parallel(self, &top_mods, initialize_analysis_pass, |db, diags| {
+                let mut finalized_diags: Vec<CompleteDiagnostic> =
+                    diags.iter().map(|d| d.to_complete(db)).collect();
+                finalized_diags.sort_by(|lhs, rhs| match lhs.error_code.cmp(&rhs.error_code) {
+                    std::cmp::Ordering::Equal => lhs.primary_span().cmp(&rhs.primary_span()),
+                    ord => ord,
+                });
+                finalized_diags
             });
-            for diag in finalized_diags {
-                let lsp_diags = diag_to_lsp(self, diag).clone();
-                for (uri, more_diags) in lsp_diags {
-                    let diags = result.entry(uri.clone()).or_insert_with(Vec::new);
-                    diags.extend(more_diags);
-                }
+        for diag in finalized_diags.into_iter().flatten() {
+            let lsp_diags = diag_to_lsp(self, diag).clone();
+            for (uri, more_diags) in lsp_diags {
+                let diags = result.entry(uri.clone()).or_insert_with(Vec::new);
+                diags.extend(more_diags);
             }
         }
 
@@ -125,6 +129,7 @@ fn initialize_analysis_pass() -> AnalysisPassManager {
     pass_manager.add_module_pass(Box::new(ParsingPass {}));
     pass_manager.add_module_pass(Box::new(DefConflictAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(ImportAnalysisPass {}));
+    pass_manager.add_module_pass(Box::new(VisibilityAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(AdtDefAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(TypeAliasAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(TraitAnalysisPass {}));
@@ -132,6 +137,7 @@ fn initialize_analysis_pass() -> AnalysisPassManager {
     pass_manager.add_module_pass(Box::new(ImplTraitAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(FuncAnalysisPass {}));
     pass_manager.add_module_pass(Box::new(BodyAnalysisPass {}));
+    pass_manager.add_module_pass(Box::new(LintAnalysisPass::with_builtin_lints()));
 
     pass_manager
 }
@@ -0,0 +1,66 @@
+use async_lsp::{
+    lsp_types::{request::Request, TextDocumentPositionParams},
+    ResponseError,
+};
+use common::{file::File, InputDb};
+use hir::lower::{expand_macros_impl, map_file_to_mod};
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::{
+    backend::{db::LanguageServerDb, Backend},
+    functionality::goto::Cursor,
+    util::to_offset_from_position,
+};
+
+/// A custom request that returns the expansion of the macro invocation at the
+/// given position.
+pub enum ExpandMacro {}
+
+impl Request for ExpandMacro {
+    type Params = TextDocumentPositionParams;
+    type Result = Option<ExpandedMacro>;
+    const METHOD: &'static str = "fe/expandMacro";
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpandedMacro {
+    /// The name of the invoked macro.
+    pub name: String,
+    /// The source text of the expansion.
+    pub expansion: String,
+}
+
+pub fn expand_macro_helper(
+    db: &dyn LanguageServerDb,
+    file: File,
+    params: TextDocumentPositionParams,
+) -> Option<ExpandedMacro> {
+    let cursor: Cursor = to_offset_from_position(params.position, file.text(db).as_str());
+    let top_mod = map_file_to_mod(db, file);
+    let expansion = expand_macros_impl(db, top_mod).find(cursor)?;
+
+    Some(ExpandedMacro {
+        name: expansion.name().to_string(),
+        expansion: expansion.syntax().to_string(),
+    })
+}
+
+pub async fn handle_expand_macro(
+    backend: &Backend,
+    params: TextDocumentPositionParams,
+) -> Result<Option<ExpandedMacro>, ResponseError> {
+    let path_str = params.text_document.uri.path();
+    let Ok(url) = url::Url::from_file_path(path_str) else {
+        warn!("handle_expand_macro failed to convert path to URL: `{path_str}`");
+        return Ok(None);
+    };
+    let Some(file) = backend.db.workspace().get(&backend.db, &url) else {
+        warn!("handle_expand_macro failed to get file for url: `{url}`");
+        return Ok(None);
+    };
+
+    info!("handling macro expansion request in file: {:?}", file);
+    Ok(expand_macro_helper(&backend.db, file, params))
+}
@@ -10,6 +10,7 @@ use async_lsp::{
 
 use common::InputDb;
 use rustc_hash::FxHashSet;
+use std::panic::AssertUnwindSafe;
 
 use super::{capabilities::server_capabilities, hover::hover_helper};
 
@@ -87,6 +88,17 @@ pub async fn initialized(
         let _ = backend.client.emit(NeedsDiagnostics(url));
     });
 
+    let mut client = backend.client.clone();
+    for (uri, diagnostics) in &backend.workspace.dependency_diagnostics {
+        let _ = client
+            .publish_diagnostics(async_lsp::lsp_types::PublishDiagnosticsParams {
+                uri: uri.clone(),
+                diagnostics: diagnostics.clone(),
+                version: None,
+            })
+            .map_err(|e| error!("Failed to publish diagnostics for {}: {:?}", uri, e));
+    }
+
     let _ = backend.client.clone().log_message(LogMessageParams {
         typ: async_lsp::lsp_types::MessageType::INFO,
         message: "language server initialized!".to_string(),
@@ -239,36 +251,59 @@ pub async fn handle_files_need_diagnostics(
     message: FilesNeedDiagnostics,
 ) -> Result<(), ResponseError> {
     let FilesNeedDiagnostics(need_diagnostics) = message;
-    let mut client = backend.client.clone();
 
-    let ingots_need_diagnostics: FxHashSet<_> = need_diagnostics
-        .iter()
-        .filter_map(|NeedsDiagnostics(url)| {
-            // url is already a url::Url
-            backend.db.workspace().containing_ingot(&backend.db, url)
+    // One URL per ingot, which is used to look the ingot up again in the
+    // worker's clone of the database.
+    let mut ingots_need_diagnostics = FxHashSet::default();
+    let ingot_urls: Vec<_> = need_diagnostics
+        .into_iter()
+        .filter(|NeedsDiagnostics(url)| {
+            backend
+                .db
+                .workspace()
+                .containing_ingot(&backend.db, url)
+                .is_some_and(|ingot| ingots_need_diagnostics.insert(ingot))
         })
+        .map(|NeedsDiagnostics(url)| url)
         .collect();
 
-    for ingot in ingots_need_diagnostics {
-        // Get diagnostics per file
-        let diagnostics_map = backend.db.diagnostics_for_ingot(ingot);
-
-        info!(
-            "Computed diagnostics: {:?}",
-            diagnostics_map.keys().collect::<Vec<_>>()
-        );
-        for uri in diagnostics_map.keys() {
-            let diagnostic = diagnostics_map.get(uri).cloned().unwrap_or_default();
-            let diagnostics_params = async_lsp::lsp_types::PublishDiagnosticsParams {
-                uri: uri.clone(),
-                diagnostics: diagnostic,
-                version: None,
-            };
-            info!("Publishing diagnostics for URI: {:?}", uri);
-            let _ = client
-                .publish_diagnostics(diagnostics_params)
-                .map_err(|e| error!("Failed to publish diagnostics for {}: {:?}", uri, e));
-        }
+    let dispatch = tracing::dispatcher::get_default(Clone::clone);
+    for url in ingot_urls {
+        // The ingots are analyzed concurrently on clones of the database. If a
+        // file changes in the meantime, salsa cancels the analysis and the
+        // change triggers new diagnostics.
+        let db = backend.db.clone();
+        let mut client = backend.client.clone();
+        let dispatch = dispatch.clone();
+        backend.workers.spawn_blocking(move || {
+            tracing::dispatcher::with_default(&dispatch, || {
+                let diagnostics_map = salsa::Cancelled::catch(AssertUnwindSafe(|| {
+                    db.workspace()
+                        .containing_ingot(&db, &url)
+                        .map(|ingot| db.diagnostics_for_ingot(ingot))
+                }));
+                let Ok(Some(diagnostics_map)) = diagnostics_map else {
+                    info!("Diagnostics for {url} were cancelled");
+                    return;
+                };
+
+                info!(
+                    "Computed diagnostics: {:?}",
+                    diagnostics_map.keys().collect::<Vec<_>>()
+                );
+                for (uri, diagnostic) in diagnostics_map {
+                    let diagnostics_params = async_lsp::lsp_types::PublishDiagnosticsParams {
+                        uri: uri.clone(),
+                        diagnostics: diagnostic,
+                        version: None,
+                    };
+                    info!("Publishing diagnostics for URI: {:?}", uri);
+                    let _ = client
+                        .publish_diagnostics(diagnostics_params)
+                        .map_err(|e| error!("Failed to publish diagnostics for {}: {:?}", uri, e));
+                }
+            })
+        });
     }
     Ok(())
 }
@@ -1,5 +1,6 @@
 mod capabilities;
 pub(super) mod diagnostics;
+pub(super) mod expand_macro;
 pub(super) mod goto;
 pub(super) mod handlers;
 pub(super) mod hover;
@@ -18,7 +18,7 @@ use tracing::instrument::WithSubscriber;
 use tracing::{info, warn};
 
 use crate::backend::Backend;
-use crate::functionality::{goto, handlers};
+use crate::functionality::{expand_macro, goto, handlers};
 use async_lsp::lsp_types::request::Initialize;
 use async_lsp::router::Router;
 
@@ -47,6 +47,7 @@ pub(crate) fn setup(
         // non-mutating handlers
         .handle_notification::<Initialized>(handlers::initialized)
         .handle_request::<HoverRequest>(handlers::handle_hover_request)
+        .handle_request::<expand_macro::ExpandMacro>(expand_macro::handle_expand_macro)
         .handle_notification::<DidOpenTextDocument>(handlers::handle_did_open_text_document)
         .handle_notification::<DidChangeTextDocument>(handlers::handle_did_change_text_document)
         .handle_notification::<DidChangeWatchedFiles>(handlers::handle_did_change_watched_files)
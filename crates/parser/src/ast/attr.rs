@@ -72,7 +72,7 @@ ast_node! {
 
 ast_node! {
     /// An Attribute argument.
-    /// `arg1: Arg` in `#foo(arg1: Arg, arg2: Arg)`
+    /// `arg1: Arg` in `#foo(arg1: Arg, arg2: Arg)`, or `arg` in `#foo(arg)`
     pub struct AttrArg,
     SK::AttrArg
 }
@@ -84,7 +84,7 @@ impl AttrArg {
     }
 
     /// Returns the value of the attribute argument.
-    /// `Arg` in `arg1: Arg`.
+    /// `Arg` in `arg1: Arg`, or `None` if the argument has no value.
     pub fn value(&self) -> Option<SyntaxToken> {
         self.syntax()
             .children_with_tokens()
@@ -187,4 +187,21 @@ mod tests {
             }
         }
     }
+    #[test]
+    #[wasm_bindgen_test]
+    fn attr_arg_without_value() {
+        let source = r#"
+            #allow(unused_variables, unused_imports)
+        "#;
+        let attr_list = parse_attr_list(source);
+        let attr = attr_list.normal_attrs().next().unwrap();
+        assert_eq!(attr.name().unwrap().text(), "allow");
+
+        let args: Vec<_> = attr.args().unwrap().iter().collect();
+        assert_eq!(args.len(), 2);
+        assert_eq!(args[0].key().unwrap().text(), "unused_variables");
+        assert!(args[0].value().is_none());
+        assert_eq!(args[1].key().unwrap().text(), "unused_imports");
+        assert!(args[1].value().is_none());
+    }
 }
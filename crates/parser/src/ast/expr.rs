@@ -22,9 +22,12 @@ ast_node! {
     | SK::LitExpr
     | SK::IfExpr
     | SK::MatchExpr
+    | SK::LoopExpr
+    | SK::UnsafeExpr
     | SK::ParenExpr
     | SK::AssignExpr
-    | SK::AugAssignExpr,
+    | SK::AugAssignExpr
+    | SK::MacroCall,
 }
 
 impl Expr {
@@ -50,9 +53,12 @@ impl Expr {
             SK::LitExpr => ExprKind::Lit(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::IfExpr => ExprKind::If(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::MatchExpr => ExprKind::Match(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::LoopExpr => ExprKind::Loop(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::UnsafeExpr => ExprKind::Unsafe(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::ParenExpr => ExprKind::Paren(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::AssignExpr => ExprKind::Assign(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::AugAssignExpr => ExprKind::AugAssign(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::MacroCall => ExprKind::MacroCall(AstNode::cast(self.syntax().clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -292,14 +298,24 @@ ast_node! {
     SK::IfExpr
 }
 impl IfExpr {
+    /// Returns the pattern of the if expression if it's an `if let`.
+    pub fn pat(&self) -> Option<super::Pat> {
+        support::child(self.syntax())
+    }
+
     /// Returns the condition of the if expression.
+    /// For `if let`, this is the expression being matched.
     pub fn cond(&self) -> Option<Expr> {
         support::child(self.syntax())
     }
 
     /// Returns the then block of the if expression.
     pub fn then(&self) -> Option<BlockExpr> {
-        self.syntax().children().skip(1).find_map(BlockExpr::cast)
+        let skip = if self.pat().is_some() { 2 } else { 1 };
+        self.syntax()
+            .children()
+            .skip(skip)
+            .find_map(BlockExpr::cast)
     }
 
     /// Returns the else block of the if expression.
@@ -325,6 +341,40 @@ impl MatchExpr {
     }
 }
 
+ast_node! {
+    /// `loop { .. }` or `'label: loop { .. }`
+    pub struct LoopExpr,
+    SK::LoopExpr
+}
+impl LoopExpr {
+    /// Returns the label of the loop, e.g., `'outer`.
+    pub fn label(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Label)
+    }
+
+    /// Returns the body of the loop.
+    pub fn body(&self) -> Option<BlockExpr> {
+        support::child(self.syntax())
+    }
+}
+
+ast_node! {
+    /// `unsafe { .. }`
+    pub struct UnsafeExpr,
+    SK::UnsafeExpr
+}
+impl UnsafeExpr {
+    /// Returns the `unsafe` keyword.
+    pub fn unsafe_kw(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::UnsafeKw)
+    }
+
+    /// Returns the block of the `unsafe` block.
+    pub fn body(&self) -> Option<BlockExpr> {
+        support::child(self.syntax())
+    }
+}
+
 ast_node! {
     /// `(expr)`
     pub struct ParenExpr,
@@ -397,9 +447,12 @@ pub enum ExprKind {
     ArrayRep(ArrayRepExpr),
     If(IfExpr),
     Match(MatchExpr),
+    Loop(LoopExpr),
+    Unsafe(UnsafeExpr),
     Paren(ParenExpr),
     Assign(AssignExpr),
     AugAssign(AugAssignExpr),
+    MacroCall(super::MacroCall),
 }
 
 ast_node! {
@@ -439,12 +492,29 @@ impl MatchArm {
         support::child(self.syntax())
     }
 
+    /// Returns the guard of the match arm.
+    pub fn guard(&self) -> Option<MatchGuard> {
+        support::child(self.syntax())
+    }
+
     /// Returns the body of the match arm.
     pub fn body(&self) -> Option<Expr> {
         support::child(self.syntax())
     }
 }
 
+ast_node! {
+    /// `if cond` in `pat if cond => expr`
+    pub struct MatchGuard,
+    SK::MatchGuard
+}
+impl MatchGuard {
+    /// Returns the condition of the guard.
+    pub fn cond(&self) -> Option<Expr> {
+        support::child(self.syntax())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum BinOp {
     Arith(ArithBinOp),
@@ -936,6 +1006,19 @@ mod tests {
 
         let if_expr: IfExpr = parse_expr("if false { return } else if true { continue }");
         assert!(matches!(if_expr.else_().unwrap().kind(), ExprKind::If(_)));
+
+        let if_expr: IfExpr = parse_expr("if let Some(x) = { foo } { x } else { 0 }");
+        assert!(matches!(
+            if_expr.pat().unwrap().kind(),
+            crate::ast::PatKind::PathTuple(_)
+        ));
+        assert!(matches!(if_expr.cond().unwrap().kind(), ExprKind::Block(_)));
+        assert!(if_expr.then().is_some());
+        assert_ne!(
+            if_expr.cond().unwrap().syntax(),
+            if_expr.then().unwrap().syntax()
+        );
+        assert!(if_expr.else_().is_some());
     }
 
     #[test]
@@ -976,8 +1059,47 @@ mod tests {
             }
             count += 1;
         }
-        assert_eq!(count, 3)
+        assert_eq!(count, 3);
+
+        let source = r#"
+            match foo {
+                Foo::Bar(x) if x > 1 => x
+                _ => 0
+            }
+        }"#;
+
+        let match_expr: MatchExpr = parse_expr(source);
+        let mut arms = match_expr.arms().unwrap().into_iter();
+        let arm = arms.next().unwrap();
+        assert!(matches!(arm.pat().unwrap().kind(), PatKind::PathTuple(_)));
+        assert!(matches!(
+            arm.guard().unwrap().cond().unwrap().kind(),
+            ExprKind::Bin(_)
+        ));
+        assert!(matches!(arm.body().unwrap().kind(), ExprKind::Path(_)));
+        assert!(arms.next().unwrap().guard().is_none());
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn loop_expr() {
+        let loop_expr: LoopExpr = parse_expr("loop { break 1 }");
+        assert!(loop_expr.label().is_none());
+        assert!(loop_expr.body().is_some());
+
+        let loop_expr: LoopExpr = parse_expr("'outer: loop { continue 'outer }");
+        assert_eq!(loop_expr.label().unwrap().text(), "'outer");
+        assert!(loop_expr.body().is_some());
     }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn unsafe_expr() {
+        let unsafe_expr: UnsafeExpr = parse_expr("unsafe { foo() }");
+        assert!(unsafe_expr.unsafe_kw().is_some());
+        assert_eq!(unsafe_expr.body().unwrap().stmts().count(), 1);
+    }
+
     #[test]
     #[wasm_bindgen_test]
     fn assign() {
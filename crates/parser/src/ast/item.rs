@@ -1,6 +1,6 @@
 use rowan::ast::{support, AstNode};
 
-use super::{ast_node, TraitRef, TupleType};
+use super::{ast_node, AstChildren, TraitRef, TupleType};
 use crate::{FeLang, SyntaxKind as SK, SyntaxToken};
 
 ast_node! {
@@ -12,6 +12,12 @@ impl Root {
     pub fn items(&self) -> Option<ItemList> {
         support::child(self.syntax())
     }
+
+    /// Returns the expression of the root of a macro expansion in expression
+    /// position.
+    pub fn expr(&self) -> Option<super::Expr> {
+        support::child(self.syntax())
+    }
 }
 
 ast_node! {
@@ -42,6 +48,8 @@ impl Item {
             .or_else(|| support::child(self.syntax()).map(ItemKind::Const))
             .or_else(|| support::child(self.syntax()).map(ItemKind::Use))
             .or_else(|| support::child(self.syntax()).map(ItemKind::Extern))
+            .or_else(|| support::child(self.syntax()).map(ItemKind::MacroDef))
+            .or_else(|| support::child(self.syntax()).map(ItemKind::MacroCall))
     }
 }
 
@@ -192,7 +200,7 @@ impl Trait {
 
     /// Returns the trait's item list.
     /// `{ .. }` in `trait Foo<..> where .. { .. }`
-    /// NOTE: Currently only supports `fn` items.
+    /// NOTE: Currently only supports `fn`, `type` and `const` items.
     pub fn item_list(&self) -> Option<TraitItemList> {
         support::child(self.syntax())
     }
@@ -259,7 +267,7 @@ impl ImplTrait {
 
     /// Returns the trait impl item list.
     /// `{ .. }` in `impl<T> Foo for Bar<T> { .. }`
-    /// NOTE: Currently only supports `fn` items.
+    /// NOTE: Currently only supports `fn` and `type` items.
     pub fn item_list(&self) -> Option<ImplTraitItemList> {
         support::child(self.syntax())
     }
@@ -267,6 +275,7 @@ impl ImplTrait {
 
 ast_node! {
     /// `const FOO: u32 = 42;`
+    /// This is also used for associated consts.
     pub struct Const,
     SK::Const,
 }
@@ -285,7 +294,8 @@ impl Const {
         support::child(self.syntax())
     }
 
-    /// Returns the value of the const.
+    /// Returns the value of the const, which is the default value if the
+    /// const is an associated const declared in a trait.
     /// `42` in `const FOO: u32 = 42;`
     pub fn value(&self) -> Option<super::Expr> {
         support::child(self.syntax())
@@ -341,6 +351,11 @@ impl RecordFieldDef {
         support::token(self.syntax(), SK::PubKw)
     }
 
+    /// Returns the restriction of `pub` if exists.
+    pub fn vis_restriction(&self) -> Option<VisRestriction> {
+        support::child(self.syntax())
+    }
+
     /// Returns the name of the field.
     pub fn name(&self) -> Option<SyntaxToken> {
         support::token(self.syntax(), SK::Ident)
@@ -401,18 +416,74 @@ ast_node! {
     SK::TraitItemList,
     IntoIterator<Item=Func>,
 }
+impl TraitItemList {
+    /// Returns the associated types declared in the trait.
+    pub fn assoc_types(&self) -> AstChildren<AssocType> {
+        support::children(self.syntax())
+    }
+
+    /// Returns the associated consts declared in the trait.
+    pub fn assoc_consts(&self) -> AstChildren<Const> {
+        support::children(self.syntax())
+    }
+}
 
 ast_node! {
     pub struct ImplItemList,
     SK::ImplItemList,
     IntoIterator<Item=Func>,
 }
+impl ImplItemList {
+    /// Returns the associated consts defined in the impl.
+    pub fn assoc_consts(&self) -> AstChildren<Const> {
+        support::children(self.syntax())
+    }
+}
 
 ast_node! {
     pub struct ImplTraitItemList,
     SK::ImplTraitItemList,
     IntoIterator<Item=Func>,
 }
+impl ImplTraitItemList {
+    /// Returns the associated types defined in the trait impl.
+    pub fn assoc_types(&self) -> AstChildren<AssocType> {
+        support::children(self.syntax())
+    }
+
+    /// Returns the associated consts defined in the trait impl.
+    pub fn assoc_consts(&self) -> AstChildren<Const> {
+        support::children(self.syntax())
+    }
+}
+
+ast_node! {
+    /// An associated type in a `trait` or `impl trait` block.
+    /// `type Item: Trait = i32`
+    pub struct AssocType,
+    SK::AssocType,
+}
+impl super::AttrListOwner for AssocType {}
+impl AssocType {
+    /// Returns the name of the associated type.
+    /// `Item` in `type Item: Trait = i32`
+    pub fn name(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Ident)
+    }
+
+    /// Returns the bounds of the associated type.
+    /// `: Trait` in `type Item: Trait = i32`
+    pub fn bounds(&self) -> Option<super::TypeBoundList> {
+        support::child(self.syntax())
+    }
+
+    /// Returns the type of the associated type, which is the default type if
+    /// the associated type is declared in a trait.
+    /// `i32` in `type Item: Trait = i32`
+    pub fn ty(&self) -> Option<super::Type> {
+        support::child(self.syntax())
+    }
+}
 
 ast_node! {
     pub struct ExternItemList,
@@ -434,6 +505,49 @@ impl ItemModifier {
     pub fn unsafe_kw(&self) -> Option<SyntaxToken> {
         support::token(self.syntax(), SK::UnsafeKw)
     }
+
+    /// Returns the restriction of `pub` if exists.
+    pub fn vis_restriction(&self) -> Option<VisRestriction> {
+        support::child(self.syntax())
+    }
+}
+
+ast_node! {
+    /// The restriction following `pub`.
+    /// `(ingot)`, `(super)` or `(in foo::bar)`
+    pub struct VisRestriction,
+    SK::VisRestriction,
+}
+impl VisRestriction {
+    pub fn kind(&self) -> Option<VisRestrictionKind> {
+        let token = self
+            .syntax()
+            .children_with_tokens()
+            .filter_map(|child| child.into_token())
+            .find(|token| matches!(token.kind(), SK::IngotKw | SK::SuperKw | SK::InKw))?;
+
+        match token.kind() {
+            SK::IngotKw => Some(VisRestrictionKind::Ingot),
+            SK::SuperKw => Some(VisRestrictionKind::Super),
+            _ => Some(VisRestrictionKind::In(self.path())),
+        }
+    }
+
+    /// Returns the module path of `pub(in path)`.
+    pub fn path(&self) -> Option<super::Path> {
+        support::child(self.syntax())
+    }
+}
+
+/// The kind of a [`VisRestriction`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum VisRestrictionKind {
+    /// `pub(ingot)`
+    Ingot,
+    /// `pub(super)`
+    Super,
+    /// `pub(in foo::bar)`
+    In(Option<super::Path>),
 }
 
 pub trait ItemModifierOwner: AstNode<Language = FeLang> {
@@ -456,6 +570,8 @@ pub enum ItemKind {
     Const(Const),
     Use(Use),
     Extern(Extern),
+    MacroDef(super::MacroDef),
+    MacroCall(super::MacroCall),
 }
 
 #[cfg(test)]
@@ -710,6 +826,87 @@ mod tests {
         assert!(i.item_list().unwrap().iter().count() == 1);
     }
 
+    #[test]
+    #[wasm_bindgen_test]
+    fn assoc_types() {
+        let source = r#"
+                pub trait Iterator {
+                    type Item: Clone
+                    type Idx = usize
+                    fn next(mut self) -> Option<Self::Item>
+                }
+            "#;
+        let t: Trait = parse_item(source);
+        let item_list = t.item_list().unwrap();
+        assert_eq!(item_list.iter().count(), 1);
+
+        let assoc_types: Vec<_> = item_list.assoc_types().collect();
+        assert_eq!(assoc_types.len(), 2);
+        assert_eq!(assoc_types[0].name().unwrap().text(), "Item");
+        assert!(assoc_types[0].bounds().is_some());
+        assert!(assoc_types[0].ty().is_none());
+        assert_eq!(assoc_types[1].name().unwrap().text(), "Idx");
+        assert!(assoc_types[1].bounds().is_none());
+        assert!(assoc_types[1].ty().is_some());
+
+        let source = r#"
+            impl Iterator for Counter {
+                type Item = u32
+                fn next(mut self) -> Option<u32> { return Option::None }
+            }"#;
+        let i: ImplTrait = parse_item(source);
+        let item_list = i.item_list().unwrap();
+        assert_eq!(item_list.iter().count(), 1);
+        let assoc_ty = item_list.assoc_types().next().unwrap();
+        assert_eq!(assoc_ty.name().unwrap().text(), "Item");
+        assert!(matches!(assoc_ty.ty().unwrap().kind(), TypeKind::Path(_)));
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn assoc_consts() {
+        let source = r#"
+                pub trait Bounded {
+                    const MIN: Self
+                    const MAX: u32 = 10
+                    fn foo(self)
+                }
+            "#;
+        let t: Trait = parse_item(source);
+        let item_list = t.item_list().unwrap();
+        assert_eq!(item_list.iter().count(), 1);
+
+        let assoc_consts: Vec<_> = item_list.assoc_consts().collect();
+        assert_eq!(assoc_consts.len(), 2);
+        assert_eq!(assoc_consts[0].name().unwrap().text(), "MIN");
+        assert!(matches!(
+            assoc_consts[0].ty().unwrap().kind(),
+            TypeKind::SelfType(_)
+        ));
+        assert!(assoc_consts[0].value().is_none());
+        assert_eq!(assoc_consts[1].name().unwrap().text(), "MAX");
+        assert!(matches!(
+            assoc_consts[1].value().unwrap().kind(),
+            ExprKind::Lit(_)
+        ));
+
+        let source = r#"
+            impl Foo {
+                pub const SIZE: u32 = 1 + 1
+                fn foo(self) {}
+            }"#;
+        let i: Impl = parse_item(source);
+        let item_list = i.item_list().unwrap();
+        assert_eq!(item_list.iter().count(), 1);
+        let assoc_const = item_list.assoc_consts().next().unwrap();
+        assert_eq!(assoc_const.name().unwrap().text(), "SIZE");
+        assert!(assoc_const.modifier().unwrap().pub_kw().is_some());
+        assert!(matches!(
+            assoc_const.value().unwrap().kind(),
+            ExprKind::Bin(_)
+        ));
+    }
+
     #[test]
     #[wasm_bindgen_test]
     fn const_() {
@@ -805,4 +1002,45 @@ mod tests {
         }
         assert_eq!(e.extern_block().unwrap().iter().count(), 2);
     }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn vis_restriction() {
+        let source = r#"
+            pub(ingot) mod foo {
+                pub(super) fn bar() {}
+                pub(in ingot::foo) unsafe fn baz() {}
+                pub struct Qux {
+                    pub(ingot) x: i32,
+                    y: i32,
+                }
+            }
+        "#;
+        let mod_: Mod = parse_item(source);
+        let restriction_kind = |modifier: Option<ItemModifier>| {
+            modifier.unwrap().vis_restriction().unwrap().kind().unwrap()
+        };
+        assert_eq!(restriction_kind(mod_.modifier()), VisRestrictionKind::Ingot);
+
+        let mut items = mod_.items().unwrap().into_iter();
+        let bar: Func = items.next().unwrap().kind().unwrap().try_into().unwrap();
+        assert_eq!(restriction_kind(bar.modifier()), VisRestrictionKind::Super);
+
+        let baz: Func = items.next().unwrap().kind().unwrap().try_into().unwrap();
+        assert!(baz.modifier().unwrap().unsafe_kw().is_some());
+        let VisRestrictionKind::In(Some(path)) = restriction_kind(baz.modifier()) else {
+            panic!("expected `pub(in path)`");
+        };
+        assert_eq!(path.syntax().to_string(), "ingot::foo");
+
+        let qux: Struct = items.next().unwrap().kind().unwrap().try_into().unwrap();
+        assert!(qux.modifier().unwrap().vis_restriction().is_none());
+        let mut fields = qux.fields().unwrap().into_iter();
+        let x = fields.next().unwrap();
+        assert_eq!(
+            x.vis_restriction().unwrap().kind().unwrap(),
+            VisRestrictionKind::Ingot
+        );
+        assert!(fields.next().unwrap().vis_restriction().is_none());
+    }
 }
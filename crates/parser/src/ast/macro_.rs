@@ -0,0 +1,163 @@
+use rowan::ast::{support, AstNode};
+
+use super::ast_node;
+use crate::{SyntaxKind as SK, SyntaxToken};
+
+ast_node! {
+    /// `macro foo { ($x:expr) => { $x + 1 } }`
+    pub struct MacroDef,
+    SK::MacroDef,
+}
+impl super::AttrListOwner for MacroDef {}
+impl super::ItemModifierOwner for MacroDef {}
+impl MacroDef {
+    /// Returns the name of the macro.
+    pub fn name(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Ident)
+    }
+
+    /// Returns the rules of the macro.
+    pub fn rules(&self) -> Option<MacroRuleList> {
+        support::child(self.syntax())
+    }
+}
+
+ast_node! {
+    /// `{ ($x:expr) => { $x + 1 } }`
+    pub struct MacroRuleList,
+    SK::MacroRuleList,
+    IntoIterator<Item=MacroRule>,
+}
+
+ast_node! {
+    /// `($x:expr) => { $x + 1 }`
+    pub struct MacroRule,
+    SK::MacroRule,
+}
+impl MacroRule {
+    /// Returns the token tree the invocation is matched against.
+    pub fn matcher(&self) -> Option<TokenTree> {
+        support::children(self.syntax()).next()
+    }
+
+    /// Returns the token tree the invocation is expanded into.
+    pub fn transcriber(&self) -> Option<TokenTree> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+ast_node! {
+    /// A delimited sequence of arbitrary tokens.
+    /// `($x:expr)`, `{ $x + 1 }`
+    pub struct TokenTree,
+    SK::TokenTree,
+}
+impl TokenTree {
+    /// Returns the opening delimiter.
+    pub fn l_delim(&self) -> Option<SyntaxToken> {
+        self.syntax().first_token()
+    }
+
+    /// Returns the closing delimiter.
+    pub fn r_delim(&self) -> Option<SyntaxToken> {
+        let token = self.syntax().last_token()?;
+        matches!(token.kind(), SK::RParen | SK::RBracket | SK::RBrace).then_some(token)
+    }
+}
+
+ast_node! {
+    /// `foo!(x, y)`
+    pub struct MacroCall,
+    SK::MacroCall,
+}
+impl MacroCall {
+    /// Returns the name of the invoked macro.
+    pub fn name(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Ident)
+    }
+
+    /// Returns the arguments of the invocation.
+    pub fn token_tree(&self) -> Option<TokenTree> {
+        support::child(self.syntax())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    use super::*;
+    use crate::{
+        ast::{ExprKind, Func, ItemKind, Root, StmtKind},
+        parse_source_file, SyntaxNode,
+    };
+
+    fn parse_items(source: &str) -> Vec<ItemKind> {
+        let (node, errs) = parse_source_file(source);
+        assert!(errs.is_empty(), "{errs:?}");
+        let root = Root::cast(SyntaxNode::new_root(node)).unwrap();
+        root.items()
+            .unwrap()
+            .into_iter()
+            .map(|item| item.kind().unwrap())
+            .collect()
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn macro_def() {
+        let source = r#"
+            macro getter {
+                ($name:ident: $ty:ty) => {
+                    fn $name(self) -> $ty { self.$name }
+                }
+                ($($x:expr),*) => [ $($x +)* 0 ]
+            }
+        "#;
+        let items = parse_items(source);
+        assert_eq!(items.len(), 1);
+        let macro_def: MacroDef = items.into_iter().next().unwrap().try_into().unwrap();
+        assert_eq!(macro_def.name().unwrap().text(), "getter");
+
+        let rules: Vec<_> = macro_def.rules().unwrap().into_iter().collect();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(
+            rules[0].matcher().unwrap().syntax().to_string(),
+            "($name:ident: $ty:ty)"
+        );
+        let transcriber = rules[1].transcriber().unwrap();
+        assert_eq!(transcriber.l_delim().unwrap().kind(), SK::LBracket);
+        assert_eq!(transcriber.r_delim().unwrap().kind(), SK::RBracket);
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn macro_call() {
+        let source = r#"
+            getter!(balance: u256)
+            fn foo() {
+                let x = sum!(1, (2, 3), [4])
+            }
+        "#;
+        let items = parse_items(source);
+        assert_eq!(items.len(), 2);
+
+        let mut items = items.into_iter();
+        let call: MacroCall = items.next().unwrap().try_into().unwrap();
+        assert_eq!(call.name().unwrap().text(), "getter");
+        assert_eq!(
+            call.token_tree().unwrap().syntax().to_string(),
+            "(balance: u256)"
+        );
+
+        let func: Func = items.next().unwrap().try_into().unwrap();
+        let stmt = func.body().unwrap().stmts().next().unwrap();
+        let StmtKind::Let(let_) = stmt.kind() else {
+            panic!("expected a `let` statement");
+        };
+        let ExprKind::MacroCall(call) = let_.initializer().unwrap().kind() else {
+            panic!("expected a macro invocation");
+        };
+        assert_eq!(call.name().unwrap().text(), "sum");
+    }
+}
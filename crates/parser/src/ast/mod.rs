@@ -2,6 +2,7 @@ pub mod attr;
 pub mod expr;
 pub mod item;
 pub mod lit;
+pub mod macro_;
 pub mod param;
 pub mod pat;
 pub mod path;
@@ -13,6 +14,7 @@ pub use attr::*;
 pub use expr::*;
 pub use item::*;
 pub use lit::*;
+pub use macro_::*;
 pub use param::*;
 pub use pat::*;
 pub use path::*;
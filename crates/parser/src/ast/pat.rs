@@ -15,6 +15,9 @@ ast_node! {
     | SK::PathTuplePat
     | SK::RecordPat
     | SK::OrPat
+    | SK::RangePat
+    | SK::BindingPat
+    | SK::ArrayPat
 }
 impl Pat {
     /// Returns the specific kind of the pattern.
@@ -30,6 +33,9 @@ impl Pat {
             }
             SK::RecordPat => PatKind::Record(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::OrPat => PatKind::Or(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::RangePat => PatKind::Range(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::BindingPat => PatKind::Binding(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::ArrayPat => PatKind::Array(AstNode::cast(self.syntax().clone()).unwrap()),
             _ => unreachable!(),
         }
     }
@@ -160,6 +166,52 @@ impl OrPat {
     }
 }
 
+ast_node! {
+    /// `0..=9`
+    pub struct RangePat,
+    SK::RangePat,
+}
+impl RangePat {
+    /// Returns the lower bound of the range.
+    pub fn start(&self) -> Option<LitPat> {
+        support::child(self.syntax())
+    }
+
+    /// Returns the upper bound of the range.
+    pub fn end(&self) -> Option<LitPat> {
+        support::children(self.syntax()).nth(1)
+    }
+}
+
+ast_node! {
+    /// `x @ Foo::Bar(..)`
+    pub struct BindingPat,
+    SK::BindingPat,
+}
+impl BindingPat {
+    /// Returns the name of the binding.
+    pub fn name(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Ident)
+    }
+
+    /// Returns the `mut` keyword if the binding is mutable.
+    pub fn mut_token(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::MutKw)
+    }
+
+    /// Returns the pattern the binding is bound to.
+    pub fn pat(&self) -> Option<Pat> {
+        support::child(self.syntax())
+    }
+}
+
+ast_node! {
+    /// `[a, .., b]`
+    pub struct ArrayPat,
+    SK::ArrayPat,
+    IntoIterator<Item=Pat>
+}
+
 /// A specific pattern kind.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::From, derive_more::TryInto)]
 pub enum PatKind {
@@ -171,6 +223,9 @@ pub enum PatKind {
     PathTuple(PathTuplePat),
     Record(RecordPat),
     Or(OrPat),
+    Range(RangePat),
+    Binding(BindingPat),
+    Array(ArrayPat),
 }
 
 #[cfg(test)]
@@ -304,4 +359,49 @@ mod tests {
         assert!(matches!(or_pat.lhs().unwrap().kind(), PatKind::Path(_)));
         assert!(matches!(or_pat.rhs().unwrap().kind(), PatKind::Or(_)));
     }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn range() {
+        let range_pat: RangePat = parse_pat("0..=9");
+        assert_eq!(range_pat.start().unwrap().syntax().text(), "0");
+        assert_eq!(range_pat.end().unwrap().syntax().text(), "9");
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn binding() {
+        let binding_pat: BindingPat = parse_pat("mut x @ Foo::Bar(..)");
+        assert_eq!(binding_pat.name().unwrap().text(), "x");
+        assert!(binding_pat.mut_token().is_some());
+        assert!(matches!(
+            binding_pat.pat().unwrap().kind(),
+            PatKind::PathTuple(_)
+        ));
+
+        let binding_pat: BindingPat = parse_pat("x @ 1..=3");
+        assert!(binding_pat.mut_token().is_none());
+        assert!(matches!(
+            binding_pat.pat().unwrap().kind(),
+            PatKind::Range(_)
+        ));
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn array() {
+        let array_pat: ArrayPat = parse_pat("[a, .., 1]");
+
+        for (i, pat) in array_pat.iter().enumerate() {
+            match i {
+                0 => assert!(matches!(pat.kind(), PatKind::Path(_))),
+                1 => assert!(matches!(pat.kind(), PatKind::Rest(_))),
+                2 => assert!(matches!(pat.kind(), PatKind::Lit(_))),
+                _ => panic!("unexpected array pat"),
+            }
+        }
+
+        let array_pat: ArrayPat = parse_pat("[]");
+        assert!(array_pat.iter().next().is_none());
+    }
 }
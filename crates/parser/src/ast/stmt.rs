@@ -1,7 +1,7 @@
 use rowan::ast::{support, AstNode};
 
 use super::ast_node;
-use crate::SyntaxKind as SK;
+use crate::{SyntaxKind as SK, SyntaxToken};
 
 ast_node! {
     /// A statement.
@@ -51,6 +51,14 @@ impl LetStmt {
     pub fn initializer(&self) -> Option<super::Expr> {
         support::child(self.syntax())
     }
+
+    /// Returns the `else` block of `let pat = expr else { .. }`.
+    pub fn else_block(&self) -> Option<super::Expr> {
+        self.syntax()
+            .children()
+            .filter_map(super::Expr::cast)
+            .nth(1)
+    }
 }
 
 ast_node! {
@@ -59,6 +67,11 @@ ast_node! {
     SK::ForStmt
 }
 impl ForStmt {
+    /// Returns the label of the loop, e.g., `'outer`.
+    pub fn label(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Label)
+    }
+
     /// Returns the pattern of the binding in the for loop.
     pub fn pat(&self) -> Option<super::Pat> {
         support::child(self.syntax())
@@ -85,7 +98,18 @@ ast_node! {
     SK::WhileStmt
 }
 impl WhileStmt {
+    /// Returns the label of the loop, e.g., `'outer`.
+    pub fn label(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Label)
+    }
+
+    /// Returns the pattern of the while loop if it's a `while let`.
+    pub fn pat(&self) -> Option<super::Pat> {
+        support::child(self.syntax())
+    }
+
     /// Returns the condition of the while loop.
+    /// For `while let`, this is the expression being matched.
     pub fn cond(&self) -> Option<super::Expr> {
         support::child(self.syntax())
     }
@@ -101,16 +125,33 @@ impl WhileStmt {
 }
 
 ast_node! {
-    /// `continue`
+    /// `continue` or `continue 'label`
     pub struct ContinueStmt,
     SK::ContinueStmt
 }
+impl ContinueStmt {
+    /// Returns the label of the loop to continue, if any.
+    pub fn label(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Label)
+    }
+}
 
 ast_node! {
-    /// `break`
+    /// `break`, `break 'label` or `break expr`
     pub struct BreakStmt,
     SK::BreakStmt
 }
+impl BreakStmt {
+    /// Returns the label of the loop to break out of, if any.
+    pub fn label(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Label)
+    }
+
+    /// Returns the value the loop evaluates to.
+    pub fn expr(&self) -> Option<super::Expr> {
+        support::child(self.syntax())
+    }
+}
 
 ast_node! {
     /// `return` or
@@ -160,7 +201,7 @@ mod tests {
 
     use super::*;
     use crate::{
-        ast::{PatKind, TypeKind},
+        ast::{ExprKind, PatKind, TypeKind},
         lexer::Lexer,
         parser::Parser,
     };
@@ -195,6 +236,21 @@ mod tests {
         assert!(matches!(let_stmt.pat().unwrap().kind(), PatKind::Path(_)));
         assert!(let_stmt.type_annotation().is_none());
         assert!(let_stmt.initializer().is_none());
+        assert!(let_stmt.else_block().is_none());
+
+        let let_stmt: LetStmt = parse_stmt("let Some(x) = foo else { return }");
+        assert!(matches!(
+            let_stmt.pat().unwrap().kind(),
+            PatKind::PathTuple(_)
+        ));
+        assert!(matches!(
+            let_stmt.initializer().unwrap().kind(),
+            ExprKind::Path(_)
+        ));
+        assert!(matches!(
+            let_stmt.else_block().unwrap().kind(),
+            ExprKind::Block(_)
+        ));
     }
 
     #[test]
@@ -225,6 +281,46 @@ mod tests {
         assert!(while_stmt.cond().is_some());
         assert!(while_stmt.body().is_some());
         assert_ne!(while_stmt.cond(), while_stmt.body());
+        assert!(while_stmt.pat().is_none());
+
+        let while_stmt: WhileStmt = parse_stmt("while let Some(x) = foo { bar }");
+        assert!(matches!(
+            while_stmt.pat().unwrap().kind(),
+            PatKind::PathTuple(_)
+        ));
+        assert!(while_stmt.cond().is_some());
+        assert!(while_stmt.body().is_some());
+        assert_ne!(while_stmt.cond(), while_stmt.body());
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn labeled_loops() {
+        let for_stmt: ForStmt = parse_stmt("'outer: for x in foo { bar }");
+        assert_eq!(for_stmt.label().unwrap().text(), "'outer");
+        assert!(for_stmt.iterable().is_some());
+        assert!(for_stmt.body().is_some());
+
+        let while_stmt: WhileStmt = parse_stmt("'outer: while true { bar }");
+        assert_eq!(while_stmt.label().unwrap().text(), "'outer");
+        assert!(while_stmt.cond().is_some());
+
+        let break_stmt: BreakStmt = parse_stmt("break 'outer");
+        assert_eq!(break_stmt.label().unwrap().text(), "'outer");
+        assert!(break_stmt.expr().is_none());
+
+        let break_stmt: BreakStmt = parse_stmt("break 'outer x + 1");
+        assert!(break_stmt.label().is_some());
+        assert!(matches!(
+            break_stmt.expr().unwrap().kind(),
+            ExprKind::Bin(_)
+        ));
+
+        let continue_stmt: ContinueStmt = parse_stmt("continue 'outer");
+        assert_eq!(continue_stmt.label().unwrap().text(), "'outer");
+
+        let continue_stmt: ContinueStmt = parse_stmt("continue");
+        assert!(continue_stmt.label().is_none());
     }
 
     #[test]
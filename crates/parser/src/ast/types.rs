@@ -13,6 +13,7 @@ ast_node! {
     | SK::TupleType
     | SK::ArrayType
     | SK::NeverType
+    | SK::QualifiedPathType
 }
 impl Type {
     pub fn kind(&self) -> TypeKind {
@@ -23,6 +24,9 @@ impl Type {
             SK::TupleType => TypeKind::Tuple(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::ArrayType => TypeKind::Array(AstNode::cast(self.syntax().clone()).unwrap()),
             SK::NeverType => TypeKind::Never(AstNode::cast(self.syntax().clone()).unwrap()),
+            SK::QualifiedPathType => {
+                TypeKind::QualifiedPath(AstNode::cast(self.syntax().clone()).unwrap())
+            }
             _ => unreachable!(),
         }
     }
@@ -126,6 +130,32 @@ ast_node! {
     SK::NeverType,
 }
 
+ast_node! {
+    /// A qualified path type.
+    /// `<T as Trait>::Item`
+    pub struct QualifiedPathType,
+    SK::QualifiedPathType,
+}
+impl QualifiedPathType {
+    /// Returns the qualified type.
+    /// `T` in `<T as Trait>::Item`
+    pub fn ty(&self) -> Option<Type> {
+        support::child(self.syntax())
+    }
+
+    /// Returns the trait reference.
+    /// `Trait` in `<T as Trait>::Item`
+    pub fn trait_ref(&self) -> Option<super::TraitRef> {
+        support::child(self.syntax())
+    }
+
+    /// Returns the name of the associated item.
+    /// `Item` in `<T as Trait>::Item`
+    pub fn name(&self) -> Option<SyntaxToken> {
+        support::token(self.syntax(), SK::Ident)
+    }
+}
+
 /// A specific kind of type.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, derive_more::From, derive_more::TryInto)]
 pub enum TypeKind {
@@ -135,6 +165,7 @@ pub enum TypeKind {
     Tuple(TupleType),
     Array(ArrayType),
     Never(NeverType),
+    QualifiedPath(QualifiedPathType),
 }
 
 #[cfg(test)]
@@ -200,6 +231,44 @@ mod tests {
         let _: SelfType = parse_type("Self");
     }
 
+    #[test]
+    #[wasm_bindgen_test]
+    fn self_assoc_type() {
+        let path_ty: PathType = parse_type("Self::Item");
+        let mut segments = path_ty.path().unwrap().segments();
+        assert!(matches!(
+            segments.next().unwrap().kind(),
+            Some(crate::ast::PathSegmentKind::SelfTy(_))
+        ));
+        assert_eq!(segments.next().unwrap().ident().unwrap().text(), "Item");
+    }
+
+    #[test]
+    #[wasm_bindgen_test]
+    fn qualified_path_type() {
+        let qualified_ty: QualifiedPathType = parse_type("<T as Iterator<u8>>::Item");
+
+        assert!(matches!(
+            qualified_ty.ty().unwrap().kind(),
+            TypeKind::Path(_)
+        ));
+        assert_eq!(
+            qualified_ty
+                .trait_ref()
+                .unwrap()
+                .path()
+                .unwrap()
+                .segments()
+                .next()
+                .unwrap()
+                .ident()
+                .unwrap()
+                .text(),
+            "Iterator"
+        );
+        assert_eq!(qualified_ty.name().unwrap().text(), "Item");
+    }
+
     #[test]
     #[wasm_bindgen_test]
     fn tuple_type() {
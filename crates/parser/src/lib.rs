@@ -9,7 +9,7 @@ use smallvec::SmallVec;
 pub use syntax_kind::SyntaxKind;
 pub use syntax_node::{FeLang, GreenNode, NodeOrToken, SyntaxNode, SyntaxToken, TextRange};
 
-use parser::RootScope;
+use parser::{token_stream::TokenStream, RootScope};
 
 pub fn parse_source_file(text: &str) -> (GreenNode, Vec<ParseError>) {
     let lexer = lexer::Lexer::new(text);
@@ -23,6 +23,32 @@ pub fn parse_source_file(text: &str) -> (GreenNode, Vec<ParseError>) {
     (node, errs)
 }
 
+/// Parses the tokens produced by a macro expansion in item position.
+/// The resulting tree has the same shape as the tree of a source file.
+pub fn parse_expanded_items<S: TokenStream>(tokens: S) -> (GreenNode, Vec<ParseError>) {
+    let mut parser = parser::Parser::new(tokens);
+    let checkpoint = parser.enter(RootScope::default(), None);
+
+    let _ = parser.parse(parser::ItemListScope::default());
+
+    parser.leave(checkpoint);
+    parser.finish()
+}
+
+/// Parses the tokens produced by a macro expansion in expression position.
+/// The root of the resulting tree contains a single expression.
+pub fn parse_expanded_expr<S: TokenStream>(tokens: S) -> (GreenNode, Vec<ParseError>) {
+    let mut parser = parser::Parser::new(tokens);
+    let checkpoint = parser.enter(RootScope::default(), None);
+
+    if parser::expr::parse_expr(&mut parser).is_ok() && parser.current_kind().is_some() {
+        let _ = parser.error_and_recover("unexpected tokens after the expression");
+    }
+
+    parser.leave(checkpoint);
+    parser.finish()
+}
+
 /// An parse error which is accumulated in the [`parser::Parser`] while parsing.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ParseError {
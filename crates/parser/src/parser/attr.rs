@@ -92,14 +92,13 @@ impl super::Parse for AttrArgScope {
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
         let expected_err = ExpectedKind::Syntax(SyntaxKind::AttrArg);
 
-        parser.set_scope_recovery_stack(&[SyntaxKind::Ident, SyntaxKind::Colon]);
+        parser.set_scope_recovery_stack(&[SyntaxKind::Ident]);
         if parser.find_and_pop(SyntaxKind::Ident, expected_err)? {
             parser.bump();
         }
-        if parser.find_and_pop(SyntaxKind::Colon, expected_err)? {
-            parser.bump();
-        }
-        if parser.find(SyntaxKind::Ident, expected_err)? {
+
+        // The value is optional, e.g., `#allow(unused_variables)`.
+        if parser.bump_if(SyntaxKind::Colon) && parser.find(SyntaxKind::Ident, expected_err)? {
             parser.bump();
         }
         Ok(())
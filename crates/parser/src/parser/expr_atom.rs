@@ -7,8 +7,9 @@ use super::{
     define_scope,
     expr::{parse_expr, parse_expr_no_struct},
     item::ItemScope,
+    macro_::{is_macro_call_start, MacroCallScope},
     parse_list, parse_pat,
-    stmt::parse_stmt,
+    stmt::{parse_loop_label, parse_stmt},
     token_stream::TokenStream,
     ErrProof, Parser, Recovery,
 };
@@ -21,7 +22,7 @@ use crate::{
 pub(super) fn is_expr_atom_head(kind: SyntaxKind) -> bool {
     use SyntaxKind::*;
     match kind {
-        IfKw | MatchKw | LBrace | LParen | LBracket => true,
+        IfKw | MatchKw | LoopKw | Label | UnsafeKw | LBrace | LParen | LBracket => true,
         kind if lit::is_lit(kind) => true,
         kind if path::is_path_segment(kind) => true,
         _ => false,
@@ -37,12 +38,17 @@ pub(super) fn parse_expr_atom<S: TokenStream>(
     match parser.current_kind() {
         Some(IfKw) => parser.parse_cp(IfExprScope::default(), None),
         Some(MatchKw) => parser.parse_cp(MatchExprScope::default(), None),
+        Some(LoopKw | Label) => parser.parse_cp(LoopExprScope::default(), None),
+        Some(UnsafeKw) => parser.parse_cp(UnsafeExprScope::default(), None),
         Some(LBrace) => parser.parse_cp(BlockExprScope::default(), None),
         Some(LParen) => parser.parse_cp(ParenScope::default(), None),
         Some(LBracket) => parser.parse_cp(ArrayScope::default(), None),
         Some(kind) if lit::is_lit(kind) => Ok(parser
             .parse_cp(LitExprScope::default(), None)
             .unwrap_infallible()),
+        Some(Ident) if is_macro_call_start(parser) => Ok(parser
+            .parse_cp(MacroCallScope::default(), None)
+            .unwrap_infallible()),
         Some(kind) if path::is_path_segment(kind) => {
             parser.parse_cp(PathExprScope::new(allow_record_init), None)
         }
@@ -81,6 +87,7 @@ impl super::Parse for BlockExprScope {
                 .current_kind()
                 .map(SyntaxKind::is_item_head)
                 .unwrap_or_default()
+                && !is_unsafe_block_start(parser)
             {
                 parser.parse(ItemScope::default())?;
                 continue;
@@ -114,7 +121,7 @@ impl super::Parse for IfExprScope {
         parser.bump_expected(SyntaxKind::IfKw);
 
         parser.set_scope_recovery_stack(&[SyntaxKind::LBrace, SyntaxKind::ElseKw]);
-        parse_expr_no_struct(parser)?;
+        parse_cond(parser)?;
 
         if parser.find_and_pop(SyntaxKind::LBrace, ExpectedKind::Body(SyntaxKind::IfExpr))? {
             parser.parse(BlockExprScope::default())?;
@@ -130,6 +137,63 @@ impl super::Parse for IfExprScope {
     }
 }
 
+/// Parses the condition of `if` expressions and `while` statements, which is
+/// either a plain expression or a `let PAT = EXPR` binding.
+pub(super) fn parse_cond<S: TokenStream>(parser: &mut Parser<S>) -> Result<(), Recovery<ErrProof>> {
+    if parser.bump_if(SyntaxKind::LetKw) {
+        parser.with_recovery_tokens(parse_pat, &[SyntaxKind::Eq])?;
+        if parser.find(SyntaxKind::Eq, ExpectedKind::Syntax(SyntaxKind::Eq))? {
+            parser.bump();
+        }
+    }
+
+    parse_expr_no_struct(parser)
+}
+
+define_scope! { LoopExprScope, LoopExpr }
+impl super::Parse for LoopExprScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parse_loop_label(parser)?;
+        if parser.find(SyntaxKind::LoopKw, ExpectedKind::Unspecified)? {
+            parser.bump();
+        }
+
+        if parser.find(SyntaxKind::LBrace, ExpectedKind::Body(SyntaxKind::LoopExpr))? {
+            parser.parse(BlockExprScope::default())?;
+        }
+        Ok(())
+    }
+}
+
+define_scope! { UnsafeExprScope, UnsafeExpr }
+impl super::Parse for UnsafeExprScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.bump_expected(SyntaxKind::UnsafeKw);
+
+        if parser.find(
+            SyntaxKind::LBrace,
+            ExpectedKind::Body(SyntaxKind::UnsafeExpr),
+        )? {
+            parser.parse(BlockExprScope::default())?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns `true` if the parser is at the start of an `unsafe` block, which
+/// is distinguished from an `unsafe` item modifier by the following `{`.
+fn is_unsafe_block_start<S: TokenStream>(parser: &mut Parser<S>) -> bool {
+    parser.current_kind() == Some(SyntaxKind::UnsafeKw)
+        && parser.dry_run(|parser| {
+            parser.bump();
+            parser.current_kind() == Some(SyntaxKind::LBrace)
+        })
+}
+
 define_scope! { MatchExprScope, MatchExpr }
 impl super::Parse for MatchExprScope {
     type Error = Recovery<ErrProof>;
@@ -184,6 +248,10 @@ impl super::Parse for MatchArmScope {
         parser.set_scope_recovery_stack(&[SyntaxKind::FatArrow]);
         parse_pat(parser)?;
 
+        if parser.current_kind() == Some(SyntaxKind::IfKw) {
+            parser.parse(MatchGuardScope::default())?;
+        }
+
         if parser.find_and_pop(SyntaxKind::FatArrow, ExpectedKind::Unspecified)? {
             parser.bump();
         }
@@ -191,6 +259,16 @@ impl super::Parse for MatchArmScope {
     }
 }
 
+define_scope! { MatchGuardScope, MatchGuard }
+impl super::Parse for MatchGuardScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.bump_expected(SyntaxKind::IfKw);
+        parse_expr(parser)
+    }
+}
+
 define_scope! { pub(crate) LitExprScope, LitExpr }
 impl super::Parse for LitExprScope {
     type Error = Infallible;
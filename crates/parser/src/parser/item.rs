@@ -7,8 +7,10 @@ use super::{
     define_scope,
     expr::parse_expr,
     func::FuncDefScope,
-    param::{parse_generic_params_opt, parse_where_clause_opt, TraitRefScope},
+    macro_::{is_macro_call_start, MacroCallScope, MacroDefScope},
+    param::{parse_generic_params_opt, parse_where_clause_opt, TraitRefScope, TypeBoundListScope},
     parse_list,
+    path::PathScope,
     struct_::RecordFieldDefListScope,
     token_stream::{LexicalToken, TokenStream},
     type_::{parse_type, TupleTypeScope},
@@ -35,6 +37,7 @@ define_scope! {
         TypeKw,
         PubKw,
         UnsafeKw,
+        MacroKw,
         DocComment,
         Pound
     )
@@ -101,6 +104,11 @@ impl super::Parse for ItemScope {
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
         use crate::SyntaxKind::*;
 
+        if is_macro_call_start(parser) {
+            parser.parse(MacroCallScope::default()).unwrap_infallible();
+            return Ok(());
+        }
+
         let mut checkpoint = attr::parse_attr_list(parser)?;
         let modifier_scope = ItemModifierScope::default();
         let modifier = match parser.current_kind() {
@@ -114,7 +122,9 @@ impl super::Parse for ItemScope {
 
         if modifier.is_unsafe() && parser.current_kind() != Some(FnKw) {
             parser.error("expected `fn` after `unsafe` keyword");
-        } else if modifier.is_pub() && matches!(parser.current_kind(), Some(ImplKw | ExternKw)) {
+        } else if modifier.is_pub()
+            && matches!(parser.current_kind(), Some(ImplKw | ExternKw | MacroKw))
+        {
             let error_msg = format!(
                 "`pub` can't be used for `{}`",
                 parser.current_token().unwrap().text()
@@ -125,7 +135,7 @@ impl super::Parse for ItemScope {
         parser.expect(
             &[
                 ModKw, FnKw, StructKw, ContractKw, EnumKw, TraitKw, ImplKw, UseKw, ConstKw,
-                ExternKw, TypeKw,
+                ExternKw, TypeKw, MacroKw,
             ],
             Some(ExpectedKind::Syntax(SyntaxKind::Item)),
         )?;
@@ -142,6 +152,7 @@ impl super::Parse for ItemScope {
             Some(ConstKw) => parser.parse_cp(ConstScope::default(), checkpoint),
             Some(ExternKw) => parser.parse_cp(ExternScope::default(), checkpoint),
             Some(TypeKw) => parser.parse_cp(TypeAliasScope::default(), checkpoint),
+            Some(MacroKw) => parser.parse_cp(MacroDefScope::default(), checkpoint),
             _ => unreachable!(),
         }?;
 
@@ -174,6 +185,9 @@ impl super::Parse for ItemModifierScope {
                         );
                     } else {
                         parser.bump();
+                        if kind == SyntaxKind::PubKw {
+                            parse_vis_restriction_opt(parser);
+                        }
                     }
                     modifier_kind = new_kind;
                 }
@@ -184,6 +198,49 @@ impl super::Parse for ItemModifierScope {
     }
 }
 
+/// Parses the restriction of `pub`, e.g., `(ingot)`, if it follows.
+pub(super) fn parse_vis_restriction_opt<S: TokenStream>(parser: &mut Parser<S>) {
+    if parser.current_kind() == Some(SyntaxKind::LParen) {
+        parser
+            .parse(VisRestrictionScope::default())
+            .unwrap_infallible();
+    }
+}
+
+define_scope! { VisRestrictionScope, VisRestriction }
+impl super::Parse for VisRestrictionScope {
+    type Error = Infallible;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.bump_expected(SyntaxKind::LParen);
+
+        match parser.current_kind() {
+            Some(SyntaxKind::IngotKw | SyntaxKind::SuperKw) => parser.bump(),
+            Some(SyntaxKind::InKw) => {
+                parser.bump();
+                if parser.parse(PathScope::default()).is_err() {
+                    parser.error_msg_on_current_token("expected a module path after `in`");
+                }
+            }
+            _ => {
+                parser.error_msg_on_current_token("expected `ingot`, `super` or `in` after `pub(`");
+            }
+        }
+
+        if !parser.bump_if(SyntaxKind::RParen) {
+            parser.add_error(crate::ParseError::expected(
+                &[SyntaxKind::RParen],
+                Some(ExpectedKind::ClosingBracket {
+                    bracket: SyntaxKind::RParen,
+                    parent: SyntaxKind::VisRestriction,
+                }),
+                parser.current_pos,
+            ));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ModifierKind {
     None,
@@ -379,12 +436,12 @@ impl super::Parse for SuperTraitListScope {
     }
 }
 
-define_scope! { TraitItemListScope, TraitItemList, (RBrace, Newline, FnKw) }
+define_scope! { TraitItemListScope, TraitItemList, (RBrace, Newline, FnKw, TypeKw, ConstKw) }
 impl super::Parse for TraitItemListScope {
     type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
-        parse_fn_item_block(parser, false, FuncDefScope::TraitDef)
+        parse_item_block(parser, false, true, true, FuncDefScope::TraitDef)
     }
 }
 
@@ -439,21 +496,21 @@ impl super::Parse for ImplScope {
     }
 }
 
-define_scope! { ImplTraitItemListScope, ImplTraitItemList, (RBrace, FnKw) }
+define_scope! { ImplTraitItemListScope, ImplTraitItemList, (RBrace, FnKw, TypeKw, ConstKw) }
 impl super::Parse for ImplTraitItemListScope {
     type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
-        parse_fn_item_block(parser, false, FuncDefScope::Impl)
+        parse_item_block(parser, false, true, true, FuncDefScope::Impl)
     }
 }
 
-define_scope! { ImplItemListScope, ImplItemList, (RBrace, FnKw) }
+define_scope! { ImplItemListScope, ImplItemList, (RBrace, FnKw, ConstKw) }
 impl super::Parse for ImplItemListScope {
     type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
-        parse_fn_item_block(parser, true, FuncDefScope::Impl)
+        parse_item_block(parser, true, false, true, FuncDefScope::Impl)
     }
 }
 
@@ -467,7 +524,7 @@ impl super::Parse for UseScope {
     }
 }
 
-define_scope! { ConstScope, Const }
+define_scope! { ConstScope { allow_missing_value: bool }, Const }
 impl super::Parse for ConstScope {
     type Error = Recovery<ErrProof>;
 
@@ -489,6 +546,15 @@ impl super::Parse for ConstScope {
             parse_type(parser, None)?;
         }
 
+        if self.allow_missing_value {
+            parser.pop_recovery_stack();
+            if parser.bump_if(SyntaxKind::Eq) {
+                parser.set_newline_as_trivia(true);
+                parse_expr(parser)?;
+            }
+            return Ok(());
+        }
+
         parser.set_newline_as_trivia(true);
         if parser.find_and_pop(SyntaxKind::Eq, ExpectedKind::Unspecified)? {
             parser.bump();
@@ -518,7 +584,7 @@ impl super::Parse for ExternItemListScope {
     type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
-        parse_fn_item_block(parser, true, FuncDefScope::Extern)
+        parse_item_block(parser, true, false, false, FuncDefScope::Extern)
     }
 }
 
@@ -546,14 +612,43 @@ impl super::Parse for TypeAliasScope {
     }
 }
 
-/// Currently, `impl` block, `impl trait` block, `trait` block and `extern`
-/// block only allow `fn` as their items. This function is used to parse the
-/// `fn` item in these blocks. NOTE: This function will be invalidated when
-/// these block have their own allowed items, eg. `trait` block will allow
-/// `type` item.
-fn parse_fn_item_block<S: TokenStream>(
+define_scope! { AssocTypeScope, AssocType }
+impl super::Parse for AssocTypeScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.set_newline_as_trivia(false);
+        parser.bump_expected(SyntaxKind::TypeKw);
+
+        parser.set_scope_recovery_stack(&[SyntaxKind::Ident, SyntaxKind::Colon, SyntaxKind::Eq]);
+        if parser.find_and_pop(SyntaxKind::Ident, ExpectedKind::Name(SyntaxKind::AssocType))? {
+            parser.bump();
+        }
+
+        parser.pop_recovery_stack();
+        if parser.current_kind() == Some(SyntaxKind::Colon) {
+            parser.parse(TypeBoundListScope::new(false))?;
+        }
+
+        parser.pop_recovery_stack();
+        if parser.bump_if(SyntaxKind::Eq) {
+            parse_type(parser, None)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the item list of `impl` block, `impl trait` block, `trait` block
+/// and `extern` block.
+/// All of these blocks allow `fn` items, `trait` and `impl trait` blocks
+/// additionally allow associated `type` items if `allow_assoc_type` is `true`,
+/// and `trait`, `impl trait` and `impl` blocks allow associated `const` items
+/// if `allow_assoc_const` is `true`.
+fn parse_item_block<S: TokenStream>(
     parser: &mut Parser<S>,
     allow_modifier: bool,
+    allow_assoc_type: bool,
+    allow_assoc_const: bool,
     fn_def_scope: FuncDefScope,
 ) -> Result<(), Recovery<ErrProof>> {
     parser.bump_expected(SyntaxKind::LBrace);
@@ -594,8 +689,29 @@ fn parse_fn_item_block<S: TokenStream>(
                 parser.set_newline_as_trivia(false);
                 parser.expect(&[SyntaxKind::Newline, SyntaxKind::RBrace], None)?;
             }
+            Some(SyntaxKind::TypeKw) if allow_assoc_type => {
+                parser.parse_cp(AssocTypeScope::default(), checkpoint)?;
+
+                parser.set_newline_as_trivia(false);
+                parser.expect(&[SyntaxKind::Newline, SyntaxKind::RBrace], None)?;
+            }
+            Some(SyntaxKind::ConstKw) if allow_assoc_const => {
+                // The value of an associated const can be omitted only in a trait,
+                // where it's the default value.
+                let allow_missing_value = matches!(fn_def_scope, FuncDefScope::TraitDef);
+                parser.parse_cp(ConstScope::new(allow_missing_value), checkpoint)?;
+
+                parser.set_newline_as_trivia(false);
+                parser.expect(&[SyntaxKind::Newline, SyntaxKind::RBrace], None)?;
+            }
             _ => {
-                let proof = parser.error_msg_on_current_token("only `fn` is allowed in this block");
+                let msg = match (allow_assoc_type, allow_assoc_const) {
+                    (true, true) => "only `fn`, `type` and `const` are allowed in this block",
+                    (true, false) => "only `fn` and `type` are allowed in this block",
+                    (false, true) => "only `fn` and `const` are allowed in this block",
+                    (false, false) => "only `fn` is allowed in this block",
+                };
+                let proof = parser.error_msg_on_current_token(msg);
                 parser.try_recover().map_err(|r| r.add_err_proof(proof))?;
             }
         }
@@ -0,0 +1,171 @@
+use std::convert::Infallible;
+
+use unwrap_infallible::UnwrapInfallible;
+
+use super::{define_scope, token_stream::TokenStream, ErrProof, Parser, Recovery};
+use crate::{ExpectedKind, ParseError, SyntaxKind};
+
+define_scope! { pub(crate) MacroDefScope, MacroDef }
+impl super::Parse for MacroDefScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.bump_expected(SyntaxKind::MacroKw);
+
+        parser.set_scope_recovery_stack(&[SyntaxKind::Ident, SyntaxKind::LBrace]);
+        if parser.find_and_pop(SyntaxKind::Ident, ExpectedKind::Name(SyntaxKind::MacroDef))? {
+            parser.bump();
+        }
+        if parser.find_and_pop(SyntaxKind::LBrace, ExpectedKind::Body(SyntaxKind::MacroDef))? {
+            parser.parse(MacroRuleListScope::default())?;
+        }
+        Ok(())
+    }
+}
+
+define_scope! { MacroRuleListScope, MacroRuleList, (RBrace, Newline) }
+impl super::Parse for MacroRuleListScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.bump_expected(SyntaxKind::LBrace);
+
+        loop {
+            parser.set_newline_as_trivia(true);
+            if matches!(parser.current_kind(), Some(SyntaxKind::RBrace) | None) {
+                break;
+            }
+
+            parser.parse(MacroRuleScope::default())?;
+
+            parser.set_newline_as_trivia(false);
+            parser.expect(&[SyntaxKind::Newline, SyntaxKind::RBrace], None)?;
+            parser.bump_if(SyntaxKind::Newline);
+        }
+
+        parser.bump_or_recover(SyntaxKind::RBrace, "expected `}` to close the macro rules")
+    }
+}
+
+define_scope! { MacroRuleScope, MacroRule }
+impl super::Parse for MacroRuleScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.set_scope_recovery_stack(&[SyntaxKind::FatArrow]);
+        if !parser.current_kind().is_some_and(is_token_tree_open) {
+            let proof = parser.error_msg_on_current_token(
+                "expected a macro matcher delimited by `(`, `[` or `{`",
+            );
+            parser.try_recover().map_err(|r| r.add_err_proof(proof))?;
+        } else {
+            parser.parse(TokenTreeScope::default()).unwrap_infallible();
+        }
+
+        if parser.find_and_pop(SyntaxKind::FatArrow, ExpectedKind::Unspecified)? {
+            parser.bump();
+        }
+
+        if parser.current_kind().is_some_and(is_token_tree_open) {
+            parser.parse(TokenTreeScope::default()).unwrap_infallible();
+            Ok(())
+        } else {
+            let proof = parser.error_msg_on_current_token(
+                "expected a macro transcriber delimited by `(`, `[` or `{`",
+            );
+            parser.try_recover().map_err(|r| r.add_err_proof(proof))
+        }
+    }
+}
+
+define_scope! { pub(crate) TokenTreeScope, TokenTree }
+impl super::Parse for TokenTreeScope {
+    type Error = Infallible;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        // A token tree is a delimited sequence of arbitrary tokens. Nested
+        // delimited sequences are parsed into nested token trees.
+        let open = parser.current_kind().unwrap();
+        let close = token_tree_close(open).unwrap();
+        parser.bump();
+
+        loop {
+            match parser.current_kind() {
+                Some(kind) if kind == close => {
+                    parser.bump();
+                    break;
+                }
+                Some(kind) if is_token_tree_open(kind) => {
+                    parser.parse(TokenTreeScope::default())?;
+                }
+                Some(kind) if !is_token_tree_close(kind) => {
+                    parser.bump();
+                }
+                Some(_) => {
+                    parser.unexpected_token_error("mismatched closing delimiter".into());
+                }
+                None => {
+                    parser.add_error(ParseError::expected(
+                        &[close],
+                        Some(ExpectedKind::ClosingBracket {
+                            bracket: close,
+                            parent: SyntaxKind::TokenTree,
+                        }),
+                        parser.current_pos,
+                    ));
+                    break;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+define_scope! { pub(crate) MacroCallScope, MacroCall }
+impl super::Parse for MacroCallScope {
+    type Error = Infallible;
+
+    // The caller must ensure that `is_macro_call_start` returns `true`.
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.bump_expected(SyntaxKind::Ident);
+        parser.bump_expected(SyntaxKind::Not);
+        parser.parse(TokenTreeScope::default())
+    }
+}
+
+/// Returns `true` if the parser is at the start of a macro invocation, i.e.,
+/// an identifier directly followed by `!` and a delimited token tree.
+pub(crate) fn is_macro_call_start<S: TokenStream>(parser: &mut Parser<S>) -> bool {
+    if parser.current_kind() != Some(SyntaxKind::Ident) {
+        return false;
+    }
+
+    parser.dry_run(|parser| {
+        let is_newline_trivia = parser.set_newline_as_trivia(false);
+        parser.bump();
+        let is_call = parser.bump_if(SyntaxKind::Not)
+            && parser.current_kind().is_some_and(is_token_tree_open);
+        parser.set_newline_as_trivia(is_newline_trivia);
+        is_call
+    })
+}
+
+fn is_token_tree_open(kind: SyntaxKind) -> bool {
+    token_tree_close(kind).is_some()
+}
+
+fn is_token_tree_close(kind: SyntaxKind) -> bool {
+    matches!(
+        kind,
+        SyntaxKind::RParen | SyntaxKind::RBracket | SyntaxKind::RBrace
+    )
+}
+
+fn token_tree_close(open: SyntaxKind) -> Option<SyntaxKind> {
+    match open {
+        SyntaxKind::LParen => Some(SyntaxKind::RParen),
+        SyntaxKind::LBracket => Some(SyntaxKind::RBracket),
+        SyntaxKind::LBrace => Some(SyntaxKind::RBrace),
+        _ => None,
+    }
+}
@@ -15,6 +15,7 @@ pub mod expr;
 pub mod func;
 pub mod item;
 pub mod lit;
+pub mod macro_;
 pub mod param;
 pub mod pat;
 pub mod path;
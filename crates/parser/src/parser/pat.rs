@@ -19,7 +19,7 @@ pub fn parse_pat<S: TokenStream>(parser: &mut Parser<S>) -> Result<(), Recovery<
     let token = parser.current_token();
     if has_mut {
         match token.as_ref().map(|t| t.syntax_kind()) {
-            Some(Underscore | Dot2 | LParen) => {
+            Some(Underscore | Dot2 | LParen | LBracket) => {
                 parser.error_msg_on_current_token(&format!(
                     "`mut` is not allowed on `{}`",
                     token.unwrap().text()
@@ -45,9 +45,19 @@ pub fn parse_pat<S: TokenStream>(parser: &mut Parser<S>) -> Result<(), Recovery<
             .parse_cp(RestPatScope::default(), Some(checkpoint))
             .unwrap(),
         Some(LParen) => parser.parse_cp(TuplePatScope::default(), Some(checkpoint))?,
-        Some(kind) if is_lit(kind) => parser
-            .parse_cp(LitPatScope::default(), Some(checkpoint))
-            .unwrap(),
+        Some(LBracket) => parser.parse_cp(ArrayPatScope::default(), Some(checkpoint))?,
+        Some(kind) if is_lit(kind) => {
+            parser
+                .parse_cp(LitPatScope::default(), Some(checkpoint))
+                .unwrap();
+            if parser.current_kind() == Some(Dot2Eq) {
+                parser.parse_cp(RangePatScope::default(), Some(checkpoint))?;
+            }
+            checkpoint
+        }
+        Some(Ident) if is_binding_pat(parser) => {
+            parser.parse_cp(BindingPatScope::default(), Some(checkpoint))?
+        }
         _ => parser.parse_cp(PathPatScope::default(), Some(checkpoint))?,
     };
 
@@ -57,6 +67,14 @@ pub fn parse_pat<S: TokenStream>(parser: &mut Parser<S>) -> Result<(), Recovery<
     Ok(())
 }
 
+/// Returns `true` if the current `Ident` is followed by `@`.
+fn is_binding_pat<S: TokenStream>(parser: &mut Parser<S>) -> bool {
+    parser.dry_run(|parser| {
+        parser.bump_expected(SyntaxKind::Ident);
+        parser.current_kind() == Some(SyntaxKind::At)
+    })
+}
+
 define_scope! { WildCardPatScope, WildCardPat, (Pipe) }
 impl super::Parse for WildCardPatScope {
     type Error = Infallible;
@@ -89,6 +107,48 @@ impl super::Parse for LitPatScope {
     }
 }
 
+define_scope! { RangePatScope, RangePat, (Pipe) }
+impl super::Parse for RangePatScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.set_newline_as_trivia(false);
+        parser.bump_expected(SyntaxKind::Dot2Eq);
+        parser.expect(&[SyntaxKind::Int], None)?;
+        if parser.current_kind() == Some(SyntaxKind::Int) {
+            parser.parse(LitPatScope::default()).unwrap();
+        }
+        Ok(())
+    }
+}
+
+define_scope! { BindingPatScope, BindingPat, (Pipe) }
+impl super::Parse for BindingPatScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.set_newline_as_trivia(false);
+        parser.bump_expected(SyntaxKind::Ident);
+        parser.bump_expected(SyntaxKind::At);
+        parse_pat(parser)
+    }
+}
+
+define_scope! { ArrayPatScope, ArrayPat, (RBracket, Comma) }
+impl super::Parse for ArrayPatScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parse_list(
+            parser,
+            false,
+            SyntaxKind::ArrayPat,
+            (SyntaxKind::LBracket, SyntaxKind::RBracket),
+            parse_pat,
+        )
+    }
+}
+
 define_scope! { TuplePatScope, TuplePat }
 impl super::Parse for TuplePatScope {
     type Error = Recovery<ErrProof>;
@@ -7,7 +7,7 @@ use crate::{ExpectedKind, SyntaxKind};
 use super::{
     define_scope,
     expr::{parse_expr, parse_expr_no_struct},
-    expr_atom::BlockExprScope,
+    expr_atom::{parse_cond, BlockExprScope},
     pat::parse_pat,
     token_stream::TokenStream,
     type_::parse_type,
@@ -21,21 +21,47 @@ pub fn parse_stmt<S: TokenStream>(parser: &mut Parser<S>) -> Result<(), Recovery
         Some(LetKw) => parser.parse(LetStmtScope::default()),
         Some(ForKw) => parser.parse(ForStmtScope::default()),
         Some(WhileKw) => parser.parse(WhileStmtScope::default()),
+        Some(Label) => match peek_labeled_kind(parser) {
+            Some(ForKw) => parser.parse(ForStmtScope::default()),
+            Some(WhileKw) => parser.parse(WhileStmtScope::default()),
+            _ => parser.parse(ExprStmtScope::default()),
+        },
         Some(ContinueKw) => {
             parser
                 .parse(ContinueStmtScope::default())
                 .unwrap_infallible();
             Ok(())
         }
-        Some(BreakKw) => {
-            parser.parse(BreakStmtScope::default()).unwrap_infallible();
-            Ok(())
-        }
+        Some(BreakKw) => parser.parse(BreakStmtScope::default()),
         Some(ReturnKw) => parser.parse(ReturnStmtScope::default()),
         _ => parser.parse(ExprStmtScope::default()),
     }
 }
 
+/// Returns the kind of the token that follows `'label:`.
+fn peek_labeled_kind<S: TokenStream>(parser: &mut Parser<S>) -> Option<SyntaxKind> {
+    parser.dry_run(|parser| {
+        parser.bump_expected(SyntaxKind::Label);
+        if parser.bump_if(SyntaxKind::Colon) {
+            parser.current_kind()
+        } else {
+            None
+        }
+    })
+}
+
+/// Parses the optional `'label:` in front of `for`, `while` and `loop`.
+pub(super) fn parse_loop_label<S: TokenStream>(
+    parser: &mut Parser<S>,
+) -> Result<(), Recovery<ErrProof>> {
+    if parser.bump_if(SyntaxKind::Label)
+        && parser.find(SyntaxKind::Colon, ExpectedKind::Unspecified)?
+    {
+        parser.bump();
+    }
+    Ok(())
+}
+
 define_scope! { LetStmtScope, LetStmt }
 impl super::Parse for LetStmtScope {
     type Error = Recovery<ErrProof>;
@@ -52,6 +78,12 @@ impl super::Parse for LetStmtScope {
 
         if parser.bump_if(SyntaxKind::Eq) {
             parse_expr(parser)?;
+
+            if parser.bump_if(SyntaxKind::ElseKw)
+                && parser.find(SyntaxKind::LBrace, ExpectedKind::Body(SyntaxKind::LetStmt))?
+            {
+                parser.parse(BlockExprScope::default())?;
+            }
         }
         Ok(())
     }
@@ -62,6 +94,7 @@ impl super::Parse for ForStmtScope {
     type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parse_loop_label(parser)?;
         parser.bump_expected(SyntaxKind::ForKw);
 
         parser.set_scope_recovery_stack(&[SyntaxKind::InKw, SyntaxKind::Ident, SyntaxKind::LBrace]);
@@ -87,10 +120,11 @@ impl super::Parse for WhileStmtScope {
     type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parse_loop_label(parser)?;
         parser.bump_expected(SyntaxKind::WhileKw);
 
         parser.set_scope_recovery_stack(&[SyntaxKind::LBrace]);
-        parse_expr_no_struct(parser)?;
+        parse_cond(parser)?;
 
         if parser.find_and_pop(
             SyntaxKind::LBrace,
@@ -108,16 +142,27 @@ impl super::Parse for ContinueStmtScope {
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
         parser.bump_expected(SyntaxKind::ContinueKw);
+        parser.set_newline_as_trivia(false);
+        parser.bump_if(SyntaxKind::Label);
         Ok(())
     }
 }
 
 define_scope! { BreakStmtScope, BreakStmt }
 impl super::Parse for BreakStmtScope {
-    type Error = Infallible;
+    type Error = Recovery<ErrProof>;
 
     fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
         parser.bump_expected(SyntaxKind::BreakKw);
+        parser.set_newline_as_trivia(false);
+        parser.bump_if(SyntaxKind::Label);
+
+        if !matches!(
+            parser.current_kind(),
+            None | Some(SyntaxKind::Newline | SyntaxKind::RBrace)
+        ) {
+            parse_expr(parser)?;
+        }
         Ok(())
     }
 }
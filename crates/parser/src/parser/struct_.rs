@@ -4,6 +4,7 @@ use super::{
     attr::parse_attr_list,
     define_scope,
     func::FuncScope,
+    item::parse_vis_restriction_opt,
     param::{parse_generic_params_opt, parse_where_clause_opt},
     parse_list,
     token_stream::TokenStream,
@@ -69,7 +70,9 @@ impl super::Parse for RecordFieldDefScope {
         parser.set_newline_as_trivia(false);
         parse_attr_list(parser)?;
 
-        parser.bump_if(SyntaxKind::PubKw);
+        if parser.bump_if(SyntaxKind::PubKw) {
+            parse_vis_restriction_opt(parser);
+        }
         // Since the Fe-V2 doesn't support method definition in a struct, we add an
         // ad-hoc check for the method definition in a struct to avoid the confusing
         // error message.
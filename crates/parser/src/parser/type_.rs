@@ -3,7 +3,7 @@ use std::convert::Infallible;
 use super::{
     define_scope,
     expr::parse_expr,
-    param::GenericArgListScope,
+    param::{GenericArgListScope, TraitRefScope},
     parse_list,
     path::{is_path_segment, PathScope},
     token_stream::TokenStream,
@@ -17,7 +17,11 @@ pub fn parse_type<S: TokenStream>(
 ) -> Result<Checkpoint, Recovery<ErrProof>> {
     match parser.current_kind() {
         Some(SyntaxKind::Star) => parser.parse_cp(PtrTypeScope::default(), checkpoint),
+        Some(SyntaxKind::SelfTypeKw) if parser.peek_two().1 == Some(SyntaxKind::Colon2) => {
+            parser.parse_cp(PathTypeScope::default(), checkpoint)
+        }
         Some(SyntaxKind::SelfTypeKw) => parser.parse_cp(SelfTypeScope::new(), checkpoint),
+        Some(SyntaxKind::Lt) => parser.parse_cp(QualifiedPathTypeScope::default(), checkpoint),
         Some(SyntaxKind::LParen) => parser.parse_cp(TupleTypeScope::default(), checkpoint),
         Some(SyntaxKind::LBracket) => parser.parse_cp(ArrayTypeScope::default(), checkpoint),
         Some(SyntaxKind::Not) => parser
@@ -29,9 +33,11 @@ pub fn parse_type<S: TokenStream>(
 
 pub(crate) fn is_type_start(kind: SyntaxKind) -> bool {
     match kind {
-        SyntaxKind::Star | SyntaxKind::SelfTypeKw | SyntaxKind::LParen | SyntaxKind::LBracket => {
-            true
-        }
+        SyntaxKind::Star
+        | SyntaxKind::SelfTypeKw
+        | SyntaxKind::LParen
+        | SyntaxKind::LBracket
+        | SyntaxKind::Lt => true,
         kind if is_path_segment(kind) => true,
         _ => false,
     }
@@ -66,6 +72,51 @@ impl super::Parse for PathTypeScope {
     }
 }
 
+define_scope! { QualifiedPathTypeScope, QualifiedPathType }
+impl super::Parse for QualifiedPathTypeScope {
+    type Error = Recovery<ErrProof>;
+
+    fn parse<S: TokenStream>(&mut self, parser: &mut Parser<S>) -> Result<(), Self::Error> {
+        parser.set_newline_as_trivia(false);
+        parser.bump_expected(SyntaxKind::Lt);
+
+        parser.set_scope_recovery_stack(&[
+            SyntaxKind::AsKw,
+            SyntaxKind::Gt,
+            SyntaxKind::Colon2,
+            SyntaxKind::Ident,
+        ]);
+        parse_type(parser, None)?;
+
+        if parser.find_and_pop(SyntaxKind::AsKw, ExpectedKind::Unspecified)? {
+            parser.bump();
+            parser.parse(TraitRefScope::default())?;
+        }
+
+        if parser.find_and_pop(
+            SyntaxKind::Gt,
+            ExpectedKind::ClosingBracket {
+                bracket: SyntaxKind::Gt,
+                parent: SyntaxKind::QualifiedPathType,
+            },
+        )? {
+            parser.bump();
+        }
+
+        if parser.find_and_pop(SyntaxKind::Colon2, ExpectedKind::Unspecified)? {
+            parser.bump();
+        }
+
+        if parser.find_and_pop(
+            SyntaxKind::Ident,
+            ExpectedKind::Name(SyntaxKind::QualifiedPathType),
+        )? {
+            parser.bump();
+        }
+        Ok(())
+    }
+}
+
 define_scope!(pub(super) SelfTypeScope, SelfType);
 impl super::Parse for SelfTypeScope {
     type Error = Recovery<ErrProof>;
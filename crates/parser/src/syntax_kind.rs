@@ -25,6 +25,9 @@ pub enum SyntaxKind {
     /// "MyString"
     #[regex(r#""([^"\\]|\\.)*""#)]
     String,
+    /// `'outer`
+    #[regex("'[a-zA-Z_][a-zA-Z0-9_]*")]
+    Label,
     /// `(`
     #[token("(")]
     LParen,
@@ -58,6 +61,9 @@ pub enum SyntaxKind {
     /// `..`
     #[token("..")]
     Dot2,
+    /// `..=`
+    #[token("..=")]
+    Dot2Eq,
     /// `,`
     #[token(",")]
     Comma,
@@ -73,6 +79,12 @@ pub enum SyntaxKind {
     /// `#`
     #[token("#")]
     Pound,
+    /// `@`
+    #[token("@")]
+    At,
+    /// `$`
+    #[token("$")]
+    Dollar,
     /// `// Comment`
     #[regex(r"//[^\n\r]*")]
     Comment,
@@ -179,6 +191,9 @@ pub enum SyntaxKind {
     /// `while`
     #[token("while")]
     WhileKw,
+    /// `loop`
+    #[token("loop")]
+    LoopKw,
     /// `pub`
     #[token("pub")]
     PubKw,
@@ -226,6 +241,9 @@ pub enum SyntaxKind {
     /// `super`
     #[token("super")]
     SuperKw,
+    /// `macro`
+    #[token("macro")]
+    MacroKw,
 
     /// `<<`
     LShift,
@@ -280,10 +298,14 @@ pub enum SyntaxKind {
     ArrayRepExpr,
     /// `1`
     LitExpr,
-    /// `if x { 1 } else { 2 }`
+    /// `if x { 1 } else { 2 }` or `if let pat = x { 1 } else { 2 }`
     IfExpr,
     /// `match x { pat => { .. } }`
     MatchExpr,
+    /// `loop { .. }` or `'outer: loop { .. }`
+    LoopExpr,
+    /// `unsafe { .. }`
+    UnsafeExpr,
     /// `(1 + 2)`
     ParenExpr,
     /// x = 1
@@ -292,15 +314,15 @@ pub enum SyntaxKind {
     AugAssignExpr,
 
     // Statements. These are non-leaf nodes.
-    /// `let x = 1`
+    /// `let x = 1` or `let pat = x else { .. }`
     LetStmt,
-    /// `for x in y {..}`
+    /// `for x in y {..}` or `'outer: for x in y {..}`
     ForStmt,
-    /// `while expr {..}`
+    /// `while expr {..}` or `while let pat = expr {..}`
     WhileStmt,
-    /// `continue`
+    /// `continue` or `continue 'outer`
     ContinueStmt,
-    /// `break`
+    /// `break`, `break 'outer` or `break value`
     BreakStmt,
 
     /// `return 1`
@@ -331,11 +353,19 @@ pub enum SyntaxKind {
     RecordPatField,
     /// `pat1 | pat2`
     OrPat,
+    /// `0..=9`
+    RangePat,
+    /// `x @ pat`
+    BindingPat,
+    /// `[x, .., y]`
+    ArrayPat,
 
     // MatchArms.
     // `pat => { stmtlist }`
     MatchArm,
     MatchArmList,
+    /// `if cond` in `pat if cond => { stmtlist }`
+    MatchGuard,
 
     // Items. These are non-leaf nodes.
     Item,
@@ -365,7 +395,11 @@ pub enum SyntaxKind {
     ImplTrait,
     /// `{ fn foo() {..} }`
     ImplTraitItemList,
+    /// `type Item: Trait = i32`
+    AssocType,
     /// `const FOO: i32 = 1`
+    /// This is also used for associated consts in `trait`, `impl` and `impl
+    /// trait` blocks, where the value can be omitted in `trait` blocks.
     Const,
     /// `use foo::{Foo as Foo1, bar::Baz}`
     Use,
@@ -384,9 +418,21 @@ pub enum SyntaxKind {
     /// `extern { .. }`
     ExternItemList,
     ItemList,
+    /// `macro foo { ($x:expr) => { $x + 1 } }`
+    MacroDef,
+    /// `{ ($x:expr) => { $x + 1 } }`
+    MacroRuleList,
+    /// `($x:expr) => { $x + 1 }`
+    MacroRule,
+    /// `($x:expr)`, `{ $x + 1 }`
+    TokenTree,
+    /// `foo!(x, y)`
+    MacroCall,
 
     /// `pub unsafe `
     ItemModifier,
+    /// `(ingot)`, `(super)` or `(in foo::bar)` following `pub`
+    VisRestriction,
 
     // Types. These are non-leaf nodes.
     /// `*i32`
@@ -401,6 +447,8 @@ pub enum SyntaxKind {
     ArrayType,
     /// `!`
     NeverType,
+    /// `<T as Trait>::Item`
+    QualifiedPathType,
 
     // Paths. These are non-leaf nodes.
     /// `Segment1::Segment2`
@@ -519,6 +567,7 @@ impl SyntaxKind {
                     | SyntaxKind::ConstKw
                     | SyntaxKind::UseKw
                     | SyntaxKind::ExternKw
+                    | SyntaxKind::MacroKw
             )
     }
 
@@ -528,6 +577,7 @@ impl SyntaxKind {
             SyntaxKind::Ident => "identifier",
             SyntaxKind::Int => "integer",
             SyntaxKind::String => "string literal",
+            SyntaxKind::Label => "label",
             SyntaxKind::LParen => "`(`",
             SyntaxKind::RParen => "`)`",
             SyntaxKind::LBrace => "`{`",
@@ -539,11 +589,14 @@ impl SyntaxKind {
             SyntaxKind::SemiColon => "`;`",
             SyntaxKind::Dot => "`.`",
             SyntaxKind::Dot2 => "`..`",
+            SyntaxKind::Dot2Eq => "`..=`",
             SyntaxKind::Comma => "`,`",
             SyntaxKind::Arrow => "`->`",
             SyntaxKind::FatArrow => "`=>`",
             SyntaxKind::Underscore => "`_`",
             SyntaxKind::Pound => "`#`",
+            SyntaxKind::At => "`@`",
+            SyntaxKind::Dollar => "`$`",
             SyntaxKind::Plus => "`+`",
             SyntaxKind::Minus => "`-`",
             SyntaxKind::Star => "`*`",
@@ -578,6 +631,7 @@ impl SyntaxKind {
             SyntaxKind::InKw => "`in`",
             SyntaxKind::WhereKw => "`where`",
             SyntaxKind::WhileKw => "`while`",
+            SyntaxKind::LoopKw => "`loop`",
             SyntaxKind::PubKw => "`pub`",
             SyntaxKind::ReturnKw => "`return`",
             SyntaxKind::SelfKw => "`self`",
@@ -594,6 +648,7 @@ impl SyntaxKind {
             SyntaxKind::UnsafeKw => "`unsafe`",
             SyntaxKind::IngotKw => "`ingot`",
             SyntaxKind::SuperKw => "`super`",
+            SyntaxKind::MacroKw => "`macro`",
             SyntaxKind::LShift => "`<<`",
             SyntaxKind::RShift => "`>>`",
             SyntaxKind::LtEq => "`<=`",
@@ -635,6 +690,8 @@ impl SyntaxKind {
             SyntaxKind::LitExpr => "literal expression",
             SyntaxKind::IfExpr => "`if` expression",
             SyntaxKind::MatchExpr => "`match` expression",
+            SyntaxKind::LoopExpr => "`loop` expression",
+            SyntaxKind::UnsafeExpr => "`unsafe` block",
             SyntaxKind::ParenExpr => "parenthesized expression",
             SyntaxKind::AssignExpr => "assignment expression",
             SyntaxKind::AugAssignExpr => "augmented assignment expression",
@@ -655,8 +712,12 @@ impl SyntaxKind {
             SyntaxKind::RecordPatFieldList => "record pattern field list",
             SyntaxKind::RecordPatField => "record pattern field",
             SyntaxKind::OrPat => "`or` pattern",
+            SyntaxKind::RangePat => "range pattern",
+            SyntaxKind::BindingPat => "binding pattern",
+            SyntaxKind::ArrayPat => "array pattern",
             SyntaxKind::MatchArm => "`match` arm",
             SyntaxKind::MatchArmList => "`match` arm list",
+            SyntaxKind::MatchGuard => "`match` arm guard",
             SyntaxKind::Item => "item",
             SyntaxKind::Mod => "`mod`",
             SyntaxKind::Func => "function definition",
@@ -671,6 +732,7 @@ impl SyntaxKind {
             SyntaxKind::TraitItemList => "`trait` item list",
             SyntaxKind::ImplTrait => "`impl` trait block",
             SyntaxKind::ImplTraitItemList => "`impl` trait item list",
+            SyntaxKind::AssocType => "associated type",
             SyntaxKind::Const => "const definition",
             SyntaxKind::Use => "`use` statement",
             SyntaxKind::UseTree => "`use` tree",
@@ -681,12 +743,19 @@ impl SyntaxKind {
             SyntaxKind::Extern => "`extern` block",
             SyntaxKind::ExternItemList => "`extern` body",
             SyntaxKind::ItemList => "item list",
+            SyntaxKind::MacroDef => "macro definition",
+            SyntaxKind::MacroRuleList => "macro rule list",
+            SyntaxKind::MacroRule => "macro rule",
+            SyntaxKind::TokenTree => "token tree",
+            SyntaxKind::MacroCall => "macro invocation",
             SyntaxKind::ItemModifier => "item modifier",
+            SyntaxKind::VisRestriction => "visibility restriction",
             SyntaxKind::PtrType => "pointer type",
             SyntaxKind::SelfType => "`Self` type",
             SyntaxKind::TupleType => "tuple type definition",
             SyntaxKind::NeverType => "never type",
             SyntaxKind::ArrayType => "array type definition",
+            SyntaxKind::QualifiedPathType => "qualified path type",
             SyntaxKind::Path => "path",
             SyntaxKind::Attr => "attribute",
             SyntaxKind::AttrArgList => "attribute argument list",
@@ -720,6 +789,7 @@ impl SyntaxKind {
                 | SyntaxKind::Ident
                 | SyntaxKind::Int
                 | SyntaxKind::String
+                | SyntaxKind::Label
                 | SyntaxKind::LParen
                 | SyntaxKind::RParen
                 | SyntaxKind::LBrace
@@ -731,11 +801,14 @@ impl SyntaxKind {
                 | SyntaxKind::SemiColon
                 | SyntaxKind::Dot
                 | SyntaxKind::Dot2
+                | SyntaxKind::Dot2Eq
                 | SyntaxKind::Comma
                 | SyntaxKind::Arrow
                 | SyntaxKind::FatArrow
                 | SyntaxKind::Underscore
                 | SyntaxKind::Pound
+                | SyntaxKind::At
+                | SyntaxKind::Dollar
                 | SyntaxKind::Plus
                 | SyntaxKind::Minus
                 | SyntaxKind::Star
@@ -770,6 +843,7 @@ impl SyntaxKind {
                 | SyntaxKind::InKw
                 | SyntaxKind::WhereKw
                 | SyntaxKind::WhileKw
+                | SyntaxKind::LoopKw
                 | SyntaxKind::PubKw
                 | SyntaxKind::ReturnKw
                 | SyntaxKind::SelfKw
@@ -786,6 +860,7 @@ impl SyntaxKind {
                 | SyntaxKind::UnsafeKw
                 | SyntaxKind::IngotKw
                 | SyntaxKind::SuperKw
+                | SyntaxKind::MacroKw
                 | SyntaxKind::LShift
                 | SyntaxKind::RShift
                 | SyntaxKind::LtEq
@@ -8,6 +8,8 @@ use url::Url;
 use crate::Resolver;
 
 pub mod config;
+pub mod dependency;
+pub mod lock;
 pub mod source_files;
 
 #[derive(Debug)]
@@ -0,0 +1,673 @@
+use std::{collections::HashMap, fmt, fs};
+
+use camino::Utf8PathBuf;
+use common::{
+    config::{BasedDependency, Config as IngotConfig, DependencyLocation, IngotMetadata},
+    ingot::{Version, VersionReq},
+};
+use sha2::{Digest, Sha256};
+use smol_str::SmolStr;
+use url::Url;
+
+use super::{
+    config::{Config, ConfigResolver},
+    lock::{Lockfile, LOCKFILE_NAME},
+    Ingot, IngotResolver,
+};
+use crate::Resolver;
+
+/// The directory of an ingot that vendored dependencies are looked up in.
+///
+/// Vendored sources and the registry share the same layout, where each
+/// version of an ingot lives in `<name>/<version>/`.
+pub const VENDOR_DIR: &str = "vendor";
+
+/// Where a resolved dependency was loaded from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencySource {
+    Path,
+    Vendor,
+    Registry,
+}
+
+/// A dependency ingot that was loaded from the file system.
+#[derive(Debug)]
+pub struct ResolvedDependency {
+    pub url: Url,
+    pub metadata: IngotMetadata,
+    pub source: DependencySource,
+    pub config: Config,
+    pub files: Vec<(Url, String)>,
+}
+
+impl ResolvedDependency {
+    /// Returns the SHA-256 hash of the config and source files of the ingot.
+    pub fn checksum(&self) -> String {
+        let mut files: Vec<_> = self
+            .files
+            .iter()
+            .map(|(url, content)| {
+                let path = self
+                    .url
+                    .make_relative(url)
+                    .unwrap_or_else(|| url.to_string());
+                (path, content)
+            })
+            .collect();
+        files.sort();
+
+        let mut hasher = Sha256::new();
+        hasher.update(self.config.content.as_bytes());
+        for (path, content) in files {
+            hasher.update(path.as_bytes());
+            hasher.update([0]);
+            hasher.update(content.as_bytes());
+            hasher.update([0]);
+        }
+        format!("{:x}", hasher.finalize())
+    }
+}
+
+#[derive(Debug)]
+pub enum Diagnostic {
+    MissingDependency {
+        alias: SmolStr,
+        url: Url,
+    },
+    DependencyCycle(Vec<Url>),
+    NameMismatch {
+        alias: SmolStr,
+        expected: SmolStr,
+        found: Option<SmolStr>,
+    },
+    VersionMismatch {
+        alias: SmolStr,
+        expected: VersionReq,
+        found: Option<Version>,
+    },
+    NoMatchingVersion {
+        alias: SmolStr,
+        name: SmolStr,
+        requirement: VersionReq,
+    },
+    InvalidConfig {
+        url: Url,
+        diagnostics: String,
+    },
+    IngotDiagnostic(Url, super::Diagnostic),
+}
+
+/// Resolves the dependencies of an ingot recursively.
+///
+/// The resolved resource contains every ingot that is transitively depended
+/// on, excluding the root ingot itself. Registry dependencies are looked up in
+/// the vendor directory of the root ingot and then in the registry, and a
+/// single version is selected for each ingot name. Versions recorded in the
+/// `fe.lock` of the root ingot are kept while they satisfy the requirements.
+#[derive(Default)]
+pub struct DependencyResolver {
+    ingot_resolver: IngotResolver,
+    registry: Option<Utf8PathBuf>,
+    vendor: Option<Utf8PathBuf>,
+    lockfile: Lockfile,
+    /// The ingot selected for each ingot name.
+    selected: HashMap<SmolStr, (Url, DependencySource)>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Resolver for DependencyResolver {
+    type Description = Url;
+    type Resource = Vec<ResolvedDependency>;
+    type Error = super::Error;
+    type Diagnostic = Diagnostic;
+
+    fn resolve(&mut self, ingot_url: &Url) -> Result<Vec<ResolvedDependency>, super::Error> {
+        let mut resolved = vec![];
+
+        // Issues with the root config are reported by whoever resolved the root
+        // ingot, so an ingot without a valid config simply has no dependencies.
+        let Ok(config) = ConfigResolver.resolve(ingot_url) else {
+            return Ok(resolved);
+        };
+        let Ok(config) = IngotConfig::parse(&config.content) else {
+            return Ok(resolved);
+        };
+
+        let root = ingot_url
+            .to_file_path()
+            .ok()
+            .and_then(|path| Utf8PathBuf::from_path_buf(path).ok());
+        self.vendor = root.as_ref().map(|root| root.join(VENDOR_DIR));
+        self.lockfile = root
+            .and_then(|root| fs::read_to_string(root.join(LOCKFILE_NAME)).ok())
+            .and_then(|content| Lockfile::parse(&content).ok())
+            .unwrap_or_default();
+        self.selected.clear();
+
+        let mut stack = vec![ingot_url.clone()];
+        self.resolve_dependencies(ingot_url, &config, &mut stack, &mut resolved);
+        Ok(resolved)
+    }
+
+    fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+}
+
+impl DependencyResolver {
+    /// Sets the registry directory that registry dependencies are looked up in.
+    pub fn with_registry(mut self, registry: Utf8PathBuf) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    fn resolve_dependencies(
+        &mut self,
+        base_url: &Url,
+        config: &IngotConfig,
+        stack: &mut Vec<Url>,
+        resolved: &mut Vec<ResolvedDependency>,
+    ) {
+        for dependency in config.based_dependencies(base_url) {
+            let (url, source) = match &dependency.location {
+                DependencyLocation::Path(url) => (url.clone(), DependencySource::Path),
+                DependencyLocation::Registry => match self.select(&dependency) {
+                    Some(selected) => selected,
+                    None => continue,
+                },
+            };
+
+            if let Some(idx) = stack.iter().position(|stacked| *stacked == url) {
+                let mut cycle = stack[idx..].to_vec();
+                cycle.push(url);
+                self.diagnostics.push(Diagnostic::DependencyCycle(cycle));
+                continue;
+            }
+
+            if let Some(existing) = resolved.iter().find(|dep| dep.url == url) {
+                self.check_parameters(&dependency, &existing.metadata);
+                continue;
+            }
+
+            let (config, source_files) = match self.ingot_resolver.resolve(&url) {
+                Ok(Ingot::Folder {
+                    config,
+                    source_files,
+                }) => (config, source_files),
+                Ok(Ingot::SingleFile { .. }) | Err(_) => {
+                    self.diagnostics.push(Diagnostic::MissingDependency {
+                        alias: dependency.alias.clone(),
+                        url,
+                    });
+                    continue;
+                }
+            };
+
+            for diagnostic in self.ingot_resolver.take_diagnostics() {
+                self.diagnostics
+                    .push(Diagnostic::IngotDiagnostic(url.clone(), diagnostic));
+            }
+            let Some(config) = config else {
+                continue;
+            };
+
+            let dependency_config = match IngotConfig::parse(&config.content) {
+                Ok(dependency_config) => dependency_config,
+                Err(error) => {
+                    self.diagnostics.push(Diagnostic::InvalidConfig {
+                        url: config.url.clone(),
+                        diagnostics: format!("  {error}"),
+                    });
+                    continue;
+                }
+            };
+            if let Some(diagnostics) = dependency_config.formatted_diagnostics() {
+                self.diagnostics.push(Diagnostic::InvalidConfig {
+                    url: config.url.clone(),
+                    diagnostics,
+                });
+            }
+            self.check_parameters(&dependency, &dependency_config.metadata);
+
+            let name = dependency_config
+                .metadata
+                .name
+                .clone()
+                .unwrap_or_else(|| dependency.name().clone());
+            self.selected
+                .entry(name)
+                .or_insert_with(|| (url.clone(), source));
+
+            resolved.push(ResolvedDependency {
+                url: url.clone(),
+                metadata: dependency_config.metadata.clone(),
+                source,
+                config,
+                files: source_files
+                    .map(|source_files| source_files.files)
+                    .unwrap_or_default(),
+            });
+
+            stack.push(url.clone());
+            self.resolve_dependencies(&url, &dependency_config, stack, resolved);
+            stack.pop();
+        }
+    }
+
+    /// Selects the location of a registry dependency.
+    ///
+    /// An ingot that was already selected is reused so that only one version of
+    /// each ingot is depended on. Otherwise, the locked version is selected if it
+    /// satisfies the requirement, and the highest version that does if not,
+    /// preferring vendored sources.
+    fn select(&mut self, dependency: &BasedDependency) -> Option<(Url, DependencySource)> {
+        let name = dependency.name();
+        if let Some(selected) = self.selected.get(name) {
+            return Some(selected.clone());
+        }
+        let requirement = dependency.parameters.version.as_ref()?;
+        let locked: Option<Version> = self
+            .lockfile
+            .ingots
+            .iter()
+            .find(|ingot| ingot.name == name.as_str() && !ingot.source.starts_with("path+"))
+            .and_then(|ingot| ingot.version.parse().ok());
+
+        let mut best: Option<(Version, Utf8PathBuf, DependencySource)> = None;
+        let directories = [
+            (self.vendor.as_ref(), DependencySource::Vendor),
+            (self.registry.as_ref(), DependencySource::Registry),
+        ];
+        for (directory, source) in directories {
+            let Some(directory) = directory else {
+                continue;
+            };
+            let Ok(entries) = fs::read_dir(directory.join(name.as_str())) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let Ok(path) = Utf8PathBuf::from_path_buf(entry.path()) else {
+                    continue;
+                };
+                let Some(version) = path
+                    .file_name()
+                    .and_then(|file_name| file_name.parse::<Version>().ok())
+                else {
+                    continue;
+                };
+                let preferred = |best: &Version| match &locked {
+                    Some(locked) if *best == *locked => false,
+                    Some(locked) if version == *locked => true,
+                    _ => version > *best,
+                };
+                if path.is_dir()
+                    && requirement.matches(&version)
+                    && best.as_ref().is_none_or(|(best, ..)| preferred(best))
+                {
+                    best = Some((version, path, source));
+                }
+            }
+        }
+
+        match best {
+            Some((_, path, source)) => {
+                let url = Url::from_directory_path(path).ok()?;
+                self.selected.insert(name.clone(), (url.clone(), source));
+                Some((url, source))
+            }
+            None => {
+                self.diagnostics.push(Diagnostic::NoMatchingVersion {
+                    alias: dependency.alias.clone(),
+                    name: name.clone(),
+                    requirement: requirement.clone(),
+                });
+                None
+            }
+        }
+    }
+
+    /// Checks the name and version of a dependency against the parameters
+    /// given in the dependent's config.
+    fn check_parameters(&mut self, dependency: &BasedDependency, metadata: &IngotMetadata) {
+        let parameters = &dependency.parameters;
+        if let Some(expected) = &parameters.name {
+            if metadata.name.as_ref() != Some(expected) {
+                self.diagnostics.push(Diagnostic::NameMismatch {
+                    alias: dependency.alias.clone(),
+                    expected: expected.clone(),
+                    found: metadata.name.clone(),
+                });
+            }
+        }
+        if let Some(expected) = &parameters.version {
+            if !metadata
+                .version
+                .as_ref()
+                .is_some_and(|version| expected.matches(version))
+            {
+                self.diagnostics.push(Diagnostic::VersionMismatch {
+                    alias: dependency.alias.clone(),
+                    expected: expected.clone(),
+                    found: metadata.version.clone(),
+                });
+            }
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingDependency { alias, url } => {
+                write!(f, "the dependency `{alias}` does not exist at {url}")
+            }
+            Self::DependencyCycle(cycle) => {
+                let cycle = cycle
+                    .iter()
+                    .map(Url::as_str)
+                    .collect::<Vec<_>>()
+                    .join(" -> ");
+                write!(f, "a dependency cycle was detected: {cycle}")
+            }
+            Self::NameMismatch {
+                alias,
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "the dependency `{alias}` is expected to be named `{expected}`, but is named `{found}`"
+            ),
+            Self::NameMismatch {
+                alias,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "the dependency `{alias}` is expected to be named `{expected}`, but has no name"
+            ),
+            Self::VersionMismatch {
+                alias,
+                expected,
+                found: Some(found),
+            } => write!(
+                f,
+                "the dependency `{alias}` requires version `{expected}`, but has version {found}"
+            ),
+            Self::VersionMismatch {
+                alias,
+                expected,
+                found: None,
+            } => write!(
+                f,
+                "the dependency `{alias}` requires version `{expected}`, but has no version"
+            ),
+            Self::NoMatchingVersion {
+                alias,
+                name,
+                requirement,
+            } => write!(
+                f,
+                "no version of `{name}` matching `{requirement}` was found for the dependency `{alias}`"
+            ),
+            Self::InvalidConfig { url, diagnostics } => {
+                write!(f, "there are issues with the dependency config {url}\n{diagnostics}")
+            }
+            Self::IngotDiagnostic(url, diagnostic) => {
+                write!(f, "an error was encountered while resolving {url}: {diagnostic}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ingot::lock::LockedIngot;
+
+    /// A directory of ingots that is removed when the test ends.
+    struct Fixture(Utf8PathBuf);
+
+    impl Fixture {
+        fn new(test: &str) -> Self {
+            let root = Utf8PathBuf::from_path_buf(std::env::temp_dir())
+                .unwrap()
+                .join(format!("fe-dependency-{test}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            Self(root)
+        }
+
+        /// Writes an ingot with the given config to `dir`.
+        fn ingot(&self, dir: &str, config: &str) -> Url {
+            let path = self.0.join(dir);
+            fs::create_dir_all(path.join("src")).unwrap();
+            fs::write(path.join("fe.toml"), config).unwrap();
+            fs::write(path.join("src/lib.fe"), "").unwrap();
+            Url::from_directory_path(path).unwrap()
+        }
+
+        fn resolve(&self, url: &Url) -> (Vec<ResolvedDependency>, Vec<Diagnostic>) {
+            let mut resolver = DependencyResolver::default().with_registry(self.0.join("registry"));
+            let dependencies = resolver.resolve(url).unwrap();
+            (dependencies, resolver.take_diagnostics())
+        }
+
+        /// Writes a version of `name` to the registry, or to the vendor
+        /// directory of `vendor` if given.
+        fn version(&self, name: &str, version: &str, vendor: Option<&str>) -> Url {
+            let dir = match vendor {
+                Some(ingot) => format!("{ingot}/{VENDOR_DIR}/{name}/{version}"),
+                None => format!("registry/{name}/{version}"),
+            };
+            self.ingot(
+                &dir,
+                &format!("[ingot]\nname = \"{name}\"\nversion = \"{version}\"\n"),
+            )
+        }
+    }
+
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn transitive() {
+        let fixture = Fixture::new("transitive");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = \"../bar\"\n",
+        );
+        let bar = fixture.ingot(
+            "bar",
+            "[ingot]\nname = \"bar\"\nversion = \"1.0.0\"\n[dependencies]\nbaz = \"../baz\"\n",
+        );
+        let baz = fixture.ingot("baz", "[ingot]\nname = \"baz\"\nversion = \"1.0.0\"\n");
+
+        let (dependencies, diagnostics) = fixture.resolve(&foo);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        let urls: Vec<_> = dependencies.iter().map(|dep| dep.url.clone()).collect();
+        assert_eq!(urls, vec![bar, baz]);
+        assert!(dependencies
+            .iter()
+            .all(|dep| dep.source == DependencySource::Path));
+    }
+
+    #[test]
+    fn cycle() {
+        let fixture = Fixture::new("cycle");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = \"../bar\"\n",
+        );
+        let bar = fixture.ingot(
+            "bar",
+            "[ingot]\nname = \"bar\"\nversion = \"1.0.0\"\n[dependencies]\nfoo = \"../foo\"\n",
+        );
+
+        let (_, diagnostics) = fixture.resolve(&foo);
+        let expected = vec![foo.clone(), bar, foo];
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [Diagnostic::DependencyCycle(cycle)] if *cycle == expected
+        ));
+    }
+
+    #[test]
+    fn missing_path() {
+        let fixture = Fixture::new("missing");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = \"../bar\"\n",
+        );
+
+        let (dependencies, diagnostics) = fixture.resolve(&foo);
+        assert!(dependencies.is_empty());
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [Diagnostic::MissingDependency { alias, .. }] if alias == "bar"
+        ));
+    }
+
+    #[test]
+    fn name_mismatch() {
+        let fixture = Fixture::new("name");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = { path = \"../bar\", name = \"baz\" }\n",
+        );
+        fixture.ingot("bar", "[ingot]\nname = \"bar\"\nversion = \"1.0.0\"\n");
+
+        let (_, diagnostics) = fixture.resolve(&foo);
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [Diagnostic::NameMismatch { expected, found: Some(found), .. }]
+                if expected == "baz" && found == "bar"
+        ));
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let fixture = Fixture::new("version");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = { path = \"../bar\", version = \"2.0.0\" }\n",
+        );
+        fixture.ingot("bar", "[ingot]\nname = \"bar\"\nversion = \"1.0.0\"\n");
+
+        let (_, diagnostics) = fixture.resolve(&foo);
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [Diagnostic::VersionMismatch { found: Some(found), .. }]
+                if *found == "1.0.0".parse::<Version>().unwrap()
+        ));
+    }
+
+    #[test]
+    fn invalid_config() {
+        let fixture = Fixture::new("config");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = \"../bar\"\nbaz = \"../baz\"\n",
+        );
+        let bar = fixture.ingot("bar", "[ingot]\nname = \"bar\"\n");
+        let baz = fixture.ingot("baz", "[ingot\n");
+
+        let (_, diagnostics) = fixture.resolve(&foo);
+        let urls: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| match diagnostic {
+                Diagnostic::InvalidConfig { url, .. } => url.clone(),
+                diagnostic => panic!("unexpected diagnostic: {diagnostic}"),
+            })
+            .collect();
+        assert_eq!(
+            urls,
+            vec![bar.join("fe.toml").unwrap(), baz.join("fe.toml").unwrap()]
+        );
+    }
+
+    #[test]
+    fn version_selection() {
+        let fixture = Fixture::new("selection");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = { version = \"1\" }\n",
+        );
+        let bar_1_0 = fixture.version("bar", "1.0.0", None);
+        let bar_1_2 = fixture.version("bar", "1.2.0", None);
+        fixture.version("bar", "2.0.0", None);
+
+        // The highest matching version is selected.
+        let (dependencies, diagnostics) = fixture.resolve(&foo);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].url, bar_1_2);
+        assert_eq!(dependencies[0].source, DependencySource::Registry);
+
+        // Vendored sources are preferred over the registry.
+        let vendored = fixture.version("bar", "1.2.0", Some("foo"));
+        let (dependencies, _) = fixture.resolve(&foo);
+        assert_eq!(dependencies[0].url, vendored);
+        assert_eq!(dependencies[0].source, DependencySource::Vendor);
+
+        // The locked version is kept while it satisfies the requirement.
+        let lockfile = Lockfile {
+            ingots: vec![LockedIngot {
+                name: "bar".to_string(),
+                version: "1.0.0".to_string(),
+                source: "registry".to_string(),
+                checksum: String::new(),
+            }],
+        };
+        fs::write(
+            fixture.0.join("foo").join(LOCKFILE_NAME),
+            lockfile.to_string(),
+        )
+        .unwrap();
+        let (dependencies, _) = fixture.resolve(&foo);
+        assert_eq!(dependencies[0].url, bar_1_0);
+
+        // No matching version is an error.
+        let baz = fixture.ingot(
+            "baz",
+            "[ingot]\nname = \"baz\"\nversion = \"0.1.0\"\n[dependencies]\nbar = { version = \"3\" }\n",
+        );
+        let (dependencies, diagnostics) = fixture.resolve(&baz);
+        assert!(dependencies.is_empty());
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [Diagnostic::NoMatchingVersion { name, .. }] if name == "bar"
+        ));
+    }
+
+    #[test]
+    fn conflicting_requirements() {
+        let fixture = Fixture::new("conflict");
+        let foo = fixture.ingot(
+            "foo",
+            "[ingot]\nname = \"foo\"\nversion = \"0.1.0\"\n[dependencies]\nbar = { version = \"1\" }\nbaz = \"../baz\"\n",
+        );
+        fixture.ingot(
+            "baz",
+            "[ingot]\nname = \"baz\"\nversion = \"0.1.0\"\n[dependencies]\nbar = { version = \"2\" }\n",
+        );
+        let bar_1 = fixture.version("bar", "1.0.0", None);
+        fixture.version("bar", "2.0.0", None);
+
+        // Only one version of `bar` is selected, which doesn't satisfy `baz`.
+        let (dependencies, diagnostics) = fixture.resolve(&foo);
+        let bars: Vec<_> = dependencies
+            .iter()
+            .filter(|dep| dep.metadata.name.as_deref() == Some("bar"))
+            .map(|dep| dep.url.clone())
+            .collect();
+        assert_eq!(bars, vec![bar_1]);
+        assert!(matches!(
+            diagnostics.as_slice(),
+            [Diagnostic::VersionMismatch { expected, found: Some(found), .. }]
+                if *expected == "2".parse::<VersionReq>().unwrap()
+                    && *found == "1.0.0".parse::<Version>().unwrap()
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use super::dependency::{DependencySource, ResolvedDependency};
+
+pub const LOCKFILE_NAME: &str = "fe.lock";
+
+const LOCKFILE_HEADER: &str =
+    "# This file is generated by `fe check`. It is not intended for manual editing.\n\n";
+
+/// The exact versions and content hashes of the resolved dependencies of an
+/// ingot, stored in `fe.lock` next to its `fe.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockfile {
+    #[serde(default, rename = "ingot")]
+    pub ingots: Vec<LockedIngot>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct LockedIngot {
+    pub name: String,
+    pub version: String,
+    pub source: String,
+    pub checksum: String,
+}
+
+impl Lockfile {
+    /// Creates the lockfile of the ingot at `root_url` from its resolved
+    /// dependencies.
+    pub fn new(root_url: &Url, dependencies: &[ResolvedDependency]) -> Self {
+        let mut ingots: Vec<_> = dependencies
+            .iter()
+            .map(|dependency| LockedIngot {
+                name: dependency
+                    .metadata
+                    .name
+                    .as_deref()
+                    .unwrap_or_default()
+                    .to_string(),
+                version: dependency
+                    .metadata
+                    .version
+                    .as_ref()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+                source: match dependency.source {
+                    DependencySource::Path => {
+                        let path = root_url
+                            .make_relative(&dependency.url)
+                            .unwrap_or_else(|| dependency.url.to_string());
+                        format!("path+{path}")
+                    }
+                    DependencySource::Vendor => "vendor".to_string(),
+                    DependencySource::Registry => "registry".to_string(),
+                },
+                checksum: dependency.checksum(),
+            })
+            .collect();
+        ingots.sort();
+
+        Self { ingots }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+}
+
+impl fmt::Display for Lockfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let content = toml::to_string(self).map_err(|_| fmt::Error)?;
+        write!(f, "{LOCKFILE_HEADER}{content}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let lockfile = Lockfile {
+            ingots: vec![LockedIngot {
+                name: "bar".to_string(),
+                version: "1.2.0".to_string(),
+                source: "path+../bar/".to_string(),
+                checksum: "0123456789abcdef".to_string(),
+            }],
+        };
+
+        let content = lockfile.to_string();
+        assert!(content.contains("[[ingot]]"));
+        assert_eq!(Lockfile::parse(&content).unwrap(), lockfile);
+    }
+}
@@ -4,13 +4,16 @@ pub use insta as _insta;
 /// A macro to assert that a value matches a snapshot.
 /// If the snapshot does not exist, it will be created in the same directory as
 /// the test file.
+///
+/// An optional suffix is appended to the snapshot name, so that a fixture can
+/// have several snapshots.
 #[macro_export]
 macro_rules! snap_test {
-    ($value:expr, $fixture_path: expr) => {
+    (@assert $value:expr, $fixture_path: expr, $fixture_name: expr) => {
         let mut settings = $crate::_macro_support::_insta::Settings::new();
         let fixture_path = ::std::path::Path::new($fixture_path);
         let fixture_dir = fixture_path.parent().unwrap();
-        let fixture_name = fixture_path.file_stem().unwrap().to_str().unwrap();
+        let fixture_name = $fixture_name;
 
         settings.set_snapshot_path(fixture_dir);
         settings.set_input_file($fixture_path);
@@ -32,4 +35,18 @@ macro_rules! snap_test {
             .unwrap()
         })
     };
+    ($value:expr, $fixture_path: expr) => {
+        let fixture_path = ::std::path::Path::new($fixture_path);
+        let fixture_name = fixture_path.file_stem().unwrap().to_str().unwrap();
+        $crate::snap_test!(@assert $value, $fixture_path, fixture_name);
+    };
+    ($value:expr, $fixture_path: expr, $suffix: expr) => {
+        let fixture_path = ::std::path::Path::new($fixture_path);
+        let fixture_name = format!(
+            "{}_{}",
+            fixture_path.file_stem().unwrap().to_str().unwrap(),
+            $suffix
+        );
+        $crate::snap_test!(@assert $value, $fixture_path, fixture_name.as_str());
+    };
 }
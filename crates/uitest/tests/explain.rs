@@ -0,0 +1,31 @@
+use common::InputDb;
+use dir_test::{dir_test, Fixture};
+use driver::DriverDataBase;
+use url::Url;
+
+/// Checks that every error code emitted for the fixtures is explained by
+/// `fe explain`. That every code the compiler can emit is documented is checked
+/// by the tests of `driver::explain`.
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/fixtures",
+    glob: "**/*.fe"
+)]
+fn explained_codes(fixture: Fixture<&str>) {
+    let mut db = DriverDataBase::default();
+    let file = db.workspace().touch(
+        &mut db,
+        Url::from_file_path(fixture.path()).expect("path should be absolute"),
+        Some(fixture.content().to_string()),
+    );
+
+    let top_mod = db.top_mod(file);
+
+    for diag in db.run_on_top_mod(top_mod).finalize() {
+        let code = diag.error_code.to_string();
+        assert!(
+            driver::explain::explain(&code).is_some(),
+            "`{code}` emitted for {} has no explanation",
+            fixture.path()
+        );
+    }
+}
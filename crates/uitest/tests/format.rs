@@ -0,0 +1,57 @@
+use common::InputDb;
+use dir_test::{dir_test, Fixture};
+use driver::DriverDataBase;
+use test_utils::snap_test;
+use url::Url;
+
+#[cfg(target_arch = "wasm32")]
+use test_utils::url_utils::UrlExt;
+
+#[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/fixtures/format",
+    glob: "*.fe"
+)]
+fn run_format(fixture: Fixture<&str>) {
+    let mut db = DriverDataBase::default();
+    let file = db.workspace().touch(
+        &mut db,
+        Url::from_file_path(fixture.path()).expect("path should be absolute"),
+        Some(fixture.content().to_string()),
+    );
+
+    let top_mod = db.top_mod(file);
+
+    let diags = db.run_on_top_mod(top_mod);
+    let json = diags.format_json(&db);
+    snap_test!(json, fixture.path(), "json");
+    let sarif = diags.format_sarif(&db);
+    snap_test!(sarif, fixture.path(), "sarif");
+}
+
+#[cfg(target_family = "wasm")]
+mod wasm {
+    use super::*;
+    use wasm_bindgen_test::wasm_bindgen_test;
+
+    #[dir_test(
+    dir: "$CARGO_MANIFEST_DIR/fixtures/format",
+    glob: "*.fe",
+    postfix: "wasm"
+    )]
+    #[dir_test_attr(
+        #[wasm_bindgen_test]
+    )]
+    fn run_format(fixture: Fixture<&str>) {
+        let mut db = DriverDataBase::default();
+        let file = db.workspace().touch(
+            &mut db,
+            Url::from_file_path(fixture.path()).unwrap_or_else(|_| Url::parse("file:///").unwrap()),
+            Some(fixture.content().to_string()),
+        );
+
+        let top_mod = db.top_mod(file);
+        let diags = db.run_on_top_mod(top_mod);
+        diags.format_json(&db);
+        diags.format_sarif(&db);
+    }
+}